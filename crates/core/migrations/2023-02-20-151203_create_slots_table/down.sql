@@ -0,0 +1,2 @@
+drop table slots;
+drop type slot_status;
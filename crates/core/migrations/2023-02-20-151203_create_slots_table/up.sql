@@ -0,0 +1,11 @@
+create type slot_status as enum ('Processed', 'Confirmed', 'Rooted', 'Dead');
+
+create table slots (
+  slot bigint primary key,
+  parent bigint,
+  status slot_status not null,
+  updated_at timestamp not null default now()
+);
+
+create index slots_parent_idx on slots (parent);
+create index slots_status_idx on slots (status);
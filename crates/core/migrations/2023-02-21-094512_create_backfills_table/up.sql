@@ -0,0 +1,12 @@
+create table backfills (
+  id uuid primary key default gen_random_uuid(),
+  start_slot bigint not null,
+  end_slot bigint not null,
+  programs text[] not null default '{}',
+  next_slot bigint not null,
+  dispatched bigint not null default 0,
+  created_at timestamp not null default now(),
+  updated_at timestamp not null default now(),
+  completed_at timestamp,
+  constraint backfills_unique_fields unique (start_slot, end_slot, programs)
+);
@@ -0,0 +1,13 @@
+create table dead_letters (
+  id uuid primary key default gen_random_uuid(),
+  queue text not null,
+  message_id text not null,
+  payload jsonb not null,
+  error text not null,
+  attempts integer not null default 1,
+  first_failed_at timestamp not null default now(),
+  last_failed_at timestamp not null default now()
+);
+
+create unique index dead_letters_queue_payload_idx on dead_letters (queue, md5(payload::text));
+create index dead_letters_last_failed_at_idx on dead_letters (last_failed_at);
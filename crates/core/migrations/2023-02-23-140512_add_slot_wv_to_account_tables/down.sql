@@ -0,0 +1,114 @@
+alter table auction_caches
+drop column slot,
+drop column write_version;
+
+alter table auction_datas
+drop column slot,
+drop column write_version;
+
+alter table auction_datas_ext
+drop column slot,
+drop column write_version;
+
+alter table auction_houses
+drop column slot,
+drop column write_version;
+
+alter table bids
+drop column slot,
+drop column write_version;
+
+alter table candy_machines
+drop column slot,
+drop column write_version;
+
+alter table cardinal_paid_claim_approvers
+drop column slot,
+drop column write_version;
+
+alter table cardinal_time_invalidators
+drop column slot,
+drop column write_version;
+
+alter table cardinal_token_managers
+drop column slot,
+drop column write_version;
+
+alter table cardinal_use_invalidators
+drop column slot,
+drop column write_version;
+
+alter table escrows
+drop column slot,
+drop column write_version;
+
+alter table lockers
+drop column slot,
+drop column write_version;
+
+alter table locker_whitelist_entries
+drop column slot,
+drop column write_version;
+
+alter table governors
+drop column slot,
+drop column write_version;
+
+alter table proposals
+drop column slot,
+drop column write_version;
+
+alter table votes
+drop column slot,
+drop column write_version;
+
+alter table stores
+drop column slot,
+drop column write_version;
+
+alter table store_configs
+drop column slot,
+drop column write_version;
+
+alter table whitelisted_creators
+drop column slot,
+drop column write_version;
+
+alter table smart_wallets
+drop column slot,
+drop column write_version;
+
+alter table transactions
+drop column slot,
+drop column write_version;
+
+alter table sub_account_infos
+drop column slot,
+drop column write_version;
+
+alter table instruction_buffers
+drop column slot,
+drop column write_version;
+
+alter table metadatas
+drop column write_version;
+
+alter table editions
+drop column write_version;
+
+alter table master_editions
+drop column write_version;
+
+alter table bonding_changes
+drop column write_version;
+
+alter table current_metadata_owners
+drop column write_version;
+
+alter table candy_machine_collection_pdas
+drop column slot,
+drop column write_version;
+
+alter table proposal_metas
+drop column slot,
+drop column write_version;
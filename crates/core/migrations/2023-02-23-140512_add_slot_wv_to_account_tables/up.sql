@@ -0,0 +1,229 @@
+alter table auction_caches
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table auction_datas
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table auction_datas_ext
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table auction_houses
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table bids
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table candy_machines
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table cardinal_paid_claim_approvers
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table cardinal_time_invalidators
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table cardinal_token_managers
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table cardinal_use_invalidators
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table escrows
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table lockers
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table locker_whitelist_entries
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table governors
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table proposals
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table votes
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table stores
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table store_configs
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table whitelisted_creators
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table smart_wallets
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table transactions
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table sub_account_infos
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table instruction_buffers
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table metadatas
+add column write_version bigint not null default 0;
+
+alter table editions
+add column write_version bigint not null default 0;
+
+alter table master_editions
+add column write_version bigint not null default 0;
+
+alter table bonding_changes
+add column write_version bigint not null default 0;
+
+alter table current_metadata_owners
+add column write_version bigint not null default 0;
+
+alter table candy_machine_collection_pdas
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table proposal_metas
+add column slot          bigint not null default 0,
+add column write_version bigint not null default 0;
+
+alter table auction_caches
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table auction_datas
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table auction_datas_ext
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table auction_houses
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table bids
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table candy_machines
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table cardinal_paid_claim_approvers
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table cardinal_time_invalidators
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table cardinal_token_managers
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table cardinal_use_invalidators
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table escrows
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table lockers
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table locker_whitelist_entries
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table governors
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table proposals
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table votes
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table stores
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table store_configs
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table whitelisted_creators
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table smart_wallets
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table transactions
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table sub_account_infos
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table instruction_buffers
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table metadatas
+alter column write_version drop default;
+
+alter table editions
+alter column write_version drop default;
+
+alter table master_editions
+alter column write_version drop default;
+
+alter table bonding_changes
+alter column write_version drop default;
+
+alter table current_metadata_owners
+alter column write_version drop default;
+
+alter table candy_machine_collection_pdas
+alter column slot          drop default,
+alter column write_version drop default;
+
+alter table proposal_metas
+alter column slot          drop default,
+alter column write_version drop default;
@@ -0,0 +1,3 @@
+drop table metadata_collection_details;
+drop table metadata_uses;
+drop type use_method;
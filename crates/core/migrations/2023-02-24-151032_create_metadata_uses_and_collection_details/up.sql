@@ -0,0 +1,13 @@
+create type use_method as enum ('Burn', 'Multiple', 'Single');
+
+create table metadata_uses (
+  metadata_address varchar(48) primary key,
+  use_method use_method not null,
+  remaining bigint not null,
+  total bigint not null
+);
+
+create table metadata_collection_details (
+  metadata_address varchar(48) primary key,
+  size bigint not null
+);
@@ -0,0 +1,5 @@
+drop table compressed_asset_creators;
+drop table compressed_asset_datas;
+drop table compressed_assets;
+drop table merkle_tree_nodes;
+drop table merkle_trees;
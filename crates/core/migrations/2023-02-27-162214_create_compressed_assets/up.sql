@@ -0,0 +1,59 @@
+create table merkle_trees (
+  address varchar(48) primary key,
+  max_depth integer not null,
+  slot bigint not null,
+  seq bigint not null
+);
+
+create table merkle_tree_nodes (
+  tree varchar(48) not null,
+  node_index bigint not null,
+  hash varchar(48) not null,
+  slot bigint not null,
+  seq bigint not null,
+  primary key (tree, node_index)
+);
+
+create index merkle_tree_nodes_hash_idx on merkle_tree_nodes (hash);
+
+create table compressed_assets (
+  asset_id varchar(48) primary key,
+  tree varchar(48),
+  tree_authority varchar(48),
+  leaf_index bigint,
+  owner varchar(48) not null,
+  delegate varchar(48) not null,
+  data_hash varchar(48) not null,
+  creator_hash varchar(48) not null,
+  burned_at timestamp,
+  redeemed_at timestamp,
+  slot bigint not null
+);
+
+create index compressed_assets_tree_leaf_index_idx on compressed_assets (tree, leaf_index);
+create index compressed_assets_owner_idx on compressed_assets (owner);
+create index compressed_assets_data_hash_idx on compressed_assets (data_hash);
+
+create table compressed_asset_datas (
+  data_hash varchar(48) primary key,
+  name text not null,
+  symbol text not null,
+  uri text not null,
+  seller_fee_basis_points integer not null,
+  primary_sale_happened boolean not null,
+  is_mutable boolean not null,
+  edition_nonce integer,
+  token_standard token_standard,
+  collection_address varchar(48),
+  collection_verified boolean not null,
+  slot bigint not null
+);
+
+create table compressed_asset_creators (
+  data_hash varchar(48) not null,
+  creator_address varchar(48) not null,
+  share integer not null,
+  verified boolean not null,
+  position integer not null,
+  primary key (data_hash, creator_address)
+);
@@ -0,0 +1,21 @@
+create table token_mint_extensions (
+    mint_address varchar(48) primary key,
+    transfer_fee_config_authority varchar(48),
+    withdraw_withheld_authority varchar(48),
+    withheld_amount bigint,
+    transfer_fee_epoch bigint,
+    transfer_fee_basis_points integer,
+    maximum_transfer_fee bigint,
+    non_transferable boolean not null,
+    metadata_pointer_authority varchar(48),
+    metadata_pointer_address varchar(48),
+    slot bigint not null,
+    write_version bigint not null
+);
+
+create index if not exists token_mint_extensions_metadata_pointer_address_idx
+on token_mint_extensions (metadata_pointer_address);
+
+create trigger token_mint_extensions_check_slot_wv
+before update on token_mint_extensions for row
+execute function check_slot_wv();
@@ -0,0 +1,4 @@
+drop table candy_guard_groups;
+drop table candy_guards;
+drop table candy_machine_config_line_settings;
+drop table core_candy_machines;
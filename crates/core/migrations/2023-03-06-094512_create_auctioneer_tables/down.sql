@@ -0,0 +1,2 @@
+drop table auctioneer_listings;
+drop table auctioneer_listing_configs;
@@ -0,0 +1,32 @@
+create table auctioneer_listing_configs (
+    address varchar(48) primary key,
+    highest_bid_amount bigint not null,
+    highest_bid_trade_state varchar(48) not null,
+    start_time timestamp not null,
+    end_time timestamp not null,
+    time_ext_period integer not null,
+    time_ext_delta integer not null,
+    allow_high_bid_cancel boolean not null,
+    reserve_price bigint not null,
+    min_bid_increment bigint not null,
+    bump smallint not null,
+    slot bigint not null,
+    write_version bigint not null
+);
+
+create trigger auctioneer_listing_configs_check_slot_wv
+before update on auctioneer_listing_configs for row
+execute function check_slot_wv();
+
+create table auctioneer_listings (
+    trade_state varchar(48) primary key,
+    listing_config varchar(48) not null,
+    auction_house varchar(48) not null,
+    seller varchar(48) not null,
+    metadata varchar(48) not null,
+    auctioneer_authority varchar(48) not null,
+    slot bigint not null
+);
+
+create index if not exists auctioneer_listings_listing_config_idx
+on auctioneer_listings (listing_config);
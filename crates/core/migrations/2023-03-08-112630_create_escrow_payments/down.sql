@@ -0,0 +1 @@
+drop table escrow_payments;
@@ -0,0 +1,14 @@
+create table escrow_payments (
+    txn_signature text not null,
+    escrow_payment_account varchar(48) not null,
+    amount bigint not null,
+    wallet varchar(48) not null,
+    auction_house varchar(48) not null,
+    marketplace_program varchar(48) not null,
+    created_at timestamp not null,
+    slot bigint not null,
+    primary key (txn_signature, escrow_payment_account, amount)
+);
+
+create index if not exists escrow_payments_wallet_auction_house_idx
+on escrow_payments (wallet, auction_house);
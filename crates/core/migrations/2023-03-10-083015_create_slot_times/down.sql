@@ -0,0 +1 @@
+drop table slot_times;
@@ -0,0 +1,8 @@
+create table slot_times (
+    slot bigint primary key,
+    block_time timestamp not null,
+    estimated boolean not null
+);
+
+create index if not exists slot_times_estimated_idx
+on slot_times (slot) where estimated;
@@ -0,0 +1,2 @@
+drop table program_instructions;
+drop table program_accounts;
@@ -0,0 +1,32 @@
+create table program_accounts (
+    address varchar(48) primary key,
+    program_id varchar(48) not null,
+    account_type text not null,
+    data jsonb not null,
+    slot bigint not null,
+    write_version bigint not null
+);
+
+create trigger program_accounts_check_slot_wv
+before update on program_accounts for row
+execute function check_slot_wv();
+
+create index if not exists program_accounts_program_id_account_type_idx
+on program_accounts (program_id, account_type);
+
+create table program_instructions (
+    id uuid primary key default gen_random_uuid(),
+    program_id varchar(48) not null,
+    instruction_name text not null,
+    txn_signature text not null,
+    accounts jsonb not null,
+    data jsonb not null,
+    created_at timestamp not null,
+    slot bigint not null
+);
+
+create unique index program_instructions_txn_signature_content_idx
+on program_instructions (txn_signature, program_id, md5(accounts::text || data::text));
+
+create index if not exists program_instructions_program_id_instruction_name_idx
+on program_instructions (program_id, instruction_name, slot desc);
@@ -0,0 +1,5 @@
+drop trigger feed_event_wallets_notify on feed_event_wallets;
+drop function notify_feed_event_wallet();
+
+drop trigger marketplace_activities_notify on marketplace_activities;
+drop function notify_marketplace_activity();
@@ -0,0 +1,31 @@
+create or replace function notify_marketplace_activity()
+returns trigger as $$
+begin
+    perform pg_notify('marketplace_activities', json_build_object(
+        'activity_type', new.activity_type,
+        'activity_id', new.activity_id
+    )::text);
+
+    return null;
+end
+$$ language plpgsql;
+
+create trigger marketplace_activities_notify
+after insert on marketplace_activities for each row
+execute function notify_marketplace_activity();
+
+create or replace function notify_feed_event_wallet()
+returns trigger as $$
+begin
+    perform pg_notify('feed_event_wallets', json_build_object(
+        'feed_event_id', new.feed_event_id,
+        'wallet_address', new.wallet_address
+    )::text);
+
+    return null;
+end
+$$ language plpgsql;
+
+create trigger feed_event_wallets_notify
+after insert on feed_event_wallets for each row
+execute function notify_feed_event_wallet();
@@ -0,0 +1,18 @@
+create table api_keys (
+  id                  uuid      primary key default gen_random_uuid(),
+  key_hash            text      not null unique,
+  name                text      not null,
+  requests_per_minute integer   not null,
+  burst               integer   not null,
+  created_at          timestamp not null default now(),
+  revoked_at          timestamp
+);
+
+create table api_key_usage (
+  api_key_id    uuid   not null references api_keys (id) on delete cascade,
+  day           date   not null,
+  request_count bigint not null,
+  total_cost    bigint not null,
+
+  primary key (api_key_id, day)
+);
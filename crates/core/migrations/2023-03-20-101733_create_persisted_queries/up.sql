@@ -0,0 +1,6 @@
+create table persisted_queries (
+  hash         text      primary key,
+  query        text      not null,
+  allow_listed boolean   not null default false,
+  created_at   timestamp not null default now()
+);
@@ -0,0 +1,7 @@
+drop index if exists offers_created_at_idx;
+
+drop index if exists rewards_listings_created_at_idx;
+
+drop index if exists rewards_offers_created_at_idx;
+
+drop index if exists escrow_payments_created_at_idx;
@@ -0,0 +1,7 @@
+create index if not exists offers_created_at_idx on offers (created_at);
+
+create index if not exists rewards_listings_created_at_idx on rewards_listings (created_at);
+
+create index if not exists rewards_offers_created_at_idx on rewards_offers (created_at);
+
+create index if not exists escrow_payments_created_at_idx on escrow_payments (created_at);
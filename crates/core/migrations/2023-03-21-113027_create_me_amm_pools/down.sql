@@ -0,0 +1 @@
+drop table me_amm_pools;
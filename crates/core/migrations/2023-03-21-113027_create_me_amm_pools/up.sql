@@ -0,0 +1,21 @@
+create table me_amm_pools (
+    address varchar(48) primary key,
+    owner varchar(48) not null,
+    cosigner varchar(48) not null,
+    payment_mint varchar(48) not null,
+    spot_price bigint not null,
+    curve_type smallint not null,
+    curve_delta bigint not null,
+    reinvest_fulfill_buy boolean not null,
+    reinvest_fulfill_sell boolean not null,
+    expiry timestamp null,
+    lp_fee_bp integer not null,
+    buyside_creator_royalty_bp integer not null,
+    sellside_asset_amount bigint not null,
+    buyside_payment_amount bigint not null,
+    lp_fee_earned bigint not null,
+    slot bigint not null,
+    write_version bigint not null
+);
+
+create index if not exists me_amm_pools_owner_idx on me_amm_pools (owner);
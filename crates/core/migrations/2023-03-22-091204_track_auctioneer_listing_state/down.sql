@@ -0,0 +1,5 @@
+drop index if exists auctioneer_listings_metadata_idx;
+
+alter table auctioneer_listings
+drop column canceled_at,
+drop column purchase_id;
@@ -0,0 +1,20 @@
+alter table auctioneer_listings
+add column canceled_at timestamp,
+add column purchase_id uuid;
+
+create index if not exists auctioneer_listings_metadata_idx
+on auctioneer_listings (metadata);
+
+-- Auctioneer listings were previously recorded in listings at their reserve
+-- price.  Cancel them first so the floor price triggers drop them, then remove
+-- them and their activities.
+update listings set canceled_at = now()
+where marketplace_program = 'neer8g6yJq2mQM6KbnViEDAD4gr3gRZyMMf4F2p3MEh'
+and canceled_at is null;
+
+delete from marketplace_activities
+where marketplace_program = 'neer8g6yJq2mQM6KbnViEDAD4gr3gRZyMMf4F2p3MEh'
+and activity_type in ('ListingCreated', 'ListingCanceled');
+
+delete from listings
+where marketplace_program = 'neer8g6yJq2mQM6KbnViEDAD4gr3gRZyMMf4F2p3MEh';
@@ -0,0 +1 @@
+alter table compressed_assets drop column seq;
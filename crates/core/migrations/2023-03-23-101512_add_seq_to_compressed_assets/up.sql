@@ -0,0 +1 @@
+alter table compressed_assets add column seq bigint;
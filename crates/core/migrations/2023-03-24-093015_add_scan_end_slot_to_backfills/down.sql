@@ -0,0 +1 @@
+alter table backfills drop column scan_end_slot;
@@ -0,0 +1 @@
+alter table backfills add column scan_end_slot bigint;
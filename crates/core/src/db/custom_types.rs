@@ -670,3 +670,79 @@ impl FromSql<ActivityType, Pg> for ActivityTypeEnum {
         from_bytes(bytes)
     }
 }
+
+/// Geyser slot commitment `slot_status` enum
+#[derive(SqlType, Debug, Clone, Copy)]
+/// Represents database `slot_status` enum
+#[postgres(type_name = "slot_status")]
+pub struct SlotStatus;
+
+/// The commitment status of a tracked slot
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    FromSqlRow,
+    AsExpression,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::Display,
+)]
+#[sql_type = "SlotStatus"]
+pub enum SlotStatusEnum {
+    /// The slot was processed by the validator but may still be skipped
+    Processed,
+    /// The slot was confirmed by a supermajority of the cluster
+    Confirmed,
+    /// The slot was rooted and can no longer be rolled back
+    Rooted,
+    /// The slot was orphaned when a sibling fork was rooted
+    Dead,
+}
+
+impl ToSql<SlotStatus, Pg> for SlotStatusEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        to_bytes(self, out, |_| false)
+    }
+}
+
+impl FromSql<SlotStatus, Pg> for SlotStatusEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        from_bytes(bytes)
+    }
+}
+
+/// A Metaplex NFT use method
+#[derive(SqlType, Debug, Clone, Copy)]
+#[postgres(type_name = "use_method")]
+/// Represents database `use_method` type
+pub struct UseMethod;
+
+/// The way uses of a consumable NFT are spent
+#[derive(
+    Debug, PartialEq, Eq, FromSqlRow, AsExpression, Clone, Copy, strum::EnumString, strum::Display,
+)]
+#[sql_type = "UseMethod"]
+pub enum UseMethodEnum {
+    /// The NFT is burned once its uses are exhausted
+    Burn,
+    /// The NFT can be used multiple times
+    Multiple,
+    /// The NFT can be used once
+    Single,
+}
+
+impl ToSql<UseMethod, Pg> for UseMethodEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        to_bytes(self, out, |_| false)
+    }
+}
+
+impl FromSql<UseMethod, Pg> for UseMethodEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        from_bytes(bytes)
+    }
+}
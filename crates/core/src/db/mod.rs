@@ -4,6 +4,7 @@ pub mod custom_types;
 pub mod models;
 pub mod mutations;
 pub mod queries;
+mod upsert;
 
 #[allow(missing_docs, unused_imports)]
 mod schema;
@@ -32,6 +33,7 @@ pub use diesel_full_text_search::{
     websearch_to_tsquery, TsQuery, TsQueryExtensions, TsVector, TsVectorExtensions,
 };
 pub use sea_query::Order;
+pub use upsert::{IfNewer, UpsertIfNewer};
 
 use crate::prelude::*;
 
@@ -74,6 +76,7 @@ pub enum ConnectionType {
 }
 
 /// Resturn type of [`connect`]
+#[derive(Clone)]
 pub struct ConnectResult {
     /// The database connection pool
     pub pool: Pool,
@@ -5,7 +5,7 @@
 use std::borrow::Cow;
 
 use bigdecimal::BigDecimal;
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use diesel::sql_types::{
     Array, BigInt, Bool, Int4, Int8, Nullable, Numeric, Text, Timestamp, Timestamptz, VarChar,
 };
@@ -18,9 +18,10 @@ use crate::db::custom_types::{
     InstructionExecutionFlags, InstructionExecutionFlagsEnum, ListingEventLifecycle,
     ListingEventLifecycleEnum, MintMaxVoteEnum, OfferEventLifecycle, OfferEventLifecycleEnum,
     OptionVoteResultEnum, PayoutOperationEnum, ProgrammableConfigEnum, ProposalState,
-    ProposalStateEnum, ProposalVoteType, ProposalVoteTypeEnum, TokenStandard, TokenStandardEnum,
-    TransactionExecutionStatusEnum, VoteRecordV2Vote, VoteRecordV2VoteEnum, VoteThresholdEnum,
-    VoteThresholdType, VoteTippingEnum, VoteWeightV1, VoteWeightV1Enum, WhitelistMintMode,
+    ProposalStateEnum, ProposalVoteType, ProposalVoteTypeEnum, SlotStatusEnum, TokenStandard,
+    TokenStandardEnum, TransactionExecutionStatusEnum, UseMethodEnum, VoteRecordV2Vote,
+    VoteRecordV2VoteEnum, VoteThresholdEnum, VoteThresholdType, VoteTippingEnum, VoteWeightV1,
+    VoteWeightV1Enum, WhitelistMintMode,
 };
 /* HPL LISTING REWARDS */
 
@@ -187,6 +188,10 @@ pub struct Bid<'a> {
     pub last_bid_amount: i64,
     /// Whether the bid has been cancelled or redeemed
     pub cancelled: bool,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `editions` table
@@ -202,6 +207,8 @@ pub struct Edition<'a> {
     pub edition: i64,
     /// Solana slot number
     pub slot: Option<i64>,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `listing_metadatas` table.  This is a join on `listings` and
@@ -237,6 +244,10 @@ pub struct AuctionCache<'a> {
     pub vault: Cow<'a, str>,
     /// The manager of the cached auction
     pub auction_manager: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `auction_datas` table
@@ -261,6 +272,10 @@ pub struct AuctionData<'a> {
     pub total_uncancelled_bids: Option<i32>,
     /// The timestamp of the last bid, if applicable and the auction has bids
     pub last_bid_time: Option<NaiveDateTime>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `auction_datas_ext` table
@@ -277,6 +292,10 @@ pub struct AuctionDataExt<'a> {
     pub instant_sale_price: Option<i64>,
     /// The name of the listing
     pub name: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `master_editions` table
@@ -292,6 +311,8 @@ pub struct MasterEdition<'a> {
     pub max_supply: Option<i64>,
     /// Solana slot number
     pub slot: Option<i64>,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `metadata_creators` table.  This is a join on `metadatas` and
@@ -345,6 +366,8 @@ pub struct Metadata<'a> {
     pub slot: Option<i64>,
     /// Timestamp when the NFT was burned
     pub burned_at: Option<NaiveDateTime>,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `metadata_programmable_configs` table
@@ -359,6 +382,141 @@ pub struct MetadataProgrammableConfig<'a> {
     pub rule_set: Option<Cow<'a, str>>,
 }
 
+/// A row in the `metadata_uses` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+#[table_name = "metadata_uses"]
+pub struct MetadataUse<'a> {
+    /// The address of the metadata account
+    pub metadata_address: Cow<'a, str>,
+    /// How uses of this NFT are spent
+    pub use_method: UseMethodEnum,
+    /// The number of uses left
+    pub remaining: i64,
+    /// The total number of uses this NFT was created with
+    pub total: i64,
+}
+
+/// A row in the `metadata_collection_details` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+#[table_name = "metadata_collection_details"]
+pub struct MetadataCollectionDetails<'a> {
+    /// The address of the collection parent's metadata account
+    pub metadata_address: Cow<'a, str>,
+    /// The number of NFTs verified as members of this sized collection
+    pub size: i64,
+}
+
+/// A row in the `compressed_assets` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CompressedAsset<'a> {
+    /// The asset ID of this compressed NFT
+    pub asset_id: Cow<'a, str>,
+    /// The address of the Merkle tree containing this NFT, if known
+    pub tree: Option<Cow<'a, str>>,
+    /// The address of the Bubblegum tree config for the Merkle tree, if known
+    pub tree_authority: Option<Cow<'a, str>>,
+    /// The index of this NFT's leaf in the Merkle tree, if known
+    pub leaf_index: Option<i64>,
+    /// The wallet address of this NFT's owner
+    pub owner: Cow<'a, str>,
+    /// The wallet address of this NFT's delegate
+    pub delegate: Cow<'a, str>,
+    /// The hash of this NFT's metadata
+    pub data_hash: Cow<'a, str>,
+    /// The hash of this NFT's creators
+    pub creator_hash: Cow<'a, str>,
+    /// Timestamp when the NFT was burned
+    pub burned_at: Option<NaiveDateTime>,
+    /// Timestamp when the NFT was redeemed for decompression
+    pub redeemed_at: Option<NaiveDateTime>,
+    /// The slot of the most recent instruction affecting this NFT
+    pub slot: i64,
+    /// The change log sequence number of the most recent change to this NFT's
+    /// leaf, if known
+    pub seq: Option<i64>,
+}
+
+/// A row in the `compressed_asset_datas` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CompressedAssetData<'a> {
+    /// The hash of this metadata, as stored in compressed NFT leaves
+    pub data_hash: Cow<'a, str>,
+    /// The name of this item
+    pub name: Cow<'a, str>,
+    /// The symbol for this item
+    pub symbol: Cow<'a, str>,
+    /// The URI for the off-chain item data
+    pub uri: Cow<'a, str>,
+    /// The royalty percentage of the creator, in basis points (0.01%, values
+    /// range from 0-10,000)
+    pub seller_fee_basis_points: i32,
+    /// True if this item is in the secondary market
+    pub primary_sale_happened: bool,
+    /// True if this item can be changed by the update authority
+    pub is_mutable: bool,
+    /// Metaplex isn't clear about what this is.  Assume reserved.
+    pub edition_nonce: Option<i32>,
+    /// Type of NFT token
+    pub token_standard: Option<TokenStandardEnum>,
+    /// The mint address of this item's collection
+    pub collection_address: Option<Cow<'a, str>>,
+    /// True if this item's collection has been verified
+    pub collection_verified: bool,
+    /// The slot this metadata was first seen at
+    pub slot: i64,
+}
+
+/// A row in the `compressed_asset_creators` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CompressedAssetCreator<'a> {
+    /// The hash of the metadata this creator belongs to
+    pub data_hash: Cow<'a, str>,
+    /// The wallet address of this creator
+    pub creator_address: Cow<'a, str>,
+    /// The share of royalties paid to this creator, in percent
+    pub share: i32,
+    /// True if this creator signed the metadata
+    pub verified: bool,
+    /// The position of this creator in the metadata's creator list
+    pub position: i32,
+}
+
+/// A row in the `merkle_trees` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MerkleTree<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The depth of this tree
+    pub max_depth: i32,
+    /// The slot of the most recent change to this tree
+    pub slot: i64,
+    /// The sequence number of the most recent change to this tree
+    pub seq: i64,
+}
+
+/// A row in the `merkle_tree_nodes` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MerkleTreeNode<'a> {
+    /// The address of the tree containing this node
+    pub tree: Cow<'a, str>,
+    /// The index of this node, where the root has index 1 and the children of
+    /// node `n` have indices `2n` and `2n + 1`
+    pub node_index: i64,
+    /// The hash stored in this node
+    pub hash: Cow<'a, str>,
+    /// The slot of the change that last wrote this node
+    pub slot: i64,
+    /// The sequence number of the change that last wrote this node
+    pub seq: i64,
+}
+
 /// A row in the `storefronts` table
 #[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
 #[diesel(treat_none_as_null = true)]
@@ -457,6 +615,18 @@ pub struct Nft {
     pub token_account_address: String,
 }
 
+/// An [`Nft`] joined with the price its owner has listed it for
+#[derive(Debug, Clone, QueryableByName)]
+pub struct ListedNft {
+    /// The NFT
+    #[diesel(embed)]
+    pub nft: Nft,
+
+    /// The price of the owner's listing of this NFT, if any
+    #[sql_type = "Nullable<Int8>"]
+    pub listing_price: Option<i64>,
+}
+
 /// Union of `listings` and `purchases` for an `NFTActivity`
 #[derive(Debug, Clone, Queryable, QueryableByName)]
 pub struct NftActivity {
@@ -944,6 +1114,10 @@ pub struct StoreConfig<'a> {
     pub address: Cow<'a, str>,
     /// Store settings URI
     pub settings_uri: Option<Cow<'a, str>>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `whitelisted_creators` table
@@ -956,6 +1130,10 @@ pub struct WhitelistedCreator<'a> {
     pub creator_address: Cow<'a, str>,
     /// Whether or not the specified creator is actually whitelisted
     pub activated: bool,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `stores` table
@@ -971,6 +1149,10 @@ pub struct Store<'a> {
     pub public: bool,
     /// The derived address of this store's StoreConfig account
     pub config_address: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `settings_uri_jsons` table
@@ -1036,6 +1218,10 @@ pub struct AuctionHouse<'a> {
 
     /// Auction House fee account address
     pub auction_house_fee_account: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `bid_reciepts` table
@@ -1190,6 +1376,10 @@ pub struct CandyMachine<'a> {
     pub token_mint: Option<Cow<'a, str>>,
     /// Items redeemed
     pub items_redeemed: i64,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `candy_machine_datas` table
@@ -1263,6 +1453,10 @@ pub struct CMCollectionPDA<'a> {
     pub mint: Cow<'a, str>,
     /// CandyMachine account address
     pub candy_machine: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `candy_machine_hidden_settings` table
@@ -1334,6 +1528,152 @@ pub struct CMEndSetting<'a> {
     pub number: i64,
 }
 
+/// A row in the `candy_machine_config_line_settings` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+#[table_name = "candy_machine_config_line_settings"]
+pub struct CMConfigLineSetting<'a> {
+    /// CandyMachine account address
+    pub candy_machine_address: Cow<'a, str>,
+    /// Common name prefix shared by all config lines
+    pub prefix_name: Cow<'a, str>,
+    /// Length of the remaining part of the name stored in each config line
+    pub name_length: i32,
+    /// Common URI prefix shared by all config lines
+    pub prefix_uri: Cow<'a, str>,
+    /// Length of the remaining part of the URI stored in each config line
+    pub uri_length: i32,
+    /// Whether config lines are minted in order rather than at random
+    pub is_sequential: bool,
+}
+
+/// A row in the `core_candy_machines` table, representing a Candy Machine
+/// Core (v3) account
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CoreCandyMachine<'a> {
+    /// CandyMachine account address
+    pub address: Cow<'a, str>,
+    /// Authority permitted to update the candy machine
+    pub authority: Cow<'a, str>,
+    /// Authority permitted to mint from the candy machine, usually a candy
+    /// guard account
+    pub mint_authority: Cow<'a, str>,
+    /// Mint address of the collection NFT minted items are verified into
+    pub collection_mint: Cow<'a, str>,
+    /// Number of items minted so far
+    pub items_redeemed: i64,
+    /// Number of items available to mint
+    pub items_available: i64,
+    /// Symbol of minted NFTs
+    pub symbol: Cow<'a, str>,
+    /// Secondary sales royalty basis points (0-10000)
+    pub seller_fee_basis_points: i32,
+    /// Max supply of each minted NFT's master edition
+    pub max_supply: i64,
+    /// Whether minted NFTs are mutable
+    pub is_mutable: bool,
+    /// Feature flags of the candy machine account
+    pub features: i64,
+    /// The slot number of this account's last known update
+    pub slot: i64,
+    /// The write version of this account's last known update
+    pub write_version: i64,
+}
+
+/// A row in the `candy_guards` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CandyGuard<'a> {
+    /// CandyGuard account address
+    pub address: Cow<'a, str>,
+    /// Base key used to derive the candy guard address
+    pub base: Cow<'a, str>,
+    /// PDA bump seed
+    pub bump: i16,
+    /// Authority permitted to update the candy guard
+    pub authority: Cow<'a, str>,
+    /// The slot number of this account's last known update
+    pub slot: i64,
+    /// The write version of this account's last known update
+    pub write_version: i64,
+}
+
+/// A row in the `candy_guard_groups` table, representing the default guard
+/// set of a candy guard or one of its named groups
+///
+/// Each guard's columns are `None` when the guard is not enabled.
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CandyGuardGroup<'a> {
+    /// CandyGuard account address
+    pub candy_guard_address: Cow<'a, str>,
+    /// Label of the group, or an empty string for the default guard set
+    pub label: Cow<'a, str>,
+    /// Lamports charged for an invalid mint transaction
+    pub bot_tax_lamports: Option<i64>,
+    /// Whether the mint instruction must be the last in its transaction
+    pub bot_tax_last_instruction: Option<bool>,
+    /// Lamports charged per mint
+    pub sol_payment_lamports: Option<i64>,
+    /// Wallet receiving SOL payments
+    pub sol_payment_destination: Option<Cow<'a, str>>,
+    /// Amount of tokens charged per mint
+    pub token_payment_amount: Option<i64>,
+    /// Mint of the tokens charged per mint
+    pub token_payment_mint: Option<Cow<'a, str>>,
+    /// Token account receiving token payments
+    pub token_payment_destination_ata: Option<Cow<'a, str>>,
+    /// Time at which minting starts
+    pub start_date: Option<NaiveDateTime>,
+    /// Key required to sign mint transactions
+    pub third_party_signer_key: Option<Cow<'a, str>>,
+    /// Amount of tokens a minter must hold
+    pub token_gate_amount: Option<i64>,
+    /// Mint of the tokens a minter must hold
+    pub token_gate_mint: Option<Cow<'a, str>>,
+    /// Gatekeeper network a minter must hold a valid token from
+    pub gatekeeper_network: Option<Cow<'a, str>>,
+    /// Whether the gateway token expires after minting
+    pub gatekeeper_expire_on_use: Option<bool>,
+    /// Time at which minting ends
+    pub end_date: Option<NaiveDateTime>,
+    /// Root of the Merkle tree of wallets allowed to mint
+    pub allow_list_merkle_root: Option<Vec<u8>>,
+    /// Identifier of the mint limit counter
+    pub mint_limit_id: Option<i16>,
+    /// Maximum number of mints per wallet
+    pub mint_limit_limit: Option<i32>,
+    /// Collection of the NFT charged per mint
+    pub nft_payment_required_collection: Option<Cow<'a, str>>,
+    /// Wallet receiving NFT payments
+    pub nft_payment_destination: Option<Cow<'a, str>>,
+    /// Number of items after which minting ends
+    pub redeemed_amount_maximum: Option<i64>,
+    /// The only address allowed to mint
+    pub address_gate_address: Option<Cow<'a, str>>,
+    /// Collection of the NFT a minter must hold
+    pub nft_gate_required_collection: Option<Cow<'a, str>>,
+    /// Collection of the NFT burned per mint
+    pub nft_burn_required_collection: Option<Cow<'a, str>>,
+    /// Amount of tokens burned per mint
+    pub token_burn_amount: Option<i64>,
+    /// Mint of the tokens burned per mint
+    pub token_burn_mint: Option<Cow<'a, str>>,
+    /// Lamports frozen in escrow per mint
+    pub freeze_sol_payment_lamports: Option<i64>,
+    /// Wallet receiving frozen SOL payments once thawed
+    pub freeze_sol_payment_destination: Option<Cow<'a, str>>,
+    /// Amount of tokens frozen in escrow per mint
+    pub freeze_token_payment_amount: Option<i64>,
+    /// Mint of the tokens frozen in escrow per mint
+    pub freeze_token_payment_mint: Option<Cow<'a, str>>,
+    /// Token account receiving frozen token payments once thawed
+    pub freeze_token_payment_destination_ata: Option<Cow<'a, str>>,
+    /// Additional programs allowed to be invoked in mint transactions
+    pub program_gate_additional: Option<Vec<String>>,
+}
+
 /// A row in a `mint_stats` query, representing stats for a single token type
 /// identified by its mint
 #[derive(Debug, Clone, QueryableByName)]
@@ -1619,6 +1959,10 @@ pub struct CardinalTokenManager<'a> {
     pub claim_approver: Option<Cow<'a, str>>,
     /// Optional authority that can approve transfers (defaults to self)
     pub transfer_authority: Option<Cow<'a, str>>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `cardinal_token_manager_invalidators` table
@@ -1661,6 +2005,10 @@ pub struct CardinalTimeInvalidator<'a> {
     pub time_invalidator_max_expiration: Option<NaiveDateTime>,
     /// Whether extension can be in partial increments
     pub time_invalidator_disable_partial_extension: Option<bool>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `cardinal_use_invalidators` table
@@ -1692,6 +2040,10 @@ pub struct CardinalUseInvalidator<'a> {
     pub use_invalidator_extension_usages: Option<i64>,
     /// Optional max this can ever be extended until
     pub use_invalidator_max_usages: Option<i64>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `cardinal_token_manager_invalidators` table
@@ -1713,6 +2065,10 @@ pub struct CardinalPaidClaimApprover<'a> {
     pub paid_claim_approver_payment_amount: i64,
     /// Mint that extension is denominated in
     pub paid_claim_approver_payment_mint: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `cardinal_claim_events` table
@@ -1821,6 +2177,10 @@ pub struct Locker<'a> {
     pub locked_supply: i64,
     /// Governor associated with the [Locker].
     pub governor: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `locker_params` table
@@ -1857,6 +2217,10 @@ pub struct LockerWhitelistEntry<'a> {
     pub program_id: Cow<'a, str>,
     /// The account authorized to be the [Escrow::owner] with this CPI.
     pub owner: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// `Tribeca` Locked-Voter program account
@@ -1884,6 +2248,10 @@ pub struct Escrow<'a> {
     /// Account that is authorized to vote on behalf of this [Escrow].
     /// Defaults to the [Escrow::owner].
     pub vote_delegate: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// `Tribeca` Govern program account
@@ -1908,6 +2276,10 @@ pub struct Governor<'a> {
     /// The public key of the `smart_wallet::SmartWallet` account.
     /// This smart wallet executes proposals.
     pub smart_wallet: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `governor_parameters` table
@@ -1964,6 +2336,10 @@ pub struct Proposal<'a> {
     pub queued_at: i64,
     /// If the transaction was queued, this is the associated Goki Smart Wallet transaction.
     pub queued_transaction: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `proposal_instructions` table
@@ -2009,6 +2385,10 @@ pub struct ProposalMeta<'a> {
     pub title: Cow<'a, str>,
     /// Link to a description of the proposal.
     pub description_link: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// `Tribeca` Govern program account
@@ -2028,6 +2408,10 @@ pub struct Vote<'a> {
     pub side: i16,
     /// The number of votes this vote holds.
     pub weight: i64,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `smart_wallets` table
@@ -2050,6 +2434,10 @@ pub struct SmartWallet<'a> {
     pub owner_set_seqno: i64,
     /// Total number of [Transaction]s on this [SmartWallet].
     pub num_transactions: i64,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `smart_wallet_owners` table
@@ -2090,6 +2478,10 @@ pub struct Transaction<'a> {
     pub executor: Cow<'a, str>,
     /// When the transaction was executed. -1 if not executed.
     pub executed_at: i64,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `tx_instructions` table
@@ -2136,6 +2528,10 @@ pub struct SubAccountInfo<'a> {
     pub subaccount_type: i16,
     /// Index of the sub-account.
     pub index: i64,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `instruction_buffers` table
@@ -2155,6 +2551,10 @@ pub struct InstructionBuffer<'a> {
     pub executor: Cow<'a, str>,
     /// Smart wallet the buffer belongs to.
     pub smart_wallet: Cow<'a, str>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in the `ins_buffer_bundles` table
@@ -2211,6 +2611,8 @@ pub struct BondingChange<'a> {
     pub current_reserves_from_bonding: i64,
     /// Current value of the supply_from_bonding field
     pub current_supply_from_bonding: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// An enriched query on bonding changes
@@ -2252,6 +2654,36 @@ pub struct AssociatedTokenAccount<'a> {
     pub write_version: i64,
 }
 
+/// A row in the `token_mint_extensions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct TokenMintExtension<'a> {
+    /// The address of the Token-2022 mint
+    pub mint_address: Cow<'a, str>,
+    /// The authority permitted to modify the transfer fee, if any
+    pub transfer_fee_config_authority: Option<Cow<'a, str>>,
+    /// The authority permitted to withdraw withheld transfer fees, if any
+    pub withdraw_withheld_authority: Option<Cow<'a, str>>,
+    /// The transfer fees withheld on the mint itself
+    pub withheld_amount: Option<i64>,
+    /// The epoch from which the newest transfer fee applies
+    pub transfer_fee_epoch: Option<i64>,
+    /// The newest transfer fee, in basis points of the transferred amount
+    pub transfer_fee_basis_points: Option<i32>,
+    /// The maximum fee charged for a single transfer
+    pub maximum_transfer_fee: Option<i64>,
+    /// Whether tokens of this mint are non-transferable
+    pub non_transferable: bool,
+    /// The authority permitted to change the metadata pointer, if any
+    pub metadata_pointer_authority: Option<Cow<'a, str>>,
+    /// The account holding metadata for this mint, if any
+    pub metadata_pointer_address: Option<Cow<'a, str>>,
+    /// The slot number of this account's last known update
+    pub slot: i64,
+    /// The write version of this account's last known update
+    pub write_version: i64,
+}
+
 /// A row in the `metadata_owners` table
 #[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
 #[diesel(treat_none_as_null = true)]
@@ -2265,6 +2697,8 @@ pub struct CurrentMetadataOwner<'a> {
     /// Solana slot number
     /// The period of time for which each leader ingests transactions and produces a block.
     pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
 }
 
 /// A row in a `metadatas::count_by_store_creator` query, representing stats for
@@ -3670,3 +4104,290 @@ pub struct Activity<'a> {
     pub activity_type: ActivityTypeEnum,
     pub slot: i64,
 }
+
+/// A row in the `slots` table
+#[derive(Debug, Clone, Copy, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct Slot {
+    /// The slot number
+    pub slot: i64,
+    /// The parent of this slot, if known
+    pub parent: Option<i64>,
+    /// The most advanced commitment status observed for this slot
+    pub status: SlotStatusEnum,
+    /// The time this row was last updated
+    pub updated_at: NaiveDateTime,
+}
+
+/// A row in the `backfills` table
+#[derive(Debug, Clone, Queryable)]
+pub struct Backfill {
+    /// Random Uuid primary key
+    pub id: Uuid,
+    /// The first slot of the range to reindex
+    pub start_slot: i64,
+    /// The last slot (inclusive) of the range to reindex
+    pub end_slot: i64,
+    /// Program IDs used to filter the slots in the range, or empty to
+    /// reindex every slot
+    pub programs: Vec<String>,
+    /// The next slot to be dispatched, used to resume an interrupted backfill
+    pub next_slot: i64,
+    /// The number of reindex requests dispatched so far
+    pub dispatched: i64,
+    /// The time this backfill was first started
+    pub created_at: NaiveDateTime,
+    /// The time progress for this backfill was last recorded
+    pub updated_at: NaiveDateTime,
+    /// The time this backfill finished dispatching all its slots
+    pub completed_at: Option<NaiveDateTime>,
+    /// For backfills filtered by program, the last slot (inclusive) whose
+    /// signatures have not been scanned yet.  Slots are dispatched from the
+    /// end of the range backwards, so every matching slot after this one has
+    /// been dispatched.
+    pub scan_end_slot: Option<i64>,
+}
+
+/// A row in the `dead_letters` table
+#[derive(Debug, Clone, Queryable)]
+pub struct DeadLetter {
+    /// Random Uuid primary key
+    pub id: Uuid,
+    /// The kind of queue the message was consumed from, e.g. `geyser`
+    pub queue: String,
+    /// Human-readable identifier of the failed message
+    pub message_id: String,
+    /// The failed message, serialized as JSON
+    pub payload: serde_json::Value,
+    /// The error chain from the most recent failure
+    pub error: String,
+    /// The number of times processing this message has failed
+    pub attempts: i32,
+    /// The time this message first failed to process
+    pub first_failed_at: NaiveDateTime,
+    /// The time this message most recently failed to process
+    pub last_failed_at: NaiveDateTime,
+}
+
+/// A row in the `auctioneer_listing_configs` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct AuctioneerListingConfig<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The amount of the highest bid placed on the auction, or zero if no
+    /// bids have been placed
+    pub highest_bid_amount: i64,
+    /// The trade state of the highest bid placed on the auction
+    pub highest_bid_trade_state: Cow<'a, str>,
+    /// The time the auction starts accepting bids
+    pub start_time: NaiveDateTime,
+    /// The time the auction ends, including any extensions from late bids
+    pub end_time: NaiveDateTime,
+    /// The window before the end of the auction in which a bid extends it
+    pub time_ext_period: i32,
+    /// The number of seconds a late bid extends the auction by
+    pub time_ext_delta: i32,
+    /// Whether the highest bidder may cancel their bid
+    pub allow_high_bid_cancel: bool,
+    /// The minimum price the auction will sell for
+    pub reserve_price: i64,
+    /// The minimum amount a bid must exceed the highest bid by
+    pub min_bid_increment: i64,
+    /// Listing config PDA bump seed
+    pub bump: i16,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write_version
+    pub write_version: i64,
+}
+
+/// A row in the `auctioneer_listings` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct AuctioneerListing<'a> {
+    /// The seller trade state of the listing
+    pub trade_state: Cow<'a, str>,
+    /// The address of the listing config holding the auction settings
+    pub listing_config: Cow<'a, str>,
+    /// Auction house pubkey
+    pub auction_house: Cow<'a, str>,
+    /// Seller wallet address
+    pub seller: Cow<'a, str>,
+    /// Metadata account pubkey
+    pub metadata: Cow<'a, str>,
+    /// The auctioneer authority delegated by the auction house
+    pub auctioneer_authority: Cow<'a, str>,
+    /// Solana slot number
+    pub slot: i64,
+    /// The time the listing was canceled, if it was
+    pub canceled_at: Option<NaiveDateTime>,
+    /// The purchase that settled the auction, if it sold
+    pub purchase_id: Option<Uuid>,
+}
+
+/// A row in the `escrow_payments` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct EscrowPayment<'a> {
+    /// The signature of the transaction moving funds in or out of the escrow
+    pub txn_signature: Cow<'a, str>,
+    /// Escrow payment account pubkey
+    pub escrow_payment_account: Cow<'a, str>,
+    /// The change in the escrow balance, negative for withdrawals and sales
+    pub amount: i64,
+    /// The wallet the escrow belongs to
+    pub wallet: Cow<'a, str>,
+    /// Auction house pubkey
+    pub auction_house: Cow<'a, str>,
+    /// Marketplace program address
+    pub marketplace_program: Cow<'a, str>,
+    /// The time the transaction was processed
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `me_amm_pools` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MeAmmPool<'a> {
+    /// Pool account pubkey
+    pub address: Cow<'a, str>,
+    /// The wallet which created the pool
+    pub owner: Cow<'a, str>,
+    /// The cosigner required to fulfill orders against the pool
+    pub cosigner: Cow<'a, str>,
+    /// The mint of the pool's payment token, or the default pubkey for SOL
+    pub payment_mint: Cow<'a, str>,
+    /// The current price the pool buys or sells at, in lamports
+    pub spot_price: i64,
+    /// The bonding curve of the pool, 0 for linear or 1 for exponential
+    pub curve_type: i16,
+    /// The change in spot price after each fill, in lamports for linear
+    /// curves or basis points for exponential curves
+    pub curve_delta: i64,
+    /// True if assets bought by the pool are relisted
+    pub reinvest_fulfill_buy: bool,
+    /// True if payments received by the pool fund further buys
+    pub reinvest_fulfill_sell: bool,
+    /// The time after which the pool can no longer be filled, if any
+    pub expiry: Option<NaiveDateTime>,
+    /// The fee paid to the pool owner on two-sided pools, in basis points
+    pub lp_fee_bp: i32,
+    /// The creator royalty paid by the pool when buying, in basis points
+    pub buyside_creator_royalty_bp: i32,
+    /// The number of assets the pool has listed for sale
+    pub sellside_asset_amount: i64,
+    /// The escrowed balance available for the pool's bids, in lamports
+    pub buyside_payment_amount: i64,
+    /// The total fees earned by the pool owner, in lamports
+    pub lp_fee_earned: i64,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version
+    pub write_version: i64,
+}
+
+/// A row in the `slot_times` table
+#[derive(Debug, Clone, Copy, Queryable, Insertable, AsChangeset)]
+pub struct SlotTime {
+    /// Solana slot number
+    pub slot: i64,
+    /// The time the block at this slot was produced
+    pub block_time: NaiveDateTime,
+    /// Whether `block_time` is an estimate recorded before the real block
+    /// time was available
+    pub estimated: bool,
+}
+
+/// A row in the `program_accounts` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct ProgramAccount<'a> {
+    /// Account pubkey
+    pub address: Cow<'a, str>,
+    /// The program owning this account
+    pub program_id: Cow<'a, str>,
+    /// The name of the account type in the program's IDL
+    pub account_type: Cow<'a, str>,
+    /// The account data decoded according to the program's IDL
+    pub data: serde_json::Value,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version
+    pub write_version: i64,
+}
+
+/// A row in the `program_instructions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct ProgramInstruction<'a> {
+    /// Random Uuid primary key
+    /// Optional so that it can be generated randomly when other fields are inserted into table
+    #[diesel(deserialize_as = "Uuid")]
+    pub id: Option<Uuid>,
+    /// The program the instruction was sent to
+    pub program_id: Cow<'a, str>,
+    /// The name of the instruction in the program's IDL
+    pub instruction_name: Cow<'a, str>,
+    /// The signature of the transaction containing the instruction
+    pub txn_signature: Cow<'a, str>,
+    /// The instruction accounts, keyed by their names in the program's IDL
+    pub accounts: serde_json::Value,
+    /// The instruction arguments decoded according to the program's IDL
+    pub data: serde_json::Value,
+    /// The time the instruction was processed
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `api_keys` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct ApiKey<'a> {
+    /// Random Uuid primary key
+    /// Optional so that it can be generated randomly when other fields are inserted into table
+    #[diesel(deserialize_as = "Uuid")]
+    pub id: Option<Uuid>,
+    /// Hex-encoded SHA-256 hash of the key sent by clients
+    pub key_hash: Cow<'a, str>,
+    /// A human-readable name for the client the key was issued to
+    pub name: Cow<'a, str>,
+    /// The sustained number of requests allowed per minute
+    pub requests_per_minute: i32,
+    /// The maximum number of requests allowed in a single burst
+    pub burst: i32,
+    /// The time the key was issued
+    pub created_at: NaiveDateTime,
+    /// The time the key was revoked, if it has been
+    pub revoked_at: Option<NaiveDateTime>,
+}
+
+/// A row in the `api_key_usage` table
+#[derive(Debug, Clone, Copy, Queryable, Insertable, AsChangeset)]
+#[table_name = "api_key_usage"]
+pub struct ApiKeyUsage {
+    /// The API key the requests were made with
+    pub api_key_id: Uuid,
+    /// The UTC day the requests were made on
+    pub day: NaiveDate,
+    /// The number of requests made
+    pub request_count: i64,
+    /// The sum of the estimated costs of the requests made
+    pub total_cost: i64,
+}
+
+/// A row in the `persisted_queries` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+pub struct PersistedQuery<'a> {
+    /// Hex-encoded SHA-256 hash of the query
+    pub hash: Cow<'a, str>,
+    /// The GraphQL query document
+    pub query: Cow<'a, str>,
+    /// True if the query may run when only allow-listed queries are accepted
+    pub allow_listed: bool,
+    /// The time the query was registered
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,66 @@
+use crate::{
+    db::{
+        sql_query,
+        sql_types::{Jsonb, Text},
+        PooledConnection,
+    },
+    error::Result,
+    prelude::*,
+};
+
+const UPSERT_DEAD_LETTER: &str = r"
+-- $1: queue kind
+-- $2: message ID
+-- $3: message payload
+-- $4: error chain
+INSERT INTO dead_letters (queue, message_id, payload, error)
+VALUES ($1, $2, $3, $4)
+ON CONFLICT (queue, md5(payload::text)) DO UPDATE SET
+    message_id = excluded.message_id,
+    error = excluded.error,
+    attempts = dead_letters.attempts + 1,
+    last_failed_at = now();
+";
+
+const DELETE_DEAD_LETTER: &str = r"
+-- $1: queue kind
+-- $2: message payload
+DELETE FROM dead_letters
+WHERE queue = $1 AND md5(payload::text) = md5($2::text);
+";
+
+/// Record a failed message, incrementing its attempt count if the same
+/// payload has failed on this queue before
+///
+/// # Errors
+/// This function fails if the `dead_letters` row upsert fails
+pub fn record(
+    db: &PooledConnection,
+    queue: &str,
+    message_id: &str,
+    payload: &serde_json::Value,
+    error: &str,
+) -> Result<()> {
+    sql_query(UPSERT_DEAD_LETTER)
+        .bind::<Text, _>(queue)
+        .bind::<Text, _>(message_id)
+        .bind::<Jsonb, _>(payload)
+        .bind::<Text, _>(error)
+        .execute(db)
+        .context("Failed to upsert dead letter")?;
+
+    Ok(())
+}
+
+/// Remove the recorded failure of a message which has since been processed
+/// successfully, returning the number of rows removed
+///
+/// # Errors
+/// This function fails if the `dead_letters` row delete fails
+pub fn clear(db: &PooledConnection, queue: &str, payload: &serde_json::Value) -> Result<usize> {
+    sql_query(DELETE_DEAD_LETTER)
+        .bind::<Text, _>(queue)
+        .bind::<Jsonb, _>(payload)
+        .execute(db)
+        .context("Failed to delete dead letter")
+}
@@ -2,9 +2,15 @@
 
 /// functions to insert marketplace activity
 pub mod activity;
+/// Persistence of messages which failed to process
+pub mod dead_letter;
 /// Generic listing upsert function which returns listing uuid if upsert is successful
 pub mod listing;
 /// Generic offer upsert function which returns offer uuid if upsert is successful
 pub mod offer;
 /// Generic purchase upsert function which returns purchase uuid if upsert is successful
 pub mod purchase;
+/// Slot status tracking and rollback of rows written in orphaned forks
+pub mod slot;
+/// Slot block times, and correction of rows written with estimated times
+pub mod slot_time;
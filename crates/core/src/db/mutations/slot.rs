@@ -0,0 +1,484 @@
+use crate::{
+    db::{
+        custom_types::{SlotStatus, SlotStatusEnum},
+        delete, sql_query,
+        sql_types::{BigInt, Nullable, Uuid as UuidType},
+        tables::{feed_event_wallets, feed_events, listing_events, offer_events, purchase_events},
+        PooledConnection,
+    },
+    error::Result,
+    prelude::*,
+    uuid::Uuid,
+};
+
+/// Number of slots below the latest root for which slot history is retained
+pub const RETAINED_SLOTS: i64 = 432_000;
+
+#[derive(QueryableByName)]
+struct SlotRow {
+    #[sql_type = "BigInt"]
+    slot: i64,
+}
+
+#[derive(QueryableByName)]
+struct FeedEventRow {
+    #[sql_type = "UuidType"]
+    id: Uuid,
+}
+
+const UPSERT_SLOT: &str = r"
+-- $1: slot
+-- $2: parent
+-- $3: status
+INSERT INTO slots (slot, parent, status, updated_at)
+VALUES ($1, $2, $3, now())
+ON CONFLICT (slot) DO UPDATE SET
+    parent = COALESCE(excluded.parent, slots.parent),
+    status = excluded.status,
+    updated_at = excluded.updated_at
+WHERE slots.status < excluded.status;
+";
+
+const ROOT_SLOT: &str = r"
+-- $1: rooted slot
+WITH RECURSIVE ancestry AS (
+    SELECT slot, parent, status
+        FROM slots
+        WHERE slot = $1
+    UNION ALL
+    SELECT slots.slot, slots.parent, slots.status
+        FROM slots
+        INNER JOIN ancestry ON (slots.slot = ancestry.parent)
+        WHERE ancestry.slot = $1 OR ancestry.status <> 'Rooted'
+),
+rooted AS (
+    UPDATE slots SET status = 'Rooted', updated_at = now()
+        FROM ancestry
+        WHERE slots.slot = ancestry.slot
+        AND slots.status <> 'Rooted'
+        RETURNING slots.slot
+)
+UPDATE slots SET status = 'Dead', updated_at = now()
+    WHERE slot >= (SELECT MIN(slot) FROM ancestry)
+    AND slot < $1
+    AND status IN ('Processed', 'Confirmed')
+    AND slot NOT IN (SELECT slot FROM ancestry)
+    RETURNING slot;
+";
+
+const PRUNE_SLOTS: &str = r"
+-- $1: rooted slot
+-- $2: number of slots to retain
+DELETE FROM slots
+    WHERE slot < $1 - $2
+    AND status IN ('Rooted', 'Dead');
+";
+
+/// Feed events of the listings, offers and purchases reverted by
+/// [`ROLLBACK_SLOT`], guarded by the same checks for activities replayed in a
+/// live slot
+const DEAD_FEED_EVENTS: &str = r"
+-- $1: dead slot
+SELECT listing_events.feed_event_id AS id
+    FROM listing_events
+    INNER JOIN marketplace_activities ON (marketplace_activities.activity_id = listing_events.listing_id)
+    WHERE marketplace_activities.slot = $1
+    AND marketplace_activities.activity_type = 'ListingCreated'
+    AND NOT EXISTS (
+        SELECT 1 FROM marketplace_activities AS live
+            LEFT JOIN slots ON (slots.slot = live.slot)
+            WHERE live.activity_id = listing_events.listing_id
+            AND live.slot <> $1
+            AND slots.status IS DISTINCT FROM 'Dead'
+    )
+UNION
+SELECT listing_events.feed_event_id AS id
+    FROM listing_events
+    INNER JOIN marketplace_activities ON (marketplace_activities.activity_id = listing_events.listing_id)
+    WHERE marketplace_activities.slot = $1
+    AND marketplace_activities.activity_type = 'ListingCanceled'
+    AND listing_events.lifecycle = 'Cancelled'
+    AND NOT EXISTS (
+        SELECT 1 FROM marketplace_activities AS live
+            LEFT JOIN slots ON (slots.slot = live.slot)
+            WHERE live.activity_id = listing_events.listing_id
+            AND live.activity_type = 'ListingCanceled'
+            AND live.slot <> $1
+            AND slots.status IS DISTINCT FROM 'Dead'
+    )
+UNION
+SELECT offer_events.feed_event_id AS id
+    FROM offer_events
+    INNER JOIN marketplace_activities ON (marketplace_activities.activity_id = offer_events.offer_id)
+    WHERE marketplace_activities.slot = $1
+    AND marketplace_activities.activity_type = 'OfferCreated'
+    AND NOT EXISTS (
+        SELECT 1 FROM marketplace_activities AS live
+            LEFT JOIN slots ON (slots.slot = live.slot)
+            WHERE live.activity_id = offer_events.offer_id
+            AND live.slot <> $1
+            AND slots.status IS DISTINCT FROM 'Dead'
+    )
+UNION
+SELECT offer_events.feed_event_id AS id
+    FROM offer_events
+    INNER JOIN marketplace_activities ON (marketplace_activities.activity_id = offer_events.offer_id)
+    WHERE marketplace_activities.slot = $1
+    AND marketplace_activities.activity_type = 'OfferCanceled'
+    AND offer_events.lifecycle = 'Cancelled'
+    AND NOT EXISTS (
+        SELECT 1 FROM marketplace_activities AS live
+            LEFT JOIN slots ON (slots.slot = live.slot)
+            WHERE live.activity_id = offer_events.offer_id
+            AND live.activity_type = 'OfferCanceled'
+            AND live.slot <> $1
+            AND slots.status IS DISTINCT FROM 'Dead'
+    )
+UNION
+SELECT purchase_events.feed_event_id AS id
+    FROM purchase_events
+    INNER JOIN purchases ON (purchases.id = purchase_events.purchase_id)
+    WHERE purchases.slot = $1;
+";
+
+/// Statements reverting the instruction-derived rows written in a dead slot,
+/// in the order they must be executed.  Each binds the dead slot as `$1`.
+///
+/// Instructions from a dead fork are usually replayed on the rooted fork, so
+/// a listing or offer is left alone if it was also written by an activity in
+/// a slot not known to be dead.
+const ROLLBACK_SLOT: &[&str] = &[
+    r"
+    UPDATE listings SET purchase_id = NULL
+        WHERE purchase_id IN (SELECT id FROM purchases WHERE slot = $1);
+    ",
+    r"
+    UPDATE offers SET purchase_id = NULL
+        WHERE purchase_id IN (SELECT id FROM purchases WHERE slot = $1);
+    ",
+    r"
+    DELETE FROM reward_payouts
+        WHERE purchase_id IN (SELECT id FROM purchases WHERE slot = $1);
+    ",
+    r"
+    DELETE FROM purchases WHERE slot = $1;
+    ",
+    r"
+    UPDATE listings SET canceled_at = NULL
+        WHERE id IN (
+            SELECT activity_id FROM marketplace_activities
+                WHERE slot = $1 AND activity_type = 'ListingCanceled'
+        )
+        AND NOT EXISTS (
+            SELECT 1 FROM marketplace_activities AS live
+                LEFT JOIN slots ON (slots.slot = live.slot)
+                WHERE live.activity_id = listings.id
+                AND live.activity_type = 'ListingCanceled'
+                AND live.slot <> $1
+                AND slots.status IS DISTINCT FROM 'Dead'
+        );
+    ",
+    r"
+    DELETE FROM listings
+        WHERE id IN (
+            SELECT activity_id FROM marketplace_activities
+                WHERE slot = $1 AND activity_type = 'ListingCreated'
+        )
+        AND NOT EXISTS (
+            SELECT 1 FROM marketplace_activities AS live
+                LEFT JOIN slots ON (slots.slot = live.slot)
+                WHERE live.activity_id = listings.id
+                AND live.slot <> $1
+                AND slots.status IS DISTINCT FROM 'Dead'
+        );
+    ",
+    r"
+    UPDATE offers SET canceled_at = NULL
+        WHERE id IN (
+            SELECT activity_id FROM marketplace_activities
+                WHERE slot = $1 AND activity_type = 'OfferCanceled'
+        )
+        AND NOT EXISTS (
+            SELECT 1 FROM marketplace_activities AS live
+                LEFT JOIN slots ON (slots.slot = live.slot)
+                WHERE live.activity_id = offers.id
+                AND live.activity_type = 'OfferCanceled'
+                AND live.slot <> $1
+                AND slots.status IS DISTINCT FROM 'Dead'
+        );
+    ",
+    r"
+    DELETE FROM offers
+        WHERE id IN (
+            SELECT activity_id FROM marketplace_activities
+                WHERE slot = $1 AND activity_type = 'OfferCreated'
+        )
+        AND NOT EXISTS (
+            SELECT 1 FROM marketplace_activities AS live
+                LEFT JOIN slots ON (slots.slot = live.slot)
+                WHERE live.activity_id = offers.id
+                AND live.slot <> $1
+                AND slots.status IS DISTINCT FROM 'Dead'
+        );
+    ",
+    r"
+    DELETE FROM marketplace_activities WHERE slot = $1;
+    ",
+];
+
+/// Record a slot status update, keeping the most advanced status seen for
+/// the slot
+///
+/// # Errors
+/// This function fails if the `slots` row upsert fails
+pub fn upsert(
+    db: &PooledConnection,
+    slot: i64,
+    parent: Option<i64>,
+    status: SlotStatusEnum,
+) -> Result<()> {
+    sql_query(UPSERT_SLOT)
+        .bind::<BigInt, _>(slot)
+        .bind::<Nullable<BigInt>, _>(parent)
+        .bind::<SlotStatus, _>(status)
+        .execute(db)
+        .context("Failed to upsert slot")?;
+
+    Ok(())
+}
+
+/// Mark a slot and its known ancestry as rooted, returning the slots which
+/// were orphaned by the new root and have been marked as dead.
+///
+/// Slots are only considered orphaned if they lie between the oldest known
+/// ancestor of the root and the root itself, so gaps in the recorded slot
+/// history never cause live slots to be discarded.
+///
+/// # Errors
+/// This function fails if the underlying queries fail
+pub fn root(db: &PooledConnection, slot: i64) -> Result<Vec<i64>> {
+    let dead = sql_query(ROOT_SLOT)
+        .bind::<BigInt, _>(slot)
+        .load::<SlotRow>(db)
+        .context("Failed to root slot")?;
+
+    sql_query(PRUNE_SLOTS)
+        .bind::<BigInt, _>(slot)
+        .bind::<BigInt, _>(RETAINED_SLOTS)
+        .execute(db)
+        .context("Failed to prune slot history")?;
+
+    Ok(dead.into_iter().map(|SlotRow { slot }| slot).collect())
+}
+
+/// Revert the listings, offers, purchases and associated feed events and
+/// marketplace activities written by instructions in a dead slot.
+///
+/// This should be called inside a transaction.
+///
+/// # Errors
+/// This function fails if any of the underlying queries fail
+pub fn rollback(db: &PooledConnection, slot: i64) -> Result<()> {
+    let feed_events = sql_query(DEAD_FEED_EVENTS)
+        .bind::<BigInt, _>(slot)
+        .load::<FeedEventRow>(db)
+        .context("Failed to load feed events for dead slot")?
+        .into_iter()
+        .map(|FeedEventRow { id }| id)
+        .collect::<Vec<_>>();
+
+    if !feed_events.is_empty() {
+        delete_feed_events(db, &feed_events)?;
+    }
+
+    for stmt in ROLLBACK_SLOT {
+        sql_query(*stmt)
+            .bind::<BigInt, _>(slot)
+            .execute(db)
+            .context("Failed to revert rows for dead slot")?;
+    }
+
+    Ok(())
+}
+
+fn delete_feed_events(db: &PooledConnection, ids: &[Uuid]) -> Result<()> {
+    delete(feed_event_wallets::table.filter(feed_event_wallets::feed_event_id.eq_any(ids)))
+        .execute(db)?;
+    delete(listing_events::table.filter(listing_events::feed_event_id.eq_any(ids))).execute(db)?;
+    delete(offer_events::table.filter(offer_events::feed_event_id.eq_any(ids))).execute(db)?;
+    delete(purchase_events::table.filter(purchase_events::feed_event_id.eq_any(ids)))
+        .execute(db)?;
+    delete(feed_events::table.filter(feed_events::id.eq_any(ids))).execute(db)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{
+        custom_types::{ActivityTypeEnum, ListingEventLifecycleEnum},
+        insert_into,
+        models::{Listing, ListingEvent},
+        mutations::{activity, listing},
+        tables::listings,
+        ConnectionManager, Pool,
+    };
+
+    /// Connect to the database named by `TEST_DATABASE_URL`, or return `None`
+    /// to skip tests requiring a database
+    fn connect() -> Option<PooledConnection> {
+        let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return None;
+        };
+
+        let pool = Pool::builder()
+            .max_size(1)
+            .build(ConnectionManager::new(url))
+            .unwrap();
+        let db = pool.get().unwrap();
+
+        crate::db::embedded_migrations::run(&db).unwrap();
+        db.begin_test_transaction().unwrap();
+
+        Some(db)
+    }
+
+    fn list(db: &PooledConnection, trade_state: &str, slot: i64) -> Listing<'static> {
+        let row = Listing {
+            id: None,
+            trade_state: Owned(trade_state.to_owned()),
+            auction_house: Borrowed("auction_house"),
+            seller: Borrowed("seller"),
+            metadata: Borrowed("metadata"),
+            purchase_id: None,
+            price: 1,
+            token_size: 1,
+            trade_state_bump: 0,
+            created_at: NaiveDateTime::from_timestamp_opt(slot, 0).unwrap(),
+            canceled_at: Some(None),
+            slot,
+            write_version: None,
+            marketplace_program: Borrowed("marketplace_program"),
+            expiry: None,
+        };
+
+        let id = listing::insert(db, &row).unwrap();
+        activity::listing(db, id, &row, ActivityTypeEnum::ListingCreated).unwrap();
+
+        Listing {
+            id: Some(id),
+            ..row
+        }
+    }
+
+    fn cancel(db: &PooledConnection, row: &Listing<'static>, slot: i64) {
+        let row = Listing {
+            canceled_at: Some(Some(NaiveDateTime::from_timestamp_opt(slot, 0).unwrap())),
+            slot,
+            ..row.clone()
+        };
+
+        listing::insert(db, &row).unwrap();
+        activity::listing(db, row.id.unwrap(), &row, ActivityTypeEnum::ListingCanceled).unwrap();
+    }
+
+    fn event(
+        db: &PooledConnection,
+        row: &Listing<'static>,
+        lifecycle: ListingEventLifecycleEnum,
+    ) -> Uuid {
+        let feed_event_id = insert_into(feed_events::table)
+            .default_values()
+            .returning(feed_events::id)
+            .get_result(db)
+            .unwrap();
+
+        insert_into(listing_events::table)
+            .values(&ListingEvent {
+                listing_id: row.id.unwrap(),
+                feed_event_id,
+                lifecycle,
+            })
+            .execute(db)
+            .unwrap();
+
+        feed_event_id
+    }
+
+    fn event_exists(db: &PooledConnection, id: Uuid) -> bool {
+        feed_events::table
+            .filter(feed_events::id.eq(id))
+            .select(feed_events::id)
+            .first::<Uuid>(db)
+            .optional()
+            .unwrap()
+            .is_some()
+    }
+
+    fn canceled_at(db: &PooledConnection, trade_state: &str) -> Option<Option<NaiveDateTime>> {
+        listings::table
+            .filter(listings::trade_state.eq(trade_state))
+            .select(listings::canceled_at)
+            .first(db)
+            .optional()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fork_replay() {
+        let Some(db) = connect() else { return };
+
+        // Slot 2 is orphaned when 4 is rooted, its instructions having been
+        // replayed in slot 3
+        upsert(&db, 1, None, SlotStatusEnum::Confirmed).unwrap();
+        upsert(&db, 2, Some(1), SlotStatusEnum::Processed).unwrap();
+        upsert(&db, 3, Some(1), SlotStatusEnum::Processed).unwrap();
+        upsert(&db, 4, Some(3), SlotStatusEnum::Processed).unwrap();
+
+        // Feed events are only written the first time a listing is created
+        // or canceled, so replayed instructions share the events written in
+        // the dead slot
+        let replayed = list(&db, "replayed", 2);
+        let replayed_created = event(&db, &replayed, ListingEventLifecycleEnum::Created);
+        list(&db, "replayed", 3);
+        let orphaned = list(&db, "orphaned", 2);
+        let orphaned_created = event(&db, &orphaned, ListingEventLifecycleEnum::Created);
+
+        let canceled_twice = list(&db, "canceled_twice", 1);
+        cancel(&db, &canceled_twice, 2);
+        let canceled_twice_event =
+            event(&db, &canceled_twice, ListingEventLifecycleEnum::Cancelled);
+        cancel(&db, &canceled_twice, 3);
+        let canceled_once = list(&db, "canceled_once", 1);
+        let canceled_once_created = event(&db, &canceled_once, ListingEventLifecycleEnum::Created);
+        cancel(&db, &canceled_once, 2);
+        let canceled_once_event = event(&db, &canceled_once, ListingEventLifecycleEnum::Cancelled);
+
+        assert_eq!(root(&db, 4).unwrap(), vec![2]);
+        rollback(&db, 2).unwrap();
+
+        assert_eq!(canceled_at(&db, "replayed"), Some(None));
+        assert_eq!(
+            listings::table
+                .filter(listings::trade_state.eq("replayed"))
+                .select(listings::id)
+                .first::<Uuid>(&db)
+                .unwrap(),
+            replayed.id.unwrap()
+        );
+        assert_eq!(canceled_at(&db, "orphaned"), None);
+        assert_eq!(
+            canceled_at(&db, "canceled_twice"),
+            Some(Some(NaiveDateTime::from_timestamp_opt(3, 0).unwrap()))
+        );
+        assert_eq!(canceled_at(&db, "canceled_once"), Some(None));
+
+        assert!(event_exists(&db, replayed_created));
+        assert!(!event_exists(&db, orphaned_created));
+        assert!(event_exists(&db, canceled_twice_event));
+        assert!(event_exists(&db, canceled_once_created));
+        assert!(!event_exists(&db, canceled_once_event));
+    }
+}
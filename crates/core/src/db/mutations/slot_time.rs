@@ -0,0 +1,120 @@
+use crate::{
+    db::{
+        insert_into, models::SlotTime, sql_query, sql_types::Timestamp, tables::slot_times,
+        PooledConnection,
+    },
+    error::Result,
+    prelude::*,
+};
+
+/// Statements replacing an estimated `created_at` with the real block time.
+/// Each binds the real block time as `$1` and the estimate as `$2`.
+///
+/// Rows are matched on the estimate alone, using the `created_at` index of
+/// each table, because later writes such as cancels and purchases may move a
+/// row's `slot` away from the slot it was created in.  Estimates are read
+/// from the clock with microsecond precision when a slot is first seen, so
+/// rows from other slots do not share them in practice.
+const CORRECT_CREATED_AT: &[&str] = &[
+    r"
+    UPDATE listings SET created_at = $1 WHERE created_at = $2;
+    ",
+    r"
+    UPDATE offers SET created_at = $1 WHERE created_at = $2;
+    ",
+    r"
+    UPDATE purchases SET created_at = $1 WHERE created_at = $2;
+    ",
+    r"
+    UPDATE marketplace_activities SET created_at = $1 WHERE created_at = $2;
+    ",
+    r"
+    UPDATE rewards_listings SET created_at = $1 WHERE created_at = $2;
+    ",
+    r"
+    UPDATE rewards_offers SET created_at = $1 WHERE created_at = $2;
+    ",
+    r"
+    UPDATE escrow_payments SET created_at = $1 WHERE created_at = $2;
+    ",
+];
+
+/// Get the recorded block time of a slot, real or estimated
+///
+/// # Errors
+/// This function fails if the `slot_times` query fails
+pub fn get(db: &PooledConnection, slot: i64) -> Result<Option<SlotTime>> {
+    slot_times::table
+        .filter(slot_times::slot.eq(slot))
+        .first(db)
+        .optional()
+        .context("Failed to load slot time")
+}
+
+/// Record an estimated block time for a slot, returning the time already
+/// recorded for the slot if there is one, so every row written in the slot
+/// shares the same estimate
+///
+/// # Errors
+/// This function fails if the `slot_times` upsert fails
+pub fn estimate(db: &PooledConnection, slot: i64, block_time: NaiveDateTime) -> Result<SlotTime> {
+    insert_into(slot_times::table)
+        .values(&SlotTime {
+            slot,
+            block_time,
+            estimated: true,
+        })
+        .on_conflict(slot_times::slot)
+        .do_update()
+        .set(slot_times::slot.eq(slot_times::slot))
+        .get_result(db)
+        .context("Failed to record estimated slot time")
+}
+
+/// Record the real block time of a slot, replacing any estimate and
+/// correcting the `created_at` timestamps of rows written with it.
+///
+/// This should be called inside a transaction.
+///
+/// # Errors
+/// This function fails if the `slot_times` upsert or any correction fails
+pub fn record(db: &PooledConnection, slot: i64, block_time: NaiveDateTime) -> Result<()> {
+    let previous = get(db, slot)?;
+
+    insert_into(slot_times::table)
+        .values(&SlotTime {
+            slot,
+            block_time,
+            estimated: false,
+        })
+        .on_conflict(slot_times::slot)
+        .do_update()
+        .set((
+            slot_times::block_time.eq(block_time),
+            slot_times::estimated.eq(false),
+        ))
+        .execute(db)
+        .context("Failed to record slot time")?;
+
+    let Some(SlotTime {
+        block_time: estimate,
+        estimated: true,
+        ..
+    }) = previous else {
+        return Ok(());
+    };
+
+    if estimate == block_time {
+        return Ok(());
+    }
+
+    for stmt in CORRECT_CREATED_AT {
+        sql_query(*stmt)
+            .bind::<Timestamp, _>(block_time)
+            .bind::<Timestamp, _>(estimate)
+            .execute(db)
+            .context("Failed to correct estimated timestamps")?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 //! Query utilities for collections.
 
 use anyhow::Context;
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use diesel::{
     expression::{operators::Eq, AsExpression, NonAggregate},
@@ -13,6 +14,7 @@ use diesel::{
 };
 use sea_query::{Alias, Expr, Func, Iden, Order, PostgresQueryBuilder, Query};
 
+use super::Pagination;
 use crate::{
     db::{
         custom_types::{CollectionSort, OrderDirection},
@@ -25,7 +27,7 @@ use crate::{
     prelude::*,
 };
 
-#[derive(Iden)]
+#[derive(Iden, Clone, Copy)]
 #[allow(missing_docs)]
 enum DolphinStats {
     Table,
@@ -506,10 +508,8 @@ pub struct TrendingQueryOptions {
     pub sort_by: CollectionSort,
     /// Order the resulting rows by 'Asc' or 'Desc'
     pub order: Option<Order>,
-    /// Limit the number of returned rows
-    pub limit: u64,
-    /// Skip the first `n` resulting rows
-    pub offset: u64,
+    /// Page of results to return, keyed by [`trend_sort_key`]
+    pub page: Pagination<(BigDecimal, String)>,
 }
 
 impl From<CollectionSort> for DolphinStats {
@@ -528,23 +528,42 @@ impl From<CollectionSort> for DolphinStats {
     }
 }
 
+/// Get the keyset cursor of a trending collection under the given sort
+#[must_use]
+pub fn trend_sort_key(sort_by: CollectionSort, stats: &DolphinStatsDB) -> (BigDecimal, String) {
+    let value = match sort_by {
+        CollectionSort::OneDayFloorPrice => stats.floor_1d.clone(),
+        CollectionSort::SevenDayFloorPrice => stats.floor_7d.clone(),
+        CollectionSort::ThirtyDayFloorPrice => stats.floor_30d.clone(),
+        CollectionSort::OneDayVolume => stats.volume_1d.clone(),
+        CollectionSort::SevenDayVolume => stats.volume_7d.clone(),
+        CollectionSort::ThirtyDayVolume => stats.volume_30d.clone(),
+        CollectionSort::OneDayListedCount => stats.listed_1d.into(),
+        CollectionSort::SevenDayListedCount => stats.listed_7d.into(),
+        CollectionSort::ThirtyDayListedCount => stats.listed_30d.into(),
+    };
+
+    (value, stats.collection_symbol.to_string())
+}
+
 /// Handles queries for trending collections
 ///
+/// Keyset pages break ties in the sort column by collection ID.
+///
 /// # Errors
 /// returns an error when the underlying queries throw an error
 pub fn trends(conn: &Connection, options: TrendingQueryOptions) -> Result<Vec<DolphinStatsDB>> {
     let TrendingQueryOptions {
         sort_by,
         order,
-        limit,
-        offset,
+        page,
     } = options;
 
     let sort_by: DolphinStats = sort_by.into();
 
     let order = order.unwrap_or(Order::Desc);
 
-    let query = Query::select()
+    let mut query = Query::select()
         .expr_as(
             Expr::col((Collections::Table, Collections::Id)),
             Alias::new(&DolphinStats::CollectionSymbol.to_string()),
@@ -586,11 +605,43 @@ pub fn trends(conn: &Connection, options: TrendingQueryOptions) -> Result<Vec<Do
                 Expr::col((Collections::Table, Collections::Id)),
             ])),
         )
-        .limit(limit)
-        .offset(offset)
-        .order_by((DolphinStats::Table, sort_by), order)
         .take();
 
+    match page {
+        Pagination::Offset { limit, offset } => {
+            query
+                .limit(limit)
+                .offset(offset)
+                .order_by((DolphinStats::Table, sort_by), order);
+        },
+        Pagination::Keyset {
+            cursor,
+            forward,
+            limit,
+        } => {
+            let ascending = matches!(order, Order::Asc) == forward;
+
+            if let Some((value, id)) = cursor {
+                let op = if ascending { ">" } else { "<" };
+
+                query.and_where(Expr::cust_with_values(
+                    &format!(
+                        r#"("dolphin_stats"."{}", "collections"."id") {op} (CAST(? AS numeric), ?)"#,
+                        sort_by.to_string(),
+                    ),
+                    [value.to_string(), id],
+                ));
+            }
+
+            let order = if ascending { Order::Asc } else { Order::Desc };
+
+            query
+                .limit(limit)
+                .order_by((DolphinStats::Table, sort_by), order.clone())
+                .order_by((Collections::Table, Collections::Id), order);
+        },
+    }
+
     let query = query.to_string(PostgresQueryBuilder);
 
     diesel::sql_query(query)
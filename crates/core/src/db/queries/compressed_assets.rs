@@ -0,0 +1,287 @@
+//! Query utilities for compressed NFTs and the Merkle trees containing them.
+
+use anyhow::Context;
+use diesel::{
+    pg::Pg,
+    prelude::*,
+    serialize::ToSql,
+    sql_types::{Array, Text},
+};
+use solana_program::keccak;
+
+use crate::{
+    db::{
+        models::Nft,
+        tables::{compressed_assets, merkle_tree_nodes, merkle_trees},
+        Connection,
+    },
+    error::prelude::*,
+    hash::HashMap,
+};
+
+const NFTS_QUERY: &str = r"
+SELECT
+    ca.asset_id AS address,
+    cd.name,
+    cd.seller_fee_basis_points,
+    ca.asset_id AS mint_address,
+    cd.primary_sale_happened,
+    COALESCE(ca.tree_authority, '') AS update_authority_address,
+    cd.uri,
+    ca.slot,
+    cd.token_standard,
+    mj.description,
+    mj.image,
+    mj.animation_url,
+    mj.external_url,
+    mj.category,
+    mj.model,
+    '' AS token_account_address
+FROM compressed_assets ca
+    INNER JOIN compressed_asset_datas cd ON cd.data_hash = ca.data_hash
+    LEFT JOIN metadata_jsons mj ON mj.metadata_address = ca.asset_id
+WHERE ca.asset_id = ANY($1)
+    AND ca.burned_at IS NULL
+    AND ca.redeemed_at IS NULL;
+-- $1: addresses::text[]";
+
+/// Load compressed NFTs with the given asset IDs
+///
+/// Compressed NFTs have no mint or token account, so the asset ID is returned
+/// as the mint address and the token account address is left empty.
+///
+/// # Errors
+/// This function fails if the underlying SQL query returns an error
+pub fn nfts(conn: &Connection, addresses: impl ToSql<Array<Text>, Pg>) -> Result<Vec<Nft>> {
+    diesel::sql_query(NFTS_QUERY)
+        .bind(addresses)
+        .load(conn)
+        .context("Failed to load compressed NFTs")
+}
+
+/// A proof of inclusion of a compressed NFT's leaf in its Merkle tree
+#[derive(Debug, Clone)]
+pub struct MerkleProof {
+    /// The address of the Merkle tree
+    pub tree: String,
+    /// The index of the leaf in the tree
+    pub leaf_index: i64,
+    /// The hash stored in the leaf
+    pub leaf: String,
+    /// The sibling hashes along the path from the leaf to the root, ordered
+    /// from the leaf upwards
+    pub proof: Vec<String>,
+    /// The root hash of the tree
+    pub root: String,
+}
+
+/// Compute the hash of an empty subtree of the given height
+fn empty_node(height: usize) -> keccak::Hash {
+    (0..height).fold(keccak::Hash::default(), |node, _| {
+        keccak::hashv(&[node.as_ref(), node.as_ref()])
+    })
+}
+
+/// Assemble a Merkle proof for the compressed NFT with the given asset ID
+///
+/// Returns `None` if the asset or its tree have not been indexed.  Nodes which
+/// have never been written are assumed to be empty.
+///
+/// # Errors
+/// This function fails if an underlying database query fails or the stored
+/// tree depth is invalid
+pub fn proof(conn: &Connection, asset_id: &str) -> Result<Option<MerkleProof>> {
+    let asset = compressed_assets::table
+        .inner_join(
+            merkle_trees::table.on(compressed_assets::tree.eq(merkle_trees::address.nullable())),
+        )
+        .filter(compressed_assets::asset_id.eq(asset_id))
+        .select((
+            compressed_assets::tree,
+            compressed_assets::leaf_index,
+            merkle_trees::max_depth,
+        ))
+        .first::<(Option<String>, Option<i64>, i32)>(conn)
+        .optional()
+        .context("Failed to load compressed NFT tree")?;
+
+    let Some((Some(tree), Some(leaf_index), max_depth)) = asset else {
+        return Ok(None);
+    };
+
+    let depth = u32::try_from(max_depth).context("Invalid Merkle tree depth")?;
+    let leaf_node = (1_i64 << depth) + leaf_index;
+    let mut indices = vec![leaf_node];
+    let mut node = leaf_node;
+
+    while node > 1 {
+        indices.push(node ^ 1);
+        node >>= 1;
+    }
+
+    indices.push(1);
+
+    let nodes: HashMap<i64, String> = merkle_tree_nodes::table
+        .filter(merkle_tree_nodes::tree.eq(&tree))
+        .filter(merkle_tree_nodes::node_index.eq_any(&indices))
+        .select((merkle_tree_nodes::node_index, merkle_tree_nodes::hash))
+        .load::<(i64, String)>(conn)
+        .context("Failed to load Merkle tree nodes")?
+        .into_iter()
+        .collect();
+
+    let hash_at = |index: i64, height: usize| {
+        nodes
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(|| empty_node(height).to_string())
+    };
+
+    let proof = indices[1..indices.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(height, &index)| hash_at(index, height))
+        .collect();
+
+    Ok(Some(MerkleProof {
+        leaf: hash_at(leaf_node, 0),
+        root: hash_at(1, depth as usize),
+        tree,
+        leaf_index,
+        proof,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow::{Borrowed, Owned};
+
+    use super::*;
+    use crate::db::{
+        insert_into,
+        models::{CompressedAsset, MerkleTree, MerkleTreeNode},
+        ConnectionManager, Pool, PooledConnection,
+    };
+
+    /// Connect to the database named by `TEST_DATABASE_URL`, or return `None`
+    /// to skip tests requiring a database
+    fn connect() -> Option<PooledConnection> {
+        let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return None;
+        };
+
+        let pool = Pool::builder()
+            .max_size(1)
+            .build(ConnectionManager::new(url))
+            .unwrap();
+        let db = pool.get().unwrap();
+
+        crate::db::embedded_migrations::run(&db).unwrap();
+        db.begin_test_transaction().unwrap();
+
+        Some(db)
+    }
+
+    fn parent(left: keccak::Hash, right: keccak::Hash) -> keccak::Hash {
+        keccak::hashv(&[left.as_ref(), right.as_ref()])
+    }
+
+    fn insert_asset(db: &PooledConnection, asset_id: &str, leaf_index: Option<i64>) {
+        insert_into(compressed_assets::table)
+            .values(CompressedAsset {
+                asset_id: Borrowed(asset_id),
+                tree: leaf_index.map(|_| Borrowed("tree")),
+                tree_authority: None,
+                leaf_index,
+                owner: Borrowed("owner"),
+                delegate: Borrowed("delegate"),
+                data_hash: Borrowed("data_hash"),
+                creator_hash: Borrowed("creator_hash"),
+                burned_at: None,
+                redeemed_at: None,
+                slot: 1,
+                seq: None,
+            })
+            .execute(db)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_empty_node() {
+        assert_eq!(empty_node(0), keccak::Hash::default());
+        assert_eq!(
+            empty_node(2),
+            parent(
+                parent(keccak::Hash::default(), keccak::Hash::default()),
+                parent(keccak::Hash::default(), keccak::Hash::default())
+            )
+        );
+    }
+
+    #[test]
+    fn test_proof() {
+        let Some(db) = connect() else { return };
+
+        // A depth-2 tree with leaves 0-2 written and leaf 3 never written
+        let leaves = [
+            keccak::hash(&[0]),
+            keccak::hash(&[1]),
+            keccak::hash(&[2]),
+            empty_node(0),
+        ];
+        let left = parent(leaves[0], leaves[1]);
+        let right = parent(leaves[2], leaves[3]);
+        let root = parent(left, right);
+
+        insert_into(merkle_trees::table)
+            .values(MerkleTree {
+                address: Borrowed("tree"),
+                max_depth: 2,
+                slot: 1,
+                seq: 3,
+            })
+            .execute(&db)
+            .unwrap();
+
+        let nodes = [
+            (1, root),
+            (2, left),
+            (3, right),
+            (4, leaves[0]),
+            (5, leaves[1]),
+            (6, leaves[2]),
+        ]
+        .into_iter()
+        .map(|(node_index, hash)| MerkleTreeNode {
+            tree: Borrowed("tree"),
+            node_index,
+            hash: Owned(hash.to_string()),
+            slot: 1,
+            seq: 3,
+        })
+        .collect::<Vec<_>>();
+
+        insert_into(merkle_tree_nodes::table)
+            .values(&nodes)
+            .execute(&db)
+            .unwrap();
+
+        insert_asset(&db, "asset", Some(2));
+        insert_asset(&db, "unplaced", None);
+
+        let asset = proof(&db, "asset").unwrap().unwrap();
+
+        assert_eq!(asset.tree, "tree");
+        assert_eq!(asset.leaf_index, 2);
+        assert_eq!(asset.leaf, leaves[2].to_string());
+        assert_eq!(asset.proof, vec![
+            empty_node(0).to_string(),
+            left.to_string()
+        ]);
+        assert_eq!(asset.root, root.to_string());
+
+        assert!(proof(&db, "unplaced").unwrap().is_none());
+        assert!(proof(&db, "missing").unwrap().is_none());
+    }
+}
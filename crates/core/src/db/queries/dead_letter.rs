@@ -0,0 +1,27 @@
+//! Query utilities for the `dead_letters` table.
+
+use diesel::{dsl::now, pg::data_types::PgInterval};
+
+use crate::{
+    db::{select, tables::dead_letters, Connection},
+    error::Result,
+    prelude::*,
+};
+
+/// Check whether any message consumed from the given queue kind has failed
+/// within the given window
+///
+/// # Errors
+/// This function fails if the underlying query fails to execute.
+pub fn failed_within(conn: &Connection, queue: &str, window: StdDuration) -> Result<bool> {
+    let window =
+        PgInterval::from_microseconds(i64::try_from(window.as_micros()).unwrap_or(i64::MAX));
+
+    select(exists(
+        dead_letters::table
+            .filter(dead_letters::queue.eq(queue))
+            .filter(dead_letters::last_failed_at.gt(now - window)),
+    ))
+    .get_result(conn)
+    .context("Failed to check for recent dead letters")
+}
@@ -2,13 +2,15 @@
 
 use diesel::prelude::*;
 use sea_query::{
-    Alias, CommonTableExpression, Expr, Iden, Order, PostgresQueryBuilder, Query,
+    Alias, CommonTableExpression, Condition, Expr, Iden, Order, PostgresQueryBuilder, Query,
     QueryStatementWriter,
 };
+use uuid::Uuid;
 
 use crate::{
     db::{models::CompleteFeedEvent, Connection},
     error::prelude::*,
+    prelude::NaiveDateTime,
 };
 
 #[derive(Iden)]
@@ -239,18 +241,20 @@ pub fn list(
         .context("Failed to load feed events")
 }
 
-/// Return polymorphic list of feed events based on who the wallet is following
+/// Return a page of the polymorphic list of feed events based on who the
+/// wallet is following, most recent first and starting after the given
+/// `(created_at, id)` cursor, or before it if `forward` is false
 ///
 /// # Errors
 /// This function fails if the underlying query fails to execute.
 #[allow(clippy::too_many_lines)]
 pub fn list_relay(
     conn: &Connection,
-    limit: u64,
-    is_forward: bool,
-    cursor: String,
     wallet: Option<String>,
     include_types: Option<Vec<EventType>>,
+    cursor: Option<(NaiveDateTime, Uuid)>,
+    forward: bool,
+    limit: u64,
 ) -> Result<Vec<CompleteFeedEvent>> {
     let mut events_query = Query::select()
         .distinct()
@@ -323,46 +327,67 @@ pub fn list_relay(
             Expr::tbl(ListingEvents::Table, ListingEvents::FeedEventId)
                 .equals(FeedEvents::Table, FeedEvents::Id),
         )
-        .order_by(FeedEvents::CreatedAt, Order::Desc)
         .clone();
 
     if let Some(wallet) = wallet {
-        events_query.and_where(
-            Expr::col((GraphConnections::Table, GraphConnections::FromAccount)).eq(wallet),
-        );
+        events_query
+            .and_where(
+                Expr::col((GraphConnections::Table, GraphConnections::FromAccount)).eq(wallet),
+            )
+            .and_where(
+                Expr::col((GraphConnections::Table, GraphConnections::DisconnectedAt)).is_null(),
+            );
     }
 
-    if is_forward {
-        events_query.and_where(Expr::col((FeedEvents::Table, FeedEvents::CreatedAt)).gt(cursor));
-    } else {
-        events_query.and_where(Expr::col((FeedEvents::Table, FeedEvents::CreatedAt)).lt(cursor));
+    if let Some((created_at, id)) = cursor {
+        let op = if forward { "<" } else { ">" };
+
+        // The cursor is sent with an explicit UTC offset so the cast does not
+        // depend on the session time zone
+        events_query.and_where(Expr::cust_with_values(
+            &format!(
+                r#"("feed_events"."created_at", "feed_events"."id") {op} (CAST(? AS timestamptz), CAST(? AS uuid))"#
+            ),
+            [
+                created_at.format("%Y-%m-%d %H:%M:%S%.6f+00").to_string(),
+                id.to_string(),
+            ],
+        ));
     }
 
-    events_query.limit(limit);
-
     if let Some(event_types) = include_types {
+        let mut types = Condition::any();
+
         for event_type in event_types {
-            match event_type {
-                EventType::Follow => events_query.and_where(
+            types = types.add(match event_type {
+                EventType::Follow => {
                     Expr::col((FollowEvents::Table, FollowEvents::GraphConnectionAddress))
-                        .is_not_null(),
-                ),
-                EventType::Offer => events_query
-                    .and_where(Expr::col((OfferEvents::Table, OfferEvents::OfferId)).is_not_null()),
-                EventType::Mint => events_query.and_where(
-                    Expr::col((MintEvents::Table, MintEvents::MetadataAddress)).is_not_null(),
-                ),
-                EventType::Purchase => events_query.and_where(
-                    Expr::col((PurchaseEvents::Table, PurchaseEvents::PurchaseId)).is_not_null(),
-                ),
-                EventType::Listing => events_query.and_where(
-                    Expr::col((ListingEvents::Table, ListingEvents::ListingId)).is_not_null(),
-                ),
-            };
+                        .is_not_null()
+                },
+                EventType::Offer => {
+                    Expr::col((OfferEvents::Table, OfferEvents::OfferId)).is_not_null()
+                },
+                EventType::Mint => {
+                    Expr::col((MintEvents::Table, MintEvents::MetadataAddress)).is_not_null()
+                },
+                EventType::Purchase => {
+                    Expr::col((PurchaseEvents::Table, PurchaseEvents::PurchaseId)).is_not_null()
+                },
+                EventType::Listing => {
+                    Expr::col((ListingEvents::Table, ListingEvents::ListingId)).is_not_null()
+                },
+            });
         }
+
+        events_query.cond_where(types);
     }
 
-    events_query.order_by(FeedEvents::CreatedAt, Order::Desc);
+    let order = if forward { Order::Desc } else { Order::Asc };
+
+    events_query
+        .order_by((FeedEvents::Table, FeedEvents::CreatedAt), order.clone())
+        .order_by((FeedEvents::Table, FeedEvents::Id), order)
+        .limit(limit);
 
     let events_query = events_query.to_string(PostgresQueryBuilder);
 
@@ -11,7 +11,7 @@ use diesel::{
     sql_types::{Nullable, Text},
 };
 
-use super::handle_range;
+use super::{handle_range, Pagination};
 use crate::{
     db::{
         models::GenoHabitatData,
@@ -80,15 +80,13 @@ pub struct ListHabitatOptions<M, W, H> {
     pub has_max_ki: Option<bool>,
     /// Select activated habitats only i.e expiry_timestamp != 0
     pub is_activated: Option<bool>,
-    /// Field to sort results on
+    /// Field to sort results on, if paginating by offset
     pub sort_field: HabitatSortField,
     /// True if rows should be sorted in descending order, false if they should
     /// be sorted ascending
     pub sort_desc: bool,
-    /// Limit the number of returned rows
-    pub limit: i64,
-    /// Skip the first `n` resulting rows
-    pub offset: i64,
+    /// Page of results to return, keyed by address if paginating by keyset
+    pub page: Pagination<String>,
 }
 
 /// Tuple of `(habitats, total_count_hint)`
@@ -96,6 +94,9 @@ pub type HabitatList = (Vec<GenoHabitatData<'static>>, Option<i64>);
 
 /// List the Genopets `HabitatData` accounts matching the given query parameters
 ///
+/// Keyset pages are always ordered by address, ignoring `sort_field` and
+/// `sort_desc`.
+///
 /// # Errors
 /// This function fails if the underlying query returns an error.
 #[allow(clippy::too_many_lines)] // splitting this function would require naming eldritch types
@@ -127,12 +128,11 @@ pub fn list_habitats<
         is_activated,
         sort_field,
         sort_desc,
-        limit,
-        offset,
+        page,
     } = opts;
 
     let minimum_unix_timestamp = unix_timestamp(0)?;
-    let build_query = |sort| {
+    let build_query = |page: Option<&Pagination<String>>| {
         let mut query = geno_habitat_datas::table.into_boxed();
         let mut count = true;
 
@@ -240,9 +240,28 @@ pub fn list_habitats<
             count = false;
         }
 
-        if sort {
+        match page {
+            Some(Pagination::Keyset {
+                cursor, forward, ..
+            }) => {
+                query = match (*forward, cursor) {
+                    (true, Some(address)) => {
+                        query.filter(geno_habitat_datas::address.gt(address.clone()))
+                    },
+                    (false, Some(address)) => {
+                        query.filter(geno_habitat_datas::address.lt(address.clone()))
+                    },
+                    (_, None) => query,
+                };
+
+                query = if *forward {
+                    query.order_by(geno_habitat_datas::address.asc())
+                } else {
+                    query.order_by(geno_habitat_datas::address.desc())
+                };
+            },
             // If someone has a less stupid way to do this, I'm all ears
-            match (sort_field, sort_desc) {
+            Some(Pagination::Offset { .. }) => match (sort_field, sort_desc) {
                 (HabitatSortField::Address, false) => {
                     query = query.order_by(geno_habitat_datas::address.asc());
                 },
@@ -285,17 +304,18 @@ pub fn list_habitats<
                 (HabitatSortField::KiAvailableToHarvest, true) => {
                     query = query.order_by(geno_habitat_datas::ki_available_to_harvest.desc());
                 },
-            }
+            },
+            None => (),
         }
 
         (query, count)
     };
 
-    let (query, count) = build_query(true);
+    let (query, count) = build_query(Some(&page));
 
     let count = if count {
         // I can't figure out any way to clone or borrow a boxed select statement.
-        let (query, _) = build_query(false);
+        let (query, _) = build_query(None);
 
         Some(
             query
@@ -307,11 +327,16 @@ pub fn list_habitats<
         None
     };
 
+    let query = match page {
+        Pagination::Offset { limit, offset } => {
+            query.limit(limit.try_into()?).offset(offset.try_into()?)
+        },
+        Pagination::Keyset { limit, .. } => query.limit(limit.try_into()?),
+    };
+
     Ok((
         query
             .select(geno_habitat_datas::all_columns)
-            .limit(limit)
-            .offset(offset)
             .load(conn)
             .context("Failed to load Genopets habitats")?,
         count,
@@ -4,7 +4,7 @@ use diesel::{
     pg::Pg,
     serialize::ToSql,
     sql_query,
-    sql_types::{Array, Int4, Text},
+    sql_types::{Array, BigInt, Bool, Int4, Nullable, Text},
 };
 
 use crate::{
@@ -47,6 +47,46 @@ pub fn connections(
         .context("failed to load twitter enriched graph connections by parameters")
 }
 
+const CONNECTIONS_PAGE_QUERY: &str = r"
+SELECT gc.address AS connection_address, from_account, to_account, connected_at, disconnected_at, fth.twitter_handle AS from_twitter_handle, tth.twitter_handle AS to_twitter_handle
+    FROM graph_connections gc
+    LEFT JOIN twitter_handle_name_services fth ON gc.from_account = fth.wallet_address
+    LEFT JOIN twitter_handle_name_services tth ON gc.to_account = tth.wallet_address
+    WHERE ($1 = '{}' OR from_account = ANY($1)) AND ($2 = '{}' OR to_account = ANY($2)) AND disconnected_at is null
+        AND ($3 IS NULL OR ($4 AND gc.address > $3) OR (NOT $4 AND gc.address < $3))
+    ORDER BY CASE WHEN $4 THEN gc.address END ASC, CASE WHEN NOT $4 THEN gc.address END DESC
+    LIMIT $5;
+ -- $1: from::text[]
+ -- $2: to::text[]
+ -- $3: cursor::text
+ -- $4: forward::boolean
+ -- $5: limit::bigint
+ ";
+
+/// Return a page of connections based on from and to filters, ordered by
+/// address and starting after the given cursor, or before it if `forward` is
+/// false
+///
+/// # Errors
+/// This function fails if the underlying query fails to execute.
+pub fn connections_page(
+    conn: &Connection,
+    from: impl ToSql<Array<Text>, Pg>,
+    to: impl ToSql<Array<Text>, Pg>,
+    cursor: impl ToSql<Nullable<Text>, Pg>,
+    forward: impl ToSql<Bool, Pg>,
+    limit: impl ToSql<BigInt, Pg>,
+) -> Result<Vec<TwitterEnrichedGraphConnection>> {
+    sql_query(CONNECTIONS_PAGE_QUERY)
+        .bind(from)
+        .bind(to)
+        .bind(cursor)
+        .bind(forward)
+        .bind(limit)
+        .load(conn)
+        .context("failed to load twitter enriched graph connections by parameters")
+}
+
 const LIST_QUERY: &str = r"
 SELECT gc.address AS connection_address, from_account, to_account, connected_at, disconnected_at, fth.twitter_handle AS from_twitter_handle, tth.twitter_handle AS to_twitter_handle
     FROM graph_connections gc
@@ -7,14 +7,15 @@ use diesel::{
 };
 use sea_query::{
     Alias, Condition, DynIden, Expr, Iden, JoinType, NullOrdering, Order, OrderedStatement,
-    PostgresQueryBuilder, Query, SeaRc, Value,
+    PostgresQueryBuilder, Query, SeaRc, SimpleExpr, Value,
 };
 use uuid::Uuid;
 
+use super::Pagination;
 use crate::{
     db::{
         custom_types::NftSort,
-        models::{Nft, NftActivity},
+        models::{ListedNft, Nft, NftActivity},
         tables::{current_metadata_owners, metadata_jsons, metadatas},
         Connection,
     },
@@ -154,10 +155,8 @@ pub struct ListQueryOptions {
     pub with_offers: Option<bool>,
     /// nft in one or more specific collections
     pub collections: Option<Vec<String>>,
-    /// limit to apply to query
-    pub limit: u64,
-    /// offset to apply to query
-    pub offset: u64,
+    /// page of results to return, keyed by `(listing_price, address)`
+    pub page: Pagination<(Option<i64>, String)>,
 }
 
 /// The column set for an NFT
@@ -200,7 +199,26 @@ pub const NFT_COLUMNS: NftColumns = (
     current_metadata_owners::token_account_address,
 );
 
-/// Handles queries for NFTs
+/// Filter for NFT rows following the `(listing_price, address)` keyset cursor,
+/// with unlisted NFTs sorted after listed ones
+fn price_keyset(price: Option<i64>, address: String, forward: bool) -> SimpleExpr {
+    let price_col = || Expr::col((Listings::Table, Listings::Price));
+    let address_col = || Expr::col((Metadatas::Table, Metadatas::Address));
+
+    match (price, forward) {
+        (Some(price), true) => price_col()
+            .gt(price)
+            .or(price_col().is_null())
+            .or(price_col().eq(price).and(address_col().gt(address))),
+        (Some(price), false) => price_col()
+            .lt(price)
+            .or(price_col().eq(price).and(address_col().lt(address))),
+        (None, true) => price_col().is_null().and(address_col().gt(address)),
+        (None, false) => price_col().is_not_null().or(address_col().lt(address)),
+    }
+}
+
+/// Handles queries for NFTs, ordered by the price they are listed for
 ///
 /// # Errors
 /// returns an error when the underlying queries throw an error
@@ -219,11 +237,10 @@ pub fn list<O: Clone + Into<Value>>(
         allow_unverified,
         with_offers,
         collections,
-        limit,
-        offset,
+        page,
     }: ListQueryOptions,
     opensea_auction_house: O,
-) -> Result<Vec<Nft>> {
+) -> Result<Vec<ListedNft>> {
     let current_time = Utc::now().naive_utc();
 
     let mut listings_query = Query::select()
@@ -279,6 +296,10 @@ pub fn list<O: Clone + Into<Value>>(
             CurrentMetadataOwners::Table,
             CurrentMetadataOwners::TokenAccountAddress,
         )])
+        .expr_as(
+            Expr::col((Listings::Table, Listings::Price)),
+            Alias::new("listing_price"),
+        )
         .from(MetadataJsons::Table)
         .inner_join(
             Metadatas::Table,
@@ -307,11 +328,33 @@ pub fn list<O: Clone + Into<Value>>(
                 )),
         )
         .and_where(Expr::col((Metadatas::Table, Metadatas::BurnedAt)).is_null())
-        .limit(limit)
-        .offset(offset)
-        .order_by((Listings::Table, Listings::Price), Order::Asc)
         .take();
 
+    match page {
+        Pagination::Offset { limit, offset } => {
+            query
+                .limit(limit)
+                .offset(offset)
+                .order_by((Listings::Table, Listings::Price), Order::Asc);
+        },
+        Pagination::Keyset {
+            cursor,
+            forward,
+            limit,
+        } => {
+            if let Some((price, address)) = cursor {
+                query.and_where(price_keyset(price, address, forward));
+            }
+
+            let order = if forward { Order::Asc } else { Order::Desc };
+
+            query
+                .limit(limit)
+                .order_by((Listings::Table, Listings::Price), order.clone())
+                .order_by((Metadatas::Table, Metadatas::Address), order);
+        },
+    }
+
     if let Some(addresses) = addresses {
         query.and_where(Expr::col(Metadatas::Address).is_in(addresses));
     }
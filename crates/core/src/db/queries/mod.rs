@@ -5,6 +5,9 @@ pub mod auction_house;
 pub mod bonding_changes;
 pub mod charts;
 pub mod collections;
+#[cfg(feature = "solana")]
+pub mod compressed_assets;
+pub mod dead_letter;
 pub mod featured_listings;
 pub mod feed_event;
 pub mod genopets;
@@ -33,6 +36,31 @@ mod util {
         Expression, ExpressionMethods,
     };
 
+    /// The rows of a list query to return
+    #[derive(Debug, Clone)]
+    pub enum Pagination<K> {
+        /// Skip the first `offset` rows of the query's ordering and return
+        /// at most `limit` rows
+        Offset {
+            /// Limit the number of returned rows
+            limit: u64,
+            /// Skip the first `n` resulting rows
+            offset: u64,
+        },
+        /// Return at most `limit` rows following the row keyed by `cursor` in
+        /// the query's keyset ordering, or preceding it in reverse order if
+        /// `forward` is false
+        Keyset {
+            /// The sort key of the row to start from, or `None` to start from
+            /// the first (or last) row
+            cursor: Option<K>,
+            /// True to read rows in the query's natural order
+            forward: bool,
+            /// Limit the number of returned rows
+            limit: u64,
+        },
+    }
+
     /// Perform a range query on an expression
     pub fn handle_range<
         Q: FilterDsl<Gt<E, T>, Output = Q>
@@ -3,7 +3,7 @@
 use anyhow::Context;
 use diesel::{
     pg::Pg,
-    sql_types::{Array, Bool, Nullable, Text, Timestamp},
+    sql_types::{Array, BigInt, Bool, Nullable, Text, Timestamp},
     types::{Int4, ToSql},
     RunQueryDsl,
 };
@@ -57,6 +57,66 @@ pub fn vote_records(
         .context("Failed to load vote records")
 }
 
+const VOTE_RECORDS_PAGE_QUERY: &str = r"
+select address, account_type, proposal, governing_token_owner, is_relinquished, voter_weight,
+		vote, vote_type, vote_weight, slot, write_version
+from (
+		select 	address, account_type, proposal, governing_token_owner, is_relinquished, null as voter_weight,
+				null as vote, vote_type, vote_weight, slot, write_version
+		from vote_records_v1
+		where (address = any($1) or $1 is null)
+			and (proposal = any($2) or $2 is null)
+			and (governing_token_owner = any($3) or $3 is null)
+			and (is_relinquished = $4 or $4 is null)
+		union all
+		select 	address, account_type, proposal, governing_token_owner, is_relinquished, voter_weight,
+				vote, null as vote_type, null as vote_weight, slot, write_version
+		from vote_records_v2
+		where (address = any($1) or $1 is null)
+			and (proposal = any($2) or $2 is null)
+			and (governing_token_owner = any($3) or $3 is null)
+			and (is_relinquished = $4 or $4 is null)
+) v
+where $5 is null or ($6 and address > $5) or (not $6 and address < $5)
+order by case when $6 then address end asc, case when not $6 then address end desc
+limit $7;
+ -- $1: addresses::text[]
+ -- $2: proposals::text[]
+ -- $3: governing_token_owners::text[]
+ -- $4: is_relinquished::bool
+ -- $5: cursor::text
+ -- $6: forward::boolean
+ -- $7: limit::bigint";
+
+/// Load a page of spl governance vote records including V1 and V2, ordered by
+/// address and starting after the given cursor, or before it if `forward` is
+/// false
+///
+/// # Errors
+/// This function fails if the underlying SQL query returns an error
+#[allow(clippy::too_many_arguments)]
+pub fn vote_records_page(
+    conn: &Connection,
+    addresses: impl ToSql<Nullable<Array<Text>>, Pg>,
+    proposals: impl ToSql<Nullable<Array<Text>>, Pg>,
+    governing_token_owners: impl ToSql<Nullable<Array<Text>>, Pg>,
+    is_relinquished: impl ToSql<Nullable<Bool>, Pg>,
+    cursor: impl ToSql<Nullable<Text>, Pg>,
+    forward: impl ToSql<Bool, Pg>,
+    limit: impl ToSql<BigInt, Pg>,
+) -> Result<Vec<VoteRecord>> {
+    diesel::sql_query(VOTE_RECORDS_PAGE_QUERY)
+        .bind(addresses)
+        .bind(proposals)
+        .bind(governing_token_owners)
+        .bind(is_relinquished)
+        .bind(cursor)
+        .bind(forward)
+        .bind(limit)
+        .load(conn)
+        .context("Failed to load vote records")
+}
+
 const PROPOSALS_QUERY: &str = r"
 select address, account_type, governance, governing_token_mint, state, token_owner_record, signatories_count,
 		signatories_signed_off_count, yes_votes_count, no_votes_count, instructions_executed_count,
@@ -118,3 +178,79 @@ pub fn proposals(
         .load(conn)
         .context("Failed to load proposals")
 }
+
+const PROPOSALS_PAGE_QUERY: &str = r"
+select address, account_type, governance, governing_token_mint, state, token_owner_record, signatories_count,
+		signatories_signed_off_count, yes_votes_count, no_votes_count, instructions_executed_count,
+		instructions_count, instructions_next_index,  vote_type,  deny_vote_weight, veto_vote_weight,
+		 abstain_vote_weight, start_voting_at, draft_at, signing_off_at, voting_at, voting_at_slot,
+		voting_completed_at, executing_at, closed_at, execution_flags, max_vote_weight, max_voting_time,
+		vote_threshold_type, vote_threshold_percentage, name, description_link, program_id
+from (
+		(select 	address, account_type, governance, governing_token_mint, state, token_owner_record, signatories_count,
+				signatories_signed_off_count, yes_votes_count, no_votes_count, instructions_executed_count,
+				instructions_count, instructions_next_index, null as vote_type, null as deny_vote_weight, null as veto_vote_weight,
+				null as abstain_vote_weight, null as start_voting_at, draft_at, signing_off_at, voting_at, null as voting_at_slot,
+				voting_completed_at, executing_at, closed_at, execution_flags, max_vote_weight, null as max_voting_time,
+				vote_threshold_type, vote_threshold_percentage, name, description_link, program_id
+		from proposals_v1
+		where (address = any($1) or $1 is null) and (governance = any($2) or $2 is null)
+		and (draft_at >= $3 or $3 is null) and (draft_at <= $4 or $4 is null))
+		union all
+		(select 	address, account_type, governance, governing_token_mint, state, token_owner_record, signatories_count,
+				signatories_signed_off_count, null as yes_votes_count, null as no_votes_count, null as instructions_executed_count,
+				null as instructions_count, null as instructions_next_index, vote_type, deny_vote_weight, veto_vote_weight, abstain_vote_weight,
+				start_voting_at, draft_at, signing_off_at, voting_at, voting_at_slot, voting_completed_at, executing_at, closed_at, execution_flags,
+				max_vote_weight, max_voting_time, vote_threshold_type, vote_threshold_percentage, name, description_link, program_id
+		from proposals_v2
+		where (address = any($1) or $1 is null) and (governance = any($2) or $2 is null)
+		and (draft_at >= $3 or $3 is null) and (draft_at <= $4 or $4 is null))
+) p
+where $5 is null
+	or ($7 and (draft_at, address) < ($5, $6))
+	or (not $7 and (draft_at, address) > ($5, $6))
+order by
+	case when $7 then draft_at end desc,
+	case when $7 then address end desc,
+	case when not $7 then draft_at end asc,
+	case when not $7 then address end asc
+limit $8;
+ -- $1: addresses::text[]
+ -- $2: governances::text[]
+ -- $3: start_ts::timestamp
+ -- $4: stop_ts::timestamp
+ -- $5: cursor_draft_at::timestamp
+ -- $6: cursor_address::text
+ -- $7: forward::boolean
+ -- $8: limit::bigint";
+
+/// Load a page of spl governance proposals including V1 and V2, ordered by
+/// most recently drafted and starting after the given `(draft_at, address)`
+/// cursor, or before it if `forward` is false
+///
+/// # Errors
+/// This function fails if the underlying SQL query returns an error
+#[allow(clippy::too_many_arguments)]
+pub fn proposals_page(
+    conn: &Connection,
+    addresses: impl ToSql<Nullable<Array<Text>>, Pg>,
+    governances: impl ToSql<Nullable<Array<Text>>, Pg>,
+    start_time: impl ToSql<Nullable<Timestamp>, Pg>,
+    end_time: impl ToSql<Nullable<Timestamp>, Pg>,
+    cursor_draft_at: impl ToSql<Nullable<Timestamp>, Pg>,
+    cursor_address: impl ToSql<Nullable<Text>, Pg>,
+    forward: impl ToSql<Bool, Pg>,
+    limit: impl ToSql<BigInt, Pg>,
+) -> Result<Vec<SplGovernanceProposal>> {
+    diesel::sql_query(PROPOSALS_PAGE_QUERY)
+        .bind(addresses)
+        .bind(governances)
+        .bind(start_time)
+        .bind(end_time)
+        .bind(cursor_draft_at)
+        .bind(cursor_address)
+        .bind(forward)
+        .bind(limit)
+        .load(conn)
+        .context("Failed to load proposals")
+}
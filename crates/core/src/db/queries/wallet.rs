@@ -9,6 +9,7 @@ use diesel::{
 use crate::{
     db::{
         models::{CollectedCollection, Offer, WalletActivity},
+        tables::associated_token_accounts,
         Connection,
     },
     error::prelude::*,
@@ -138,3 +139,109 @@ pub fn collected_collections(
         .load(conn)
         .context("Failed to load wallet(s) collected collections")
 }
+
+/// Load a wallet's total balance of each of the given token mints, in the
+/// order given.  Mints the wallet holds no token accounts of have a balance
+/// of zero.
+///
+/// # Errors
+/// This function fails if the underlying SQL query returns an error or a
+/// stored token amount is negative
+pub fn token_balances<M: AsRef<str>>(
+    conn: &Connection,
+    owner: &str,
+    mints: &[M],
+) -> Result<Vec<u64>> {
+    let accounts: Vec<(String, i64)> = associated_token_accounts::table
+        .select((
+            associated_token_accounts::mint,
+            associated_token_accounts::amount,
+        ))
+        .filter(associated_token_accounts::owner.eq(owner))
+        .filter(associated_token_accounts::mint.eq(diesel::dsl::any(
+            mints.iter().map(AsRef::as_ref).collect::<Vec<_>>(),
+        )))
+        .load(conn)
+        .context("Failed to load token accounts")?;
+
+    mints
+        .iter()
+        .map(|mint| {
+            accounts
+                .iter()
+                .filter(|(m, _)| m == mint.as_ref())
+                .try_fold(0_u64, |sum, (_, amount)| {
+                    u64::try_from(*amount).map(|a| sum.saturating_add(a))
+                })
+                .context("Token account amount was negative")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow::Borrowed;
+
+    use super::*;
+    use crate::db::{
+        insert_into, models::AssociatedTokenAccount, ConnectionManager, Pool, PooledConnection,
+    };
+
+    /// Connect to the database named by `TEST_DATABASE_URL`, or return `None`
+    /// to skip tests requiring a database
+    fn connect() -> Option<PooledConnection> {
+        let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return None;
+        };
+
+        let pool = Pool::builder()
+            .max_size(1)
+            .build(ConnectionManager::new(url))
+            .unwrap();
+        let db = pool.get().unwrap();
+
+        crate::db::embedded_migrations::run(&db).unwrap();
+        db.begin_test_transaction().unwrap();
+
+        Some(db)
+    }
+
+    #[test]
+    fn test_token_balances() {
+        let Some(db) = connect() else { return };
+
+        let accounts = [
+            ("acct_1", "mint_a", "wallet", 3),
+            ("acct_2", "mint_a", "wallet", 4),
+            ("acct_3", "mint_b", "other", 5),
+            ("acct_4", "mint_c", "wallet", 0),
+        ]
+        .into_iter()
+        .map(|(address, mint, owner, amount)| AssociatedTokenAccount {
+            address: Borrowed(address),
+            mint: Borrowed(mint),
+            owner: Borrowed(owner),
+            amount,
+            slot: 1,
+            write_version: 1,
+        })
+        .collect::<Vec<_>>();
+
+        insert_into(associated_token_accounts::table)
+            .values(&accounts)
+            .execute(&db)
+            .unwrap();
+
+        assert_eq!(
+            token_balances(&db, "wallet", &["mint_b", "mint_a", "mint_c", "mint_d"]).unwrap(),
+            [0, 7, 0, 0]
+        );
+        assert_eq!(token_balances(&db, "other", &["mint_b"]).unwrap(), [5]);
+        assert!(
+            token_balances::<&str>(&db, "wallet", &[])
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
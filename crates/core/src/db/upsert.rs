@@ -0,0 +1,118 @@
+//! Conditional upserts for rows sourced from versioned account updates
+//!
+//! Geyser does not guarantee that account updates are delivered in order, so
+//! any row written from an account update records the `slot` and
+//! `write_version` it was read at.  An upsert guarded with
+//! [`UpsertIfNewer::if_newer`] only overwrites an existing row if the incoming
+//! row was written strictly later, leaving newer state untouched when a stale
+//! update arrives.
+
+use diesel::{
+    pg::Pg,
+    query_builder::{AstPass, InsertStatement, QueryFragment, QueryId},
+    query_dsl::RunQueryDsl,
+    result::QueryResult,
+    Column,
+};
+
+/// An `INSERT ... ON CONFLICT DO UPDATE` statement whose update only applies
+/// if the incoming row is newer than the stored row
+///
+/// Executing this query returns zero affected rows if the update was skipped.
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct IfNewer<Q, S, W> {
+    query: Q,
+    slot: S,
+    write_version: W,
+}
+
+/// Extension trait for guarding Postgres upserts against out-of-order account
+/// updates
+pub trait UpsertIfNewer: Sized {
+    /// Only apply the `DO UPDATE` clause of this upsert if the incoming
+    /// `(slot, write_version)` pair is greater than the stored one
+    ///
+    /// Missing values on either side are treated as older than any real
+    /// value.  The statement must have an `ON CONFLICT ... DO UPDATE` clause
+    /// and no `RETURNING` clause.
+    fn if_newer<S: Column, W: Column>(self, slot: S, write_version: W) -> IfNewer<Self, S, W> {
+        IfNewer {
+            query: self,
+            slot,
+            write_version,
+        }
+    }
+}
+
+impl<T, U, Op> UpsertIfNewer for InsertStatement<T, U, Op> {}
+
+impl<Q, S, W> QueryFragment<Pg> for IfNewer<Q, S, W>
+where
+    Q: QueryFragment<Pg>,
+    S: Column + QueryFragment<Pg>,
+    W: Column + QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.query.walk_ast(out.reborrow())?;
+
+        out.push_sql(" WHERE (COALESCE(");
+        self.slot.walk_ast(out.reborrow())?;
+        out.push_sql(", -1), COALESCE(");
+        self.write_version.walk_ast(out.reborrow())?;
+        out.push_sql(", -1)) < (COALESCE(excluded.");
+        out.push_identifier(S::NAME)?;
+        out.push_sql(", -1), COALESCE(excluded.");
+        out.push_identifier(W::NAME)?;
+        out.push_sql(", -1))");
+
+        Ok(())
+    }
+}
+
+impl<Q, S, W> QueryId for IfNewer<Q, S, W> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<Q, S, W, Conn> RunQueryDsl<Conn> for IfNewer<Q, S, W> {}
+
+#[cfg(test)]
+mod tests {
+    use diesel::{debug_query, pg::Pg, ExpressionMethods};
+
+    use super::UpsertIfNewer;
+    use crate::db::{excluded, insert_into, tables::twitter_handle_name_services};
+
+    #[test]
+    fn test_if_newer_sql() {
+        let query = insert_into(twitter_handle_name_services::table)
+            .values((
+                twitter_handle_name_services::address.eq("address"),
+                twitter_handle_name_services::slot.eq(1),
+                twitter_handle_name_services::write_version.eq(2),
+            ))
+            .on_conflict(twitter_handle_name_services::address)
+            .do_update()
+            .set(
+                twitter_handle_name_services::slot.eq(excluded(twitter_handle_name_services::slot)),
+            )
+            .if_newer(
+                twitter_handle_name_services::slot,
+                twitter_handle_name_services::write_version,
+            );
+
+        let sql = debug_query::<Pg, _>(&query).to_string();
+        let (sql, _binds) = sql.split_once(" -- binds:").unwrap();
+
+        assert!(sql.starts_with(r#"INSERT INTO "twitter_handle_name_services""#));
+        assert!(sql.ends_with(concat!(
+            r#" ON CONFLICT ("address") DO UPDATE SET "slot" = excluded."slot""#,
+            r#" WHERE (COALESCE("twitter_handle_name_services"."slot", -1),"#,
+            r#" COALESCE("twitter_handle_name_services"."write_version", -1))"#,
+            r#" < (COALESCE(excluded."slot", -1),"#,
+            r#" COALESCE(excluded."write_version", -1))"#,
+        )));
+    }
+}
@@ -24,6 +24,7 @@ pub extern crate bigdecimal;
 pub extern crate chrono;
 pub extern crate clap;
 pub extern crate num_cpus;
+pub extern crate serde_json;
 pub extern crate url;
 pub extern crate uuid;
 
@@ -60,6 +61,7 @@ pub mod prelude {
         pg::Pg,
         query_dsl::{BelongingToDsl, GroupByDsl, JoinOnDsl, QueryDsl, RunQueryDsl, SaveChangesDsl},
         result::OptionalExtension,
+        Connection as _,
     };
     #[cfg(feature = "db")]
     pub use diesel_full_text_search::{TsQueryExtensions, TsVectorExtensions};
@@ -16,16 +16,26 @@ pub static AUCTION: Pubkey = pubkey!("auctxRXPeJoc4817jDhf4HbjnhEcr1cCXenosMhK5R
 pub static ME_ESCROW: Pubkey = pubkey!("MEisE1HzehtrDpAAT8PnLHjpSSkRYakotTuJRPjTpo8");
 /// ``MagicEden`` program key 2
 pub static ME_HAUS: Pubkey = pubkey!("M2mx93ekt1fmXSVkTrUL9xVFHkmME8HTUi5Cyc5aF7K");
+/// ``MagicEden`` AMM program key
+pub static ME_AMM: Pubkey = pubkey!("mmm3XBJg5gk8XJxEKBvdgptZz6SgK4tXvn36sodowMc");
 /// Metaplex auction processing program key
 pub static METAPLEX: Pubkey = pubkey!("p1exdMJcjVao65QdewkaZRUnU6VPSXhus9n2GzWfh98");
 /// SPL token program key
 pub static TOKEN: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+/// SPL Token-2022 program key
+pub static TOKEN_2022: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PQnBqkxtamSPjRiaWtEL");
 /// MPL auction house program key
 pub static AUCTION_HOUSE: Pubkey = pubkey!("hausS13jsjafwWwGqZTUQRmWyvyxn9EQpqMwV1PBBmk");
+/// MPL auctioneer program key
+pub static AUCTIONEER: Pubkey = pubkey!("neer8g6yJq2mQM6KbnViEDAD4gr3gRZyMMf4F2p3MEh");
 /// HPL reward center program key
 pub static REWARD_CENTER: Pubkey = pubkey!("RwDDvPp7ta9qqUwxbBfShsNreBaSsKvFcHzMxfBC3Ki");
 /// Metaplex candy machine program key
 pub static CANDY_MACHINE: Pubkey = pubkey!("cndy3Z4yapfJBmL3ShUp5exZKqR3z33thTzeNMm2gRZ");
+/// Metaplex Candy Machine Core (v3) program key
+pub static CANDY_MACHINE_CORE: Pubkey = pubkey!("CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR");
+/// Metaplex Candy Guard program key
+pub static CANDY_GUARD: Pubkey = pubkey!("Guard1JwRhJkVH6XZhzoYxeBVQe872VH6QggF4BWmS9g");
 /// HPL graph program key
 pub static GRAPH_PROGRAM: Pubkey = pubkey!("grphAFGNvCjLKHeEmPNa91eGJChcUhrdaYYharcZCTQ");
 /// SPL name service program key
@@ -51,6 +61,12 @@ pub static TRIBECA_LOCKED_VOTER: Pubkey = pubkey!("LocktDzaV1W2Bm9DeZeiyz4J9zs4f
 pub static TRIBECA_GOVERN: Pubkey = pubkey!("Govz1VyoyLD5BL6CSCxUJLVLsQHRwjfFj1prNsdNg5Jw");
 /// Strata token bonding program key
 pub static TOKEN_BONDING: Pubkey = pubkey!("TBondmkCYxaPCKG4CHYfVTcwQ8on31xnJrPzk8F8WsS");
+/// Metaplex Bubblegum compressed NFT program key
+pub static BUBBLEGUM: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+/// SPL account compression program key
+pub static ACCOUNT_COMPRESSION: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+/// SPL no-op program key, used by account compression to log events
+pub static SPL_NOOP: Pubkey = pubkey!("noopb9bkMVfRPU8ShW8RpHqe9Vxp7qHiu4xGG5DJd");
 /// Cardinal .twitter namespace pubkey
 pub static CARDINAL_TWITTER_NAMESPACE: Pubkey =
     pubkey!("2zwXjjGEUrFMyE2CF2Ju4CJwMzwdbBMYnF2boEzgPhGu");
@@ -170,3 +186,22 @@ pub fn find_store_config(store: impl Borrow<Pubkey>) -> (Pubkey, u8) {
         &METAPLEX,
     )
 }
+
+/// Find the address of a Bubblegum `TreeConfig` account, given the Merkle tree
+/// address
+pub fn find_tree_authority(tree: impl Borrow<Pubkey>) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&tree.borrow().to_bytes()], &BUBBLEGUM)
+}
+
+/// Find the asset ID of a compressed NFT, given its Merkle tree address and
+/// leaf nonce
+pub fn find_compressed_asset(tree: impl Borrow<Pubkey>, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "asset".as_bytes(),
+            &tree.borrow().to_bytes(),
+            &nonce.to_le_bytes(),
+        ],
+        &BUBBLEGUM,
+    )
+}
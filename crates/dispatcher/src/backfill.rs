@@ -0,0 +1,471 @@
+//! Checkpointed dispatching of reindex requests for a range of slots
+
+use std::{collections::VecDeque, sync::Arc, time::Instant};
+
+use indexer_core::{
+    db::{insert_into, models::Backfill, tables::backfills, update, PooledConnection},
+    prelude::*,
+};
+use indexer_rabbitmq::{
+    geyser,
+    job_runner::{Message, Producer, SlotReindex},
+};
+use solana_client::{
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_config::RpcBlockConfig,
+    rpc_response::RpcConfirmedTransactionStatusWithSignature,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
+use solana_transaction_status::TransactionDetails;
+
+/// Options for a range backfill
+#[derive(Debug)]
+pub struct Opts {
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub programs: Vec<Pubkey>,
+    pub solana_endpoint: Option<String>,
+    pub startup: geyser::StartupType,
+    pub rate: f64,
+    pub checkpoint_interval: u64,
+}
+
+/// Find the first transaction of the first block after the given slot, so
+/// that signature history can be scanned backwards from the end of a range
+/// rather than from the chain tip.  Returns `None` if no later block has been
+/// confirmed yet.
+fn signature_after(rpc: &RpcClient, slot: u64) -> Result<Option<Signature>> {
+    let commitment = CommitmentConfig::confirmed();
+    let Some(&next_slot) = rpc
+        .get_blocks_with_limit_and_commitment(slot + 1, 1, commitment)
+        .context("Failed to get blocks after end slot")?
+        .first()
+    else {
+        return Ok(None);
+    };
+
+    let block = rpc
+        .get_block_with_config(next_slot, RpcBlockConfig {
+            transaction_details: Some(TransactionDetails::Signatures),
+            rewards: Some(false),
+            commitment: Some(commitment),
+            max_supported_transaction_version: Some(0),
+            ..RpcBlockConfig::default()
+        })
+        .with_context(|| format!("Failed to get block {next_slot}"))?;
+
+    block
+        .signatures
+        .into_iter()
+        .flatten()
+        .next()
+        .map(|s| s.parse().context("Failed to parse transaction signature"))
+        .transpose()
+}
+
+/// Read one page of a program's signature history, ending just before the
+/// given signature
+fn signature_page(
+    rpc: &RpcClient,
+    program: Pubkey,
+    before: Option<Signature>,
+) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+    rpc.get_signatures_for_address_with_config(&program, GetConfirmedSignaturesForAddress2Config {
+        before,
+        until: None,
+        limit: None,
+        commitment: Some(CommitmentConfig::confirmed()),
+    })
+    .with_context(|| format!("Failed to get signatures for program {program}"))
+}
+
+/// A program's signature history, read a page at a time backwards from the
+/// end of the range until the start slot
+struct SignatureScan {
+    program: Pubkey,
+    before: Option<Signature>,
+    /// Slots in the range read but not yet dispatched, latest first
+    pending: VecDeque<u64>,
+    /// The earliest slot read so far.  Signatures not yet read are all at or
+    /// before this slot.
+    earliest: Option<u64>,
+    done: bool,
+}
+
+impl SignatureScan {
+    fn new(program: Pubkey, before: Option<Signature>) -> Self {
+        Self {
+            program,
+            before,
+            pending: VecDeque::new(),
+            earliest: None,
+            done: false,
+        }
+    }
+
+    /// Returns true if signatures not yet read may fall after the given slot,
+    /// so another page must be read before it can be dispatched
+    fn behind(&self, slot: Option<u64>) -> bool {
+        !self.done
+            && match (self.earliest, slot) {
+                (Some(earliest), Some(slot)) => earliest > slot,
+                _ => true,
+            }
+    }
+
+    fn push_page(
+        &mut self,
+        page: &[RpcConfirmedTransactionStatusWithSignature],
+        start_slot: u64,
+        end_slot: u64,
+    ) -> Result<()> {
+        let Some(last) = page.last() else {
+            self.done = true;
+            return Ok(());
+        };
+
+        self.before = Some(
+            last.signature
+                .parse()
+                .context("Failed to parse transaction signature")?,
+        );
+        self.earliest = Some(last.slot);
+        self.done = last.slot < start_slot;
+
+        for slot in page
+            .iter()
+            .map(|s| s.slot)
+            .filter(|s| (start_slot..=end_slot).contains(s))
+        {
+            if self.pending.back() != Some(&slot) {
+                self.pending.push_back(slot);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Rate-limited dispatch of reindex requests, recording progress in the
+/// backfill's checkpoint
+struct Dispatch<'a> {
+    db: &'a PooledConnection,
+    producer: &'a Producer,
+    startup: geyser::StartupType,
+    checkpoint_interval: u64,
+    interval: StdDuration,
+    start: Instant,
+    job: Backfill,
+    unsaved: u64,
+}
+
+impl Dispatch<'_> {
+    /// Send a reindex request for the given slot.  The job's resume position
+    /// must already include the slot, so it is only saved once the request is
+    /// sent.
+    async fn send(&mut self, slot: u64) -> Result<()> {
+        let deadline = Instant::now() + self.interval;
+
+        self.producer
+            .write(Message::ReindexSlot(SlotReindex {
+                slot,
+                startup: self.startup,
+            }))
+            .await
+            .with_context(|| format!("Failed to dispatch reindex for slot {slot}"))?;
+
+        self.job.dispatched += 1;
+        self.unsaved += 1;
+
+        if self.unsaved >= self.checkpoint_interval {
+            self.save()?;
+            info!(
+                "Dispatched {} slot(s), up to slot {slot} ({:.0?} elapsed)",
+                self.job.dispatched,
+                self.start.elapsed()
+            );
+        }
+
+        smol::Timer::at(deadline).await;
+
+        Ok(())
+    }
+
+    /// Save any progress not yet recorded
+    fn save(&mut self) -> Result<()> {
+        if self.unsaved > 0 {
+            save_checkpoint(self.db, &self.job, false)?;
+            self.unsaved = 0;
+        }
+
+        Ok(())
+    }
+}
+
+/// Dispatch every slot of the range not yet dispatched, in order
+async fn dispatch_range(dispatch: &mut Dispatch<'_>, end_slot: u64) -> Result<()> {
+    let next_slot: u64 = dispatch.job.next_slot.try_into()?;
+
+    info!(
+        "Dispatching {} slot(s)",
+        (end_slot + 1).saturating_sub(next_slot)
+    );
+
+    for slot in next_slot..=end_slot {
+        dispatch.job.next_slot = (slot + 1).try_into()?;
+        dispatch.send(slot).await?;
+    }
+
+    Ok(())
+}
+
+/// Dispatch every slot in the range containing a transaction for one of the
+/// given programs, latest first, as each program's signature history is
+/// scanned backwards from the end of the range
+async fn dispatch_program_slots(
+    dispatch: &mut Dispatch<'_>,
+    endpoint: String,
+    programs: &[Pubkey],
+    start_slot: u64,
+) -> Result<()> {
+    let rpc = Arc::new(RpcClient::new(endpoint));
+    let end_slot = match dispatch.job.scan_end_slot {
+        Some(s) => s.try_into()?,
+        None => dispatch.job.end_slot.try_into()?,
+    };
+
+    if end_slot < start_slot {
+        return Ok(());
+    }
+
+    let end = {
+        let rpc = Arc::clone(&rpc);
+        smol::unblock(move || signature_after(&rpc, end_slot)).await?
+    };
+
+    let mut scans: Vec<_> = programs
+        .iter()
+        .map(|&p| SignatureScan::new(p, end))
+        .collect();
+    let mut last_sent = None;
+
+    info!(
+        "Scanning {} program(s) back from slot {end_slot}",
+        scans.len()
+    );
+
+    loop {
+        let latest = scans
+            .iter()
+            .filter_map(|s| s.pending.front().copied())
+            .max();
+
+        // A slot can only be dispatched once every program has been read
+        // past it, so later slots are never skipped
+        if let Some(scan) = scans.iter_mut().find(|s| s.behind(latest)) {
+            dispatch.save()?;
+
+            let (program, before) = (scan.program, scan.before);
+            let rpc = Arc::clone(&rpc);
+            let page = smol::unblock(move || signature_page(&rpc, program, before)).await?;
+
+            scan.push_page(&page, start_slot, end_slot)?;
+            continue;
+        }
+
+        let Some(slot) = latest else {
+            break;
+        };
+
+        for scan in &mut scans {
+            if scan.pending.front() == Some(&slot) {
+                scan.pending.pop_front();
+            }
+        }
+
+        if last_sent == Some(slot) {
+            continue;
+        }
+
+        dispatch.job.scan_end_slot = Some(i64::try_from(slot)? - 1);
+        dispatch.send(slot).await?;
+        last_sent = Some(slot);
+    }
+
+    Ok(())
+}
+
+/// Load the checkpoint for a backfill, creating it if this range has not been
+/// backfilled before
+fn checkpoint(db: &PooledConnection, opts: &Opts) -> Result<Backfill> {
+    let start_slot: i64 = opts.start_slot.try_into()?;
+    let end_slot: i64 = opts.end_slot.try_into()?;
+    let mut programs: Vec<_> = opts.programs.iter().map(ToString::to_string).collect();
+    programs.sort_unstable();
+
+    insert_into(backfills::table)
+        .values((
+            backfills::start_slot.eq(start_slot),
+            backfills::end_slot.eq(end_slot),
+            backfills::programs.eq(programs),
+            backfills::next_slot.eq(start_slot),
+        ))
+        .on_conflict((
+            backfills::start_slot,
+            backfills::end_slot,
+            backfills::programs,
+        ))
+        .do_update()
+        .set(backfills::updated_at.eq(Utc::now().naive_utc()))
+        .get_result::<Backfill>(db)
+        .context("Failed to load backfill checkpoint")
+}
+
+fn save_checkpoint(db: &PooledConnection, job: &Backfill, completed: bool) -> Result<()> {
+    let now = Utc::now().naive_utc();
+
+    update(backfills::table.filter(backfills::id.eq(job.id)))
+        .set((
+            backfills::next_slot.eq(job.next_slot),
+            backfills::scan_end_slot.eq(job.scan_end_slot),
+            backfills::dispatched.eq(job.dispatched),
+            backfills::updated_at.eq(now),
+            backfills::completed_at.eq(completed.then_some(now)),
+        ))
+        .execute(db)
+        .context("Failed to save backfill checkpoint")?;
+
+    Ok(())
+}
+
+/// Dispatch reindex requests for every slot in the requested range, resuming
+/// from the last recorded checkpoint if the range was previously started
+///
+/// Backfills filtered by program dispatch slots from the end of the range
+/// backwards, as the programs' signature histories are scanned.
+pub async fn run(db: &PooledConnection, producer: &Producer, opts: Opts) -> Result<()> {
+    ensure!(
+        opts.start_slot <= opts.end_slot,
+        "Start slot must not be after end slot"
+    );
+    ensure!(opts.rate > 0.0, "Dispatch rate must be positive");
+    ensure!(
+        opts.checkpoint_interval > 0,
+        "Checkpoint interval must be positive"
+    );
+
+    let job = checkpoint(db, &opts)?;
+
+    if job.completed_at.is_some() {
+        info!(
+            "Backfill {} of slots {}..={} already completed ({} request(s) dispatched)",
+            job.id, job.start_slot, job.end_slot, job.dispatched
+        );
+        return Ok(());
+    }
+
+    if job.dispatched > 0 {
+        info!(
+            "Resuming backfill {} after {} dispatched request(s)",
+            job.id, job.dispatched
+        );
+    } else {
+        info!("Starting backfill {}", job.id);
+    }
+
+    let mut dispatch = Dispatch {
+        db,
+        producer,
+        startup: opts.startup,
+        checkpoint_interval: opts.checkpoint_interval,
+        interval: StdDuration::from_secs_f64(opts.rate.recip()),
+        start: Instant::now(),
+        job,
+        unsaved: 0,
+    };
+
+    info!("Dispatching at up to {} slot(s)/s", opts.rate);
+
+    if opts.programs.is_empty() {
+        dispatch_range(&mut dispatch, opts.end_slot).await?;
+    } else {
+        let endpoint = opts
+            .solana_endpoint
+            .clone()
+            .context("A Solana RPC endpoint is required to filter by program")?;
+
+        dispatch_program_slots(&mut dispatch, endpoint, &opts.programs, opts.start_slot).await?;
+    }
+
+    let mut job = dispatch.job;
+    job.next_slot = (opts.end_slot + 1).try_into()?;
+    job.scan_end_slot = (!opts.programs.is_empty())
+        .then(|| i64::try_from(opts.start_slot).map(|s| s - 1))
+        .transpose()?;
+    save_checkpoint(db, &job, true)?;
+
+    info!(
+        "Backfill {} of slots {}..={} completed: {} request(s) dispatched in {:.0?}",
+        job.id,
+        job.start_slot,
+        job.end_slot,
+        job.dispatched,
+        dispatch.start.elapsed()
+    );
+
+    Ok(())
+}
+
+/// Print the progress of all recorded backfills
+pub fn status(db: &PooledConnection) -> Result<()> {
+    let jobs: Vec<Backfill> = backfills::table
+        .order(backfills::created_at.desc())
+        .load(db)
+        .context("Failed to load backfills")?;
+
+    if jobs.is_empty() {
+        println!("No backfills recorded");
+    }
+
+    for Backfill {
+        id,
+        start_slot,
+        end_slot,
+        programs,
+        next_slot,
+        dispatched,
+        created_at,
+        updated_at,
+        completed_at,
+        scan_end_slot,
+    } in jobs
+    {
+        let (done, next) = match scan_end_slot {
+            Some(scan_end) => (
+                end_slot - scan_end,
+                format!("scanning back from slot {scan_end}"),
+            ),
+            None if !programs.is_empty() => (0, format!("scanning back from slot {end_slot}")),
+            None => (next_slot - start_slot, format!("next slot {next_slot}")),
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let progress = done as f64 * 100.0 / (end_slot - start_slot + 1) as f64;
+        let status = completed_at.map_or_else(
+            || format!("in progress ({progress:.1}%, {next})"),
+            |t| format!("completed at {t}"),
+        );
+
+        println!(
+            "{id}: slots {start_slot}..={end_slot}{} - {status}, {dispatched} request(s) \
+             dispatched, started {created_at}, last updated {updated_at}",
+            if programs.is_empty() {
+                String::new()
+            } else {
+                format!(" for {}", programs.join(", "))
+            },
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,283 @@
+//! Inspection and replay of messages which failed to process
+
+use indexer_core::{
+    clap,
+    db::{delete, models::DeadLetter, tables::dead_letters, Pg, PooledConnection},
+    prelude::*,
+    queue_suffix::QueueSuffix,
+    serde_json,
+    uuid::Uuid,
+};
+use indexer_rabbitmq::{geyser, job_runner, lapin, suffix::Suffix};
+
+/// Queue kinds whose messages can be re-sent by [`replay`]
+const REPLAYABLE_QUEUES: &[&str] = &["geyser", "job_runner"];
+
+/// Filters for selecting dead-lettered messages
+#[derive(Debug, clap::Args)]
+pub struct Filter {
+    /// Only match messages consumed from this queue kind, e.g. `geyser`
+    #[arg(long)]
+    queue: Option<String>,
+
+    /// Only match messages whose ID mentions this program or account
+    #[arg(long)]
+    program: Option<String>,
+
+    /// Only match messages whose error chain contains this text
+    #[arg(long)]
+    error: Option<String>,
+
+    /// Maximum number of messages to match, oldest failures first.  All
+    /// matching messages are selected if omitted.
+    #[arg(long)]
+    limit: Option<i64>,
+}
+
+/// Commands for managing dead-lettered messages
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// List failed messages
+    List {
+        #[command(flatten)]
+        filter: Filter,
+
+        /// Print the full error chain and payload of each message
+        #[arg(long, short)]
+        verbose: bool,
+    },
+    /// Re-send failed messages to their original queues and remove them
+    Replay {
+        #[command(flatten)]
+        filter: Filter,
+
+        /// The network of the Geyser queue to replay Geyser messages to
+        #[arg(long, env)]
+        network: Option<geyser::Network>,
+
+        /// The startup type of the Geyser queue to replay Geyser messages to
+        #[arg(long, env, default_value_t = geyser::StartupType::Normal)]
+        startup: geyser::StartupType,
+
+        /// The suffix of the queues to replay messages to
+        #[command(flatten)]
+        queue_suffix: QueueSuffix,
+    },
+    /// Permanently delete failed messages
+    Purge {
+        #[command(flatten)]
+        filter: Filter,
+    },
+}
+
+fn filtered<'a>(
+    filter: &'a Filter,
+    queues: Option<&'a [&'a str]>,
+) -> dead_letters::BoxedQuery<'a, Pg> {
+    let Filter {
+        queue,
+        program,
+        error,
+        limit: _,
+    } = filter;
+
+    let mut query = dead_letters::table.into_boxed();
+
+    if let Some(queues) = queues {
+        query = query.filter(dead_letters::queue.eq_any(queues.iter().copied()));
+    }
+
+    if let Some(queue) = queue {
+        query = query.filter(dead_letters::queue.eq(queue));
+    }
+
+    if let Some(program) = program {
+        query = query.filter(dead_letters::message_id.ilike(format!("%{program}%")));
+    }
+
+    if let Some(error) = error {
+        query = query.filter(dead_letters::error.ilike(format!("%{error}%")));
+    }
+
+    query
+}
+
+/// Load the failed messages matching a filter, optionally only from the given
+/// queue kinds
+fn load(
+    db: &PooledConnection,
+    filter: &Filter,
+    queues: Option<&[&str]>,
+) -> Result<Vec<DeadLetter>> {
+    let mut query = filtered(filter, queues).order(dead_letters::first_failed_at.asc());
+
+    if let Some(limit) = filter.limit {
+        query = query.limit(limit);
+    }
+
+    let letters: Vec<DeadLetter> = query.load(db).context("Failed to load dead letters")?;
+    let count = i64::try_from(letters.len()).unwrap_or(i64::MAX);
+
+    if filter.limit.map_or(false, |l| count >= l) {
+        let total: i64 = filtered(filter, queues)
+            .count()
+            .get_result(db)
+            .context("Failed to count dead letters")?;
+
+        if total > count {
+            warn!("Only selected {count} of {total} matching message(s) due to --limit");
+        }
+    }
+
+    Ok(letters)
+}
+
+fn remove(db: &PooledConnection, ids: &[Uuid]) -> Result<usize> {
+    delete(dead_letters::table.filter(dead_letters::id.eq_any(ids)))
+        .execute(db)
+        .context("Failed to delete dead letters")
+}
+
+/// Print the failed messages matching a filter
+pub fn list(db: &PooledConnection, filter: &Filter, verbose: bool) -> Result<()> {
+    let letters = load(db, filter, None)?;
+
+    if letters.is_empty() {
+        println!("No failed messages found");
+    }
+
+    for DeadLetter {
+        id,
+        queue,
+        message_id,
+        payload,
+        error,
+        attempts,
+        first_failed_at,
+        last_failed_at,
+    } in letters
+    {
+        println!(
+            "{id} [{queue}] {message_id}: {attempts} attempt(s), first failed {first_failed_at}, \
+             last failed {last_failed_at}"
+        );
+
+        if verbose {
+            println!("{error}");
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        } else {
+            println!("    {}", error.lines().next().unwrap_or_default());
+        }
+    }
+
+    Ok(())
+}
+
+/// Permanently delete the failed messages matching a filter
+pub fn purge(db: &PooledConnection, filter: &Filter) -> Result<()> {
+    let ids: Vec<_> = load(db, filter, None)?.into_iter().map(|l| l.id).collect();
+    let count = remove(db, &ids)?;
+
+    info!("Purged {count} failed message(s)");
+
+    Ok(())
+}
+
+/// Re-send the failed messages matching a filter and remove them from the
+/// dead-letter table.  Messages which fail again will be re-recorded by the
+/// consumer.  Only messages from the Geyser and job runner queues can be
+/// replayed; messages from other queues are left in place.
+///
+/// # Errors
+/// This function fails if the filter selects a queue kind which cannot be
+/// replayed, if the dead letters cannot be loaded or if a message producer
+/// cannot be created.
+#[allow(clippy::too_many_arguments)]
+pub async fn replay(
+    db: &PooledConnection,
+    conn: &lapin::Connection,
+    sender: &str,
+    filter: &Filter,
+    network: Option<geyser::Network>,
+    startup: geyser::StartupType,
+    suffix: &Suffix,
+) -> Result<()> {
+    let mut network = network;
+    let mut geyser_prod = None;
+    let mut job_prod = None;
+    let mut replayed = vec![];
+
+    if let Some(queue) = &filter.queue {
+        ensure!(
+            REPLAYABLE_QUEUES.contains(&queue.as_str()),
+            "Replaying messages from {queue:?} queues is not supported"
+        );
+    }
+
+    for DeadLetter {
+        id,
+        queue,
+        message_id,
+        payload,
+        ..
+    } in load(db, filter, Some(REPLAYABLE_QUEUES))?
+    {
+        let res: Result<()> = match &*queue {
+            "geyser" => {
+                let prod = match geyser_prod {
+                    Some(ref p) => p,
+                    None => {
+                        let network = network
+                            .take()
+                            .context("A network is required to replay Geyser messages")?;
+                        let queue_type = geyser::QueueType::new(network, startup, suffix)?;
+
+                        geyser_prod.insert(
+                            geyser::Producer::new(conn, queue_type)
+                                .await
+                                .context("Failed to create Geyser message producer")?,
+                        )
+                    },
+                };
+
+                match serde_json::from_value::<geyser::Message>(payload) {
+                    Ok(m) => prod.write(m).await.map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                }
+            },
+            "job_runner" => {
+                let prod = match job_prod {
+                    Some(ref p) => p,
+                    None => {
+                        let queue_type = job_runner::QueueType::new(sender, suffix)?;
+
+                        job_prod.insert(
+                            job_runner::Producer::new(conn, queue_type)
+                                .await
+                                .context("Failed to create job message producer")?,
+                        )
+                    },
+                };
+
+                match serde_json::from_value::<job_runner::Message>(payload) {
+                    Ok(m) => prod.write(m).await.map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                }
+            },
+            q => Err(anyhow!(
+                "Replaying messages from {q:?} queues is not supported"
+            )),
+        };
+
+        match res {
+            Ok(()) => replayed.push(id),
+            Err(e) => warn!("Failed to replay {message_id} ({id}): {e:?}"),
+        }
+    }
+
+    let count = remove(db, &replayed)?;
+
+    info!("Replayed {count} failed message(s)");
+
+    Ok(())
+}
@@ -9,12 +9,16 @@
 )]
 #![warn(clippy::pedantic, clippy::cargo, missing_docs)]
 
-use indexer_core::{clap, clap::Parser, prelude::*};
+use indexer_core::{clap, clap::Parser, db, prelude::*};
 use indexer_rabbitmq::{
     geyser,
     job_runner::{self, Message, SlotReindex},
     lapin,
 };
+use solana_program::pubkey::Pubkey;
+
+mod backfill;
+mod dead_letter;
 
 #[derive(Debug, Parser)]
 #[command(about, version, long_about = None)]
@@ -35,7 +39,8 @@ struct Opts {
 enum Command {
     /// Request a refresh of a table of cached data
     RefreshTable {
-        /// The name of the table to request a data refresh for
+        /// The name of the table to request a data refresh for, e.g.
+        /// `collection_trends` or the name of a materialized view
         #[arg(env)]
         name: String,
     },
@@ -49,6 +54,85 @@ enum Command {
         #[arg(env)]
         slot: u64,
     },
+    /// Request a re-indexing of every block in a range of slots
+    ///
+    /// Progress is checkpointed in the database, so re-running the same
+    /// command after an interruption resumes where it left off.
+    Backfill {
+        #[command(flatten)]
+        db: db::ConnectArgs,
+
+        /// The startup-type hint for the target AMQP queue
+        #[arg(long, env, default_value_t = geyser::StartupType::Normal)]
+        startup: geyser::StartupType,
+
+        /// Only reindex slots containing transactions for these programs
+        #[arg(long, value_delimiter = ',')]
+        program: Vec<Pubkey>,
+
+        /// Solana RPC endpoint, required when filtering by program
+        #[arg(long, env)]
+        solana_endpoint: Option<String>,
+
+        /// Maximum number of reindex requests to dispatch per second
+        #[arg(long, env = "BACKFILL_RATE", default_value_t = 10.0)]
+        rate: f64,
+
+        /// Number of dispatched requests between progress checkpoints
+        #[arg(long, env = "BACKFILL_CHECKPOINT_INTERVAL", default_value_t = 100)]
+        checkpoint_interval: u64,
+
+        /// The first slot of the range to reindex
+        start_slot: u64,
+
+        /// The last slot of the range to reindex (inclusive)
+        end_slot: u64,
+    },
+    /// Show the progress of all recorded backfills
+    BackfillStatus {
+        #[command(flatten)]
+        db: db::ConnectArgs,
+    },
+    /// Inspect, replay, or purge messages which indexers failed to process
+    DeadLetters {
+        #[command(flatten)]
+        db: db::ConnectArgs,
+
+        #[command(subcommand)]
+        cmd: dead_letter::Command,
+    },
+}
+
+async fn amqp_connect(amqp_url: &str) -> Result<lapin::Connection> {
+    lapin::Connection::connect(
+        amqp_url,
+        lapin::ConnectionProperties::default()
+            .with_connection_name(
+                format!(
+                    "dispatcher@{}",
+                    hostname::get()
+                        .context("Failed to get system hostname")?
+                        .into_string()
+                        .map_err(|_| anyhow!("Failed to parse system hostname"))?,
+                )
+                .into(),
+            )
+            .with_executor(smol_executor_trait::Smol)
+            .with_reactor(async_reactor_trait::AsyncIo),
+    )
+    .await
+    .context("Failed to connect to the AMQP server")
+}
+
+async fn job_producer(conn: &lapin::Connection, sender: &str) -> Result<job_runner::Producer> {
+    let queue_type = job_runner::QueueType::new(
+        sender,
+        &indexer_rabbitmq::suffix::Suffix::ProductionUnchecked,
+    )?;
+
+    job_runner::Producer::new(conn, queue_type)
+        .await
+        .context("Failed to create message producer")
 }
 
 fn main() {
@@ -65,44 +149,104 @@ fn main() {
                 cmd,
             } = opts;
 
-            let conn = lapin::Connection::connect(
-                &amqp_url,
-                lapin::ConnectionProperties::default()
-                    .with_connection_name(
-                        format!(
-                            "dispatcher@{}",
-                            hostname::get()
-                                .context("Failed to get system hostname")?
-                                .into_string()
-                                .map_err(|_| anyhow!("Failed to parse system hostname"))?,
-                        )
-                        .into(),
-                    )
-                    .with_executor(smol_executor_trait::Smol)
-                    .with_reactor(async_reactor_trait::AsyncIo),
-            )
-            .await
-            .context("Failed to connect to the AMQP server")?;
-
-            let queue_type = job_runner::QueueType::new(
-                &sender,
-                &indexer_rabbitmq::suffix::Suffix::ProductionUnchecked,
-            )?;
-            let producer = job_runner::Producer::new(&conn, queue_type)
-                .await
-                .context("Failed to create message producer")?;
-
             match cmd {
-                Command::RefreshTable { name } => producer.write(Message::RefreshTable(name)).await,
+                Command::RefreshTable { name } => {
+                    let conn = amqp_connect(&amqp_url).await?;
+
+                    job_producer(&conn, &sender)
+                        .await?
+                        .write(Message::RefreshTable(name))
+                        .await
+                        .context("Failed to send requested message")
+                },
                 Command::ReindexBlock { slot, startup } => {
-                    producer
+                    let conn = amqp_connect(&amqp_url).await?;
+
+                    job_producer(&conn, &sender)
+                        .await?
                         .write(Message::ReindexSlot(SlotReindex { slot, startup }))
                         .await
+                        .context("Failed to send requested message")
                 },
-            }
-            .context("Failed to send requested message")?;
+                Command::Backfill {
+                    db,
+                    startup,
+                    program,
+                    solana_endpoint,
+                    rate,
+                    checkpoint_interval,
+                    start_slot,
+                    end_slot,
+                } => {
+                    let db::ConnectResult { pool, .. } =
+                        db::connect(db, db::ConnectMode::Write { migrate: false })
+                            .context("Failed to connect to Postgres")?;
+                    let conn = amqp_connect(&amqp_url).await?;
+                    let producer = job_producer(&conn, &sender).await?;
+
+                    backfill::run(&pool.get()?, &producer, backfill::Opts {
+                        start_slot,
+                        end_slot,
+                        programs: program,
+                        solana_endpoint,
+                        startup,
+                        rate,
+                        checkpoint_interval,
+                    })
+                    .await
+                },
+                Command::BackfillStatus { db } => {
+                    let db::ConnectResult { pool, .. } = db::connect(db, db::ConnectMode::Read)
+                        .context("Failed to connect to Postgres")?;
+
+                    backfill::status(&pool.get()?)
+                },
+                Command::DeadLetters {
+                    db,
+                    cmd: dead_letter::Command::List { filter, verbose },
+                } => {
+                    let db::ConnectResult { pool, .. } = db::connect(db, db::ConnectMode::Read)
+                        .context("Failed to connect to Postgres")?;
+
+                    dead_letter::list(&pool.get()?, &filter, verbose)
+                },
+                Command::DeadLetters {
+                    db,
+                    cmd: dead_letter::Command::Purge { filter },
+                } => {
+                    let db::ConnectResult { pool, .. } =
+                        db::connect(db, db::ConnectMode::Write { migrate: false })
+                            .context("Failed to connect to Postgres")?;
 
-            Ok(())
+                    dead_letter::purge(&pool.get()?, &filter)
+                },
+                Command::DeadLetters {
+                    db,
+                    cmd:
+                        dead_letter::Command::Replay {
+                            filter,
+                            network,
+                            startup,
+                            queue_suffix,
+                        },
+                } => {
+                    let db::ConnectResult { pool, .. } =
+                        db::connect(db, db::ConnectMode::Write { migrate: false })
+                            .context("Failed to connect to Postgres")?;
+                    let conn = amqp_connect(&amqp_url).await?;
+
+                    dead_letter::replay(
+                        &pool.get()?,
+                        &conn,
+                        &sender,
+                        &filter,
+                        network,
+                        startup,
+                        &queue_suffix.into(),
+                    )
+                    .await
+                },
+            }
         }))
     })
 }
@@ -0,0 +1,47 @@
+use indexer::prelude::*;
+use indexer_core::{
+    db::{insert_into, models::ProgramAccount, tables::program_accounts, UpsertIfNewer},
+    serde_json::Value,
+};
+
+use super::Client;
+
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    program: Pubkey,
+    account_type: String,
+    data: Value,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = ProgramAccount {
+        address: Owned(key.to_string()),
+        program_id: Owned(program.to_string()),
+        account_type: Owned(account_type),
+        data,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .batch()
+        .upsert(
+            "program_accounts",
+            row.address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(program_accounts::table)
+                    .values(&row)
+                    .on_conflict(program_accounts::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(program_accounts::slot, program_accounts::write_version)
+                    .execute(db)
+            },
+        )
+        .await
+        .context("Failed to insert program account")?;
+
+    Ok(())
+}
@@ -4,6 +4,7 @@ use indexer_core::{
         insert_into,
         models::{AuctionCache, ListingMetadata},
         tables::{auction_caches, listing_metadatas},
+        UpsertIfNewer,
     },
     pubkeys::find_auction_data_extended,
     util,
@@ -16,6 +17,8 @@ pub(crate) async fn process(
     client: &Client,
     cache_key: Pubkey,
     cache: AuctionCacheAccount,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let AuctionCacheAccount {
         metadata,
@@ -37,6 +40,8 @@ pub(crate) async fn process(
         auction_ext: Owned(bs58::encode(auction_ext).into_string()),
         vault: Owned(bs58::encode(vault).into_string()),
         auction_manager: Owned(bs58::encode(auction_manager).into_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     let listing_address: Cow<str> = Owned(bs58::encode(auction).into_string());
@@ -55,24 +60,28 @@ pub(crate) async fn process(
         .collect::<Result<Vec<_>>>()?;
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(auction_caches::table)
+        .transaction(move |db| {
+            let updated = insert_into(auction_caches::table)
                 .values(&values)
                 .on_conflict(auction_caches::address)
                 .do_update()
                 .set(&values)
+                .if_newer(auction_caches::slot, auction_caches::write_version)
                 .execute(db)?;
 
-            metadata_values.into_iter().try_for_each(|v| {
+            if updated == 0 {
+                return Ok(());
+            }
+
+            metadata_values.iter().try_for_each(|v| {
                 insert_into(listing_metadatas::table)
-                    .values(&v)
+                    .values(v)
                     .on_conflict((
                         listing_metadatas::listing_address,
                         listing_metadatas::metadata_address,
                     ))
                     .do_update()
-                    .set(&v)
+                    .set(v)
                     .execute(db)
                     .map(|_| ())
             })
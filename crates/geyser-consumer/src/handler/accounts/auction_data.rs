@@ -5,6 +5,7 @@ use indexer_core::{
         insert_into,
         models::{AuctionData, AuctionDataExt},
         tables::{auction_datas, auction_datas_ext},
+        UpsertIfNewer,
     },
     util,
 };
@@ -18,6 +19,8 @@ pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     auction: AuctionDataAccount,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let total_uncancelled_bids;
     let highest_bid;
@@ -68,6 +71,8 @@ pub(crate) async fn process(
             PriceFloor::BlindedPrice(_) => Some(-1),
         },
         total_uncancelled_bids,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -78,6 +83,7 @@ pub(crate) async fn process(
                 .on_conflict(auction_datas::address)
                 .do_update()
                 .set(&values)
+                .if_newer(auction_datas::slot, auction_datas::write_version)
                 .execute(db)
         })
         .await
@@ -90,6 +96,8 @@ pub(crate) async fn process_extended(
     client: &Client,
     key: Pubkey,
     ext: AuctionDataExtended,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let values = AuctionDataExt {
         address: Owned(bs58::encode(key).into_string()),
@@ -109,6 +117,8 @@ pub(crate) async fn process_extended(
                 .trim_end_matches('\0')
                 .to_owned(),
         ),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -119,6 +129,7 @@ pub(crate) async fn process_extended(
                 .on_conflict(auction_datas_ext::address)
                 .do_update()
                 .set(&values)
+                .if_newer(auction_datas_ext::slot, auction_datas_ext::write_version)
                 .execute(db)
         })
         .await
@@ -1,6 +1,6 @@
 use indexer::prelude::*;
 use indexer_core::db::{
-    insert_into, models::AuctionHouse as DbAuctionHouse, tables::auction_houses,
+    insert_into, models::AuctionHouse as DbAuctionHouse, tables::auction_houses, UpsertIfNewer,
 };
 use mpl_auction_house::AuctionHouse;
 
@@ -10,6 +10,8 @@ pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     account_data: AuctionHouse,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = DbAuctionHouse {
         address: Owned(bs58::encode(key).into_string()),
@@ -37,6 +39,8 @@ pub(crate) async fn process(
         auction_house_fee_account: Owned(
             bs58::encode(account_data.auction_house_fee_account).into_string(),
         ),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -47,6 +51,7 @@ pub(crate) async fn process(
                 .on_conflict(auction_houses::address)
                 .do_update()
                 .set(&row)
+                .if_newer(auction_houses::slot, auction_houses::write_version)
                 .execute(db)
         })
         .await
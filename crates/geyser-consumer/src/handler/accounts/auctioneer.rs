@@ -0,0 +1,66 @@
+use indexer::prelude::*;
+use indexer_core::{
+    db::{
+        insert_into, models::AuctioneerListingConfig, tables::auctioneer_listing_configs,
+        UpsertIfNewer,
+    },
+    util,
+};
+
+use super::Client;
+use crate::handler::programs::auctioneer::ListingConfig;
+
+pub(crate) async fn process_listing_config(
+    client: &Client,
+    key: Pubkey,
+    config: ListingConfig,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = AuctioneerListingConfig {
+        address: Owned(key.to_string()),
+        highest_bid_amount: config
+            .highest_bid
+            .amount
+            .try_into()
+            .context("Highest bid is too big to store")?,
+        highest_bid_trade_state: Owned(config.highest_bid.buyer_trade_state.to_string()),
+        start_time: util::unix_timestamp(config.start_time)?,
+        end_time: util::unix_timestamp(config.end_time)?,
+        time_ext_period: config.time_ext_period.try_into()?,
+        time_ext_delta: config.time_ext_delta.try_into()?,
+        allow_high_bid_cancel: config.allow_high_bid_cancel,
+        reserve_price: config
+            .reserve_price
+            .try_into()
+            .context("Reserve price is too big to store")?,
+        min_bid_increment: config.min_bid_increment.try_into()?,
+        bump: config.bump.into(),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .batch()
+        .upsert(
+            "auctioneer_listing_configs",
+            row.address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(auctioneer_listing_configs::table)
+                    .values(&row)
+                    .on_conflict(auctioneer_listing_configs::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(
+                        auctioneer_listing_configs::slot,
+                        auctioneer_listing_configs::write_version,
+                    )
+                    .execute(db)
+            },
+        )
+        .await
+        .context("Failed to insert listing config")?;
+
+    Ok(())
+}
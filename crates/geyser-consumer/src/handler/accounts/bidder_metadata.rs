@@ -1,6 +1,6 @@
 use indexer::prelude::*;
 use indexer_core::{
-    db::{insert_into, models::Bid, tables::bids},
+    db::{insert_into, models::Bid, tables::bids, UpsertIfNewer},
     util,
 };
 use metaplex_auction::processor::BidderMetadata as BidderMetadataAccount;
@@ -11,6 +11,8 @@ pub(crate) async fn process(
     client: &Client,
     _key: Pubkey,
     meta: BidderMetadataAccount,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let BidderMetadataAccount {
         bidder_pubkey,
@@ -28,6 +30,8 @@ pub(crate) async fn process(
             .try_into()
             .context("Last bid amount was too high to store")?,
         cancelled,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -38,6 +42,7 @@ pub(crate) async fn process(
                 .on_conflict((bids::listing_address, bids::bidder_address))
                 .do_update()
                 .set(&values)
+                .if_newer(bids::slot, bids::write_version)
                 .execute(db)
         })
         .await
@@ -1,5 +1,7 @@
 use indexer::prelude::*;
-use indexer_core::db::{insert_into, models::BondingChange, tables::bonding_changes};
+use indexer_core::db::{
+    insert_into, models::BondingChange, tables::bonding_changes, UpsertIfNewer,
+};
 use spl_token_bonding::state::TokenBondingV0;
 
 use super::Client;
@@ -8,6 +10,7 @@ pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     slot: i64,
+    write_version: i64,
     bonding: TokenBondingV0,
 ) -> Result<()> {
     let row = BondingChange {
@@ -18,11 +21,20 @@ pub(crate) async fn process(
             .context("Reserves from bonding was too big to store")?,
         current_supply_from_bonding: i64::try_from(bonding.supply_from_bonding)
             .context("Supply from bonding was too big to store")?,
+        write_version,
     };
 
     client
         .db()
-        .run(move |db| insert_into(bonding_changes::table).values(&row).execute(db))
+        .run(move |db| {
+            insert_into(bonding_changes::table)
+                .values(&row)
+                .on_conflict((bonding_changes::address, bonding_changes::slot))
+                .do_update()
+                .set(&row)
+                .if_newer(bonding_changes::slot, bonding_changes::write_version)
+                .execute(db)
+        })
         .await
         .context("Failed to insert token bonding change")?;
 
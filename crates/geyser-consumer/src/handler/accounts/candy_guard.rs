@@ -0,0 +1,164 @@
+use indexer::prelude::*;
+use indexer_core::{
+    db::{
+        delete, insert_into,
+        models::{CandyGuard as DbCandyGuard, CandyGuardGroup},
+        tables::{candy_guard_groups, candy_guards},
+        UpsertIfNewer,
+    },
+    util,
+};
+
+use super::Client;
+use crate::handler::programs::candy_guard::{CandyGuardData, CandyGuardHeader, GuardSet};
+
+fn key(key: Pubkey) -> Cow<'static, str> {
+    Owned(key.to_string())
+}
+
+fn guard_group(address: &str, label: String, guards: GuardSet) -> Result<CandyGuardGroup<'static>> {
+    let GuardSet {
+        bot_tax,
+        sol_payment,
+        token_payment,
+        start_date,
+        third_party_signer,
+        token_gate,
+        gatekeeper,
+        end_date,
+        allow_list,
+        mint_limit,
+        nft_payment,
+        redeemed_amount,
+        address_gate,
+        nft_gate,
+        nft_burn,
+        token_burn,
+        freeze_sol_payment,
+        freeze_token_payment,
+        program_gate,
+    } = guards;
+
+    Ok(CandyGuardGroup {
+        candy_guard_address: Owned(address.to_owned()),
+        label: Owned(label),
+        bot_tax_lamports: bot_tax
+            .as_ref()
+            .map(|g| g.lamports.try_into())
+            .transpose()?,
+        bot_tax_last_instruction: bot_tax.map(|g| g.last_instruction),
+        sol_payment_lamports: sol_payment
+            .as_ref()
+            .map(|g| g.lamports.try_into())
+            .transpose()?,
+        sol_payment_destination: sol_payment.map(|g| key(g.destination)),
+        token_payment_amount: token_payment
+            .as_ref()
+            .map(|g| g.amount.try_into())
+            .transpose()?,
+        token_payment_mint: token_payment.as_ref().map(|g| key(g.mint)),
+        token_payment_destination_ata: token_payment.map(|g| key(g.destination_ata)),
+        start_date: start_date.map(util::unix_timestamp).transpose()?,
+        third_party_signer_key: third_party_signer.map(key),
+        token_gate_amount: token_gate
+            .as_ref()
+            .map(|g| g.amount.try_into())
+            .transpose()?,
+        token_gate_mint: token_gate.map(|g| key(g.mint)),
+        gatekeeper_network: gatekeeper.as_ref().map(|g| key(g.gatekeeper_network)),
+        gatekeeper_expire_on_use: gatekeeper.map(|g| g.expire_on_use),
+        end_date: end_date.map(util::unix_timestamp).transpose()?,
+        allow_list_merkle_root: allow_list.map(|r| r.to_vec()),
+        mint_limit_id: mint_limit.as_ref().map(|g| g.id.into()),
+        mint_limit_limit: mint_limit.map(|g| g.limit.into()),
+        nft_payment_required_collection: nft_payment.as_ref().map(|g| key(g.required_collection)),
+        nft_payment_destination: nft_payment.map(|g| key(g.destination)),
+        redeemed_amount_maximum: redeemed_amount.map(TryInto::try_into).transpose()?,
+        address_gate_address: address_gate.map(key),
+        nft_gate_required_collection: nft_gate.map(key),
+        nft_burn_required_collection: nft_burn.map(key),
+        token_burn_amount: token_burn
+            .as_ref()
+            .map(|g| g.amount.try_into())
+            .transpose()?,
+        token_burn_mint: token_burn.map(|g| key(g.mint)),
+        freeze_sol_payment_lamports: freeze_sol_payment
+            .as_ref()
+            .map(|g| g.lamports.try_into())
+            .transpose()?,
+        freeze_sol_payment_destination: freeze_sol_payment.map(|g| key(g.destination)),
+        freeze_token_payment_amount: freeze_token_payment
+            .as_ref()
+            .map(|g| g.amount.try_into())
+            .transpose()?,
+        freeze_token_payment_mint: freeze_token_payment.as_ref().map(|g| key(g.mint)),
+        freeze_token_payment_destination_ata: freeze_token_payment.map(|g| key(g.destination_ata)),
+        program_gate_additional: program_gate
+            .map(|p| p.into_iter().map(|p| p.to_string()).collect()),
+    })
+}
+
+pub(crate) async fn process(
+    client: &Client,
+    address: Pubkey,
+    header: CandyGuardHeader,
+    data: CandyGuardData,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = DbCandyGuard {
+        address: Owned(address.to_string()),
+        base: Owned(header.base.to_string()),
+        bump: header.bump.into(),
+        authority: Owned(header.authority.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    let groups = std::iter::once(guard_group(&row.address, String::new(), data.default))
+        .chain(
+            data.groups
+                .into_iter()
+                .map(|g| guard_group(&row.address, g.label, g.guards)),
+        )
+        .collect::<Result<Vec<_>>>()?;
+
+    client
+        .batch()
+        .upsert(
+            "candy_guards",
+            row.address.clone(),
+            (slot, write_version),
+            move |db| {
+                let updated = insert_into(candy_guards::table)
+                    .values(&row)
+                    .on_conflict(candy_guards::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(candy_guards::slot, candy_guards::write_version)
+                    .execute(db)
+                    .context("failed to insert candy guard")?;
+
+                if updated == 0 {
+                    return Ok(0);
+                }
+
+                delete(
+                    candy_guard_groups::table
+                        .filter(candy_guard_groups::candy_guard_address.eq(&row.address)),
+                )
+                .execute(db)
+                .context("failed to delete candy guard groups")?;
+
+                insert_into(candy_guard_groups::table)
+                    .values(&groups)
+                    .execute(db)
+                    .context("failed to insert candy guard groups")?;
+
+                Result::<_>::Ok(updated)
+            },
+        )
+        .await?;
+
+    Ok(())
+}
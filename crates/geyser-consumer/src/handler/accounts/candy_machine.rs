@@ -1,4 +1,3 @@
-use futures_util::{future::join_all, Future};
 use indexer::prelude::*;
 use indexer_core::db::{
     custom_types::{EndSettingType as DbEndSettingType, WhitelistMintMode as DbWhitelistMintMode},
@@ -12,6 +11,7 @@ use indexer_core::db::{
         candy_machine_end_settings, candy_machine_gate_keeper_configs,
         candy_machine_hidden_settings, candy_machine_whitelist_mint_settings, candy_machines,
     },
+    Connection, UpsertIfNewer,
 };
 use mpl_candy_machine::{
     CandyMachine, CandyMachineData, CollectionPDA, Creator, EndSettingType, EndSettings,
@@ -24,6 +24,8 @@ pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     candy_machine: CandyMachine,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let cm = DbCandyMachine {
         address: Owned(bs58::encode(key).into_string()),
@@ -33,48 +35,60 @@ pub(crate) async fn process(
             .token_mint
             .map(|t| Owned(bs58::encode(t).into_string())),
         items_redeemed: candy_machine.items_redeemed.try_into()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(candy_machines::table)
-                .values(&cm)
-                .on_conflict(candy_machines::address)
-                .do_update()
-                .set(&cm)
-                .execute(db)
-        })
-        .await
-        .context("failed to insert candy machine")?;
+        .batch()
+        .upsert(
+            "candy_machines",
+            cm.address.clone(),
+            (slot, write_version),
+            move |db| {
+                let updated = insert_into(candy_machines::table)
+                    .values(&cm)
+                    .on_conflict(candy_machines::address)
+                    .do_update()
+                    .set(&cm)
+                    .if_newer(candy_machines::slot, candy_machines::write_version)
+                    .execute(db)
+                    .context("failed to insert candy machine")?;
 
-    let mut futures: Vec<std::pin::Pin<Box<dyn Future<Output = Result<()>> + Send>>> = vec![
-        Box::pin(process_data(client, key, candy_machine.data.clone())),
-        Box::pin(process_creators(client, key, candy_machine.data.creators)),
-    ];
+                if updated == 0 {
+                    return Ok(0);
+                }
 
-    if let Some(es) = candy_machine.data.end_settings {
-        futures.push(Box::pin(process_end_settings(client, key, es)));
-    };
+                let data = candy_machine.data.clone();
 
-    if let Some(hs) = candy_machine.data.hidden_settings {
-        futures.push(Box::pin(process_hidden_settings(client, key, hs)));
-    }
+                process_data(db, key, data.clone())?;
+                process_creators(db, key, data.creators)?;
 
-    if let Some(gk) = candy_machine.data.gatekeeper {
-        futures.push(Box::pin(process_gatekeeper_config(client, key, gk)));
-    }
+                if let Some(es) = data.end_settings {
+                    process_end_settings(db, key, es)?;
+                }
 
-    if let Some(wlms) = candy_machine.data.whitelist_mint_settings {
-        futures.push(Box::pin(process_whitelist_mint_settings(client, key, wlms)));
-    }
+                if let Some(hs) = data.hidden_settings {
+                    process_hidden_settings(db, key, hs)?;
+                }
 
-    join_all(futures).await;
+                if let Some(gk) = data.gatekeeper {
+                    process_gatekeeper_config(db, key, gk)?;
+                }
+
+                if let Some(wlms) = data.whitelist_mint_settings {
+                    process_whitelist_mint_settings(db, key, wlms)?;
+                }
+
+                Result::<_>::Ok(updated)
+            },
+        )
+        .await?;
 
     Ok(())
 }
 
-async fn process_data(client: &Client, key: Pubkey, data: CandyMachineData) -> Result<()> {
+fn process_data(db: &Connection, key: Pubkey, data: CandyMachineData) -> Result<()> {
     let cm_data = CMData {
         candy_machine_address: Owned(bs58::encode(key).into_string()),
         uuid: Owned(data.uuid),
@@ -88,22 +102,17 @@ async fn process_data(client: &Client, key: Pubkey, data: CandyMachineData) -> R
         items_available: data.items_available.try_into()?,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(candy_machine_datas::table)
-                .values(&cm_data)
-                .on_conflict(candy_machine_datas::candy_machine_address)
-                .do_update()
-                .set(&cm_data)
-                .execute(db)
-        })
-        .await
+    insert_into(candy_machine_datas::table)
+        .values(&cm_data)
+        .on_conflict(candy_machine_datas::candy_machine_address)
+        .do_update()
+        .set(&cm_data)
+        .execute(db)
         .context("failed to insert candy machine data")?;
     Ok(())
 }
 
-async fn process_creators(client: &Client, key: Pubkey, creators: Vec<Creator>) -> Result<()> {
+fn process_creators(db: &Connection, key: Pubkey, creators: Vec<Creator>) -> Result<()> {
     for creator in creators {
         let c = CMCreator {
             candy_machine_address: Owned(bs58::encode(key).into_string()),
@@ -112,26 +121,21 @@ async fn process_creators(client: &Client, key: Pubkey, creators: Vec<Creator>)
             share: creator.share.into(),
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(candy_machine_creators::table)
-                    .values(&c)
-                    .on_conflict((
-                        candy_machine_creators::candy_machine_address,
-                        candy_machine_creators::creator_address,
-                    ))
-                    .do_update()
-                    .set(&c)
-                    .execute(db)
-            })
-            .await
+        insert_into(candy_machine_creators::table)
+            .values(&c)
+            .on_conflict((
+                candy_machine_creators::candy_machine_address,
+                candy_machine_creators::creator_address,
+            ))
+            .do_update()
+            .set(&c)
+            .execute(db)
             .context("failed to insert creator")?;
     }
     Ok(())
 }
 
-async fn process_end_settings(client: &Client, key: Pubkey, es: EndSettings) -> Result<()> {
+fn process_end_settings(db: &Connection, key: Pubkey, es: EndSettings) -> Result<()> {
     let end_setting = CMEndSetting {
         candy_machine_address: Owned(bs58::encode(key).into_string()),
         end_setting_type: match es.end_setting_type {
@@ -141,22 +145,17 @@ async fn process_end_settings(client: &Client, key: Pubkey, es: EndSettings) ->
         number: es.number.try_into()?,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(candy_machine_end_settings::table)
-                .values(&end_setting)
-                .on_conflict(candy_machine_end_settings::candy_machine_address)
-                .do_update()
-                .set(&end_setting)
-                .execute(db)
-        })
-        .await
+    insert_into(candy_machine_end_settings::table)
+        .values(&end_setting)
+        .on_conflict(candy_machine_end_settings::candy_machine_address)
+        .do_update()
+        .set(&end_setting)
+        .execute(db)
         .context("failed to insert candy machine end setting")?;
     Ok(())
 }
 
-async fn process_hidden_settings(client: &Client, key: Pubkey, hs: HiddenSettings) -> Result<()> {
+fn process_hidden_settings(db: &Connection, key: Pubkey, hs: HiddenSettings) -> Result<()> {
     let hidden_setting = CMHiddenSetting {
         candy_machine_address: Owned(bs58::encode(key).into_string()),
         name: Owned(hs.name),
@@ -164,48 +163,34 @@ async fn process_hidden_settings(client: &Client, key: Pubkey, hs: HiddenSetting
         hash: hs.hash.to_vec(),
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(candy_machine_hidden_settings::table)
-                .values(&hidden_setting)
-                .on_conflict(candy_machine_hidden_settings::candy_machine_address)
-                .do_update()
-                .set(&hidden_setting)
-                .execute(db)
-        })
-        .await
+    insert_into(candy_machine_hidden_settings::table)
+        .values(&hidden_setting)
+        .on_conflict(candy_machine_hidden_settings::candy_machine_address)
+        .do_update()
+        .set(&hidden_setting)
+        .execute(db)
         .context("failed to insert hidden setting")?;
     Ok(())
 }
 
-async fn process_gatekeeper_config(
-    client: &Client,
-    key: Pubkey,
-    gk: GatekeeperConfig,
-) -> Result<()> {
+fn process_gatekeeper_config(db: &Connection, key: Pubkey, gk: GatekeeperConfig) -> Result<()> {
     let gatekeeper = CMGateKeeperConfig {
         candy_machine_address: Owned(bs58::encode(key).into_string()),
         gatekeeper_network: Owned(bs58::encode(gk.gatekeeper_network).into_string()),
         expire_on_use: gk.expire_on_use,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(candy_machine_gate_keeper_configs::table)
-                .values(&gatekeeper)
-                .on_conflict(candy_machine_gate_keeper_configs::candy_machine_address)
-                .do_update()
-                .set(&gatekeeper)
-                .execute(db)
-        })
-        .await
+    insert_into(candy_machine_gate_keeper_configs::table)
+        .values(&gatekeeper)
+        .on_conflict(candy_machine_gate_keeper_configs::candy_machine_address)
+        .do_update()
+        .set(&gatekeeper)
+        .execute(db)
         .context("failed to insert gate keeper config")?;
     Ok(())
 }
-async fn process_whitelist_mint_settings(
-    client: &Client,
+fn process_whitelist_mint_settings(
+    db: &Connection,
     key: Pubkey,
     wlms: WhitelistMintSettings,
 ) -> Result<()> {
@@ -224,17 +209,12 @@ async fn process_whitelist_mint_settings(
             .context("error casting u64 to i64!")?,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(candy_machine_whitelist_mint_settings::table)
-                .values(&whitelist_mint_setting)
-                .on_conflict(candy_machine_whitelist_mint_settings::candy_machine_address)
-                .do_update()
-                .set(&whitelist_mint_setting)
-                .execute(db)
-        })
-        .await
+    insert_into(candy_machine_whitelist_mint_settings::table)
+        .values(&whitelist_mint_setting)
+        .on_conflict(candy_machine_whitelist_mint_settings::candy_machine_address)
+        .do_update()
+        .set(&whitelist_mint_setting)
+        .execute(db)
         .context("failed to insert whitelist mint setting")?;
     Ok(())
 }
@@ -243,24 +223,38 @@ pub(crate) async fn process_collection_pda(
     client: &Client,
     key: Pubkey,
     collection_pda: CollectionPDA,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = CMCollectionPDA {
         address: Owned(bs58::encode(key).into_string()),
         mint: Owned(bs58::encode(collection_pda.mint).into_string()),
         candy_machine: Owned(bs58::encode(collection_pda.candy_machine).into_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(candy_machine_collection_pdas::table)
-                .values(&row)
-                .on_conflict(candy_machine_collection_pdas::address)
-                .do_update()
-                .set(&row)
-                .execute(db)
-        })
+        .batch()
+        .upsert(
+            "candy_machine_collection_pdas",
+            row.address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(candy_machine_collection_pdas::table)
+                    .values(&row)
+                    .on_conflict(candy_machine_collection_pdas::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(
+                        candy_machine_collection_pdas::slot,
+                        candy_machine_collection_pdas::write_version,
+                    )
+                    .execute(db)
+            },
+        )
         .await
         .context("failed to insert collection pda")?;
+
     Ok(())
 }
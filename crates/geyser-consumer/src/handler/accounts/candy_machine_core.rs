@@ -0,0 +1,178 @@
+use indexer::prelude::*;
+use indexer_core::db::{
+    delete, insert_into,
+    models::{CMConfigLineSetting, CMCreator, CMHiddenSetting, CoreCandyMachine},
+    tables::{
+        candy_machine_config_line_settings, candy_machine_creators, candy_machine_hidden_settings,
+        core_candy_machines,
+    },
+    Connection, UpsertIfNewer,
+};
+
+use super::Client;
+use crate::handler::programs::candy_machine_core::{
+    CandyMachine, ConfigLineSettings, Creator, HiddenSettings,
+};
+
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    candy_machine: CandyMachine,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let CandyMachine {
+        features,
+        authority,
+        mint_authority,
+        collection_mint,
+        items_redeemed,
+        data,
+    } = candy_machine;
+
+    let cm = CoreCandyMachine {
+        address: Owned(key.to_string()),
+        authority: Owned(authority.to_string()),
+        mint_authority: Owned(mint_authority.to_string()),
+        collection_mint: Owned(collection_mint.to_string()),
+        items_redeemed: items_redeemed.try_into()?,
+        items_available: data.items_available.try_into()?,
+        symbol: Owned(data.symbol.trim_end_matches('\0').to_owned()),
+        seller_fee_basis_points: data.seller_fee_basis_points.into(),
+        max_supply: data.max_supply.try_into()?,
+        is_mutable: data.is_mutable,
+        features: features.try_into()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .batch()
+        .upsert(
+            "core_candy_machines",
+            cm.address.clone(),
+            (slot, write_version),
+            move |db| {
+                let updated = insert_into(core_candy_machines::table)
+                    .values(&cm)
+                    .on_conflict(core_candy_machines::address)
+                    .do_update()
+                    .set(&cm)
+                    .if_newer(
+                        core_candy_machines::slot,
+                        core_candy_machines::write_version,
+                    )
+                    .execute(db)
+                    .context("failed to insert candy machine")?;
+
+                if updated == 0 {
+                    return Ok(0);
+                }
+
+                process_creators(db, &cm.address, data.creators.clone())?;
+                process_config_line_settings(db, &cm.address, data.config_line_settings.clone())?;
+                process_hidden_settings(db, &cm.address, data.hidden_settings.clone())?;
+
+                Result::<_>::Ok(updated)
+            },
+        )
+        .await?;
+
+    Ok(())
+}
+
+fn process_creators(db: &Connection, address: &str, creators: Vec<Creator>) -> Result<()> {
+    let rows = creators
+        .into_iter()
+        .map(|creator| CMCreator {
+            candy_machine_address: Owned(address.to_owned()),
+            creator_address: Owned(creator.address.to_string()),
+            verified: creator.verified,
+            share: creator.percentage_share.into(),
+        })
+        .collect::<Vec<_>>();
+
+    delete(
+        candy_machine_creators::table
+            .filter(candy_machine_creators::candy_machine_address.eq(address)),
+    )
+    .execute(db)
+    .context("failed to delete candy machine creators")?;
+
+    insert_into(candy_machine_creators::table)
+        .values(&rows)
+        .execute(db)
+        .context("failed to insert candy machine creators")?;
+
+    Ok(())
+}
+
+fn process_config_line_settings(
+    db: &Connection,
+    address: &str,
+    settings: Option<ConfigLineSettings>,
+) -> Result<()> {
+    let Some(settings) = settings else {
+        delete(
+            candy_machine_config_line_settings::table
+                .filter(candy_machine_config_line_settings::candy_machine_address.eq(address)),
+        )
+        .execute(db)
+        .context("failed to delete config line settings")?;
+
+        return Ok(());
+    };
+
+    let row = CMConfigLineSetting {
+        candy_machine_address: Owned(address.to_owned()),
+        prefix_name: Owned(settings.prefix_name.trim_end_matches('\0').to_owned()),
+        name_length: settings.name_length.try_into()?,
+        prefix_uri: Owned(settings.prefix_uri.trim_end_matches('\0').to_owned()),
+        uri_length: settings.uri_length.try_into()?,
+        is_sequential: settings.is_sequential,
+    };
+
+    insert_into(candy_machine_config_line_settings::table)
+        .values(&row)
+        .on_conflict(candy_machine_config_line_settings::candy_machine_address)
+        .do_update()
+        .set(&row)
+        .execute(db)
+        .context("failed to insert config line settings")?;
+
+    Ok(())
+}
+
+fn process_hidden_settings(
+    db: &Connection,
+    address: &str,
+    settings: Option<HiddenSettings>,
+) -> Result<()> {
+    let Some(settings) = settings else {
+        delete(
+            candy_machine_hidden_settings::table
+                .filter(candy_machine_hidden_settings::candy_machine_address.eq(address)),
+        )
+        .execute(db)
+        .context("failed to delete hidden setting")?;
+
+        return Ok(());
+    };
+
+    let row = CMHiddenSetting {
+        candy_machine_address: Owned(address.to_owned()),
+        name: Owned(settings.name),
+        uri: Owned(settings.uri),
+        hash: settings.hash.to_vec(),
+    };
+
+    insert_into(candy_machine_hidden_settings::table)
+        .values(&row)
+        .on_conflict(candy_machine_hidden_settings::candy_machine_address)
+        .do_update()
+        .set(&row)
+        .execute(db)
+        .context("failed to insert hidden setting")?;
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use cardinal_paid_claim_approver::state::PaidClaimApprover as PaidClaimApproverA
 use indexer::prelude::*;
 use indexer_core::db::{
     insert_into, models::CardinalPaidClaimApprover, tables::cardinal_paid_claim_approvers,
+    UpsertIfNewer,
 };
 
 use super::Client;
@@ -10,6 +11,8 @@ pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     paid_claim_approver: PaidClaimApproverAccount,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = CardinalPaidClaimApprover {
         paid_claim_approver_address: Owned(bs58::encode(key).into_string()),
@@ -27,6 +30,8 @@ pub(crate) async fn process(
         paid_claim_approver_payment_mint: Owned(
             bs58::encode(paid_claim_approver.payment_mint).into_string(),
         ),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
     trace!("Paid Claim Approver {:?}", row);
     client
@@ -37,6 +42,10 @@ pub(crate) async fn process(
                 .on_conflict(cardinal_paid_claim_approvers::paid_claim_approver_address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    cardinal_paid_claim_approvers::slot,
+                    cardinal_paid_claim_approvers::write_version,
+                )
                 .execute(db)
         })
         .await
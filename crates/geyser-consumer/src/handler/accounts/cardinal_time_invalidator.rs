@@ -1,7 +1,10 @@
 use cardinal_time_invalidator::state::TimeInvalidator as TimeInvalidatorAccount;
 use indexer::prelude::*;
 use indexer_core::{
-    db::{insert_into, models::CardinalTimeInvalidator, tables::cardinal_time_invalidators},
+    db::{
+        insert_into, models::CardinalTimeInvalidator, tables::cardinal_time_invalidators,
+        UpsertIfNewer,
+    },
     util,
 };
 
@@ -11,6 +14,8 @@ pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     time_invalidator: TimeInvalidatorAccount,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = CardinalTimeInvalidator {
         time_invalidator_address: Owned(bs58::encode(key).into_string()),
@@ -46,6 +51,8 @@ pub(crate) async fn process(
             .disable_partial_extension
             .map(TryFrom::try_from)
             .transpose()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
     trace!("Time invalidator {:?}", row);
     client
@@ -56,6 +63,10 @@ pub(crate) async fn process(
                 .on_conflict(cardinal_time_invalidators::time_invalidator_address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    cardinal_time_invalidators::slot,
+                    cardinal_time_invalidators::write_version,
+                )
                 .execute(db)
         })
         .await
@@ -12,6 +12,7 @@ use indexer_core::{
             cardinal_token_manager_invalidators, cardinal_token_managers,
             cardinal_use_invalidators,
         },
+        UpsertIfNewer,
     },
     util,
 };
@@ -112,13 +113,12 @@ async fn get_current_token_managers(
 }
 
 #[allow(clippy::too_many_lines)]
-async fn store_claim_event(
-    client: &Client,
+fn build_claim_event(
     key: Pubkey,
     token_manager: &TokenManagerAccount,
     current_token_manager: &CardinalTokenManagerQuery,
-) -> Result<()> {
-    let claim_event = CardinalClaimEvent {
+) -> Result<CardinalClaimEvent<'static>> {
+    Ok(CardinalClaimEvent {
         token_manager_address: Owned(bs58::encode(key).into_string()),
         version: token_manager.version.try_into()?,
         bump: token_manager.bump.try_into()?,
@@ -214,39 +214,18 @@ async fn store_claim_event(
             .map(Owned),
         use_invalidator_extension_usages: current_token_manager.use_invalidator_extension_usages,
         use_invalidator_max_usages: current_token_manager.use_invalidator_max_usages,
-    };
-
-    client
-        .db()
-        .run(move |db| {
-            insert_into(cardinal_claim_events::table)
-                .values(&claim_event)
-                .on_conflict((
-                    cardinal_claim_events::token_manager_address,
-                    cardinal_claim_events::state_changed_at,
-                ))
-                .do_update()
-                .set(&claim_event)
-                .execute(db)
-        })
-        .await
-        .context("Failed to insert Claim Event")?;
-
-    Ok(())
+    })
 }
 
 pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     token_manager: TokenManagerAccount,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let current_token_managers = get_current_token_managers(client, key).await?;
 
-    if !current_token_managers.is_empty() && token_manager.state == TokenManagerState::Claimed as u8
-    {
-        store_claim_event(client, key, &token_manager, &current_token_managers[0]).await?;
-    }
-
     let row = CardinalTokenManager {
         address: Owned(bs58::encode(key).into_string()),
         version: token_manager.version.into(),
@@ -272,70 +251,87 @@ pub(crate) async fn process(
         transfer_authority: token_manager
             .transfer_authority
             .map(|k| Owned(bs58::encode(k).into_string())),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
     trace!("Processing token manager {:?}", row);
 
+    let claim_event = if !current_token_managers.is_empty()
+        && token_manager.state == TokenManagerState::Claimed as u8
+    {
+        Some(build_claim_event(
+            key,
+            &token_manager,
+            &current_token_managers[0],
+        )?)
+    } else {
+        None
+    };
+
+    let invalidators = token_manager
+        .invalidators
+        .into_iter()
+        .map(|i| CardinalTokenManagerInvalidator {
+            token_manager_address: row.address.clone(),
+            invalidator: Owned(bs58::encode(i).into_string()),
+        })
+        .collect::<Vec<_>>();
+
     client
-        .db()
-        .run(move |db| {
-            insert_into(cardinal_token_managers::table)
+        .transaction(move |db| {
+            let updated = insert_into(cardinal_token_managers::table)
                 .values(&row)
                 .on_conflict(cardinal_token_managers::address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    cardinal_token_managers::slot,
+                    cardinal_token_managers::write_version,
+                )
                 .execute(db)
-        })
-        .await
-        .context("Failed to insert TokenManager")?;
+                .context("Failed to insert TokenManager")?;
 
-    let invalidator_strings: Vec<_> = token_manager
-        .invalidators
-        .into_iter()
-        .map(|i| bs58::encode(i).into_string())
-        .collect();
-    // process invalidators into separate table
-    process_invalidators(client, key, invalidator_strings).await?;
-    Ok(())
-}
+            if updated == 0 {
+                return Ok(());
+            }
 
-async fn process_invalidators(
-    client: &Client,
-    token_manager_address: Pubkey,
-    invalidators: Vec<String>,
-) -> Result<()> {
-    for invalidator in invalidators {
-        let row = CardinalTokenManagerInvalidator {
-            token_manager_address: Owned(bs58::encode(token_manager_address).into_string()),
-            invalidator: Owned(invalidator),
-        };
-        client
-            .db()
-            .run(move |db| {
-                delete(cardinal_token_manager_invalidators::table)
-                    .filter(
-                        cardinal_token_manager_invalidators::token_manager_address
-                            .eq(bs58::encode(token_manager_address).into_string()),
-                    )
+            if let Some(ref claim_event) = claim_event {
+                insert_into(cardinal_claim_events::table)
+                    .values(claim_event)
+                    .on_conflict((
+                        cardinal_claim_events::token_manager_address,
+                        cardinal_claim_events::state_changed_at,
+                    ))
+                    .do_update()
+                    .set(claim_event)
                     .execute(db)
-            })
-            .await
-            .context("failed to delete existing invalidators")?;
+                    .context("Failed to insert Claim Event")?;
+            }
+
+            // process invalidators into separate table
+            delete(cardinal_token_manager_invalidators::table)
+                .filter(
+                    cardinal_token_manager_invalidators::token_manager_address
+                        .eq(row.address.clone()),
+                )
+                .execute(db)
+                .context("failed to delete existing invalidators")?;
 
-        client
-            .db()
-            .run(move |db| {
+            for invalidator in &invalidators {
                 insert_into(cardinal_token_manager_invalidators::table)
-                    .values(&row)
+                    .values(invalidator)
                     .on_conflict((
                         cardinal_token_manager_invalidators::token_manager_address,
                         cardinal_token_manager_invalidators::invalidator,
                     ))
                     .do_update()
-                    .set(&row)
+                    .set(invalidator)
                     .execute(db)
-            })
-            .await
-            .context("failed to insert invalidator")?;
-    }
-    Ok(())
+                    .context("failed to insert invalidator")?;
+            }
+
+            Result::<_>::Ok(())
+        })
+        .await
+        .context("Failed to store TokenManager")
 }
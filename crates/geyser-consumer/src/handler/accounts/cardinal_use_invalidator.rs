@@ -1,7 +1,7 @@
 use cardinal_use_invalidator::state::UseInvalidator as UseInvalidatorAccount;
 use indexer::prelude::*;
 use indexer_core::db::{
-    insert_into, models::CardinalUseInvalidator, tables::cardinal_use_invalidators,
+    insert_into, models::CardinalUseInvalidator, tables::cardinal_use_invalidators, UpsertIfNewer,
 };
 
 use super::Client;
@@ -10,6 +10,8 @@ pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     use_invalidator: UseInvalidatorAccount,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = CardinalUseInvalidator {
         use_invalidator_address: Owned(bs58::encode(key).into_string()),
@@ -44,6 +46,8 @@ pub(crate) async fn process(
             .max_usages
             .map(TryFrom::try_from)
             .transpose()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
     client
         .db()
@@ -53,6 +57,10 @@ pub(crate) async fn process(
                 .on_conflict(cardinal_use_invalidators::use_invalidator_address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    cardinal_use_invalidators::slot,
+                    cardinal_use_invalidators::write_version,
+                )
                 .execute(db)
         })
         .await
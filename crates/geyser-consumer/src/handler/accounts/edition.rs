@@ -3,6 +3,7 @@ use indexer_core::db::{
     insert_into,
     models::{Edition, MasterEdition},
     tables::{editions, master_editions},
+    UpsertIfNewer,
 };
 use mpl_token_metadata::state::{
     Edition as EditionAccount, MasterEdition as MasterEditionTrait,
@@ -16,6 +17,7 @@ pub(crate) async fn process(
     edition_key: Pubkey,
     edition: EditionAccount,
     slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = Edition {
         address: Owned(bs58::encode(edition_key).into_string()),
@@ -28,18 +30,27 @@ pub(crate) async fn process(
             slot.try_into()
                 .context("Edition slot was too big to store")?,
         ),
+        write_version: write_version
+            .try_into()
+            .context("Edition write version was too big to store")?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(editions::table)
-                .values(&row)
-                .on_conflict(editions::address)
-                .do_update()
-                .set(&row)
-                .execute(db)
-        })
+        .batch()
+        .upsert(
+            "editions",
+            row.address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(editions::table)
+                    .values(&row)
+                    .on_conflict(editions::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(editions::slot, editions::write_version)
+                    .execute(db)
+            },
+        )
         .await
         .context("Failed to insert edition")?;
 
@@ -51,6 +62,7 @@ pub(crate) async fn process_master(
     master_key: Pubkey,
     master_edition: MasterEditionV2Account,
     slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = MasterEdition {
         address: Owned(bs58::encode(master_key).into_string()),
@@ -69,18 +81,27 @@ pub(crate) async fn process_master(
             slot.try_into()
                 .context("Master edition slot was too big to store")?,
         ),
+        write_version: write_version
+            .try_into()
+            .context("Master edition write version was too big to store")?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(master_editions::table)
-                .values(&row)
-                .on_conflict(master_editions::address)
-                .do_update()
-                .set(&row)
-                .execute(db)
-        })
+        .batch()
+        .upsert(
+            "master_editions",
+            row.address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(master_editions::table)
+                    .values(&row)
+                    .on_conflict(master_editions::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(master_editions::slot, master_editions::write_version)
+                    .execute(db)
+            },
+        )
         .await
         .context("Failed to insert master edition")?;
 
@@ -3,24 +3,23 @@ use indexer::prelude::*;
 use indexer_core::{
     bigdecimal::BigDecimal,
     db::{
-        delete, insert_into, models, select,
+        insert_into, models,
         tables::{geno_habitat_datas, geno_rental_agreements},
-        update,
+        update, UpsertIfNewer,
     },
     util,
 };
 
 use super::Client;
 
-async fn process_rent(
-    client: &Client,
+fn rent_row(
     addr: String,
     rent: RentalAgreement,
     slot: i64,
     write_version: i64,
-) -> Result<()> {
-    let row = models::GenoRentalAgreement {
-        habitat_address: Owned(addr.clone()),
+) -> Result<models::GenoRentalAgreement<'static>> {
+    Ok(models::GenoRentalAgreement {
+        habitat_address: Owned(addr),
         alchemist: rent.alchemist.map(|a| Owned(a.to_string())),
         rental_period: rent
             .rental_period
@@ -43,37 +42,7 @@ async fn process_rent(
         open_market: rent.open_market,
         slot,
         write_version,
-    };
-
-    client
-        .db()
-        .run(move |db| {
-            let geno_rental = select(exists(
-                geno_rental_agreements::table.filter(
-                    geno_rental_agreements::habitat_address
-                        .eq(addr.clone())
-                        .and(geno_rental_agreements::slot.lt(slot)),
-                ),
-            ))
-            .get_result::<bool>(db);
-
-            if geno_rental == Ok(true) {
-                delete(
-                    geno_rental_agreements::table
-                        .filter(geno_rental_agreements::habitat_address.eq(addr)),
-                )
-                .execute(db)?;
-            }
-
-            insert_into(geno_rental_agreements::table)
-                .values(&row)
-                .on_conflict_do_nothing()
-                .execute(db)
-        })
-        .await
-        .context("Failed to insert Genopets habitat data")?;
-
-    Ok(())
+    })
 }
 
 #[allow(clippy::too_many_lines)]
@@ -96,25 +65,10 @@ pub(crate) async fn process(
         .context("failed to get primary habitat daily cap")?
         .unwrap_or_default();
 
-    if let Some(parent) = habitat.parent_habitat {
-        client
-            .db()
-            .run({
-                let daily_cap = daily_ki_harvesting_cap.clone();
-                move |db| {
-                    update(
-                        geno_habitat_datas::table
-                            .filter(geno_habitat_datas::habitat_mint.eq(parent.to_string())),
-                    )
-                    .set(geno_habitat_datas::daily_ki_harvesting_cap.eq(daily_cap))
-                    .execute(db)
-                }
-            })
-            .await
-            .context("failed to update daily ki harvesting cap of parent habitat ")?;
-
-        daily_ki_harvesting_cap = 0.into();
-    }
+    // A sub-habitat's harvesting cap is pooled into its parent's
+    let parent_cap = habitat
+        .parent_habitat
+        .map(|p| (p, std::mem::take(&mut daily_ki_harvesting_cap)));
 
     let row = models::GenoHabitatData {
         address: Owned(addr.clone()),
@@ -182,36 +136,56 @@ pub(crate) async fn process(
         has_max_ki: None,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            let geno_exists = select(exists(
-                geno_habitat_datas::table.filter(
-                    geno_habitat_datas::address
-                        .eq(key.to_string())
-                        .and(geno_habitat_datas::slot.lt(slot)),
-                ),
-            ))
-            .get_result::<bool>(db);
+    let rent = habitat
+        .rental_agreement
+        .map(|r| rent_row(addr, r, slot, write_version))
+        .transpose()?;
 
-            if geno_exists == Ok(true) {
-                delete(
+    let updated = client
+        .transaction(move |db| {
+            if let Some((parent, daily_cap)) = parent_cap {
+                update(
                     geno_habitat_datas::table
-                        .filter(geno_habitat_datas::address.eq(key.to_string())),
+                        .filter(geno_habitat_datas::habitat_mint.eq(parent.to_string())),
                 )
-                .execute(db)?;
+                .set(geno_habitat_datas::daily_ki_harvesting_cap.eq(daily_cap))
+                .execute(db)
+                .context("failed to update daily ki harvesting cap of parent habitat ")?;
             }
 
-            insert_into(geno_habitat_datas::table)
+            let updated = insert_into(geno_habitat_datas::table)
                 .values(&row)
-                .on_conflict_do_nothing()
+                .on_conflict(geno_habitat_datas::address)
+                .do_update()
+                .set(&row)
+                .if_newer(geno_habitat_datas::slot, geno_habitat_datas::write_version)
                 .execute(db)
+                .context("Failed to insert Genopets habitat data")?;
+
+            if updated == 0 {
+                return Result::<_>::Ok(updated);
+            }
+
+            if let Some(ref rent) = rent {
+                insert_into(geno_rental_agreements::table)
+                    .values(rent)
+                    .on_conflict(geno_rental_agreements::habitat_address)
+                    .do_update()
+                    .set(rent)
+                    .if_newer(
+                        geno_rental_agreements::slot,
+                        geno_rental_agreements::write_version,
+                    )
+                    .execute(db)
+                    .context("Failed to insert Genopets rental agreement")?;
+            }
+
+            Ok(updated)
         })
-        .await
-        .context("Failed to insert Genopets habitat data")?;
+        .await?;
 
-    if let Some(rent) = habitat.rental_agreement {
-        process_rent(client, addr, rent, slot, write_version).await?;
+    if updated == 0 {
+        return Ok(());
     }
 
     client
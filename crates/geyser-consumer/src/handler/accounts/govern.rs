@@ -12,6 +12,7 @@ use indexer_core::db::{
         governance_parameters, governors, proposal_account_metas, proposal_instructions,
         proposal_metas, proposals, votes,
     },
+    Connection, UpsertIfNewer,
 };
 use tribeca_govern::{
     GovernanceParameters, Governor, Proposal, ProposalAccountMeta, ProposalInstruction,
@@ -20,7 +21,13 @@ use tribeca_govern::{
 
 use super::Client;
 
-pub(crate) async fn process_governor(client: &Client, key: Pubkey, g: Governor) -> Result<()> {
+pub(crate) async fn process_governor(
+    client: &Client,
+    key: Pubkey,
+    g: Governor,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
     let row = GovernorModel {
         address: Owned(key.to_string()),
         base: Owned(g.base.to_string()),
@@ -28,28 +35,34 @@ pub(crate) async fn process_governor(client: &Client, key: Pubkey, g: Governor)
         proposal_count: g.proposal_count.try_into()?,
         electorate: Owned(g.electorate.to_string()),
         smart_wallet: Owned(g.smart_wallet.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(governors::table)
+        .transaction(move |db| {
+            let updated = insert_into(governors::table)
                 .values(&row)
                 .on_conflict(governors::address)
                 .do_update()
                 .set(&row)
+                .if_newer(governors::slot, governors::write_version)
                 .execute(db)
+                .context("failed to insert governor ")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            process_governance_params(db, key, &g.params)
         })
         .await
-        .context("failed to insert governor ")?;
-
-    process_governance_params(client, key, g.params).await
 }
 
-async fn process_governance_params(
-    client: &Client,
+fn process_governance_params(
+    db: &Connection,
     key: Pubkey,
-    param: GovernanceParameters,
+    param: &GovernanceParameters,
 ) -> Result<()> {
     let row = GovernanceParameterModel {
         governor_address: Owned(key.to_string()),
@@ -59,23 +72,24 @@ async fn process_governance_params(
         timelock_delay_seconds: param.timelock_delay_seconds,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(governance_parameters::table)
-                .values(&row)
-                .on_conflict(governance_parameters::governor_address)
-                .do_update()
-                .set(&row)
-                .execute(db)
-        })
-        .await
+    insert_into(governance_parameters::table)
+        .values(&row)
+        .on_conflict(governance_parameters::governor_address)
+        .do_update()
+        .set(&row)
+        .execute(db)
         .context("failed to insert governance parameters ")?;
 
     Ok(())
 }
 
-pub(crate) async fn process_vote(client: &Client, key: Pubkey, v: Vote) -> Result<()> {
+pub(crate) async fn process_vote(
+    client: &Client,
+    key: Pubkey,
+    v: Vote,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
     let row = VoteModel {
         address: Owned(key.to_string()),
         proposal: Owned(v.proposal.to_string()),
@@ -83,6 +97,8 @@ pub(crate) async fn process_vote(client: &Client, key: Pubkey, v: Vote) -> Resul
         bump: v.bump.try_into()?,
         side: v.side.try_into()?,
         weight: v.weight.try_into()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -93,6 +109,7 @@ pub(crate) async fn process_vote(client: &Client, key: Pubkey, v: Vote) -> Resul
                 .on_conflict(votes::address)
                 .do_update()
                 .set(&row)
+                .if_newer(votes::slot, votes::write_version)
                 .execute(db)
         })
         .await
@@ -101,12 +118,20 @@ pub(crate) async fn process_vote(client: &Client, key: Pubkey, v: Vote) -> Resul
     Ok(())
 }
 
-pub(crate) async fn process_meta(client: &Client, key: Pubkey, meta: ProposalMeta) -> Result<()> {
+pub(crate) async fn process_meta(
+    client: &Client,
+    key: Pubkey,
+    meta: ProposalMeta,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
     let row = ProposalMetaModel {
         address: Owned(key.to_string()),
         proposal: Owned(meta.proposal.to_string()),
         title: Owned(meta.title),
         description_link: Owned(meta.description_link),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -117,6 +142,7 @@ pub(crate) async fn process_meta(client: &Client, key: Pubkey, meta: ProposalMet
                 .on_conflict(proposal_metas::address)
                 .do_update()
                 .set(&row)
+                .if_newer(proposal_metas::slot, proposal_metas::write_version)
                 .execute(db)
         })
         .await
@@ -129,6 +155,8 @@ pub(crate) async fn process_proposal(
     client: &Client,
     key: Pubkey,
     proposal: Proposal,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = ProposalModel {
         address: Owned(key.to_string()),
@@ -146,26 +174,32 @@ pub(crate) async fn process_proposal(
         voting_ends_at: proposal.voting_ends_at,
         queued_at: proposal.queued_at,
         queued_transaction: Owned(proposal.queued_transaction.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(proposals::table)
+        .transaction(move |db| {
+            let updated = insert_into(proposals::table)
                 .values(&row)
                 .on_conflict(proposals::address)
                 .do_update()
                 .set(&row)
+                .if_newer(proposals::slot, proposals::write_version)
                 .execute(db)
+                .context("failed to insert proposal ")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            process_instructions(db, key, proposal.instructions.clone())
         })
         .await
-        .context("failed to insert proposal ")?;
-
-    process_instructions(client, key, proposal.instructions).await
 }
 
-pub(crate) async fn process_instructions(
-    client: &Client,
+pub(crate) fn process_instructions(
+    db: &Connection,
     key: Pubkey,
     instructions: Vec<ProposalInstruction>,
 ) -> Result<()> {
@@ -176,29 +210,24 @@ pub(crate) async fn process_instructions(
             data: ins.data,
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(proposal_instructions::table)
-                    .values(&i)
-                    .on_conflict((
-                        proposal_instructions::proposal_address,
-                        proposal_instructions::program_id,
-                    ))
-                    .do_update()
-                    .set(&i)
-                    .execute(db)
-            })
-            .await
+        insert_into(proposal_instructions::table)
+            .values(&i)
+            .on_conflict((
+                proposal_instructions::proposal_address,
+                proposal_instructions::program_id,
+            ))
+            .do_update()
+            .set(&i)
+            .execute(db)
             .context("failed to insert proposal instruction ")?;
-        process_account_meta(client, key, ins.program_id, ins.keys).await?;
+        process_account_meta(db, key, ins.program_id, ins.keys)?;
     }
 
     Ok(())
 }
 
-async fn process_account_meta(
-    client: &Client,
+fn process_account_meta(
+    db: &Connection,
     key: Pubkey,
     program_id: Pubkey,
     account_metas: Vec<ProposalAccountMeta>,
@@ -212,21 +241,16 @@ async fn process_account_meta(
             is_writable: acc.is_writable,
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(proposal_account_metas::table)
-                    .values(&row)
-                    .on_conflict((
-                        proposal_account_metas::proposal_address,
-                        proposal_account_metas::program_id,
-                        proposal_account_metas::pubkey,
-                    ))
-                    .do_update()
-                    .set(&row)
-                    .execute(db)
-            })
-            .await
+        insert_into(proposal_account_metas::table)
+            .values(&row)
+            .on_conflict((
+                proposal_account_metas::proposal_address,
+                proposal_account_metas::program_id,
+                proposal_account_metas::pubkey,
+            ))
+            .do_update()
+            .set(&row)
+            .execute(db)
             .context("failed to insert proposal account metadata ")?;
     }
     Ok(())
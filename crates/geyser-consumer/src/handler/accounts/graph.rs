@@ -6,6 +6,7 @@ use indexer_core::{
         models::{FeedEventWallet, FollowEvent, GraphConnection as DbGraphConnection},
         select,
         tables::{feed_event_wallets, feed_events, follow_events, graph_connections},
+        UpsertIfNewer,
     },
     util,
     uuid::Uuid,
@@ -34,25 +35,25 @@ pub(crate) async fn process(
     };
 
     client
-        .db()
-        .run(move |db| {
+        .transaction(move |db| {
             let graph_connection_exists = select(exists(
                 graph_connections::table.filter(graph_connections::address.eq(row.address.clone())),
             ))
             .get_result::<bool>(db);
 
-            insert_into(graph_connections::table)
+            let updated = insert_into(graph_connections::table)
                 .values(&row)
                 .on_conflict(graph_connections::address)
                 .do_update()
                 .set(&row)
+                .if_newer(graph_connections::slot, graph_connections::write_version)
                 .execute(db)?;
 
-            if Ok(true) == graph_connection_exists {
+            if updated == 0 || Ok(true) == graph_connection_exists {
                 return Ok(());
             }
 
-            db.build_transaction().read_write().run(|| {
+            db.transaction(|| {
                 let feed_event_id = insert_into(feed_events::table)
                     .default_values()
                     .returning(feed_events::id)
@@ -62,14 +63,14 @@ pub(crate) async fn process(
                 insert_into(follow_events::table)
                     .values(&FollowEvent {
                         feed_event_id,
-                        graph_connection_address: row.address,
+                        graph_connection_address: row.address.clone(),
                     })
                     .execute(db)
                     .context("Failed to insert follow event")?;
 
                 insert_into(feed_event_wallets::table)
                     .values(&FeedEventWallet {
-                        wallet_address: row.from_account,
+                        wallet_address: row.from_account.clone(),
                         feed_event_id,
                     })
                     .execute(db)
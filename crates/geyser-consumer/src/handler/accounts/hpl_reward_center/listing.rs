@@ -15,7 +15,7 @@ use indexer_core::{
             auction_houses, current_metadata_owners, feed_event_wallets, feed_events,
             listing_events, listings, metadatas, purchases, reward_centers, rewards_listings,
         },
-        Error as DbError,
+        Error as DbError, UpsertIfNewer,
     },
     pubkeys,
     uuid::Uuid,
@@ -56,144 +56,138 @@ pub(crate) async fn process(
     };
 
     client
-        .db()
-        .run({
-            let values = row.clone();
-            move |db| {
-                insert_into(rewards_listings::table)
-                    .values(&values)
-                    .on_conflict(rewards_listings::address)
-                    .do_update()
-                    .set(&values)
-                    .execute(db)
+        .transaction(move |db| {
+            let updated = insert_into(rewards_listings::table)
+                .values(&row)
+                .on_conflict(rewards_listings::address)
+                .do_update()
+                .set(&row)
+                .if_newer(rewards_listings::slot, rewards_listings::write_version)
+                .execute(db)?;
+
+            if updated == 0 {
+                return Ok(());
             }
-        })
-        .await
-        .context("Failed to insert rewards listing")?;
 
-    client
-        .db()
-        .run({
-            move |db| {
-                let auction_houses = auction_houses::table
-                    .select(auction_houses::all_columns)
-                    .inner_join(
-                        reward_centers::table
-                            .on(auction_houses::address.eq(reward_centers::auction_house)),
-                    )
-                    .filter(reward_centers::address.eq(row.reward_center_address.clone()))
-                    .first::<AuctionHouse>(db)?;
-
-                let current_metadata_owner = current_metadata_owners::table
-                    .select((
-                        current_metadata_owners::mint_address,
-                        current_metadata_owners::owner_address,
-                        current_metadata_owners::token_account_address,
-                        current_metadata_owners::slot,
-                    ))
-                    .inner_join(
-                        metadatas::table
-                            .on(metadatas::mint_address.eq(current_metadata_owners::mint_address)),
-                    )
-                    .filter(metadatas::address.eq(row.metadata.clone()))
-                    .first::<CurrentMetadataOwner>(db)?;
-
-                let (trade_state, trade_state_bump) = find_auctioneer_trade_state_address(
-                    &account_data.seller,
-                    &Pubkey::from_str(&auction_houses.address)?,
-                    &Pubkey::from_str(&current_metadata_owner.token_account_address)?,
-                    &Pubkey::from_str(&auction_houses.treasury_mint)?,
-                    &Pubkey::from_str(&current_metadata_owner.mint_address)?,
-                    account_data.token_size,
-                );
-
-                let purchase_id = purchases::table
-                    .filter(
-                        purchases::seller
-                            .eq(row.seller.clone())
-                            .and(purchases::auction_house.eq(auction_houses.address.clone()))
-                            .and(purchases::metadata.eq(row.metadata.clone()))
-                            .and(purchases::price.eq(row.price))
-                            .and(
-                                purchases::token_size
-                                    .eq(row.token_size)
-                                    .and(purchases::slot.eq(row.slot)),
-                            ),
-                    )
-                    .select(purchases::id)
-                    .first::<Uuid>(db)
-                    .optional()?;
-
-                let listing = DbListing {
-                    id: None,
-                    trade_state: Owned(bs58::encode(trade_state).into_string()),
-                    trade_state_bump: trade_state_bump.into(),
-                    auction_house: auction_houses.address,
-                    metadata: row.metadata.clone(),
-                    token_size: row.token_size,
-                    marketplace_program: Owned(pubkeys::REWARD_CENTER.to_string()),
-                    purchase_id,
-                    seller: row.seller.clone(),
-                    price: row.price,
-                    created_at: row.created_at,
-                    expiry: None,
-                    canceled_at: Some(None),
-                    write_version: Some(row.write_version),
-                    slot: row.slot,
-                };
-
-                let listing_exists = select(exists(
-                    listings::table.filter(
-                        listings::trade_state
-                            .eq(trade_state.to_string())
-                            .and(listings::metadata.eq(row.metadata.clone())),
-                    ),
+            let auction_houses = auction_houses::table
+                .select(auction_houses::all_columns)
+                .inner_join(
+                    reward_centers::table
+                        .on(auction_houses::address.eq(reward_centers::auction_house)),
+                )
+                .filter(reward_centers::address.eq(row.reward_center_address.clone()))
+                .first::<AuctionHouse>(db)?;
+
+            let current_metadata_owner = current_metadata_owners::table
+                .select((
+                    current_metadata_owners::mint_address,
+                    current_metadata_owners::owner_address,
+                    current_metadata_owners::token_account_address,
+                    current_metadata_owners::slot,
+                    current_metadata_owners::write_version,
                 ))
-                .get_result::<bool>(db)?;
-
-                let listing_id = mutations::listing::insert(db, &listing)?;
+                .inner_join(
+                    metadatas::table
+                        .on(metadatas::mint_address.eq(current_metadata_owners::mint_address)),
+                )
+                .filter(metadatas::address.eq(row.metadata.clone()))
+                .first::<CurrentMetadataOwner>(db)?;
+
+            let (trade_state, trade_state_bump) = find_auctioneer_trade_state_address(
+                &account_data.seller,
+                &Pubkey::from_str(&auction_houses.address)?,
+                &Pubkey::from_str(&current_metadata_owner.token_account_address)?,
+                &Pubkey::from_str(&auction_houses.treasury_mint)?,
+                &Pubkey::from_str(&current_metadata_owner.mint_address)?,
+                account_data.token_size,
+            );
+
+            let purchase_id = purchases::table
+                .filter(
+                    purchases::seller
+                        .eq(row.seller.clone())
+                        .and(purchases::auction_house.eq(auction_houses.address.clone()))
+                        .and(purchases::metadata.eq(row.metadata.clone()))
+                        .and(purchases::price.eq(row.price))
+                        .and(
+                            purchases::token_size
+                                .eq(row.token_size)
+                                .and(purchases::slot.eq(row.slot)),
+                        ),
+                )
+                .select(purchases::id)
+                .first::<Uuid>(db)
+                .optional()?;
+
+            let listing = DbListing {
+                id: None,
+                trade_state: Owned(bs58::encode(trade_state).into_string()),
+                trade_state_bump: trade_state_bump.into(),
+                auction_house: auction_houses.address,
+                metadata: row.metadata.clone(),
+                token_size: row.token_size,
+                marketplace_program: Owned(pubkeys::REWARD_CENTER.to_string()),
+                purchase_id,
+                seller: row.seller.clone(),
+                price: row.price,
+                created_at: row.created_at,
+                expiry: None,
+                canceled_at: Some(None),
+                write_version: Some(row.write_version),
+                slot: row.slot,
+            };
+
+            let listing_exists = select(exists(
+                listings::table.filter(
+                    listings::trade_state
+                        .eq(trade_state.to_string())
+                        .and(listings::metadata.eq(row.metadata.clone())),
+                ),
+            ))
+            .get_result::<bool>(db)?;
+
+            let listing_id = mutations::listing::insert(db, &listing)?;
+
+            if listing_exists {
+                return Ok(());
+            }
 
-                if listing_exists {
+            mutations::activity::listing(
+                db,
+                listing_id,
+                &listing.clone(),
+                ActivityTypeEnum::ListingCreated,
+            )?;
+
+            db.transaction(|| {
+                let feed_event_id = insert_into(feed_events::table)
+                    .default_values()
+                    .returning(feed_events::id)
+                    .get_result::<Uuid>(db)
+                    .context("Failed to insert feed event")?;
+
+                let listing_event = insert_into(listing_events::table)
+                    .values(&ListingEvent {
+                        feed_event_id,
+                        lifecycle: ListingEventLifecycleEnum::Created,
+                        listing_id,
+                    })
+                    .execute(db);
+
+                if Err(DbError::RollbackTransaction) == listing_event {
                     return Ok(());
                 }
 
-                mutations::activity::listing(
-                    db,
-                    listing_id,
-                    &listing.clone(),
-                    ActivityTypeEnum::ListingCreated,
-                )?;
-
-                db.build_transaction().read_write().run(|| {
-                    let feed_event_id = insert_into(feed_events::table)
-                        .default_values()
-                        .returning(feed_events::id)
-                        .get_result::<Uuid>(db)
-                        .context("Failed to insert feed event")?;
-
-                    let listing_event = insert_into(listing_events::table)
-                        .values(&ListingEvent {
-                            feed_event_id,
-                            lifecycle: ListingEventLifecycleEnum::Created,
-                            listing_id,
-                        })
-                        .execute(db);
-
-                    if Err(DbError::RollbackTransaction) == listing_event {
-                        return Ok(());
-                    }
-
-                    insert_into(feed_event_wallets::table)
-                        .values(&FeedEventWallet {
-                            wallet_address: row.seller,
-                            feed_event_id,
-                        })
-                        .execute(db)
-                        .context("Failed to insert listing feed event wallet")?;
-
-                    Result::<_>::Ok(())
-                })
-            }
+                insert_into(feed_event_wallets::table)
+                    .values(&FeedEventWallet {
+                        wallet_address: row.seller.clone(),
+                        feed_event_id,
+                    })
+                    .execute(db)
+                    .context("Failed to insert listing feed event wallet")?;
+
+                Result::<_>::Ok(())
+            })
         })
         .await
         .context("Failed to insert rewards listing")?;
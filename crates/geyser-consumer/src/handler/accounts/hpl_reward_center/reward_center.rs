@@ -1,11 +1,11 @@
 use hpl_reward_center::state::{PayoutOperation, RewardCenter};
 use indexer::prelude::*;
 use indexer_core::db::{
-    custom_types::PayoutOperationEnum, insert_into, models::RewardCenter as DbRewardCenter,
-    tables::reward_centers,
+    custom_types::PayoutOperationEnum, insert_into, models::RewardCenter as DbRewardCenter, select,
+    tables::reward_centers, UpsertIfNewer,
 };
 
-use super::super::Client;
+use super::super::{token, Client};
 
 pub(crate) async fn process(
     client: &Client,
@@ -16,6 +16,30 @@ pub(crate) async fn process(
 ) -> Result<()> {
     let rules = account_data.reward_rules;
 
+    let mint = account_data.token_mint;
+    let mint_str = mint.to_string();
+
+    let known_mint = client
+        .db()
+        .run(move |db| {
+            select(exists(
+                reward_centers::table.filter(reward_centers::token_mint.eq(mint_str)),
+            ))
+            .get_result::<bool>(db)
+        })
+        .await
+        .context("Failed to check for reward centers of token mint")?;
+
+    client.track_mint(mint);
+
+    // Backfill before recording the reward center, so a failed backfill is
+    // retried with the message instead of being skipped as already known
+    if !known_mint {
+        token::backfill(client, mint)
+            .await
+            .context("Failed to backfill reward center token balances")?;
+    }
+
     let row = DbRewardCenter {
         address: Owned(bs58::encode(key).into_string()),
         token_mint: Owned(bs58::encode(account_data.token_mint).into_string()),
@@ -39,6 +63,7 @@ pub(crate) async fn process(
                 .on_conflict(reward_centers::address)
                 .do_update()
                 .set(&row)
+                .if_newer(reward_centers::slot, reward_centers::write_version)
                 .execute(db)
         })
         .await
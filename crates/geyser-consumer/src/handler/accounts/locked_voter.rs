@@ -7,12 +7,19 @@ use indexer_core::db::{
         LockerWhitelistEntry as LockerWhitelistEntryModel,
     },
     tables::{escrows, locker_params, locker_whitelist_entries, lockers},
+    Connection, UpsertIfNewer,
 };
 use tribeca_locked_voter::{Escrow, Locker, LockerParams, LockerWhitelistEntry};
 
 use super::Client;
 
-pub(crate) async fn process_locker(client: &Client, key: Pubkey, l: Locker) -> Result<()> {
+pub(crate) async fn process_locker(
+    client: &Client,
+    key: Pubkey,
+    l: Locker,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
     let row = LockerModel {
         address: Owned(key.to_string()),
         base: Owned(l.base.to_string()),
@@ -20,25 +27,31 @@ pub(crate) async fn process_locker(client: &Client, key: Pubkey, l: Locker) -> R
         token_mint: Owned(l.token_mint.to_string()),
         locked_supply: l.locked_supply.try_into()?,
         governor: Owned(l.governor.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(lockers::table)
+        .transaction(move |db| {
+            let updated = insert_into(lockers::table)
                 .values(&row)
                 .on_conflict(lockers::address)
                 .do_update()
                 .set(&row)
+                .if_newer(lockers::slot, lockers::write_version)
                 .execute(db)
+                .context("failed to insert locker ")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            process_locker_params(db, key, &l.params)
         })
         .await
-        .context("failed to insert locker ")?;
-
-    process_locker_params(client, key, l.params).await
 }
 
-async fn process_locker_params(client: &Client, key: Pubkey, params: LockerParams) -> Result<()> {
+fn process_locker_params(db: &Connection, key: Pubkey, params: &LockerParams) -> Result<()> {
     let row = LockerParamModel {
         locker_address: Owned(key.to_string()),
         whitelist_enabled: params.whitelist_enabled,
@@ -48,23 +61,24 @@ async fn process_locker_params(client: &Client, key: Pubkey, params: LockerParam
         proposal_activation_min_votes: params.proposal_activation_min_votes.try_into()?,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(locker_params::table)
-                .values(&row)
-                .on_conflict(locker_params::locker_address)
-                .do_update()
-                .set(&row)
-                .execute(db)
-        })
-        .await
+    insert_into(locker_params::table)
+        .values(&row)
+        .on_conflict(locker_params::locker_address)
+        .do_update()
+        .set(&row)
+        .execute(db)
         .context("failed to insert locker parameters")?;
 
     Ok(())
 }
 
-pub(crate) async fn process_escrow(client: &Client, key: Pubkey, es: Escrow) -> Result<()> {
+pub(crate) async fn process_escrow(
+    client: &Client,
+    key: Pubkey,
+    es: Escrow,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
     let row = EscrowModel {
         address: Owned(key.to_string()),
         locker: Owned(es.locker.to_string()),
@@ -75,6 +89,8 @@ pub(crate) async fn process_escrow(client: &Client, key: Pubkey, es: Escrow) ->
         escrow_started_at: es.escrow_started_at,
         escrow_ends_at: es.escrow_ends_at,
         vote_delegate: Owned(es.vote_delegate.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -85,6 +101,7 @@ pub(crate) async fn process_escrow(client: &Client, key: Pubkey, es: Escrow) ->
                 .on_conflict(escrows::address)
                 .do_update()
                 .set(&row)
+                .if_newer(escrows::slot, escrows::write_version)
                 .execute(db)
         })
         .await
@@ -97,6 +114,8 @@ pub(crate) async fn process_locker_whitelist_entry(
     client: &Client,
     key: Pubkey,
     wl: LockerWhitelistEntry,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = LockerWhitelistEntryModel {
         address: Owned(key.to_string()),
@@ -104,6 +123,8 @@ pub(crate) async fn process_locker_whitelist_entry(
         locker: Owned(wl.locker.to_string()),
         program_id: Owned(wl.program_id.to_string()),
         owner: Owned(wl.owner.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -114,6 +135,10 @@ pub(crate) async fn process_locker_whitelist_entry(
                 .on_conflict(locker_whitelist_entries::address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    locker_whitelist_entries::slot,
+                    locker_whitelist_entries::write_version,
+                )
                 .execute(db)
         })
         .await
@@ -0,0 +1,65 @@
+use indexer::prelude::*;
+use indexer_core::{
+    db::{insert_into, models::MeAmmPool, tables::me_amm_pools, UpsertIfNewer},
+    util,
+};
+
+use super::Client;
+use crate::handler::programs::magic_eden_amm::Pool;
+
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    pool: Pool,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = MeAmmPool {
+        address: Owned(key.to_string()),
+        owner: Owned(pool.owner.to_string()),
+        cosigner: Owned(pool.cosigner.to_string()),
+        payment_mint: Owned(pool.payment_mint.to_string()),
+        spot_price: pool
+            .spot_price
+            .try_into()
+            .context("Spot price is too big to store")?,
+        curve_type: pool.curve_type.into(),
+        curve_delta: pool.curve_delta.try_into()?,
+        reinvest_fulfill_buy: pool.reinvest_fulfill_buy,
+        reinvest_fulfill_sell: pool.reinvest_fulfill_sell,
+        expiry: (pool.expiry > 0)
+            .then(|| util::unix_timestamp(pool.expiry))
+            .transpose()?,
+        lp_fee_bp: pool.lp_fee_bp.into(),
+        buyside_creator_royalty_bp: pool.buyside_creator_royalty_bp.into(),
+        sellside_asset_amount: pool.sellside_asset_amount.try_into()?,
+        buyside_payment_amount: pool
+            .buyside_payment_amount
+            .try_into()
+            .context("Escrow balance is too big to store")?,
+        lp_fee_earned: pool.lp_fee_earned.try_into()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .batch()
+        .upsert(
+            "me_amm_pools",
+            row.address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(me_amm_pools::table)
+                    .values(&row)
+                    .on_conflict(me_amm_pools::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(me_amm_pools::slot, me_amm_pools::write_version)
+                    .execute(db)
+            },
+        )
+        .await
+        .context("Failed to insert Magic Eden AMM pool")?;
+
+    Ok(())
+}
@@ -1,24 +1,30 @@
 use indexer::prelude::*;
 use indexer_core::{
     db::{
-        custom_types::{ProgrammableConfigEnum, TokenStandardEnum},
+        custom_types::{ProgrammableConfigEnum, TokenStandardEnum, UseMethodEnum},
         delete, insert_into,
-        models::{Metadata, MetadataCollectionKey, MetadataCreator, MetadataProgrammableConfig},
+        models::{
+            Metadata, MetadataCollectionDetails, MetadataCollectionKey, MetadataCreator,
+            MetadataProgrammableConfig, MetadataUse,
+        },
         tables::{
-            metadata_collection_keys, metadata_creators, metadata_programmable_configs, metadatas,
+            metadata_collection_details, metadata_collection_keys, metadata_creators,
+            metadata_programmable_configs, metadata_uses, metadatas,
         },
+        UpsertIfNewer,
     },
     pubkeys::find_edition,
 };
-use mpl_token_metadata::state::{Collection, Metadata as MetadataAccount};
+use mpl_token_metadata::state::{Metadata as MetadataAccount, UseMethod};
 
 use super::Client;
-use crate::handler::programs::metadata::{ProgrammableConfig, TokenStandard};
+use crate::handler::programs::metadata::{CollectionDetails, ProgrammableConfig, TokenStandard};
 
 pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     meta: MetadataAccount,
+    collection_details: Option<CollectionDetails>,
     programmable_config: Option<ProgrammableConfig>,
     token_standard: Option<TokenStandard>,
     slot: u64,
@@ -50,143 +56,200 @@ pub(crate) async fn process(
                 .context("Metadata slot was too big to store")?,
         ),
         burned_at: None,
+        write_version: write_version
+            .try_into()
+            .context("Metadata write version was too big to store")?,
     };
 
-    let first_verified_creator: Option<Pubkey> = meta
+    let creators = meta
         .data
         .creators
-        .as_ref()
-        .and_then(|creators| creators.iter().find(|c| c.verified).map(|c| c.address));
-
-    client
-        .dispatch_metadata_json(
-            key,
-            first_verified_creator,
-            meta.data.uri.trim_end_matches('\0').to_owned(),
-            (slot, write_version),
-        )
-        .await
-        .context("Failed to dispatch metadata JSON job")?;
-
-    client
-        .db()
-        .run({
-            let addr = addr.clone();
-            move |db| {
-                delete(
-                    metadata_creators::table.filter(metadata_creators::metadata_address.eq(addr)),
-                )
-                .execute(db)
-            }
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(position, creator)| {
+            Ok(MetadataCreator {
+                metadata_address: Owned(addr.clone()),
+                creator_address: Owned(bs58::encode(creator.address).into_string()),
+                share: creator.share.into(),
+                verified: creator.verified,
+                position: Some(
+                    position
+                        .try_into()
+                        .context("Position was too big to store")?,
+                ),
+            })
         })
-        .await
-        .context("Failed to delete metadata creators")?;
-
-    for (position, creator) in meta.data.creators.iter().flatten().enumerate() {
-        let row = MetadataCreator {
-            metadata_address: Owned(addr.clone()),
-            creator_address: Owned(bs58::encode(creator.address).into_string()),
-            share: creator.share.into(),
-            verified: creator.verified,
-            position: Some(
-                position
+        .collect::<Result<Vec<_>>>()?;
+
+    let collection_key = meta.collection.map(|c| MetadataCollectionKey {
+        metadata_address: Owned(addr.clone()),
+        collection_address: Owned(c.key.to_string()),
+        verified: c.verified,
+    });
+
+    let uses = meta
+        .uses
+        .map(|u| {
+            Result::<_>::Ok(MetadataUse {
+                metadata_address: Owned(addr.clone()),
+                use_method: match u.use_method {
+                    UseMethod::Burn => UseMethodEnum::Burn,
+                    UseMethod::Multiple => UseMethodEnum::Multiple,
+                    UseMethod::Single => UseMethodEnum::Single,
+                },
+                remaining: u
+                    .remaining
                     .try_into()
-                    .context("Position was too big to store")?,
-            ),
-        };
-
-        client
-            .db()
-            .run(move |db| {
-                insert_into(metadata_creators::table)
-                    .values(&row)
-                    .on_conflict((
-                        metadata_creators::metadata_address,
-                        metadata_creators::creator_address,
-                    ))
-                    .do_update()
-                    .set(&row)
-                    .execute(db)
-            })
-            .await
-            .context("Failed to insert metadata creator")?;
-
-        client
-            .db()
-            .run({
-                let metadata = metadata.clone();
-                move |db| {
-                    insert_into(metadatas::table)
-                        .values(&metadata)
-                        .on_conflict(metadatas::address)
-                        .do_update()
-                        .set(&metadata)
-                        .execute(db)
-                }
+                    .context("Remaining uses were too big to store")?,
+                total: u
+                    .total
+                    .try_into()
+                    .context("Total uses were too big to store")?,
             })
-            .await
-            .context("Failed to insert metadata")?;
-    }
+        })
+        .transpose()?;
 
-    if meta.collection.is_some() {
-        index_metadata_collection_key(client, addr, meta.collection.context("err!")?).await?;
-    }
+    let collection_details = collection_details
+        .map(|CollectionDetails::V1 { size }| {
+            Result::<_>::Ok(MetadataCollectionDetails {
+                metadata_address: Owned(addr.clone()),
+                size: size
+                    .try_into()
+                    .context("Collection size was too big to store")?,
+            })
+        })
+        .transpose()?;
 
-    if let Some(pc) = programmable_config {
+    let programmable_config = programmable_config.map(|pc| {
         let (variant, rule_set) = match pc {
             ProgrammableConfig::V1 { rule_set } => {
                 (ProgrammableConfigEnum::V1, rule_set.map(|r| r.to_string()))
             },
         };
 
-        let val = MetadataProgrammableConfig {
+        MetadataProgrammableConfig {
             metadata_address: metadata.address.clone(),
             variant,
             rule_set: rule_set.map(Owned),
-        };
+        }
+    });
+
+    let updated = client
+        .batch()
+        .upsert("metadatas", addr.clone(), (slot, write_version), {
+            let addr = addr.clone();
+            move |db| {
+                let updated = insert_into(metadatas::table)
+                    .values(&metadata)
+                    .on_conflict(metadatas::address)
+                    .do_update()
+                    .set(&metadata)
+                    .if_newer(metadatas::slot, metadatas::write_version)
+                    .execute(db)
+                    .context("Failed to insert metadata")?;
 
-        client
-            .db()
-            .run({
-                move |db| {
+                if updated == 0 {
+                    return Ok(0);
+                }
+
+                delete(
+                    metadata_creators::table.filter(metadata_creators::metadata_address.eq(&addr)),
+                )
+                .execute(db)
+                .context("Failed to delete metadata creators")?;
+
+                for row in &creators {
+                    insert_into(metadata_creators::table)
+                        .values(row)
+                        .on_conflict((
+                            metadata_creators::metadata_address,
+                            metadata_creators::creator_address,
+                        ))
+                        .do_update()
+                        .set(row)
+                        .execute(db)
+                        .context("Failed to insert metadata creator")?;
+                }
+
+                if let Some(ref row) = collection_key {
+                    insert_into(metadata_collection_keys::table)
+                        .values(row)
+                        .on_conflict(metadata_collection_keys::metadata_address)
+                        .do_update()
+                        .set(row)
+                        .execute(db)
+                        .context("Failed to insert into metadata_collection_keys")?;
+                }
+
+                if let Some(ref row) = uses {
+                    insert_into(metadata_uses::table)
+                        .values(row)
+                        .on_conflict(metadata_uses::metadata_address)
+                        .do_update()
+                        .set(row)
+                        .execute(db)
+                        .context("Failed to insert metadata uses")?;
+                } else {
+                    delete(metadata_uses::table.filter(metadata_uses::metadata_address.eq(&addr)))
+                        .execute(db)
+                        .context("Failed to delete metadata uses")?;
+                }
+
+                if let Some(ref row) = collection_details {
+                    insert_into(metadata_collection_details::table)
+                        .values(row)
+                        .on_conflict(metadata_collection_details::metadata_address)
+                        .do_update()
+                        .set(row)
+                        .execute(db)
+                        .context("Failed to insert metadata collection details")?;
+                } else {
+                    delete(
+                        metadata_collection_details::table
+                            .filter(metadata_collection_details::metadata_address.eq(&addr)),
+                    )
+                    .execute(db)
+                    .context("Failed to delete metadata collection details")?;
+                }
+
+                if let Some(ref val) = programmable_config {
                     insert_into(metadata_programmable_configs::table)
-                        .values(val.clone())
+                        .values(val)
                         .on_conflict(metadata_programmable_configs::metadata_address)
                         .do_update()
-                        .set(&val)
+                        .set(val)
                         .execute(db)
+                        .context("Failed to insert metadata programmable config")?;
                 }
-            })
-            .await
-            .context("Failed to insert metadata programmable config")?;
-    }
 
-    Ok(())
-}
+                Result::<_>::Ok(updated)
+            }
+        })
+        .await
+        .context("Failed to write metadata")?;
 
-async fn index_metadata_collection_key(
-    client: &Client,
-    addr: String,
-    collection: Collection,
-) -> Result<()> {
-    let row = MetadataCollectionKey {
-        metadata_address: Owned(addr),
-        collection_address: Owned(collection.key.to_string()),
-        verified: collection.verified,
-    };
+    if updated == 0 {
+        trace!("Skipping stale update for metadata {}", addr);
+
+        return Ok(());
+    }
+
+    let first_verified_creator: Option<Pubkey> = meta
+        .data
+        .creators
+        .as_ref()
+        .and_then(|creators| creators.iter().find(|c| c.verified).map(|c| c.address));
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(metadata_collection_keys::table)
-                .values(&row)
-                .on_conflict(metadata_collection_keys::metadata_address)
-                .do_update()
-                .set(&row)
-                .execute(db)
-        })
+        .dispatch_metadata_json(
+            key,
+            first_verified_creator,
+            meta.data.uri.trim_end_matches('\0').to_owned(),
+            (slot, write_version),
+        )
         .await
-        .context("Failed to insert into metadata_collection_keys")?;
+        .context("Failed to dispatch metadata JSON job")?;
 
     Ok(())
 }
@@ -1,9 +1,13 @@
+pub mod anchor_idl;
 pub mod auction_cache;
 pub mod auction_data;
 pub mod auction_house;
+pub mod auctioneer;
 pub mod bidder_metadata;
 pub mod bonding_change;
+pub mod candy_guard;
 pub mod candy_machine;
+pub mod candy_machine_core;
 pub mod cardinal_paid_claim_approver;
 pub mod cardinal_time_invalidator;
 pub mod cardinal_token_manager;
@@ -14,6 +18,7 @@ pub mod govern;
 pub mod graph;
 pub mod hpl_reward_center;
 pub mod locked_voter;
+pub mod me_amm_pool;
 pub mod metadata;
 pub mod mpl_store;
 pub mod name_service;
@@ -22,5 +27,6 @@ pub mod receipt;
 pub mod smart_wallet;
 pub mod spl_governance;
 pub mod token;
+pub mod token_2022;
 
 pub(self) use super::Client;
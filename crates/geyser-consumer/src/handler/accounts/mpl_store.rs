@@ -7,6 +7,7 @@ use indexer_core::{
             WhitelistedCreator as DbWhitelistedCreator,
         },
         tables::{store_configs, stores, whitelisted_creators},
+        UpsertIfNewer,
     },
     pubkeys::find_store_config,
 };
@@ -19,24 +20,20 @@ pub(crate) async fn process_config(
     client: &Client,
     key: Pubkey,
     config: StoreConfig,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     trace!("{:?}", &config.settings_uri);
 
     let addr = bs58::encode(key).into_string();
-    if let Some(ref uri) = config.settings_uri {
-        debug!("dispatching store config: {:?}", uri);
-        client
-            .dispatch_store_config(key, uri.clone())
-            .await
-            .context("Failed to dispatch store config job")?;
-    }
-
     let row = DbStoreConfig {
         address: Owned(addr),
-        settings_uri: config.settings_uri.map(Owned),
+        settings_uri: config.settings_uri.clone().map(Owned),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
-    client
+    let updated = client
         .db()
         .run(move |db| {
             insert_into(store_configs::table)
@@ -44,10 +41,24 @@ pub(crate) async fn process_config(
                 .on_conflict(store_configs::address)
                 .do_update()
                 .set(&row)
+                .if_newer(store_configs::slot, store_configs::write_version)
                 .execute(db)
         })
         .await
         .context("Failed to insert store")?;
+
+    if updated == 0 {
+        return Ok(());
+    }
+
+    if let Some(uri) = config.settings_uri {
+        debug!("dispatching store config: {:?}", uri);
+        client
+            .dispatch_store_config(key, uri)
+            .await
+            .context("Failed to dispatch store config job")?;
+    }
+
     Ok(())
 }
 
@@ -55,11 +66,15 @@ pub(crate) async fn process_whitelisted_creator(
     client: &Client,
     key: Pubkey,
     creator: WhitelistedCreator,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = DbWhitelistedCreator {
         address: Owned(bs58::encode(key).into_string()),
         creator_address: Owned(bs58::encode(creator.address).into_string()),
         activated: creator.activated,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -70,6 +85,10 @@ pub(crate) async fn process_whitelisted_creator(
                 .on_conflict(whitelisted_creators::address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    whitelisted_creators::slot,
+                    whitelisted_creators::write_version,
+                )
                 .execute(db)
         })
         .await
@@ -77,13 +96,21 @@ pub(crate) async fn process_whitelisted_creator(
     Ok(())
 }
 
-pub(crate) async fn process(client: &Client, key: Pubkey, store: Store) -> Result<()> {
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    store: Store,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
     let (config_address, _bump) = find_store_config(key);
 
     let row = DbStore {
         address: Owned(bs58::encode(key).into_string()),
         public: store.public,
         config_address: Owned(bs58::encode(config_address).into_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -94,6 +121,7 @@ pub(crate) async fn process(client: &Client, key: Pubkey, store: Store) -> Resul
                 .on_conflict(stores::address)
                 .do_update()
                 .set(&row)
+                .if_newer(stores::slot, stores::write_version)
                 .execute(db)
         })
         .await
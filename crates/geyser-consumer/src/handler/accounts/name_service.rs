@@ -1,7 +1,7 @@
 use borsh::BorshDeserialize;
 use indexer::{prelude::*, search_dispatch::TwitterHandleDocument};
 use indexer_core::db::{
-    insert_into, models::TwitterHandle, tables::twitter_handle_name_services, update,
+    insert_into, models::TwitterHandle, select, tables::twitter_handle_name_services, UpsertIfNewer,
 };
 
 use super::Client;
@@ -23,73 +23,45 @@ pub(crate) async fn process(
     let th = TwitterHandleAndRegistry::deserialize(&mut data.as_slice())
         .context("failed to deserialize registry key and handle!")?;
 
-    let incoming_slot: i64 = slot.try_into()?;
-
     let document = TwitterHandleDocument {
         owner: wallet.to_string(),
         handle: th.clone().handle,
     };
 
-    let rows = client
-        .db()
-        .run(move |db| {
-            twitter_handle_name_services::table
-                .select(twitter_handle_name_services::all_columns)
-                .filter(twitter_handle_name_services::address.eq(key.to_string()))
-                .load::<TwitterHandle>(db)
-        })
-        .await
-        .context("failed to load twitter handle name services accounts!")?;
-
-    let pubkey: String = key.to_string();
-    let write_version = i64::try_from(write_version)?;
-
     let values = TwitterHandle {
-        address: Owned(pubkey.clone()),
+        address: Owned(key.to_string()),
         wallet_address: Owned(wallet.to_string()),
         twitter_handle: Owned(th.handle),
         slot: slot.try_into()?,
         from_bonfida: true,
         from_cardinal: false,
-        write_version,
+        write_version: write_version.try_into()?,
     };
 
-    let search_backfill;
+    let search_backfill = client
+        .transaction(move |db| {
+            let exists = select(exists(
+                twitter_handle_name_services::table
+                    .filter(twitter_handle_name_services::address.eq(values.address.clone())),
+            ))
+            .get_result::<bool>(db)
+            .context("failed to check for existing twitter handle")?;
 
-    match rows.get(0) {
-        Some(indexed) if (incoming_slot, write_version) > (indexed.slot, indexed.write_version) => {
-            search_backfill = Some(false);
+            let updated = insert_into(twitter_handle_name_services::table)
+                .values(&values)
+                .on_conflict(twitter_handle_name_services::address)
+                .do_update()
+                .set(&values)
+                .if_newer(
+                    twitter_handle_name_services::slot,
+                    twitter_handle_name_services::write_version,
+                )
+                .execute(db)
+                .context("failed to upsert twitter handle")?;
 
-            client
-                .db()
-                .run(move |db| {
-                    update(twitter_handle_name_services::table.filter(
-                        twitter_handle_name_services::wallet_address.eq(wallet.to_string()),
-                    ))
-                    .set(&values)
-                    .execute(db)
-                })
-                .await
-                .context("failed to update twitter handle")?;
-        },
-        Some(_) => search_backfill = None,
-        None => {
-            search_backfill = Some(true);
-
-            client
-                .db()
-                .run(move |db| {
-                    insert_into(twitter_handle_name_services::table)
-                        .values(&values)
-                        .on_conflict(twitter_handle_name_services::address)
-                        .do_update()
-                        .set(&values)
-                        .execute(db)
-                })
-                .await
-                .context("failed to insert twitter handle")?;
-        },
-    }
+            Result::<_>::Ok((updated > 0).then_some(!exists))
+        })
+        .await?;
 
     if let Some(backfill) = search_backfill {
         client
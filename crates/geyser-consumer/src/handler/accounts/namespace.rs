@@ -4,7 +4,7 @@ use indexer_core::{
         insert_into,
         models::{CardinalEntry, CardinalNamespace, TwitterHandle},
         tables::{cardinal_entries, cardinal_namespaces, twitter_handle_name_services},
-        update,
+        update, Connection, UpsertIfNewer,
     },
     pubkeys::CARDINAL_TWITTER_NAMESPACE,
 };
@@ -19,12 +19,6 @@ pub(crate) async fn process(
     write_version: u64,
     entry: Entry,
 ) -> Result<()> {
-    if entry.namespace == CARDINAL_TWITTER_NAMESPACE {
-        process_twitter_namespace(client, key, slot, write_version, entry.clone())
-            .await
-            .context("failed to process twitter namespace")?;
-    }
-
     let row = CardinalEntry {
         address: Owned(key.to_string()),
         namespace: Owned(entry.namespace.to_string()),
@@ -37,18 +31,49 @@ pub(crate) async fn process(
         write_version: write_version.try_into()?,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(cardinal_entries::table)
+    let twitter_handle = match entry.data {
+        Some(wallet) if entry.namespace == CARDINAL_TWITTER_NAMESPACE => Some(TwitterHandle {
+            address: Owned(bs58::encode(key).into_string()),
+            wallet_address: Owned(bs58::encode(wallet).into_string()),
+            twitter_handle: Owned(entry.name),
+            slot: slot.try_into()?,
+            from_cardinal: true,
+            from_bonfida: false,
+            write_version: write_version.try_into()?,
+        }),
+        _ => None,
+    };
+
+    let document = twitter_handle.as_ref().map(|h| TwitterHandleDocument {
+        owner: h.wallet_address.to_string(),
+        handle: h.twitter_handle.to_string(),
+    });
+
+    let search_backfill = client
+        .transaction(move |db| {
+            let updated = insert_into(cardinal_entries::table)
                 .values(&row)
                 .on_conflict(cardinal_entries::address)
                 .do_update()
                 .set(&row)
+                .if_newer(cardinal_entries::slot, cardinal_entries::write_version)
                 .execute(db)
+                .context("failed to insert cardinal entry")?;
+
+            match &twitter_handle {
+                Some(values) if updated > 0 => write_twitter_handle(db, values),
+                _ => Ok(None),
+            }
         })
-        .await
-        .context("failed to insert cardinal entry")?;
+        .await?;
+
+    if let (Some(backfill), Some(document)) = (search_backfill, document) {
+        client
+            .search()
+            .upsert_twitter_handle(backfill, key, document)
+            .await
+            .context("Failed to dispatch upsert twitter handle document job")?;
+    }
 
     Ok(())
 }
@@ -84,6 +109,10 @@ pub(crate) async fn process_namespace(
                 .on_conflict(cardinal_namespaces::address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    cardinal_namespaces::slot,
+                    cardinal_namespaces::write_version,
+                )
                 .execute(db)
         })
         .await
@@ -92,97 +121,45 @@ pub(crate) async fn process_namespace(
     Ok(())
 }
 
-async fn process_twitter_namespace(
-    client: &Client,
-    key: Pubkey,
-    slot: u64,
-    write_version: u64,
-    entry: Entry,
-) -> Result<()> {
-    let slot = i64::try_from(slot)?;
-    let write_version = i64::try_from(write_version)?;
-
-    let wallet_address: String = if let Some(wallet_address) = entry.data {
-        bs58::encode(wallet_address).into_string()
-    } else {
-        return Ok(());
-    };
-
-    let document = TwitterHandleDocument {
-        owner: wallet_address.to_string(),
-        handle: entry.name.clone(),
-    };
-
-    let values = TwitterHandle {
-        address: Owned(bs58::encode(key).into_string()),
-        wallet_address: Owned(wallet_address.clone()),
-        twitter_handle: Owned(entry.name),
-        slot,
-        from_cardinal: true,
-        from_bonfida: false,
-        write_version,
-    };
-
-    let rows = client
-        .db()
-        .run({
-            let wallet_address = wallet_address.clone();
-            move |db| {
-                twitter_handle_name_services::table
-                    .select(twitter_handle_name_services::all_columns)
-                    .filter(twitter_handle_name_services::wallet_address.eq(&wallet_address))
-                    .load::<TwitterHandle>(db)
-            }
-        })
-        .await
+/// Record the Twitter handle of a Cardinal namespace entry, returning
+/// whether a search document should be inserted, updated or left alone
+fn write_twitter_handle(db: &Connection, values: &TwitterHandle) -> Result<Option<bool>> {
+    let rows = twitter_handle_name_services::table
+        .select(twitter_handle_name_services::all_columns)
+        .filter(twitter_handle_name_services::wallet_address.eq(&values.wallet_address))
+        .load::<TwitterHandle>(db)
         .context("failed to load twitter handle name services accounts!")?;
 
-    let search_backfill;
-
     match rows.get(0) {
-        Some(indexed) if (slot, write_version) > (indexed.slot, indexed.write_version) => {
-            search_backfill = Some(false);
-
-            client
-                .db()
-                .run(move |db| {
-                    update(
-                        twitter_handle_name_services::table.filter(
-                            twitter_handle_name_services::wallet_address.eq(&wallet_address),
-                        ),
-                    )
-                    .set(&values)
-                    .execute(db)
-                })
-                .await
-                .context("failed to update twitter handle")?;
+        Some(indexed)
+            if (values.slot, values.write_version) > (indexed.slot, indexed.write_version) =>
+        {
+            update(
+                twitter_handle_name_services::table.filter(
+                    twitter_handle_name_services::wallet_address.eq(&values.wallet_address),
+                ),
+            )
+            .set(values)
+            .execute(db)
+            .context("failed to update twitter handle")?;
+
+            Ok(Some(false))
         },
-        Some(_) => search_backfill = None,
+        Some(_) => Ok(None),
         None => {
-            search_backfill = Some(true);
-
-            client
-                .db()
-                .run(move |db| {
-                    insert_into(twitter_handle_name_services::table)
-                        .values(&values)
-                        .on_conflict(twitter_handle_name_services::wallet_address)
-                        .do_update()
-                        .set(&values)
-                        .execute(db)
-                })
-                .await
+            insert_into(twitter_handle_name_services::table)
+                .values(values)
+                .on_conflict(twitter_handle_name_services::wallet_address)
+                .do_update()
+                .set(values)
+                .if_newer(
+                    twitter_handle_name_services::slot,
+                    twitter_handle_name_services::write_version,
+                )
+                .execute(db)
                 .context("failed to insert twitter handle")?;
-        },
-    }
 
-    if let Some(backfill) = search_backfill {
-        client
-            .search()
-            .upsert_twitter_handle(backfill, key, document)
-            .await
-            .context("Failed to dispatch upsert twitter handle document job")?;
+            Ok(Some(true))
+        },
     }
-
-    Ok(())
 }
@@ -9,7 +9,7 @@ use indexer_core::{
         },
         mutations, select,
         tables::{bid_receipts, listing_receipts, listings, offers, purchase_receipts, purchases},
-        update,
+        update, PooledConnection, UpsertIfNewer,
     },
     pubkeys, util,
     uuid::Uuid,
@@ -44,8 +44,7 @@ pub(crate) async fn process_listing_receipt(
     };
 
     client
-        .db()
-        .run(move |db| {
+        .transaction(move |db| {
             let listing_exists = select(exists(
                 listings::table.filter(
                     listings::trade_state
@@ -55,13 +54,18 @@ pub(crate) async fn process_listing_receipt(
             ))
             .get_result::<bool>(db)?;
 
-            insert_into(listing_receipts::table)
+            let updated = insert_into(listing_receipts::table)
                 .values(&row)
                 .on_conflict(listing_receipts::address)
                 .do_update()
                 .set(&row)
+                .if_newer(listing_receipts::slot, listing_receipts::write_version)
                 .execute(db)?;
 
+            if updated == 0 {
+                return Ok(());
+            }
+
             let values = Listing {
                 id: None,
                 trade_state: row.trade_state.clone(),
@@ -121,42 +125,39 @@ pub(crate) async fn process_purchase_receipt(
         write_version: write_version.try_into()?,
     };
 
-    let purchase_exists = client
-        .db()
-        .run({
-            let row = row.clone();
-            move |db| {
-                let purchase_exists = select(exists(
-                    purchases::table.filter(
-                        purchases::buyer
-                            .eq(row.buyer.clone())
-                            .and(purchases::seller.eq(row.seller.clone()))
-                            .and(purchases::auction_house.eq(row.auction_house.clone()))
-                            .and(purchases::metadata.eq(row.metadata.clone()))
-                            .and(purchases::price.eq(row.price))
-                            .and(purchases::token_size.eq(row.token_size)),
-                    ),
-                ))
-                .get_result::<bool>(db)?;
-
-                insert_into(purchase_receipts::table)
-                    .values(&row)
-                    .on_conflict(purchase_receipts::address)
-                    .do_update()
-                    .set(&row)
-                    .execute(db)?;
-
-                Result::<bool>::Ok(purchase_exists)
+    client
+        .transaction(move |db| {
+            let purchase_exists = select(exists(
+                purchases::table.filter(
+                    purchases::buyer
+                        .eq(row.buyer.clone())
+                        .and(purchases::seller.eq(row.seller.clone()))
+                        .and(purchases::auction_house.eq(row.auction_house.clone()))
+                        .and(purchases::metadata.eq(row.metadata.clone()))
+                        .and(purchases::price.eq(row.price))
+                        .and(purchases::token_size.eq(row.token_size)),
+                ),
+            ))
+            .get_result::<bool>(db)?;
+
+            let updated = insert_into(purchase_receipts::table)
+                .values(&row)
+                .on_conflict(purchase_receipts::address)
+                .do_update()
+                .set(&row)
+                .if_newer(purchase_receipts::slot, purchase_receipts::write_version)
+                .execute(db)?;
+
+            if updated == 0 || purchase_exists {
+                return Ok(());
             }
-        })
-        .await
-        .context("failed to check if purchase receipt exists!")?;
 
-    if purchase_exists {
-        return Ok(());
-    }
+            upsert_into_purchases_table(db, row.clone())?;
 
-    upsert_into_purchases_table(client, row.clone()).await?;
+            Result::<_>::Ok(())
+        })
+        .await
+        .context("failed to insert purchase receipt")?;
 
     Ok(())
 }
@@ -193,41 +194,40 @@ pub(crate) async fn process_bid_receipt(
         write_version: write_version.try_into()?,
     };
 
-    let offer_exists = client
-        .db()
-        .run({
-            let row = row.clone();
-            move |db| {
-                let offer_exists = select(exists(
-                    offers::table.filter(
-                        offers::trade_state
-                            .eq(row.trade_state.clone())
-                            .and(offers::metadata.eq(row.metadata.clone())),
-                    ),
-                ))
-                .get_result::<bool>(db)?;
-
-                insert_into(bid_receipts::table)
-                    .values(&row)
-                    .on_conflict(bid_receipts::address)
-                    .do_update()
-                    .set(&row)
-                    .execute(db)?;
-
-                Result::<bool>::Ok(offer_exists)
+    let inserted = client
+        .transaction(move |db| {
+            let offer_exists = select(exists(
+                offers::table.filter(
+                    offers::trade_state
+                        .eq(row.trade_state.clone())
+                        .and(offers::metadata.eq(row.metadata.clone())),
+                ),
+            ))
+            .get_result::<bool>(db)?;
+
+            let updated = insert_into(bid_receipts::table)
+                .values(&row)
+                .on_conflict(bid_receipts::address)
+                .do_update()
+                .set(&row)
+                .if_newer(bid_receipts::slot, bid_receipts::write_version)
+                .execute(db)?;
+
+            if updated == 0 || offer_exists || row.purchase_receipt.is_some() {
+                return Ok(false);
             }
+
+            upsert_into_offers_table(db, row.clone()).context("failed to insert offer")?;
+
+            Result::<_>::Ok(true)
         })
         .await
         .context("failed to insert bid reciept")?;
 
-    if offer_exists || row.purchase_receipt.is_some() {
+    if !inserted {
         return Ok(());
     }
 
-    upsert_into_offers_table(client, row.clone())
-        .await
-        .context("failed to insert offer")?;
-
     client
         .dispatch_dialect_offer_event(key, bid_receipt.metadata)
         .await?;
@@ -235,7 +235,7 @@ pub(crate) async fn process_bid_receipt(
     Ok(())
 }
 
-async fn upsert_into_offers_table<'a>(client: &Client, row: DbBidReceipt<'static>) -> Result<Uuid> {
+fn upsert_into_offers_table(db: &PooledConnection, row: DbBidReceipt<'static>) -> Result<Uuid> {
     let values = Offer {
         id: None,
         trade_state: row.trade_state,
@@ -255,28 +255,14 @@ async fn upsert_into_offers_table<'a>(client: &Client, row: DbBidReceipt<'static
         expiry: None,
     };
 
-    let offer_id = client
-        .db()
-        .run({
-            move |db| {
-                let offer_id = mutations::offer::insert(db, &values)?;
-                mutations::activity::offer(
-                    db,
-                    offer_id,
-                    &values.clone(),
-                    ActivityTypeEnum::OfferCreated,
-                )?;
-                Result::<_>::Ok(offer_id)
-            }
-        })
-        .await
-        .context("failed to insert purchase")?;
+    let offer_id = mutations::offer::insert(db, &values)?;
+    mutations::activity::offer(db, offer_id, &values, ActivityTypeEnum::OfferCreated)?;
 
     Ok(offer_id)
 }
 
-async fn upsert_into_purchases_table<'a>(
-    client: &Client,
+fn upsert_into_purchases_table(
+    db: &PooledConnection,
     row: DbPurchaseReceipt<'static>,
 ) -> Result<Uuid> {
     let row = Purchase {
@@ -293,53 +279,38 @@ async fn upsert_into_purchases_table<'a>(
         write_version: Some(row.write_version),
     };
 
-    let purchase_id = client
-        .db()
-        .run({
-            move |db| {
-                let purchase_id = mutations::purchase::insert(db, &row)?;
-                mutations::activity::purchase(
-                    db,
-                    purchase_id,
-                    &row.clone(),
-                    ActivityTypeEnum::Purchase,
-                )?;
-
-                update(
-                    offers::table.filter(
-                        offers::auction_house
-                            .eq(row.auction_house.clone())
-                            .and(offers::buyer.eq(row.buyer.clone()))
-                            .and(offers::metadata.eq(row.metadata.clone()))
-                            .and(offers::token_size.eq(row.token_size))
-                            .and(offers::price.eq(row.price))
-                            .and(offers::purchase_id.is_null())
-                            .and(offers::canceled_at.is_null()),
-                    ),
-                )
-                .set(offers::purchase_id.eq(Some(purchase_id)))
-                .execute(db)?;
-
-                update(
-                    listings::table.filter(
-                        listings::auction_house
-                            .eq(row.auction_house.clone())
-                            .and(listings::seller.eq(row.seller.clone()))
-                            .and(listings::metadata.eq(row.metadata.clone()))
-                            .and(listings::price.eq(row.price))
-                            .and(listings::token_size.eq(row.token_size))
-                            .and(listings::purchase_id.is_null())
-                            .and(listings::canceled_at.is_null()),
-                    ),
-                )
-                .set(listings::purchase_id.eq(Some(purchase_id)))
-                .execute(db)?;
-
-                Result::<_>::Ok(purchase_id)
-            }
-        })
-        .await
-        .context("failed to insert purchase")?;
+    let purchase_id = mutations::purchase::insert(db, &row)?;
+    mutations::activity::purchase(db, purchase_id, &row, ActivityTypeEnum::Purchase)?;
+
+    update(
+        offers::table.filter(
+            offers::auction_house
+                .eq(row.auction_house.clone())
+                .and(offers::buyer.eq(row.buyer.clone()))
+                .and(offers::metadata.eq(row.metadata.clone()))
+                .and(offers::token_size.eq(row.token_size))
+                .and(offers::price.eq(row.price))
+                .and(offers::purchase_id.is_null())
+                .and(offers::canceled_at.is_null()),
+        ),
+    )
+    .set(offers::purchase_id.eq(Some(purchase_id)))
+    .execute(db)?;
+
+    update(
+        listings::table.filter(
+            listings::auction_house
+                .eq(row.auction_house.clone())
+                .and(listings::seller.eq(row.seller.clone()))
+                .and(listings::metadata.eq(row.metadata.clone()))
+                .and(listings::price.eq(row.price))
+                .and(listings::token_size.eq(row.token_size))
+                .and(listings::purchase_id.is_null())
+                .and(listings::canceled_at.is_null()),
+        ),
+    )
+    .set(listings::purchase_id.eq(Some(purchase_id)))
+    .execute(db)?;
 
     Ok(purchase_id)
 }
@@ -17,6 +17,7 @@ use indexer_core::db::{
         instruction_buffers, smart_wallet_owners, smart_wallets, sub_account_infos, transactions,
         tx_instruction_keys, tx_instructions,
     },
+    Connection, UpsertIfNewer,
 };
 
 use super::Client;
@@ -25,6 +26,8 @@ pub(crate) async fn process_smart_wallet(
     client: &Client,
     key: Pubkey,
     sm: SmartWallet,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = SmartWalletModel {
         address: Owned(key.to_string()),
@@ -35,29 +38,31 @@ pub(crate) async fn process_smart_wallet(
         grace_period: sm.grace_period,
         owner_set_seqno: sm.owner_set_seqno.try_into()?,
         num_transactions: sm.num_transactions.try_into()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(smart_wallets::table)
+        .transaction(move |db| {
+            let updated = insert_into(smart_wallets::table)
                 .values(&row)
                 .on_conflict(smart_wallets::address)
                 .do_update()
                 .set(&row)
+                .if_newer(smart_wallets::slot, smart_wallets::write_version)
                 .execute(db)
+                .context("failed to insert smart wallet ")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            process_smart_wallet_owner(db, key, sm.owners.clone())
         })
         .await
-        .context("failed to insert smart wallet ")?;
-
-    process_smart_wallet_owner(client, key, sm.owners).await
 }
 
-async fn process_smart_wallet_owner(
-    client: &Client,
-    key: Pubkey,
-    owners: Vec<Pubkey>,
-) -> Result<()> {
+fn process_smart_wallet_owner(db: &Connection, key: Pubkey, owners: Vec<Pubkey>) -> Result<()> {
     for (i, owner) in owners.iter().enumerate() {
         let o = SmartWalletOwner {
             smart_wallet_address: Owned(key.to_string()),
@@ -65,20 +70,15 @@ async fn process_smart_wallet_owner(
             index: i.try_into()?,
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(smart_wallet_owners::table)
-                    .values(&o)
-                    .on_conflict((
-                        smart_wallet_owners::smart_wallet_address,
-                        smart_wallet_owners::owner_address,
-                    ))
-                    .do_update()
-                    .set(&o)
-                    .execute(db)
-            })
-            .await
+        insert_into(smart_wallet_owners::table)
+            .values(&o)
+            .on_conflict((
+                smart_wallet_owners::smart_wallet_address,
+                smart_wallet_owners::owner_address,
+            ))
+            .do_update()
+            .set(&o)
+            .execute(db)
             .context("failed to insert smart wallet owner")?;
     }
     Ok(())
@@ -88,6 +88,8 @@ pub(crate) async fn process_transaction(
     client: &Client,
     key: Pubkey,
     tx: Transaction,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let row = TransactionModel {
         address: Owned(key.to_string()),
@@ -100,24 +102,31 @@ pub(crate) async fn process_transaction(
         eta: tx.eta,
         executor: Owned(tx.executor.to_string()),
         executed_at: tx.executed_at,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(transactions::table)
+        .transaction(move |db| {
+            let updated = insert_into(transactions::table)
                 .values(&row)
                 .on_conflict(transactions::address)
                 .do_update()
                 .set(&row)
+                .if_newer(transactions::slot, transactions::write_version)
                 .execute(db)
+                .context("failed to insert transaction ")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            process_intructions(db, key, tx.instructions.clone())
         })
         .await
-        .context("failed to insert transaction ")?;
-    process_intructions(client, key, tx.instructions).await
 }
 
-async fn process_intructions(client: &Client, key: Pubkey, ins: Vec<TXInstruction>) -> Result<()> {
+fn process_intructions(db: &Connection, key: Pubkey, ins: Vec<TXInstruction>) -> Result<()> {
     for i in ins {
         let row = TXInstructionModel {
             transaction_address: Owned(key.to_string()),
@@ -125,30 +134,25 @@ async fn process_intructions(client: &Client, key: Pubkey, ins: Vec<TXInstructio
             data: i.data,
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(tx_instructions::table)
-                    .values(&row)
-                    .on_conflict((
-                        tx_instructions::transaction_address,
-                        tx_instructions::program_id,
-                    ))
-                    .do_update()
-                    .set(&row)
-                    .execute(db)
-            })
-            .await
+        insert_into(tx_instructions::table)
+            .values(&row)
+            .on_conflict((
+                tx_instructions::transaction_address,
+                tx_instructions::program_id,
+            ))
+            .do_update()
+            .set(&row)
+            .execute(db)
             .context("failed to insert transaction instruction")?;
 
-        process_tx_instruction_keys(client, key, i.program_id, i.keys).await?;
+        process_tx_instruction_keys(db, key, i.program_id, i.keys)?;
     }
 
     Ok(())
 }
 
-async fn process_tx_instruction_keys(
-    client: &Client,
+fn process_tx_instruction_keys(
+    db: &Connection,
     tx_addr: Pubkey,
     id: Pubkey,
     keys: Vec<TXAccountMeta>,
@@ -162,21 +166,16 @@ async fn process_tx_instruction_keys(
             is_writable: key.is_writable,
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(tx_instruction_keys::table)
-                    .values(&k)
-                    .on_conflict((
-                        tx_instruction_keys::transaction_address,
-                        tx_instruction_keys::program_id,
-                        tx_instruction_keys::pubkey,
-                    ))
-                    .do_update()
-                    .set(&k)
-                    .execute(db)
-            })
-            .await
+        insert_into(tx_instruction_keys::table)
+            .values(&k)
+            .on_conflict((
+                tx_instruction_keys::transaction_address,
+                tx_instruction_keys::program_id,
+                tx_instruction_keys::pubkey,
+            ))
+            .do_update()
+            .set(&k)
+            .execute(db)
             .context("failed to insert transaction instruction account metadata")?;
     }
 
@@ -187,6 +186,8 @@ pub(crate) async fn process_subaccount_info(
     client: &Client,
     key: Pubkey,
     acc: SubaccountInfo,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let s = SubaccountInfoModel {
         address: Owned(key.to_string()),
@@ -196,6 +197,8 @@ pub(crate) async fn process_subaccount_info(
             SubaccountType::OwnerInvoker => 1,
         },
         index: acc.index.try_into()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
@@ -206,6 +209,7 @@ pub(crate) async fn process_subaccount_info(
                 .on_conflict(sub_account_infos::address)
                 .do_update()
                 .set(&s)
+                .if_newer(sub_account_infos::slot, sub_account_infos::write_version)
                 .execute(db)
         })
         .await
@@ -218,6 +222,8 @@ pub(crate) async fn process_instruction_buffer(
     client: &Client,
     ib_addr: Pubkey,
     ib: InstructionBuffer,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
     let ins_buffer = InstructionBufferModel {
         address: Owned(ib_addr.to_string()),
@@ -226,26 +232,35 @@ pub(crate) async fn process_instruction_buffer(
         authority: Owned(ib.authority.to_string()),
         executor: Owned(ib.executor.to_string()),
         smart_wallet: Owned(ib.smart_wallet.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(instruction_buffers::table)
+        .transaction(move |db| {
+            let updated = insert_into(instruction_buffers::table)
                 .values(&ins_buffer)
                 .on_conflict(instruction_buffers::address)
                 .do_update()
                 .set(&ins_buffer)
+                .if_newer(
+                    instruction_buffers::slot,
+                    instruction_buffers::write_version,
+                )
                 .execute(db)
+                .context("failed to insert instruction buffer")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            process_ins_buffer_bundles(db, ib_addr, ib.bundles.clone())
         })
         .await
-        .context("failed to insert instruction buffer")?;
-
-    process_ins_buffer_bundles(client, ib_addr, ib.bundles).await
 }
 
-async fn process_ins_buffer_bundles(
-    client: &Client,
+fn process_ins_buffer_bundles(
+    db: &Connection,
     ib_addr: Pubkey,
     bundles: Vec<InstructionBundle>,
 ) -> Result<()> {
@@ -255,26 +270,21 @@ async fn process_ins_buffer_bundles(
             is_executed: bundle.is_executed,
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(ins_buffer_bundles::table)
-                    .values(&b)
-                    .on_conflict(ins_buffer_bundles::instruction_buffer_address)
-                    .do_update()
-                    .set(&b)
-                    .execute(db)
-            })
-            .await
+        insert_into(ins_buffer_bundles::table)
+            .values(&b)
+            .on_conflict(ins_buffer_bundles::instruction_buffer_address)
+            .do_update()
+            .set(&b)
+            .execute(db)
             .context("failed to insert instruction buffer bundle")?;
-        process_ins_buffer_bundle_instructions(client, ib_addr, bundle.instructions).await?;
+        process_ins_buffer_bundle_instructions(db, ib_addr, bundle.instructions)?;
     }
 
     Ok(())
 }
 
-async fn process_ins_buffer_bundle_instructions(
-    client: &Client,
+fn process_ins_buffer_bundle_instructions(
+    db: &Connection,
     ib_addr: Pubkey,
     bundle_instructions: Vec<TXInstruction>,
 ) -> Result<()> {
@@ -284,27 +294,22 @@ async fn process_ins_buffer_bundle_instructions(
             program_id: Owned(ins.program_id.to_string()),
             data: ins.data,
         };
-        client
-            .db()
-            .run(move |db| {
-                insert_into(ins_buffer_bundle_instructions::table)
-                    .values(&bundle_ins)
-                    .on_conflict((
-                        ins_buffer_bundle_instructions::instruction_buffer_address,
-                        ins_buffer_bundle_instructions::program_id,
-                    ))
-                    .do_update()
-                    .set(&bundle_ins)
-                    .execute(db)
-            })
-            .await
+        insert_into(ins_buffer_bundle_instructions::table)
+            .values(&bundle_ins)
+            .on_conflict((
+                ins_buffer_bundle_instructions::instruction_buffer_address,
+                ins_buffer_bundle_instructions::program_id,
+            ))
+            .do_update()
+            .set(&bundle_ins)
+            .execute(db)
             .context("failed to insert instruction buffer bundle instruction")?;
-        process_ins_buffer_bundle_ins_keys(client, ib_addr, ins.program_id, ins.keys).await?;
+        process_ins_buffer_bundle_ins_keys(db, ib_addr, ins.program_id, ins.keys)?;
     }
     Ok(())
 }
-async fn process_ins_buffer_bundle_ins_keys(
-    client: &Client,
+fn process_ins_buffer_bundle_ins_keys(
+    db: &Connection,
     ib_addr: Pubkey,
     program_id: Pubkey,
     keys: Vec<TXAccountMeta>,
@@ -318,21 +323,16 @@ async fn process_ins_buffer_bundle_ins_keys(
             is_writable: key.is_writable,
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(ins_buffer_bundle_ins_keys::table)
-                    .values(&k)
-                    .on_conflict((
-                        ins_buffer_bundle_ins_keys::instruction_buffer_address,
-                        ins_buffer_bundle_ins_keys::program_id,
-                        ins_buffer_bundle_ins_keys::pubkey,
-                    ))
-                    .do_update()
-                    .set(&k)
-                    .execute(db)
-            })
-            .await
+        insert_into(ins_buffer_bundle_ins_keys::table)
+            .values(&k)
+            .on_conflict((
+                ins_buffer_bundle_ins_keys::instruction_buffer_address,
+                ins_buffer_bundle_ins_keys::program_id,
+                ins_buffer_bundle_ins_keys::pubkey,
+            ))
+            .do_update()
+            .set(&k)
+            .execute(db)
             .context("failed to insert instruction buffer bundle instruction account metadata")?;
     }
     Ok(())
@@ -26,6 +26,7 @@ use indexer_core::{
             realm_config_accounts, realm_configs, realms, signatory_records, token_owner_records,
             vote_record_v2_vote_approve_vote_choices, vote_records_v1, vote_records_v2,
         },
+        Connection, UpsertIfNewer,
     },
     util::unix_timestamp,
 };
@@ -499,56 +500,61 @@ pub(crate) async fn process_governance(
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(governances::table)
+        .transaction(move |db| {
+            let updated = insert_into(governances::table)
                 .values(&row)
                 .on_conflict(governances::address)
                 .do_update()
                 .set(&row)
+                .if_newer(governances::slot, governances::write_version)
                 .execute(db)
-        })
-        .await
-        .context("Failed to insert governance account")?;
-
-    let c = data.config;
-    let (vote_threshold_type, vote_threshold_percentage) = match c.vote_threshold_percentage {
-        VoteThresholdPercentage::YesVote(p) => (VoteThresholdEnum::YesVote, i16::try_from(p)?),
-        VoteThresholdPercentage::Quorum(p) => (VoteThresholdEnum::Quorum, i16::try_from(p)?),
-    };
+                .context("Failed to insert governance account")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            let c = &data.config;
+            let (vote_threshold_type, vote_threshold_percentage) = match c.vote_threshold_percentage
+            {
+                VoteThresholdPercentage::YesVote(p) => {
+                    (VoteThresholdEnum::YesVote, i16::try_from(p)?)
+                },
+                VoteThresholdPercentage::Quorum(p) => {
+                    (VoteThresholdEnum::Quorum, i16::try_from(p)?)
+                },
+            };
 
-    let config = DbGovernanceConfig {
-        governance_address: Owned(key.to_string()),
-        vote_threshold_type,
-        vote_threshold_percentage,
-        min_community_weight_to_create_proposal: c
-            .min_community_weight_to_create_proposal
-            .try_into()?,
-        min_instruction_hold_up_time: c.min_transaction_hold_up_time.try_into()?,
-        max_voting_time: c.max_voting_time.try_into()?,
-        vote_tipping: c.vote_tipping.into(),
-        proposal_cool_off_time: c.proposal_cool_off_time.try_into()?,
-        min_council_weight_to_create_proposal: c
-            .min_council_weight_to_create_proposal
-            .try_into()?,
-        slot: slot.try_into()?,
-        write_version: write_version.try_into()?,
-    };
+            let config = DbGovernanceConfig {
+                governance_address: Owned(key.to_string()),
+                vote_threshold_type,
+                vote_threshold_percentage,
+                min_community_weight_to_create_proposal: c
+                    .min_community_weight_to_create_proposal
+                    .try_into()?,
+                min_instruction_hold_up_time: c.min_transaction_hold_up_time.try_into()?,
+                max_voting_time: c.max_voting_time.try_into()?,
+                vote_tipping: c.vote_tipping.into(),
+                proposal_cool_off_time: c.proposal_cool_off_time.try_into()?,
+                min_council_weight_to_create_proposal: c
+                    .min_council_weight_to_create_proposal
+                    .try_into()?,
+                slot: slot.try_into()?,
+                write_version: write_version.try_into()?,
+            };
 
-    client
-        .db()
-        .run(move |db| {
             insert_into(governance_configs::table)
                 .values(&config)
                 .on_conflict(governance_configs::governance_address)
                 .do_update()
                 .set(&config)
+                .if_newer(governance_configs::slot, governance_configs::write_version)
                 .execute(db)
+                .context("Failed to insert governance config")?;
+
+            Result::<_>::Ok(())
         })
         .await
-        .context("Failed to insert governance config")?;
-
-    Ok(())
 }
 
 pub(crate) async fn process_realmv2(
@@ -573,53 +579,53 @@ pub(crate) async fn process_realmv2(
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(realms::table)
+        .transaction(move |db| {
+            let updated = insert_into(realms::table)
                 .values(&row)
                 .on_conflict(realms::address)
                 .do_update()
                 .set(&row)
+                .if_newer(realms::slot, realms::write_version)
                 .execute(db)
-        })
-        .await
-        .context("Failed to insert realm account")?;
+                .context("Failed to insert realm account")?;
 
-    let c = data.config;
-    let (vote_weight_source, vote_weight) = match c.community_mint_max_vote_weight_source {
-        MintMaxVoteWeightSource::SupplyFraction(p) => (MintMaxVoteEnum::SupplyFraction, p),
-        MintMaxVoteWeightSource::Absolute(p) => (MintMaxVoteEnum::Absolute, p),
-    };
+            if updated == 0 {
+                return Ok(());
+            }
 
-    let config = DbRealmConfig {
-        realm_address: Owned(key.to_string()),
-        use_community_voter_weight_addin: c.use_community_voter_weight_addin,
-        use_max_community_voter_weight_addin: c.use_max_community_voter_weight_addin,
-        reserved: Owned(c.reserved.to_vec()),
-        min_community_weight_to_create_governance: c
-            .min_community_weight_to_create_governance
-            .try_into()?,
-        community_mint_max_vote_weight_source: vote_weight_source,
-        community_mint_max_vote_weight: vote_weight.try_into()?,
-        council_mint: c.council_mint.map(|c| Owned(c.to_string())),
-        slot: slot.try_into()?,
-        write_version: write_version.try_into()?,
-    };
+            let c = &data.config;
+            let (vote_weight_source, vote_weight) = match c.community_mint_max_vote_weight_source {
+                MintMaxVoteWeightSource::SupplyFraction(p) => (MintMaxVoteEnum::SupplyFraction, p),
+                MintMaxVoteWeightSource::Absolute(p) => (MintMaxVoteEnum::Absolute, p),
+            };
+
+            let config = DbRealmConfig {
+                realm_address: Owned(key.to_string()),
+                use_community_voter_weight_addin: c.use_community_voter_weight_addin,
+                use_max_community_voter_weight_addin: c.use_max_community_voter_weight_addin,
+                reserved: Owned(c.reserved.to_vec()),
+                min_community_weight_to_create_governance: c
+                    .min_community_weight_to_create_governance
+                    .try_into()?,
+                community_mint_max_vote_weight_source: vote_weight_source,
+                community_mint_max_vote_weight: vote_weight.try_into()?,
+                council_mint: c.council_mint.map(|c| Owned(c.to_string())),
+                slot: slot.try_into()?,
+                write_version: write_version.try_into()?,
+            };
 
-    client
-        .db()
-        .run(move |db| {
             insert_into(realm_configs::table)
                 .values(&config)
                 .on_conflict(realm_configs::realm_address)
                 .do_update()
                 .set(&config)
+                .if_newer(realm_configs::slot, realm_configs::write_version)
                 .execute(db)
+                .context("Failed to insert realm config")?;
+
+            Result::<_>::Ok(())
         })
         .await
-        .context("Failed to insert realm config")?;
-
-    Ok(())
 }
 
 pub(crate) async fn process_realm_config(
@@ -659,6 +665,10 @@ pub(crate) async fn process_realm_config(
                 .on_conflict(realm_config_accounts::address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    realm_config_accounts::slot,
+                    realm_config_accounts::write_version,
+                )
                 .execute(db)
         })
         .await
@@ -701,6 +711,7 @@ pub(crate) async fn process_vote_record_v1(
                 .on_conflict(vote_records_v1::address)
                 .do_update()
                 .set(&row)
+                .if_newer(vote_records_v1::slot, vote_records_v1::write_version)
                 .execute(db)
         })
         .await
@@ -731,31 +742,30 @@ pub(crate) async fn process_vote_record_v2(
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(vote_records_v2::table)
+        .transaction(move |db| {
+            let updated = insert_into(vote_records_v2::table)
                 .values(&row)
                 .on_conflict(vote_records_v2::address)
                 .do_update()
                 .set(&row)
+                .if_newer(vote_records_v2::slot, vote_records_v2::write_version)
                 .execute(db)
-        })
-        .await
-        .context("Failed to insert vote record v2")?;
+                .context("Failed to insert vote record v2")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            if let Vote::Approve(choices) = &data.vote {
+                for c in choices {
+                    let r = DbVoteChoice {
+                        vote_record_v2_address: Owned(key.to_string()),
+                        rank: c.rank.try_into()?,
+                        weight_percentage: c.weight_percentage.try_into()?,
+                        slot: slot.try_into()?,
+                        write_version: write_version.try_into()?,
+                    };
 
-    if let Vote::Approve(choices) = data.vote {
-        for c in choices {
-            let r = DbVoteChoice {
-                vote_record_v2_address: Owned(key.to_string()),
-                rank: c.rank.try_into()?,
-                weight_percentage: c.weight_percentage.try_into()?,
-                slot: slot.try_into()?,
-                write_version: write_version.try_into()?,
-            };
-
-            client
-                .db()
-                .run(move |db| {
                     insert_into(vote_record_v2_vote_approve_vote_choices::table)
                         .values(&r)
                         .on_conflict((
@@ -765,14 +775,18 @@ pub(crate) async fn process_vote_record_v2(
                         ))
                         .do_update()
                         .set(&r)
+                        .if_newer(
+                            vote_record_v2_vote_approve_vote_choices::slot,
+                            vote_record_v2_vote_approve_vote_choices::write_version,
+                        )
                         .execute(db)
-                })
-                .await
-                .context("Failed to insert vote record v2 approve vote choice")?;
-        }
-    }
+                        .context("Failed to insert vote record v2 approve vote choice")?;
+                }
+            }
 
-    Ok(())
+            Result::<_>::Ok(())
+        })
+        .await
 }
 
 pub(crate) async fn process_token_owner_record(
@@ -808,6 +822,10 @@ pub(crate) async fn process_token_owner_record(
                 .on_conflict(token_owner_records::address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    token_owner_records::slot,
+                    token_owner_records::write_version,
+                )
                 .execute(db)
         })
         .await
@@ -843,6 +861,7 @@ pub(crate) async fn process_signatory_record(
                 .on_conflict(signatory_records::address)
                 .do_update()
                 .set(&row)
+                .if_newer(signatory_records::slot, signatory_records::write_version)
                 .execute(db)
         })
         .await
@@ -910,6 +929,7 @@ pub(crate) async fn process_proposal_v1(
                 .on_conflict(proposals_v1::address)
                 .do_update()
                 .set(&row)
+                .if_newer(proposals_v1::slot, proposals_v1::write_version)
                 .execute(db)
         })
         .await
@@ -978,73 +998,72 @@ pub(crate) async fn process_proposal_v2(
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(proposals_v2::table)
+        .transaction(move |db| {
+            let updated = insert_into(proposals_v2::table)
                 .values(&row)
                 .on_conflict(proposals_v2::address)
                 .do_update()
                 .set(&row)
+                .if_newer(proposals_v2::slot, proposals_v2::write_version)
                 .execute(db)
-        })
-        .await
-        .context("Failed to insert proposal v2")?;
-
-    for o in data.options {
-        let row = DbProposalOption {
-            proposal_address: Owned(key.to_string()),
-            label: Owned(o.label.to_string()),
-            vote_weight: o.vote_weight.try_into()?,
-            vote_result: o.vote_result.into(),
-            transactions_executed_count: o.transactions_next_index.try_into()?,
-            transactions_count: o.transactions_count.try_into()?,
-            transactions_next_index: o.transactions_next_index.try_into()?,
-            slot: slot.try_into()?,
-            write_version: write_version.try_into()?,
-        };
+                .context("Failed to insert proposal v2")?;
+
+            if updated == 0 {
+                return Ok(());
+            }
+
+            for o in &data.options {
+                let row = DbProposalOption {
+                    proposal_address: Owned(key.to_string()),
+                    label: Owned(o.label.to_string()),
+                    vote_weight: o.vote_weight.try_into()?,
+                    vote_result: o.vote_result.into(),
+                    transactions_executed_count: o.transactions_next_index.try_into()?,
+                    transactions_count: o.transactions_count.try_into()?,
+                    transactions_next_index: o.transactions_next_index.try_into()?,
+                    slot: slot.try_into()?,
+                    write_version: write_version.try_into()?,
+                };
 
-        client
-            .db()
-            .run(move |db| {
                 insert_into(proposal_options::table)
                     .values(&row)
                     .on_conflict((proposal_options::proposal_address, proposal_options::label))
                     .do_update()
                     .set(&row)
+                    .if_newer(proposal_options::slot, proposal_options::write_version)
                     .execute(db)
-            })
-            .await
-            .context("Failed to insert proposal option")?;
-    }
+                    .context("Failed to insert proposal option")?;
+            }
+
+            if let VoteType::MultiChoice {
+                max_voter_options,
+                max_winning_options,
+            } = data.vote_type
+            {
+                let row = MultiChoice {
+                    address: Owned(key.to_string()),
+                    max_voter_options: max_voter_options.try_into()?,
+                    max_winning_options: max_winning_options.try_into()?,
+                    slot: slot.try_into()?,
+                    write_version: write_version.try_into()?,
+                };
 
-    if let VoteType::MultiChoice {
-        max_voter_options,
-        max_winning_options,
-    } = data.vote_type
-    {
-        let row = MultiChoice {
-            address: Owned(key.to_string()),
-            max_voter_options: max_voter_options.try_into()?,
-            max_winning_options: max_winning_options.try_into()?,
-            slot: slot.try_into()?,
-            write_version: write_version.try_into()?,
-        };
-
-        client
-            .db()
-            .run(move |db| {
                 insert_into(proposal_vote_type_multi_choices::table)
                     .values(&row)
                     .on_conflict(proposal_vote_type_multi_choices::address)
                     .do_update()
                     .set(&row)
+                    .if_newer(
+                        proposal_vote_type_multi_choices::slot,
+                        proposal_vote_type_multi_choices::write_version,
+                    )
                     .execute(db)
-            })
-            .await
-            .context("Failed to insert multichoice vote type")?;
-    }
+                    .context("Failed to insert multichoice vote type")?;
+            }
 
-    Ok(())
+            Result::<_>::Ok(())
+        })
+        .await
 }
 
 pub(crate) async fn process_proposal_transaction(
@@ -1070,34 +1089,35 @@ pub(crate) async fn process_proposal_transaction(
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(proposal_transactions::table)
+        .transaction(move |db| {
+            let updated = insert_into(proposal_transactions::table)
                 .values(&row)
                 .on_conflict(proposal_transactions::address)
                 .do_update()
                 .set(&row)
+                .if_newer(
+                    proposal_transactions::slot,
+                    proposal_transactions::write_version,
+                )
                 .execute(db)
-        })
-        .await
-        .context("Failed to insert proposal transaction")?;
+                .context("Failed to insert proposal transaction")?;
 
-    for ins in data.instructions {
-        let row = ProposalTransactionInstruction {
-            proposal_transaction: Owned(key.to_string()),
-            program_id: Owned(ins.program_id.to_string()),
-            data: Owned(ins.data),
-            slot: slot.try_into()?,
-            write_version: write_version.try_into()?,
-        };
+            if updated == 0 {
+                return Ok(());
+            }
+
+            for ins in &data.instructions {
+                let row = ProposalTransactionInstruction {
+                    proposal_transaction: Owned(key.to_string()),
+                    program_id: Owned(ins.program_id.to_string()),
+                    data: Owned(ins.data.clone()),
+                    slot: slot.try_into()?,
+                    write_version: write_version.try_into()?,
+                };
 
-        process_instruction_accounts(client, key, ins.accounts, slot, write_version)
-            .await
-            .context("failed to insert instruction accounts")?;
+                process_instruction_accounts(db, key, ins.accounts.clone(), slot, write_version)
+                    .context("failed to insert instruction accounts")?;
 
-        client
-            .db()
-            .run(move |db| {
                 insert_into(proposal_transaction_instructions::table)
                     .values(&row)
                     .on_conflict((
@@ -1107,17 +1127,21 @@ pub(crate) async fn process_proposal_transaction(
                     ))
                     .do_update()
                     .set(&row)
+                    .if_newer(
+                        proposal_transaction_instructions::slot,
+                        proposal_transaction_instructions::write_version,
+                    )
                     .execute(db)
-            })
-            .await
-            .context("Failed to insert proposal transaction instruction data")?;
-    }
+                    .context("Failed to insert proposal transaction instruction data")?;
+            }
 
-    Ok(())
+            Result::<_>::Ok(())
+        })
+        .await
 }
 
-async fn process_instruction_accounts(
-    client: &Client,
+fn process_instruction_accounts(
+    db: &Connection,
     key: Pubkey,
     data: Vec<AccountMetaData>,
     slot: u64,
@@ -1133,20 +1157,19 @@ async fn process_instruction_accounts(
             write_version: write_version.try_into()?,
         };
 
-        client
-            .db()
-            .run(move |db| {
-                insert_into(proposal_transaction_instruction_accounts::table)
-                    .values(&row)
-                    .on_conflict((
-                        proposal_transaction_instruction_accounts::proposal_transaction,
-                        proposal_transaction_instruction_accounts::account_pubkey,
-                    ))
-                    .do_update()
-                    .set(&row)
-                    .execute(db)
-            })
-            .await
+        insert_into(proposal_transaction_instruction_accounts::table)
+            .values(&row)
+            .on_conflict((
+                proposal_transaction_instruction_accounts::proposal_transaction,
+                proposal_transaction_instruction_accounts::account_pubkey,
+            ))
+            .do_update()
+            .set(&row)
+            .if_newer(
+                proposal_transaction_instruction_accounts::slot,
+                proposal_transaction_instruction_accounts::write_version,
+            )
+            .execute(db)
             .context("Failed to insert proposal transaction instruction account ")?;
     }
 
@@ -1,17 +1,17 @@
 use indexer::prelude::*;
-use indexer_core::{
-    db::{
-        insert_into,
-        models::{AssociatedTokenAccount, CurrentMetadataOwner},
-        tables::{associated_token_accounts, current_metadata_owners},
-        update,
-    },
-    pubkeys,
+use indexer_core::db::{
+    insert_into,
+    models::{AssociatedTokenAccount, CurrentMetadataOwner},
+    tables::{associated_token_accounts, current_metadata_owners},
+    UpsertIfNewer,
 };
 use spl_token::state::Account as TokenAccount;
 
 use super::Client;
 
+/// Number of token accounts written per transaction when backfilling a mint
+const BACKFILL_CHUNK_SIZE: usize = 1000;
+
 pub async fn process(
     client: &Client,
     key: Pubkey,
@@ -21,7 +21,7 @@ pub async fn process(
 ) -> Result<()> {
     let pubkey = key.to_string();
 
-    if pubkeys::TOKEN_MINTS.contains(&token_account.mint) {
+    if client.is_tracked_mint(&token_account.mint) {
         upsert_ata(client, key, token_account, slot, write_version)
             .await
             .context("failed to insert associated token account")?;
@@ -38,63 +38,95 @@ pub async fn process(
 
     let owner = token_account.owner.to_string();
     let mint_address = token_account.mint.to_string();
-    let incoming_slot: i64 = slot.try_into()?;
 
     let values = CurrentMetadataOwner {
         mint_address: Owned(mint_address),
         owner_address: Owned(owner),
         token_account_address: Owned(pubkey),
-        slot: incoming_slot,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
     client
-        .db()
-        .run(move |db| {
-            let rows = current_metadata_owners::table
-                .select((
-                    current_metadata_owners::mint_address,
-                    current_metadata_owners::owner_address,
-                    current_metadata_owners::token_account_address,
-                    current_metadata_owners::slot,
-                ))
-                .filter(current_metadata_owners::mint_address.eq(token_account.mint.to_string()))
-                .load::<CurrentMetadataOwner>(db)
-                .context("failed to load metadata owner!")?;
-
-            match rows.get(0) {
-                Some(r) if incoming_slot > r.slot => {
-                    db.build_transaction().read_write().run(|| {
-                        update(
-                            current_metadata_owners::table
-                                .filter(current_metadata_owners::mint_address.eq(values.clone().mint_address)),
+        .batch()
+        .upsert(
+            "current_metadata_owners",
+            values.mint_address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(current_metadata_owners::table)
+                    .values(&values)
+                    .on_conflict(current_metadata_owners::mint_address)
+                    .do_update()
+                    .set(&values)
+                    .if_newer(
+                        current_metadata_owners::slot,
+                        current_metadata_owners::write_version,
+                    )
+                    .execute(db)
+            },
+        )
+        .await
+        .context("failed to insert token metadata owner!")?;
+
+    Ok(())
+}
+
+/// Index the holder balances of every existing token account of the given
+/// mint, for mints whose balances were not tracked before
+///
+/// Accounts are recorded at the slot they were fetched at with a write
+/// version of zero, so any live update for the same slot takes precedence.
+pub(crate) async fn backfill(client: &Client, mint: Pubkey) -> Result<()> {
+    let (slot, accounts) = client
+        .token_accounts(mint)
+        .await
+        .context("Failed to fetch token accounts")?;
+
+    debug!(
+        "Backfilling {} token account(s) of mint {} at slot {}",
+        accounts.len(),
+        mint,
+        slot
+    );
+
+    let rows = accounts
+        .into_iter()
+        .map(|(key, acct)| {
+            Ok(AssociatedTokenAccount {
+                address: Owned(key.to_string()),
+                mint: Owned(acct.mint.to_string()),
+                owner: Owned(acct.owner.to_string()),
+                amount: acct.amount.try_into()?,
+                slot: slot.try_into()?,
+                write_version: 0,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    for chunk in rows.chunks(BACKFILL_CHUNK_SIZE) {
+        let chunk = chunk.to_vec();
+
+        client
+            .transaction(move |db| {
+                chunk.iter().try_for_each(|row| {
+                    insert_into(associated_token_accounts::table)
+                        .values(row)
+                        .on_conflict(associated_token_accounts::address)
+                        .do_update()
+                        .set(row)
+                        .if_newer(
+                            associated_token_accounts::slot,
+                            associated_token_accounts::write_version,
                         )
-                        .set(&values)
                         .execute(db)
-                        .context("transaction failed! unable to update metadata_owners when incoming slot > indexed slot")
                         .map(|_| ())
-                    })
-                },
-                Some(_) => Ok(()),
-                None => {
-                    db.build_transaction()
-                        .read_write()
-                        .run(|| {
-                            insert_into(current_metadata_owners::table)
-                                .values(&values)
-                                .on_conflict(current_metadata_owners::mint_address)
-                                .do_update()
-                                .set(&values)
-                                .execute(db)
-                                .map(|_| ())
-                        })
-                        .context("transaction failed! unable to insert metadata owner")?;
-
-                    Ok(())
-                },
-            }
-        })
-        .await
-        .context("failed to insert token metadata owner!")?;
+                })
+            })
+            .await
+            .context("Failed to backfill token accounts")?;
+    }
+
     Ok(())
 }
 
@@ -115,15 +147,24 @@ async fn upsert_ata(
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(associated_token_accounts::table)
-                .values(&row)
-                .on_conflict(associated_token_accounts::address)
-                .do_update()
-                .set(&row)
-                .execute(db)
-        })
+        .batch()
+        .upsert(
+            "associated_token_accounts",
+            row.address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(associated_token_accounts::table)
+                    .values(&row)
+                    .on_conflict(associated_token_accounts::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(
+                        associated_token_accounts::slot,
+                        associated_token_accounts::write_version,
+                    )
+                    .execute(db)
+            },
+        )
         .await
         .context("failed to insert token account")?;
 
@@ -0,0 +1,76 @@
+use indexer::prelude::*;
+use indexer_core::db::{
+    insert_into, models::TokenMintExtension, tables::token_mint_extensions, UpsertIfNewer,
+};
+
+use super::Client;
+use crate::handler::programs::token_2022::MintExtensions;
+
+/// Token-2022 stores unset optional keys as the default key
+fn nonzero(key: Pubkey) -> Option<Cow<'static, str>> {
+    (key != Pubkey::default()).then(|| Owned(key.to_string()))
+}
+
+pub(crate) async fn process_mint(
+    client: &Client,
+    key: Pubkey,
+    extensions: MintExtensions,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let MintExtensions {
+        transfer_fee_config,
+        non_transferable,
+        metadata_pointer,
+    } = extensions;
+
+    let (transfer_fee_config_authority, withdraw_withheld_authority, withheld_amount, fee) =
+        match transfer_fee_config {
+            Some(config) => (
+                nonzero(config.transfer_fee_config_authority),
+                nonzero(config.withdraw_withheld_authority),
+                Some(config.withheld_amount.try_into()?),
+                Some(config.newer_transfer_fee),
+            ),
+            None => (None, None, None, None),
+        };
+
+    let row = TokenMintExtension {
+        mint_address: Owned(key.to_string()),
+        transfer_fee_config_authority,
+        withdraw_withheld_authority,
+        withheld_amount,
+        transfer_fee_epoch: fee.as_ref().map(|f| f.epoch.try_into()).transpose()?,
+        transfer_fee_basis_points: fee.as_ref().map(|f| f.transfer_fee_basis_points.into()),
+        maximum_transfer_fee: fee.as_ref().map(|f| f.maximum_fee.try_into()).transpose()?,
+        non_transferable,
+        metadata_pointer_authority: metadata_pointer.as_ref().and_then(|p| nonzero(p.authority)),
+        metadata_pointer_address: metadata_pointer.and_then(|p| nonzero(p.metadata_address)),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .batch()
+        .upsert(
+            "token_mint_extensions",
+            row.mint_address.clone(),
+            (slot, write_version),
+            move |db| {
+                insert_into(token_mint_extensions::table)
+                    .values(&row)
+                    .on_conflict(token_mint_extensions::mint_address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer(
+                        token_mint_extensions::slot,
+                        token_mint_extensions::write_version,
+                    )
+                    .execute(db)
+            },
+        )
+        .await
+        .context("failed to insert Token-2022 mint extensions")?;
+
+    Ok(())
+}
@@ -0,0 +1,603 @@
+//! Generic decoding of Anchor programs from their IDL
+//!
+//! Programs without a dedicated handler can still be indexed by supplying
+//! their Anchor IDL at startup.  Accounts and instructions are matched by
+//! their Anchor discriminators and decoded into JSON, using strings for
+//! 64- and 128-bit integers and base58 for public keys.
+
+use std::{collections::HashMap, fs::File, path::PathBuf, str::FromStr};
+
+use borsh::BorshDeserialize;
+use heck::ToSnakeCase;
+use indexer::prelude::*;
+use indexer_core::{base64, serde_json};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use solana_program::hash::hash;
+
+/// The maximum nesting depth of types decoded from an IDL
+const MAX_DEPTH: usize = 32;
+
+/// A program ID paired with the path to its Anchor IDL, given on the command
+/// line as `<program_id>=<path>`
+#[derive(Debug, Clone)]
+pub struct IdlSource {
+    program: Pubkey,
+    path: PathBuf,
+}
+
+impl FromStr for IdlSource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (program, path) = s
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Expected <program_id>=<path>, got {s:?}"))?;
+
+        Ok(Self {
+            program: program.parse().context("Invalid IDL program ID")?,
+            path: path.into(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Idl {
+    #[serde(default)]
+    instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    accounts: Vec<IdlTypeDef>,
+    #[serde(default)]
+    types: Vec<IdlTypeDef>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdlInstruction {
+    name: String,
+    accounts: Vec<IdlAccountItem>,
+    args: Vec<IdlField>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum IdlAccountItem {
+    Accounts {
+        name: String,
+        accounts: Vec<IdlAccountItem>,
+    },
+    Account {
+        name: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct IdlField {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IdlType,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdlTypeDef {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IdlTypeDefTy,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum IdlTypeDefTy {
+    Struct { fields: Vec<IdlField> },
+    Enum { variants: Vec<IdlEnumVariant> },
+}
+
+#[derive(Debug, Deserialize)]
+struct IdlEnumVariant {
+    name: String,
+    fields: Option<IdlEnumFields>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum IdlEnumFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum IdlType {
+    Primitive(String),
+    Vec { vec: Box<IdlType> },
+    Option { option: Box<IdlType> },
+    COption { coption: Box<IdlType> },
+    Array { array: (Box<IdlType>, usize) },
+    Defined { defined: String },
+}
+
+/// An instruction decoded from its program's IDL
+#[derive(Debug)]
+pub struct DecodedInstruction<'a> {
+    /// The name of the instruction in the IDL
+    pub name: &'a str,
+    /// The instruction accounts, keyed by their names in the IDL
+    pub accounts: Value,
+    /// The instruction arguments
+    pub data: Value,
+}
+
+/// The parsed IDL of a single program
+#[derive(Debug)]
+pub struct ProgramIdl {
+    idl: Idl,
+    accounts: HashMap<[u8; 8], usize>,
+    instructions: HashMap<[u8; 8], usize>,
+    types: HashMap<String, usize>,
+}
+
+fn discriminator(preimage: &str) -> [u8; 8] {
+    let mut disc = [0; 8];
+    disc.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+    disc
+}
+
+/// Check that a sequence of `len` elements can be read from the remaining
+/// buffer, assuming each element occupies at least one byte.  This prevents
+/// a hostile length prefix from looping over zero-sized elements.
+fn check_len(len: usize, buf: &[u8]) -> Result<()> {
+    if len > buf.len() {
+        bail!(
+            "Sequence length {len} exceeds the {} remaining byte(s)",
+            buf.len()
+        );
+    }
+
+    Ok(())
+}
+
+impl ProgramIdl {
+    fn new(idl: Idl) -> Self {
+        let accounts = idl
+            .accounts
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (discriminator(&format!("account:{}", a.name)), i))
+            .collect();
+        let instructions = idl
+            .instructions
+            .iter()
+            .enumerate()
+            .map(|(i, ins)| {
+                (
+                    discriminator(&format!("global:{}", ins.name.to_snake_case())),
+                    i,
+                )
+            })
+            .collect();
+        let types = idl
+            .types
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.name.clone(), i))
+            .collect();
+
+        Self {
+            idl,
+            accounts,
+            instructions,
+            types,
+        }
+    }
+
+    /// Decode the given account data, returning the name of its account type
+    /// and its fields, or `None` if the discriminator does not match any
+    /// account in the IDL
+    ///
+    /// # Errors
+    /// This function fails if the account data does not match its type.
+    pub fn decode_account<'a>(&'a self, data: &[u8]) -> Result<Option<(&'a str, Value)>> {
+        let Some(def) = data
+            .get(..8)
+            .and_then(|d| self.accounts.get(d))
+            .map(|&i| &self.idl.accounts[i])
+        else {
+            return Ok(None);
+        };
+
+        let value = self
+            .decode_def(&def.ty, &mut &data[8..], 0)
+            .with_context(|| format!("Failed to decode {} account", def.name))?;
+
+        Ok(Some((&def.name, value)))
+    }
+
+    /// Decode the given instruction data and accounts, or return `None` if
+    /// the discriminator does not match any instruction in the IDL
+    ///
+    /// # Errors
+    /// This function fails if the instruction data does not match its
+    /// arguments.
+    pub fn decode_instruction<'a>(
+        &'a self,
+        data: &[u8],
+        accounts: &[Pubkey],
+    ) -> Result<Option<DecodedInstruction<'a>>> {
+        let Some(ins) = data
+            .get(..8)
+            .and_then(|d| self.instructions.get(d))
+            .map(|&i| &self.idl.instructions[i])
+        else {
+            return Ok(None);
+        };
+
+        let mut buf = &data[8..];
+        let mut args = Map::new();
+
+        for field in &ins.args {
+            let value = self
+                .decode(&field.ty, &mut buf, 0)
+                .with_context(|| format!("Failed to decode {}.{}", ins.name, field.name))?;
+
+            args.insert(field.name.clone(), value);
+        }
+
+        let mut named = Map::new();
+        let mut keys = accounts.iter();
+        name_accounts(&ins.accounts, "", &mut keys, &mut named);
+
+        let remaining: Vec<_> = keys.map(|k| Value::String(k.to_string())).collect();
+
+        if !remaining.is_empty() {
+            named.insert("remainingAccounts".into(), remaining.into());
+        }
+
+        Ok(Some(DecodedInstruction {
+            name: &ins.name,
+            accounts: named.into(),
+            data: args.into(),
+        }))
+    }
+
+    fn decode_def(&self, ty: &IdlTypeDefTy, buf: &mut &[u8], depth: usize) -> Result<Value> {
+        match ty {
+            IdlTypeDefTy::Struct { fields } => self.decode_fields(fields, buf, depth),
+            IdlTypeDefTy::Enum { variants } => {
+                let idx = u8::deserialize(buf)?;
+                let variant = variants
+                    .get(usize::from(idx))
+                    .ok_or_else(|| anyhow!("Invalid enum variant {idx}"))?;
+
+                let fields = match variant.fields {
+                    None => return Ok(Value::String(variant.name.clone())),
+                    Some(IdlEnumFields::Named(ref f)) => self.decode_fields(f, buf, depth)?,
+                    Some(IdlEnumFields::Tuple(ref t)) => t
+                        .iter()
+                        .map(|t| self.decode(t, buf, depth + 1))
+                        .collect::<Result<Vec<_>>>()?
+                        .into(),
+                };
+
+                Ok(json!({ &variant.name: fields }))
+            },
+        }
+    }
+
+    fn decode_fields(&self, fields: &[IdlField], buf: &mut &[u8], depth: usize) -> Result<Value> {
+        fields
+            .iter()
+            .map(|f| Ok((f.name.clone(), self.decode(&f.ty, buf, depth + 1)?)))
+            .collect::<Result<Map<_, _>>>()
+            .map(Value::Object)
+    }
+
+    fn decode(&self, ty: &IdlType, buf: &mut &[u8], depth: usize) -> Result<Value> {
+        if depth > MAX_DEPTH {
+            bail!("Type nesting is too deep");
+        }
+
+        Ok(match ty {
+            IdlType::Primitive(p) => decode_primitive(p, buf)?,
+            IdlType::Vec { vec } => {
+                let len = u32::deserialize(buf)?.try_into()?;
+                check_len(len, buf)?;
+
+                (0..len)
+                    .map(|_| self.decode(vec, buf, depth + 1))
+                    .collect::<Result<Vec<_>>>()?
+                    .into()
+            },
+            IdlType::Option { option } => match u8::deserialize(buf)? {
+                0 => Value::Null,
+                1 => self.decode(option, buf, depth + 1)?,
+                t => bail!("Invalid option tag {t}"),
+            },
+            IdlType::COption { coption } => match u32::deserialize(buf)? {
+                0 => Value::Null,
+                1 => self.decode(coption, buf, depth + 1)?,
+                t => bail!("Invalid COption tag {t}"),
+            },
+            IdlType::Array { array: (ty, len) } => {
+                check_len(*len, buf)?;
+
+                (0..*len)
+                    .map(|_| self.decode(ty, buf, depth + 1))
+                    .collect::<Result<Vec<_>>>()?
+                    .into()
+            },
+            IdlType::Defined { defined } => {
+                let def = self
+                    .types
+                    .get(defined)
+                    .map(|&i| &self.idl.types[i])
+                    .ok_or_else(|| anyhow!("Unknown type {defined:?}"))?;
+
+                self.decode_def(&def.ty, buf, depth + 1)?
+            },
+        })
+    }
+}
+
+fn name_accounts<'a>(
+    items: &[IdlAccountItem],
+    prefix: &str,
+    keys: &mut impl Iterator<Item = &'a Pubkey>,
+    out: &mut Map<String, Value>,
+) {
+    for item in items {
+        match item {
+            IdlAccountItem::Accounts { name, accounts } => {
+                name_accounts(accounts, &format!("{prefix}{name}."), keys, out);
+            },
+            IdlAccountItem::Account { name } => {
+                let Some(key) = keys.next() else { return };
+
+                out.insert(format!("{prefix}{name}"), key.to_string().into());
+            },
+        }
+    }
+}
+
+fn decode_primitive(name: &str, buf: &mut &[u8]) -> Result<Value> {
+    Ok(match name {
+        "bool" => bool::deserialize(buf)?.into(),
+        "u8" => u8::deserialize(buf)?.into(),
+        "i8" => i8::deserialize(buf)?.into(),
+        "u16" => u16::deserialize(buf)?.into(),
+        "i16" => i16::deserialize(buf)?.into(),
+        "u32" => u32::deserialize(buf)?.into(),
+        "i32" => i32::deserialize(buf)?.into(),
+        "f32" => f32::deserialize(buf)?.into(),
+        "f64" => f64::deserialize(buf)?.into(),
+        // Stored as strings, since JSON numbers lose precision above 2^53
+        "u64" => u64::deserialize(buf)?.to_string().into(),
+        "i64" => i64::deserialize(buf)?.to_string().into(),
+        "u128" => u128::deserialize(buf)?.to_string().into(),
+        "i128" => i128::deserialize(buf)?.to_string().into(),
+        "string" => String::deserialize(buf)?.into(),
+        "bytes" => base64::encode(Vec::<u8>::deserialize(buf)?).into(),
+        "publicKey" => Pubkey::deserialize(buf)?.to_string().into(),
+        p => bail!("Unsupported IDL type {p:?}"),
+    })
+}
+
+/// The IDLs of all programs configured for generic indexing
+#[derive(Debug, Default)]
+pub struct AnchorIdls(HashMap<Pubkey, ProgramIdl>);
+
+impl AnchorIdls {
+    /// Read and parse the IDL files of the given programs
+    ///
+    /// # Errors
+    /// This function fails if an IDL file cannot be read or parsed.
+    pub fn load(sources: impl IntoIterator<Item = IdlSource>) -> Result<Self> {
+        sources
+            .into_iter()
+            .map(|IdlSource { program, path }| {
+                let file = File::open(&path)
+                    .with_context(|| format!("Failed to open IDL file {path:?}"))?;
+                let idl: Idl = serde_json::from_reader(std::io::BufReader::new(file))
+                    .with_context(|| format!("Failed to parse IDL file {path:?}"))?;
+
+                debug!(
+                    "Loaded IDL for {program} with {} account(s) and {} instruction(s)",
+                    idl.accounts.len(),
+                    idl.instructions.len()
+                );
+
+                Ok((program, ProgramIdl::new(idl)))
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+
+    /// Get the IDL of the given program, if one was configured
+    #[inline]
+    #[must_use]
+    pub fn get(&self, program: &Pubkey) -> Option<&ProgramIdl> {
+        self.0.get(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+
+    use super::*;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    fn idl() -> ProgramIdl {
+        ProgramIdl::new(
+            serde_json::from_value(json!({
+                "instructions": [
+                    {
+                        "name": "setAuthorityPDA",
+                        "accounts": [
+                            { "name": "vault", "isMut": true, "isSigner": false },
+                            {
+                                "name": "authority",
+                                "accounts": [
+                                    { "name": "current", "isMut": false, "isSigner": true },
+                                    { "name": "next", "isMut": false, "isSigner": false },
+                                ],
+                            },
+                        ],
+                        "args": [
+                            { "name": "bump", "type": "u8" },
+                            { "name": "expiry", "type": { "option": "i64" } },
+                        ],
+                    },
+                    {
+                        "name": "markEmpty",
+                        "accounts": [],
+                        "args": [{ "name": "marks", "type": { "vec": { "defined": "Empty" } } }],
+                    },
+                ],
+                "accounts": [
+                    {
+                        "name": "Vault",
+                        "type": {
+                            "kind": "struct",
+                            "fields": [
+                                { "name": "owner", "type": "publicKey" },
+                                { "name": "amount", "type": "u64" },
+                                { "name": "tags", "type": { "vec": "string" } },
+                                { "name": "seed", "type": { "array": ["u8", 2] } },
+                                { "name": "state", "type": { "defined": "State" } },
+                            ],
+                        },
+                    },
+                ],
+                "types": [
+                    {
+                        "name": "State",
+                        "type": {
+                            "kind": "enum",
+                            "variants": [
+                                { "name": "Closed" },
+                                { "name": "Open", "fields": [{ "name": "since", "type": "i64" }] },
+                                { "name": "Locked", "fields": ["u16", "bool"] },
+                            ],
+                        },
+                    },
+                    { "name": "Empty", "type": { "kind": "struct", "fields": [] } },
+                ],
+            }))
+            .unwrap(),
+        )
+    }
+
+    fn vault(state: &[u8]) -> Vec<u8> {
+        let mut data = discriminator("account:Vault").to_vec();
+        data.extend(key(1).to_bytes());
+        data.extend(u64::MAX.to_le_bytes());
+        data.extend(vec!["a".to_owned(), "bc".to_owned()].try_to_vec().unwrap());
+        data.extend([7, 8]);
+        data.extend(state);
+        data
+    }
+
+    #[test]
+    fn test_instruction_discriminator() {
+        assert_eq!("setAuthorityPDA".to_snake_case(), "set_authority_pda");
+
+        let idl = idl();
+        let mut data = discriminator("global:set_authority_pda").to_vec();
+        data.extend([254, 0]);
+
+        let ins = idl.decode_instruction(&data, &[]).unwrap().unwrap();
+
+        assert_eq!(ins.name, "setAuthorityPDA");
+        assert_eq!(ins.data, json!({ "bump": 254, "expiry": null }));
+    }
+
+    #[test]
+    fn test_decode_instruction() {
+        let idl = idl();
+        let mut data = discriminator("global:set_authority_pda").to_vec();
+        data.push(1);
+        data.push(1);
+        data.extend((-5_i64).to_le_bytes());
+
+        let ins = idl
+            .decode_instruction(&data, &[key(1), key(2), key(3), key(4)])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(ins.data, json!({ "bump": 1, "expiry": "-5" }));
+        assert_eq!(
+            ins.accounts,
+            json!({
+                "vault": key(1).to_string(),
+                "authority.current": key(2).to_string(),
+                "authority.next": key(3).to_string(),
+                "remainingAccounts": [key(4).to_string()],
+            })
+        );
+
+        let short = idl.decode_instruction(&data, &[key(1)]).unwrap().unwrap();
+
+        assert_eq!(short.accounts, json!({ "vault": key(1).to_string() }));
+    }
+
+    #[test]
+    fn test_decode_account() {
+        let idl = idl();
+
+        let expected = |state| {
+            json!({
+                "owner": key(1).to_string(),
+                "amount": u64::MAX.to_string(),
+                "tags": ["a", "bc"],
+                "seed": [7, 8],
+                "state": state,
+            })
+        };
+
+        let (name, value) = idl.decode_account(&vault(&[0])).unwrap().unwrap();
+        assert_eq!(name, "Vault");
+        assert_eq!(value, expected(json!("Closed")));
+
+        let open = [&[1][..], &1_700_000_000_i64.to_le_bytes()[..]].concat();
+        let (_, value) = idl.decode_account(&vault(&open)).unwrap().unwrap();
+        assert_eq!(
+            value,
+            expected(json!({ "Open": { "since": "1700000000" } }))
+        );
+
+        let (_, value) = idl.decode_account(&vault(&[2, 3, 0, 1])).unwrap().unwrap();
+        assert_eq!(value, expected(json!({ "Locked": [3, true] })));
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        let idl = idl();
+
+        assert!(idl.decode_account(&[0; 16]).unwrap().is_none());
+        assert!(idl.decode_instruction(&[0; 16], &[]).unwrap().is_none());
+        assert!(idl.decode_account(&[1, 2, 3]).unwrap().is_none());
+
+        assert!(idl.decode_account(&vault(&[3])).is_err());
+
+        let truncated = vault(&[1]);
+        assert!(idl.decode_account(&truncated).is_err());
+    }
+
+    #[test]
+    fn test_hostile_length() {
+        let idl = idl();
+        let mut data = discriminator("global:mark_empty").to_vec();
+        data.extend(u32::MAX.to_le_bytes());
+
+        assert!(idl.decode_instruction(&data, &[]).is_err());
+
+        let mut data = discriminator("global:mark_empty").to_vec();
+        data.extend(2_u32.to_le_bytes());
+        data.extend([0, 0]);
+
+        let ins = idl.decode_instruction(&data, &[]).unwrap().unwrap();
+        assert_eq!(ins.data, json!({ "marks": [{}, {}] }));
+    }
+}
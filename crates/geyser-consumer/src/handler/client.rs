@@ -1,9 +1,38 @@
-use std::{fmt, sync::Arc, time::Duration};
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{Arc, RwLock, Weak},
+    time::Duration,
+};
 
-use indexer::{db::Pool, prelude::*, reqwest, search_dispatch};
-use indexer_core::clap;
+use indexer::{
+    db::{BatchArgs, Batcher, Pool},
+    prelude::*,
+    reqwest, search_dispatch,
+};
+use indexer_core::{
+    clap,
+    db::{tables::reward_centers, PooledConnection},
+    pubkeys,
+};
 use indexer_rabbitmq::{geyser, http_indexer, job_runner, search_indexer};
-use solana_client::rpc_client::RpcClient;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::program_pack::Pack;
+use spl_token::state::Account as TokenAccount;
+
+use super::{
+    anchor_idl::{AnchorIdls, IdlSource},
+    slot_times::SlotTimes,
+};
+
+/// How often the token mints of known reward centers are re-read, so mints
+/// added by another consumer are tracked without a restart
+const TRACKED_MINT_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
 
 #[derive(Debug)]
 struct HttpProducers {
@@ -37,8 +66,23 @@ pub struct Args {
     #[arg(long, env, default_value_t = false)]
     enable_block_reindex: bool,
 
+    /// Additional token mints whose holder balances should be indexed, on top
+    /// of the built-in list and the token mints of known reward centers
+    #[arg(long, env, use_value_delimiter(true))]
+    tracked_mints: Vec<Pubkey>,
+
+    /// Anchor programs to index generically from their IDL, given as
+    /// `<program_id>=<path to IDL JSON>`
+    ///
+    /// Programs with a dedicated handler are not affected.
+    #[arg(long, env, use_value_delimiter(true))]
+    anchor_idls: Vec<IdlSource>,
+
     #[command(flatten)]
     search: search_dispatch::Args,
+
+    #[command(flatten)]
+    batch: BatchArgs,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -55,17 +99,60 @@ enum DialectEvent {
 #[derive(Debug)]
 pub struct Client {
     db: Pool,
+    batch: Batcher,
     http: reqwest::Client,
     http_prod: HttpProducers,
     rpc_client: Rpc,
     job_prod: JobProducers,
     search: search_dispatch::Client,
     startup: geyser::StartupType,
+    tracked_mints: RwLock<HashSet<Pubkey>>,
+    anchor_idls: AnchorIdls,
+    slot_times: Arc<SlotTimes>,
     dialect_api_endpoint: Option<String>,
     dialect_api_key: Option<String>,
 }
 
-pub struct Rpc(RpcClient);
+pub struct Rpc(Arc<RpcClient>);
+
+async fn load_reward_mints(db: &Pool) -> Result<Vec<Pubkey>> {
+    let mints = db
+        .run(|db| {
+            reward_centers::table
+                .select(reward_centers::token_mint)
+                .distinct()
+                .load::<String>(db)
+        })
+        .await
+        .context("Failed to load reward center token mints")?;
+
+    mints
+        .iter()
+        .map(|m| m.parse())
+        .collect::<Result<_, _>>()
+        .context("Failed to parse reward center token mint")
+}
+
+/// Periodically merge the token mints of all known reward centers into the
+/// tracked set until the client is dropped
+async fn refresh_tracked_mints(client: Weak<Client>) {
+    let mut interval = tokio::time::interval(TRACKED_MINT_REFRESH_INTERVAL);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let client = match client.upgrade() {
+            Some(c) => c,
+            None => break,
+        };
+
+        match load_reward_mints(&client.db).await {
+            Ok(mints) => mints.into_iter().for_each(|m| client.track_mint(m)),
+            Err(e) => warn!("Failed to refresh tracked token mints: {e:?}"),
+        }
+    }
+}
 
 impl fmt::Debug for Rpc {
     fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
@@ -103,7 +190,10 @@ impl Client {
             dialect_api_key,
             solana_endpoint,
             enable_block_reindex,
+            tracked_mints,
+            anchor_idls,
             search,
+            batch,
         }: Args,
     ) -> Result<Arc<Self>> {
         if dialect_api_endpoint.is_none() {
@@ -112,6 +202,21 @@ impl Client {
             debug!("Dialect integration enabled");
         }
 
+        let reward_mints = load_reward_mints(&db).await?;
+
+        let tracked_mints = pubkeys::TOKEN_MINTS
+            .into_iter()
+            .chain(tracked_mints)
+            .chain(reward_mints)
+            .collect::<HashSet<Pubkey>>();
+
+        debug!(
+            "Tracking balances for {} token mint(s)",
+            tracked_mints.len()
+        );
+
+        let anchor_idls = AnchorIdls::load(anchor_idls).context("Failed to load Anchor IDLs")?;
+
         let Queues {
             metadata_json: meta_q,
             store_config: store_q,
@@ -119,7 +224,11 @@ impl Client {
             jobs: job_q,
         } = queues;
 
-        Ok(Arc::new(Self {
+        let rpc = Arc::new(RpcClient::new(solana_endpoint));
+        let slot_times = SlotTimes::new(db.clone(), Arc::clone(&rpc));
+
+        let client = Arc::new(Self {
+            batch: Batcher::new(db.clone(), batch),
             db,
             http: reqwest::Client::new(Duration::from_millis(500))?,
             http_prod: HttpProducers {
@@ -130,7 +239,7 @@ impl Client {
                     .await
                     .context("Couldn't create AMQP store config producer")?,
             },
-            rpc_client: Rpc(RpcClient::new(solana_endpoint)),
+            rpc_client: Rpc(rpc),
             job_prod: JobProducers {
                 prod: job_runner::Producer::new(conn, job_q)
                     .await
@@ -139,9 +248,16 @@ impl Client {
             },
             search: search_dispatch::Client::new(conn, search_q, search).await?,
             startup,
+            tracked_mints: RwLock::new(tracked_mints),
+            anchor_idls,
+            slot_times,
             dialect_api_endpoint,
             dialect_api_key,
-        }))
+        });
+
+        tokio::spawn(refresh_tracked_mints(Arc::downgrade(&client)));
+
+        Ok(client)
     }
 
     /// Get a reference to the database
@@ -151,6 +267,120 @@ impl Client {
         &self.db
     }
 
+    /// Get a reference to the batched database writer
+    #[inline]
+    #[must_use]
+    pub fn batch(&self) -> &Batcher {
+        &self.batch
+    }
+
+    /// Run the given closure with a connection inside a single database
+    /// transaction, so a handler writing several tables never leaves partial
+    /// state behind
+    ///
+    /// The closure may be run more than once if the batch containing it
+    /// fails to commit, so it must only write to the database.
+    ///
+    /// # Errors
+    /// This function fails if the closure returns an error or the transaction
+    /// cannot be committed.
+    #[inline]
+    pub async fn transaction<T: Send + 'static, E: Into<indexer_core::error::Error>>(
+        &self,
+        f: impl Fn(&PooledConnection) -> Result<T, E> + Send + 'static,
+    ) -> Result<T> {
+        self.batch.write(f).await
+    }
+
+    /// Returns true if holder balances should be indexed for token accounts
+    /// of the given mint
+    #[must_use]
+    pub fn is_tracked_mint(&self, mint: &Pubkey) -> bool {
+        self.tracked_mints
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .contains(mint)
+    }
+
+    /// Begin indexing holder balances for token accounts of the given mint
+    pub fn track_mint(&self, mint: Pubkey) {
+        let mut mints = self
+            .tracked_mints
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        if mints.insert(mint) {
+            debug!("Tracking balances for token mint {}", mint);
+        }
+    }
+
+    /// Fetch every token account of the given mint from RPC, along with a
+    /// slot no later than the one the accounts were read at
+    ///
+    /// # Errors
+    /// This function fails if the RPC requests fail or an account cannot be
+    /// deserialized.
+    pub async fn token_accounts(&self, mint: Pubkey) -> Result<(u64, Vec<(Pubkey, TokenAccount)>)> {
+        let rpc = Arc::clone(&self.rpc_client.0);
+
+        let (slot, accounts) = tokio::task::spawn_blocking(move || {
+            let slot = rpc.get_slot().context("Failed to get slot")?;
+
+            let accounts = rpc
+                .get_program_accounts_with_config(&spl_token::id(), RpcProgramAccountsConfig {
+                    filters: Some(vec![
+                        RpcFilterType::DataSize(TokenAccount::LEN.try_into()?),
+                        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, mint.as_ref())),
+                    ]),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        ..RpcAccountInfoConfig::default()
+                    },
+                    ..RpcProgramAccountsConfig::default()
+                })
+                .context("Failed to get token accounts")?;
+
+            Result::<_>::Ok((slot, accounts))
+        })
+        .await
+        .context("Blocking task failed")??;
+
+        let accounts = accounts
+            .into_iter()
+            .map(|(key, acct)| {
+                TokenAccount::unpack_unchecked(&acct.data)
+                    .map(|t| (key, t))
+                    .with_context(|| format!("Failed to deserialize token account {key}"))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok((slot, accounts))
+    }
+
+    /// Get a reference to the IDLs of programs indexed generically
+    #[inline]
+    #[must_use]
+    pub fn anchor_idls(&self) -> &AnchorIdls {
+        &self.anchor_idls
+    }
+
+    /// Get the block time of the given slot, or an estimate if it is not
+    /// yet known
+    ///
+    /// # Errors
+    /// This function fails if the slot time cannot be read or recorded.
+    #[inline]
+    pub async fn block_time(&self, slot: u64) -> Result<NaiveDateTime> {
+        self.slot_times.get(slot).await
+    }
+
+    /// Get a reference to the slot time lookup
+    #[inline]
+    #[must_use]
+    pub fn slot_times(&self) -> &Arc<SlotTimes> {
+        &self.slot_times
+    }
+
     /// Get a reference to the search index dispatcher
     #[inline]
     #[must_use]
@@ -0,0 +1,37 @@
+use indexer::prelude::*;
+use indexer_core::db::{insert_into, models::ProgramInstruction, tables::program_instructions};
+
+use super::Client;
+use crate::handler::anchor_idl::DecodedInstruction;
+
+pub(crate) async fn process(
+    client: &Client,
+    program: Pubkey,
+    signature: String,
+    ins: DecodedInstruction<'_>,
+    slot: u64,
+) -> Result<()> {
+    let row = ProgramInstruction {
+        id: None,
+        program_id: Owned(program.to_string()),
+        instruction_name: Owned(ins.name.to_owned()),
+        txn_signature: Owned(signature),
+        accounts: ins.accounts,
+        data: ins.data,
+        created_at: client.block_time(slot).await?,
+        slot: slot.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(program_instructions::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert program instruction")?;
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use indexer::prelude::*;
+use indexer_core::{
+    db::{
+        insert_into,
+        models::{
+            AuctioneerListing, BuyInstruction, CancelInstruction, DepositInstruction,
+            ExecuteSaleInstruction, SellInstruction, WithdrawInstruction,
+        },
+        tables::{auctioneer_listings, sell_instructions},
+        update,
+    },
+    pubkeys,
+};
+
+use super::{buy, cancel, deposit, execute_sale, withdraw, Client};
+use crate::handler::programs::auctioneer::{Buy, Cancel, Deposit, ExecuteSale, Sell, Withdraw};
+
+pub(crate) async fn process_sell(
+    client: &Client,
+    params: Sell,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() != 16 {
+        debug!("invalid accounts for auctioneer SellInstruction");
+        return Ok(());
+    }
+
+    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
+
+    // Auctioneer lists at the maximum price and the sale price is only known
+    // once the auction settles, so the listing is kept out of `listings` and
+    // its reserve price is exposed through the listing config instead
+    let row = SellInstruction {
+        wallet: Owned(accts[2].clone()),
+        token_account: Owned(accts[3].clone()),
+        metadata: Owned(accts[4].clone()),
+        authority: Owned(accts[5].clone()),
+        auction_house: Owned(accts[6].clone()),
+        auction_house_fee_account: Owned(accts[7].clone()),
+        seller_trade_state: Owned(accts[8].clone()),
+        free_seller_trader_state: Owned(accts[9].clone()),
+        program_as_signer: Owned(accts[12].clone()),
+        trade_state_bump: params.trade_state_bump.into(),
+        free_trade_state_bump: params.free_trade_state_bump.into(),
+        program_as_signer_bump: params.program_as_signer_bump.into(),
+        buyer_price: i64::MAX,
+        token_size: params.token_size.try_into()?,
+        created_at: Utc::now().naive_utc(),
+        slot: slot.try_into()?,
+    };
+
+    let listing = AuctioneerListing {
+        trade_state: row.seller_trade_state.clone(),
+        listing_config: Owned(accts[1].clone()),
+        auction_house: row.auction_house.clone(),
+        seller: row.wallet.clone(),
+        metadata: row.metadata.clone(),
+        auctioneer_authority: Owned(accts[10].clone()),
+        slot: row.slot,
+        canceled_at: None,
+        purchase_id: None,
+    };
+
+    client
+        .transaction(move |db| {
+            insert_into(auctioneer_listings::table)
+                .values(&listing)
+                .on_conflict(auctioneer_listings::trade_state)
+                .do_update()
+                .set(&listing)
+                .execute(db)
+                .context("failed to insert auctioneer listing")?;
+
+            insert_into(sell_instructions::table)
+                .values(&row)
+                .execute(db)
+                .context("failed to insert sell instruction ")?;
+
+            Result::<_>::Ok(())
+        })
+        .await
+}
+
+pub(crate) async fn process_buy(
+    client: &Client,
+    params: Buy,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() != 19 {
+        debug!("invalid accounts for auctioneer BuyInstruction");
+        return Ok(());
+    }
+
+    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
+
+    let row = BuyInstruction {
+        wallet: Owned(accts[3].clone()),
+        payment_account: Owned(accts[4].clone()),
+        transfer_authority: Owned(accts[5].clone()),
+        treasury_mint: Owned(accts[6].clone()),
+        token_account: Owned(accts[7].clone()),
+        metadata: Owned(accts[8].clone()),
+        escrow_payment_account: Owned(accts[9].clone()),
+        authority: Owned(accts[10].clone()),
+        auction_house: Owned(accts[11].clone()),
+        auction_house_fee_account: Owned(accts[12].clone()),
+        buyer_trade_state: Owned(accts[13].clone()),
+        trade_state_bump: params.trade_state_bump.into(),
+        escrow_payment_bump: params.escrow_payment_bump.into(),
+        buyer_price: params.buyer_price.try_into()?,
+        token_size: params.token_size.try_into()?,
+        created_at: Utc::now().naive_utc(),
+        slot: slot.try_into()?,
+    };
+
+    buy::process_buy(client, row, pubkeys::AUCTIONEER).await
+}
+
+pub(crate) async fn process_cancel(
+    client: &Client,
+    params: Cancel,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() != 13 {
+        debug!("invalid accounts for auctioneer CancelInstruction");
+        return Ok(());
+    }
+
+    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
+
+    let row = CancelInstruction {
+        wallet: Owned(accts[3].clone()),
+        token_account: Owned(accts[4].clone()),
+        token_mint: Owned(accts[5].clone()),
+        authority: Owned(accts[6].clone()),
+        auction_house: Owned(accts[7].clone()),
+        auction_house_fee_account: Owned(accts[8].clone()),
+        trade_state: Owned(accts[9].clone()),
+        // Listings are canceled at the maximum price
+        buyer_price: params.buyer_price.try_into().unwrap_or(i64::MAX),
+        token_size: params.token_size.try_into()?,
+        created_at: Utc::now().naive_utc(),
+        slot: slot.try_into()?,
+    };
+
+    client
+        .transaction(move |db| {
+            cancel::write_cancel(db, &row)?;
+
+            update(
+                auctioneer_listings::table.filter(
+                    auctioneer_listings::trade_state
+                        .eq(row.trade_state.clone())
+                        .and(auctioneer_listings::purchase_id.is_null())
+                        .and(auctioneer_listings::canceled_at.is_null()),
+                ),
+            )
+            .set((
+                auctioneer_listings::canceled_at.eq(Some(row.created_at)),
+                auctioneer_listings::slot.eq(row.slot),
+            ))
+            .execute(db)
+            .context("failed to cancel auctioneer listing")?;
+
+            Result::<_>::Ok(())
+        })
+        .await
+}
+
+pub(crate) async fn process_execute_sale(
+    client: &Client,
+    params: ExecuteSale,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() != 25 {
+        debug!("invalid accounts for auctioneer ExecuteSaleInstruction");
+        return Ok(());
+    }
+
+    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
+
+    let row = ExecuteSaleInstruction {
+        buyer: Owned(accts[2].clone()),
+        seller: Owned(accts[3].clone()),
+        token_account: Owned(accts[4].clone()),
+        token_mint: Owned(accts[5].clone()),
+        metadata: Owned(accts[6].clone()),
+        treasury_mint: Owned(accts[7].clone()),
+        escrow_payment_account: Owned(accts[8].clone()),
+        seller_payment_receipt_account: Owned(accts[9].clone()),
+        buyer_receipt_token_account: Owned(accts[10].clone()),
+        authority: Owned(accts[11].clone()),
+        auction_house: Owned(accts[12].clone()),
+        auction_house_fee_account: Owned(accts[13].clone()),
+        auction_house_treasury: Owned(accts[14].clone()),
+        buyer_trade_state: Owned(accts[15].clone()),
+        seller_trade_state: Owned(accts[16].clone()),
+        free_trade_state: Owned(accts[17].clone()),
+        program_as_signer: Owned(accts[23].clone()),
+        escrow_payment_bump: params.escrow_payment_bump.into(),
+        free_trade_state_bump: params.free_trade_state_bump.into(),
+        program_as_signer_bump: params.program_as_signer_bump.into(),
+        buyer_price: params.buyer_price.try_into()?,
+        token_size: params.token_size.try_into()?,
+        created_at: Utc::now().naive_utc(),
+        slot: slot.try_into()?,
+    };
+
+    client
+        .transaction(move |db| {
+            let purchase_id = execute_sale::write_execute_sale(db, &row, pubkeys::AUCTIONEER)?;
+
+            update(
+                auctioneer_listings::table.filter(
+                    auctioneer_listings::trade_state
+                        .eq(row.seller_trade_state.clone())
+                        .and(auctioneer_listings::purchase_id.is_null())
+                        .and(auctioneer_listings::canceled_at.is_null()),
+                ),
+            )
+            .set(auctioneer_listings::purchase_id.eq(Some(purchase_id)))
+            .execute(db)
+            .context("failed to settle auctioneer listing")?;
+
+            Result::<_>::Ok(())
+        })
+        .await
+}
+
+pub(crate) async fn process_deposit(
+    client: &Client,
+    params: Deposit,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() != 14 {
+        debug!("invalid accounts for auctioneer DepositInstruction");
+        return Ok(());
+    }
+
+    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
+
+    let row = DepositInstruction {
+        wallet: Owned(accts[1].clone()),
+        payment_account: Owned(accts[2].clone()),
+        transfer_authority: Owned(accts[3].clone()),
+        escrow_payment_account: Owned(accts[4].clone()),
+        treasury_mint: Owned(accts[5].clone()),
+        authority: Owned(accts[6].clone()),
+        auction_house: Owned(accts[7].clone()),
+        auction_house_fee_account: Owned(accts[8].clone()),
+        escrow_payment_bump: params.escrow_payment_bump.into(),
+        amount: params.amount.try_into()?,
+        created_at: Utc::now().naive_utc(),
+        slot: slot.try_into()?,
+    };
+
+    deposit::process_deposit(client, row).await
+}
+
+pub(crate) async fn process_withdraw(
+    client: &Client,
+    params: Withdraw,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() != 14 {
+        debug!("invalid accounts for auctioneer WithdrawInstruction");
+        return Ok(());
+    }
+
+    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
+
+    let row = WithdrawInstruction {
+        wallet: Owned(accts[1].clone()),
+        receipt_account: Owned(accts[2].clone()),
+        escrow_payment_account: Owned(accts[3].clone()),
+        treasury_mint: Owned(accts[4].clone()),
+        authority: Owned(accts[5].clone()),
+        auction_house: Owned(accts[6].clone()),
+        auction_house_fee_account: Owned(accts[7].clone()),
+        escrow_payment_bump: params.escrow_payment_bump.into(),
+        amount: params.amount.try_into()?,
+        created_at: Utc::now().naive_utc(),
+        slot: slot.try_into()?,
+    };
+
+    withdraw::process_withdraw(client, row).await
+}
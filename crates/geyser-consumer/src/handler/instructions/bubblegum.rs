@@ -0,0 +1,520 @@
+use indexer::prelude::*;
+use indexer_core::{
+    db::{
+        custom_types::TokenStandardEnum,
+        insert_into,
+        models::{
+            CompressedAsset, CompressedAssetCreator, CompressedAssetData, MerkleTree,
+            MerkleTreeNode,
+        },
+        sql_query,
+        sql_types::{BigInt, Nullable, Text, Timestamp},
+        tables::{
+            compressed_asset_creators, compressed_asset_datas, compressed_assets,
+            merkle_tree_nodes, merkle_trees,
+        },
+        update, Connection, UpsertIfNewer,
+    },
+    pubkeys::{find_compressed_asset, find_tree_authority},
+};
+
+use super::Client;
+use crate::handler::programs::{
+    bubblegum::{LeafArgs, MetadataArgs, TokenStandard},
+    spl_noop::{ChangeLogEventV1, LeafSchema, LeafSchemaEvent},
+};
+
+const UPDATE_LEAF: &str = r"
+-- $1: asset ID
+-- $2: tree address, if known
+-- $3: tree authority address, if known
+-- $4: leaf index, if known
+-- $5: owner
+-- $6: delegate
+-- $7: data hash
+-- $8: creator hash
+-- $9: burn timestamp
+-- $10: redemption timestamp
+-- $11: slot
+-- $12: change log sequence number, if known
+UPDATE compressed_assets SET
+    tree = COALESCE($2, tree),
+    tree_authority = COALESCE($3, tree_authority),
+    leaf_index = COALESCE($4, leaf_index),
+    owner = $5,
+    delegate = $6,
+    data_hash = $7,
+    creator_hash = $8,
+    burned_at = CASE WHEN COALESCE(slot < $11 OR seq < $12, false)
+        THEN $9
+        ELSE COALESCE($9, burned_at)
+    END,
+    redeemed_at = CASE WHEN COALESCE(slot < $11 OR seq < $12, false)
+        THEN $10
+        ELSE COALESCE($10, redeemed_at)
+    END,
+    slot = $11,
+    seq = CASE WHEN slot < $11 THEN $12 ELSE GREATEST(seq, $12) END
+WHERE asset_id = $1
+AND slot <= $11
+AND NOT COALESCE(slot = $11 AND seq > $12, false);
+";
+
+pub(crate) async fn process_mint(client: &Client, args: MetadataArgs, slot: u64) -> Result<()> {
+    let data_hash = args.hash()?.to_string();
+    let slot = i64::try_from(slot)?;
+
+    let row = CompressedAssetData {
+        data_hash: Owned(data_hash.clone()),
+        name: Owned(args.name.trim_end_matches('\0').to_owned()),
+        symbol: Owned(args.symbol.trim_end_matches('\0').to_owned()),
+        uri: Owned(args.uri.trim_end_matches('\0').to_owned()),
+        seller_fee_basis_points: args.seller_fee_basis_points.into(),
+        primary_sale_happened: args.primary_sale_happened,
+        is_mutable: args.is_mutable,
+        edition_nonce: args.edition_nonce.map(Into::into),
+        token_standard: args.token_standard.as_ref().map(|ts| match ts {
+            TokenStandard::NonFungible => TokenStandardEnum::NonFungible,
+            TokenStandard::FungibleAsset => TokenStandardEnum::FungibleAsset,
+            TokenStandard::Fungible => TokenStandardEnum::Fungible,
+            TokenStandard::NonFungibleEdition => TokenStandardEnum::NonFungibleEdition,
+        }),
+        collection_address: args.collection.as_ref().map(|c| Owned(c.key.to_string())),
+        collection_verified: args.collection.as_ref().map_or(false, |c| c.verified),
+        slot,
+    };
+
+    let creators = args
+        .creators
+        .iter()
+        .enumerate()
+        .map(|(position, creator)| {
+            Ok(CompressedAssetCreator {
+                data_hash: Owned(data_hash.clone()),
+                creator_address: Owned(creator.address.to_string()),
+                share: creator.share.into(),
+                verified: creator.verified,
+                position: position
+                    .try_into()
+                    .context("Position was too big to store")?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Metadata rows are keyed by their hash, so any existing row is identical
+    let assets = client
+        .transaction(move |db| {
+            insert_into(compressed_asset_datas::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+                .context("Failed to insert compressed NFT metadata")?;
+
+            insert_into(compressed_asset_creators::table)
+                .values(&creators)
+                .on_conflict_do_nothing()
+                .execute(db)
+                .context("Failed to insert compressed NFT creators")?;
+
+            compressed_assets::table
+                .filter(compressed_assets::data_hash.eq(&row.data_hash))
+                .select(compressed_assets::asset_id)
+                .load::<String>(db)
+                .context("Failed to load compressed NFTs for metadata")
+        })
+        .await?;
+
+    let first_verified_creator = args.creators.iter().find(|c| c.verified).map(|c| c.address);
+
+    for asset in assets {
+        client
+            .dispatch_metadata_json(
+                asset.parse()?,
+                first_verified_creator,
+                args.uri.trim_end_matches('\0').to_owned(),
+                (slot.try_into()?, 0),
+            )
+            .await
+            .context("Failed to dispatch compressed NFT metadata JSON job")?;
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn process_leaf(client: &Client, event: LeafSchemaEvent, slot: u64) -> Result<()> {
+    let LeafSchema::V1 {
+        id,
+        owner,
+        delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+    } = event.schema;
+    let leaf_hash = bs58::encode(event.leaf_hash).into_string();
+    let slot = i64::try_from(slot)?;
+
+    let row = CompressedAsset {
+        asset_id: Owned(id.to_string()),
+        tree: None,
+        tree_authority: None,
+        leaf_index: None,
+        owner: Owned(owner.to_string()),
+        delegate: Owned(delegate.to_string()),
+        data_hash: Owned(bs58::encode(data_hash).into_string()),
+        creator_hash: Owned(bs58::encode(creator_hash).into_string()),
+        burned_at: None,
+        redeemed_at: None,
+        slot,
+        seq: None,
+    };
+
+    let data = client
+        .transaction(move |db| {
+            // The leaf's position, and the sequence number ordering this
+            // change against others in the same slot, are only known from the
+            // change log event logged alongside this one, which may have been
+            // indexed first.  The same hash may appear elsewhere, so only the
+            // change log leaf whose tree and index derive this asset's ID is
+            // used.
+            let nodes = merkle_tree_nodes::table
+                .filter(merkle_tree_nodes::hash.eq(&leaf_hash))
+                .select((
+                    merkle_tree_nodes::tree,
+                    merkle_tree_nodes::node_index,
+                    merkle_tree_nodes::slot,
+                    merkle_tree_nodes::seq,
+                ))
+                .load::<(String, i64, i64, i64)>(db)
+                .context("Failed to look up compressed NFT leaf")?;
+
+            let mut position = None;
+            let mut seq = None;
+
+            for (tree, node_index, node_slot, node_seq) in nodes {
+                let tree: Pubkey = tree.parse()?;
+                let leaf_index = node_index ^ (1 << (63 - node_index.leading_zeros()));
+
+                if u64::try_from(leaf_index)? == nonce && find_compressed_asset(tree, nonce).0 == id
+                {
+                    position = Some((tree, leaf_index));
+                    seq = (node_slot == slot).then_some(node_seq);
+                    break;
+                }
+            }
+
+            let row = CompressedAsset { seq, ..row.clone() };
+            let inserted = write_leaf(db, &row)?;
+
+            if let Some((tree, leaf_index)) = position {
+                set_leaf_position(db, &row.asset_id, &tree, leaf_index)?;
+            }
+
+            if !inserted {
+                return Ok(None);
+            }
+
+            load_metadata_uri(db, &row.data_hash)
+        })
+        .await?;
+
+    if let Some((uri, first_verified_creator)) = data {
+        client
+            .dispatch_metadata_json(id, first_verified_creator, uri, (slot.try_into()?, 0))
+            .await
+            .context("Failed to dispatch compressed NFT metadata JSON job")?;
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn process_change_log(
+    client: &Client,
+    event: ChangeLogEventV1,
+    slot: u64,
+) -> Result<()> {
+    let tree = event.id.to_string();
+    let slot = i64::try_from(slot)?;
+    let seq = i64::try_from(event.seq)?;
+
+    let row = MerkleTree {
+        address: Owned(tree.clone()),
+        max_depth: event
+            .path
+            .len()
+            .saturating_sub(1)
+            .try_into()
+            .context("Merkle tree depth was too big to store")?,
+        slot,
+        seq,
+    };
+
+    let nodes = event
+        .path
+        .iter()
+        .map(|node| MerkleTreeNode {
+            tree: Owned(tree.clone()),
+            node_index: node.index.into(),
+            hash: Owned(bs58::encode(node.node).into_string()),
+            slot,
+            seq,
+        })
+        .collect::<Vec<_>>();
+
+    let asset_id = find_compressed_asset(event.id, event.index.into()).0;
+
+    client
+        .transaction(move |db| {
+            insert_into(merkle_trees::table)
+                .values(&row)
+                .on_conflict(merkle_trees::address)
+                .do_update()
+                .set(&row)
+                .if_newer(merkle_trees::slot, merkle_trees::seq)
+                .execute(db)
+                .context("Failed to insert Merkle tree")?;
+
+            for node in &nodes {
+                insert_into(merkle_tree_nodes::table)
+                    .values(node)
+                    .on_conflict((merkle_tree_nodes::tree, merkle_tree_nodes::node_index))
+                    .do_update()
+                    .set(node)
+                    .if_newer(merkle_tree_nodes::slot, merkle_tree_nodes::seq)
+                    .execute(db)
+                    .context("Failed to insert Merkle tree node")?;
+            }
+
+            set_leaf_position(db, &asset_id.to_string(), &event.id, event.index.into())
+        })
+        .await
+}
+
+pub(crate) async fn process_transfer(
+    client: &Client,
+    args: LeafArgs,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let new_owner = accounts.get(3).context("Missing new leaf owner account")?;
+    let tree = accounts.get(4).context("Missing Merkle tree account")?;
+
+    let leaf_hash = LeafSchema::V1 {
+        id: find_compressed_asset(tree, args.nonce).0,
+        owner: *new_owner,
+        delegate: *new_owner,
+        nonce: args.nonce,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+    }
+    .hash();
+
+    upsert_leaf(
+        client,
+        &args,
+        tree,
+        new_owner,
+        new_owner,
+        leaf_hash.to_bytes(),
+        slot,
+        |_| {},
+    )
+    .await
+}
+
+pub(crate) async fn process_burn(
+    client: &Client,
+    args: LeafArgs,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let owner = accounts.get(1).context("Missing leaf owner account")?;
+    let delegate = accounts.get(2).context("Missing leaf delegate account")?;
+    let tree = accounts.get(3).context("Missing Merkle tree account")?;
+
+    let block_time = client.block_time(slot).await?;
+
+    // Burning a leaf replaces it with an empty node
+    upsert_leaf(client, &args, tree, owner, delegate, [0; 32], slot, |row| {
+        row.burned_at = Some(block_time);
+    })
+    .await
+}
+
+pub(crate) async fn process_redeem(
+    client: &Client,
+    args: LeafArgs,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let owner = accounts.get(1).context("Missing leaf owner account")?;
+    let delegate = accounts.get(2).context("Missing leaf delegate account")?;
+    let tree = accounts.get(3).context("Missing Merkle tree account")?;
+
+    let block_time = client.block_time(slot).await?;
+
+    // Redeeming a leaf replaces it with an empty node
+    upsert_leaf(client, &args, tree, owner, delegate, [0; 32], slot, |row| {
+        row.redeemed_at = Some(block_time);
+    })
+    .await
+}
+
+/// Write the full state of a leaf modified by an instruction which names its
+/// Merkle tree, given the hash the instruction wrote to the leaf
+#[allow(clippy::too_many_arguments)]
+async fn upsert_leaf(
+    client: &Client,
+    args: &LeafArgs,
+    tree: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    leaf_hash: [u8; 32],
+    slot: u64,
+    f: impl FnOnce(&mut CompressedAsset<'static>),
+) -> Result<()> {
+    let tree = *tree;
+    let leaf_index = i64::from(args.index);
+    let leaf_hash = bs58::encode(leaf_hash).into_string();
+
+    let mut row = CompressedAsset {
+        asset_id: Owned(find_compressed_asset(tree, args.nonce).0.to_string()),
+        tree: Some(Owned(tree.to_string())),
+        tree_authority: Some(Owned(find_tree_authority(tree).0.to_string())),
+        leaf_index: Some(leaf_index),
+        owner: Owned(owner.to_string()),
+        delegate: Owned(delegate.to_string()),
+        data_hash: Owned(bs58::encode(args.data_hash).into_string()),
+        creator_hash: Owned(bs58::encode(args.creator_hash).into_string()),
+        burned_at: None,
+        redeemed_at: None,
+        slot: slot.try_into()?,
+        seq: None,
+    };
+
+    f(&mut row);
+
+    client
+        .transaction(move |db| {
+            let seq = leaf_seq(db, &tree, leaf_index, &leaf_hash, row.slot)?;
+
+            write_leaf(db, &CompressedAsset { seq, ..row.clone() })
+        })
+        .await
+        .context("Failed to write compressed NFT")?;
+
+    Ok(())
+}
+
+/// Look up the change log sequence number of the change which wrote the given
+/// hash to a leaf in the given slot, if its change log event has been indexed
+fn leaf_seq(
+    db: &Connection,
+    tree: &Pubkey,
+    leaf_index: i64,
+    hash: &str,
+    slot: i64,
+) -> Result<Option<i64>> {
+    let Some(max_depth) = merkle_trees::table
+        .filter(merkle_trees::address.eq(tree.to_string()))
+        .select(merkle_trees::max_depth)
+        .first::<i32>(db)
+        .optional()
+        .context("Failed to load Merkle tree depth")?
+    else {
+        return Ok(None);
+    };
+
+    merkle_tree_nodes::table
+        .filter(merkle_tree_nodes::tree.eq(tree.to_string()))
+        .filter(merkle_tree_nodes::node_index.eq((1 << max_depth) | leaf_index))
+        .filter(merkle_tree_nodes::hash.eq(hash))
+        .filter(merkle_tree_nodes::slot.eq(slot))
+        .select(merkle_tree_nodes::seq)
+        .first::<i64>(db)
+        .optional()
+        .context("Failed to look up compressed NFT leaf change")
+}
+
+/// Write the state of a leaf over its stored state, returning whether the
+/// asset was newly inserted
+///
+/// Changes are ordered by slot, then by the sequence number of the change log
+/// which wrote the leaf.  If two changes in the same slot cannot be ordered
+/// because a change log has not been indexed yet, a burn or redemption is kept
+/// rather than cleared.
+fn write_leaf(db: &Connection, row: &CompressedAsset) -> Result<bool> {
+    let inserted = insert_into(compressed_assets::table)
+        .values(row)
+        .on_conflict_do_nothing()
+        .execute(db)
+        .context("Failed to insert compressed NFT")?;
+
+    if inserted > 0 {
+        return Ok(true);
+    }
+
+    sql_query(UPDATE_LEAF)
+        .bind::<Text, _>(&*row.asset_id)
+        .bind::<Nullable<Text>, _>(row.tree.as_deref())
+        .bind::<Nullable<Text>, _>(row.tree_authority.as_deref())
+        .bind::<Nullable<BigInt>, _>(row.leaf_index)
+        .bind::<Text, _>(&*row.owner)
+        .bind::<Text, _>(&*row.delegate)
+        .bind::<Text, _>(&*row.data_hash)
+        .bind::<Text, _>(&*row.creator_hash)
+        .bind::<Nullable<Timestamp>, _>(row.burned_at)
+        .bind::<Nullable<Timestamp>, _>(row.redeemed_at)
+        .bind::<BigInt, _>(row.slot)
+        .bind::<Nullable<BigInt>, _>(row.seq)
+        .execute(db)
+        .context("Failed to update compressed NFT")?;
+
+    Ok(false)
+}
+
+fn set_leaf_position(
+    db: &Connection,
+    asset_id: &str,
+    tree: &Pubkey,
+    leaf_index: i64,
+) -> Result<()> {
+    update(
+        compressed_assets::table
+            .filter(compressed_assets::asset_id.eq(asset_id))
+            .filter(compressed_assets::tree.is_null()),
+    )
+    .set((
+        compressed_assets::tree.eq(Some(tree.to_string())),
+        compressed_assets::tree_authority.eq(Some(find_tree_authority(tree).0.to_string())),
+        compressed_assets::leaf_index.eq(Some(leaf_index)),
+    ))
+    .execute(db)
+    .context("Failed to set compressed NFT leaf position")?;
+
+    Ok(())
+}
+
+fn load_metadata_uri(db: &Connection, data_hash: &str) -> Result<Option<(String, Option<Pubkey>)>> {
+    let Some(uri) = compressed_asset_datas::table
+        .filter(compressed_asset_datas::data_hash.eq(data_hash))
+        .select(compressed_asset_datas::uri)
+        .first::<String>(db)
+        .optional()
+        .context("Failed to load compressed NFT metadata")?
+    else {
+        return Ok(None);
+    };
+
+    let first_verified_creator = compressed_asset_creators::table
+        .filter(compressed_asset_creators::data_hash.eq(data_hash))
+        .filter(compressed_asset_creators::verified.eq(true))
+        .order(compressed_asset_creators::position.asc())
+        .select(compressed_asset_creators::creator_address)
+        .first::<String>(db)
+        .optional()
+        .context("Failed to load compressed NFT creators")?
+        .map(|c| c.parse())
+        .transpose()?;
+
+    Ok(Some((uri, first_verified_creator)))
+}
@@ -7,6 +7,7 @@ use indexer_core::{
         models::{BuyInstruction, Offer},
         mutations,
         tables::{buy_instructions, offers, purchases},
+        PooledConnection,
     },
     pubkeys,
     uuid::Uuid,
@@ -50,106 +51,102 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
-    let values = row.clone();
-
-    let purchase_id = client
-        .db()
-        .run({
-            let row = row.clone();
-            move |db| {
-                purchases::table
-                    .filter(
-                        purchases::buyer
-                            .eq(row.wallet.clone())
-                            .and(purchases::auction_house.eq(row.auction_house))
-                            .and(purchases::metadata.eq(row.metadata))
-                            .and(purchases::price.eq(row.buyer_price))
-                            .and(
-                                purchases::token_size
-                                    .eq(row.token_size)
-                                    .and(purchases::slot.eq(row.slot)),
-                            ),
-                    )
-                    .select(purchases::id)
-                    .first::<Uuid>(db)
-                    .optional()
-                    .context("failed to get purchase ids")
-            }
-        })
-        .await?;
-
-    upsert_into_offers_table(client, Offer {
-        id: None,
-        trade_state: row.buyer_trade_state,
-        auction_house: row.auction_house,
-        marketplace_program: Owned(pubkeys::AUCTION_HOUSE.to_string()),
-        buyer: row.wallet,
-        metadata: row.metadata,
-        token_account: Some(row.token_account),
-        purchase_id,
-        price: row.buyer_price,
-        token_size: row.token_size,
-        trade_state_bump: row.trade_state_bump,
-        created_at: row.created_at,
-        canceled_at: Some(None),
-        slot: row.slot,
-        write_version: None,
-        expiry: None,
-    })
-    .await
-    .context("failed to insert offer")?;
+    process_buy(client, row, pubkeys::AUCTION_HOUSE).await
+}
 
+/// Record a buy instruction and upsert the offer it creates, attributed to
+/// the given marketplace program
+pub(crate) async fn process_buy(
+    client: &Client,
+    row: BuyInstruction<'static>,
+    marketplace_program: Pubkey,
+) -> Result<()> {
     client
-        .db()
-        .run(move |db| {
+        .transaction(move |db| {
+            let purchase_id = purchases::table
+                .filter(
+                    purchases::buyer
+                        .eq(row.wallet.clone())
+                        .and(purchases::auction_house.eq(row.auction_house.clone()))
+                        .and(purchases::metadata.eq(row.metadata.clone()))
+                        .and(purchases::price.eq(row.buyer_price))
+                        .and(
+                            purchases::token_size
+                                .eq(row.token_size)
+                                .and(purchases::slot.eq(row.slot)),
+                        ),
+                )
+                .select(purchases::id)
+                .first::<Uuid>(db)
+                .optional()
+                .context("failed to get purchase ids")?;
+
+            upsert_offer(db, &Offer {
+                id: None,
+                trade_state: row.buyer_trade_state.clone(),
+                auction_house: row.auction_house.clone(),
+                marketplace_program: Owned(marketplace_program.to_string()),
+                buyer: row.wallet.clone(),
+                metadata: row.metadata.clone(),
+                token_account: Some(row.token_account.clone()),
+                purchase_id,
+                price: row.buyer_price,
+                token_size: row.token_size,
+                trade_state_bump: row.trade_state_bump,
+                created_at: row.created_at,
+                canceled_at: Some(None),
+                slot: row.slot,
+                write_version: None,
+                expiry: None,
+            })
+            .context("failed to insert offer")?;
+
             insert_into(buy_instructions::table)
-                .values(&values)
+                .values(&row)
                 .execute(db)
+                .context("failed to insert buy instruction ")?;
+
+            Result::<_>::Ok(())
         })
         .await
-        .context("failed to insert buy instruction ")?;
-    Ok(())
 }
 
 pub async fn upsert_into_offers_table<'a>(client: &Client, data: Offer<'static>) -> Result<()> {
     client
-        .db()
-        .run(move |db| {
-            let auction_house: Pubkey = data.auction_house.to_string().parse()?;
-
-            let indexed_offer: Option<Offer> = offers::table
-                .filter(
-                    offers::trade_state
-                        .eq(data.trade_state.clone())
-                        .and(offers::metadata.eq(data.metadata.clone())),
-                )
-                .select(offers::all_columns)
-                .first(db)
-                .optional()?;
-
-            let offer_id = mutations::offer::insert(db, &data)?;
-
-            if let Some(indexed_offer) = indexed_offer {
-                if (indexed_offer.purchase_id.is_none()
-                    && indexed_offer.canceled_at.is_none()
-                    && indexed_offer.price == data.price)
-                    || auction_house == pubkeys::OPENSEA_AUCTION_HOUSE
-                    || data.slot == indexed_offer.slot
-                {
-                    return Ok(());
-                }
-            }
-
-            mutations::activity::offer(
-                db,
-                offer_id,
-                &data.clone(),
-                ActivityTypeEnum::OfferCreated,
-            )?;
-            Result::<_>::Ok(())
-        })
+        .transaction(move |db| upsert_offer(db, &data))
         .await
-        .context("Failed to insert offer")?;
+        .context("Failed to insert offer")
+}
+
+/// Upsert an offer and record its creation activity using the given
+/// connection
+pub(crate) fn upsert_offer(db: &PooledConnection, data: &Offer) -> Result<()> {
+    let auction_house: Pubkey = data.auction_house.to_string().parse()?;
+
+    let indexed_offer: Option<Offer> = offers::table
+        .filter(
+            offers::trade_state
+                .eq(data.trade_state.clone())
+                .and(offers::metadata.eq(data.metadata.clone())),
+        )
+        .select(offers::all_columns)
+        .first(db)
+        .optional()?;
+
+    let offer_id = mutations::offer::insert(db, data)?;
+
+    if let Some(indexed_offer) = indexed_offer {
+        if (indexed_offer.purchase_id.is_none()
+            && indexed_offer.canceled_at.is_none()
+            && indexed_offer.price == data.price)
+            || auction_house == pubkeys::OPENSEA_AUCTION_HOUSE
+            || data.slot == indexed_offer.slot
+        {
+            return Ok(());
+        }
+    }
+
+    mutations::activity::offer(db, offer_id, &data.clone(), ActivityTypeEnum::OfferCreated)?;
 
     Ok(())
 }
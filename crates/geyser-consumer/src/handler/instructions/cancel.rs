@@ -7,7 +7,7 @@ use indexer_core::db::{
     mutations::activity,
     select,
     tables::{cancel_instructions, listings, offers},
-    update,
+    update, PooledConnection,
 };
 use mpl_auction_house::instruction::Cancel;
 
@@ -42,80 +42,84 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(cancel_instructions::table)
-                .values(&row)
-                .execute(db)?;
-            db.build_transaction().read_write().run(|| {
-                let listing_trade_state = select(exists(
-                    listings::table.filter(
-                        listings::trade_state
-                            .eq(row.trade_state.clone())
-                            .and(listings::purchase_id.is_null())
-                            .and(listings::canceled_at.is_null()),
-                    ),
-                ))
-                .get_result::<bool>(db);
+    process_cancel(client, row).await
+}
 
-                if Ok(true) == listing_trade_state {
-                    let listing = update(
-                        listings::table.filter(
-                            listings::trade_state
-                                .eq(row.trade_state.clone())
-                                .and(listings::purchase_id.is_null())
-                                .and(listings::canceled_at.is_null()),
-                        ),
-                    )
-                    .set((
-                        listings::canceled_at.eq(Some(row.created_at)),
-                        listings::slot.eq(row.slot),
-                    ))
-                    .returning(listings::all_columns)
-                    .get_result::<Listing>(db)
-                    .optional()?;
+/// Record a cancel instruction and mark the listing or offer with its trade
+/// state as canceled
+pub(crate) async fn process_cancel(client: &Client, row: CancelInstruction<'static>) -> Result<()> {
+    client.transaction(move |db| write_cancel(db, &row)).await
+}
 
-                    if let Some(listing) = listing {
-                        activity::listing(
-                            db,
-                            listing.id.unwrap(),
-                            &listing.clone(),
-                            ActivityTypeEnum::ListingCanceled,
-                        )?;
-                    };
-                } else {
-                    let offer = update(
-                        offers::table.filter(
-                            offers::trade_state
-                                .eq(row.trade_state.clone())
-                                .and(offers::purchase_id.is_null())
-                                .and(offers::canceled_at.is_null()),
-                        ),
-                    )
-                    .set((
-                        offers::canceled_at.eq(Some(row.created_at)),
-                        offers::slot.eq(row.slot),
-                    ))
-                    .returning(offers::all_columns)
-                    .get_result::<Offer>(db)
-                    .optional()?;
+/// Write a cancel instruction and cancel the listing or offer it closes using
+/// the given connection, so callers can apply it alongside their own writes
+pub(crate) fn write_cancel(db: &PooledConnection, row: &CancelInstruction) -> Result<()> {
+    insert_into(cancel_instructions::table)
+        .values(row)
+        .execute(db)
+        .context("failed to insert cancel instruction ")?;
 
-                    if let Some(offer) = offer {
-                        activity::offer(
-                            db,
-                            offer.id.unwrap(),
-                            &offer.clone(),
-                            ActivityTypeEnum::OfferCanceled,
-                        )?;
-                    }
-                }
+    let listing_trade_state = select(exists(
+        listings::table.filter(
+            listings::trade_state
+                .eq(row.trade_state.clone())
+                .and(listings::purchase_id.is_null())
+                .and(listings::canceled_at.is_null()),
+        ),
+    ))
+    .get_result::<bool>(db);
 
-                Result::<_>::Ok(())
-            })
-        })
-        .await
-        .context("failed to insert cancel instruction ")?;
+    if Ok(true) == listing_trade_state {
+        let listing = update(
+            listings::table.filter(
+                listings::trade_state
+                    .eq(row.trade_state.clone())
+                    .and(listings::purchase_id.is_null())
+                    .and(listings::canceled_at.is_null()),
+            ),
+        )
+        .set((
+            listings::canceled_at.eq(Some(row.created_at)),
+            listings::slot.eq(row.slot),
+        ))
+        .returning(listings::all_columns)
+        .get_result::<Listing>(db)
+        .optional()?;
+
+        if let Some(listing) = listing {
+            activity::listing(
+                db,
+                listing.id.unwrap(),
+                &listing.clone(),
+                ActivityTypeEnum::ListingCanceled,
+            )?;
+        };
+    } else {
+        let offer = update(
+            offers::table.filter(
+                offers::trade_state
+                    .eq(row.trade_state.clone())
+                    .and(offers::purchase_id.is_null())
+                    .and(offers::canceled_at.is_null()),
+            ),
+        )
+        .set((
+            offers::canceled_at.eq(Some(row.created_at)),
+            offers::slot.eq(row.slot),
+        ))
+        .returning(offers::all_columns)
+        .get_result::<Offer>(db)
+        .optional()?;
+
+        if let Some(offer) = offer {
+            activity::offer(
+                db,
+                offer.id.unwrap(),
+                &offer.clone(),
+                ActivityTypeEnum::OfferCanceled,
+            )?;
+        }
+    }
 
     Ok(())
 }
@@ -35,6 +35,14 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
+    process_deposit(client, row).await
+}
+
+/// Record a deposit instruction
+pub(crate) async fn process_deposit(
+    client: &Client,
+    row: DepositInstruction<'static>,
+) -> Result<()> {
     client
         .db()
         .run(move |db| {
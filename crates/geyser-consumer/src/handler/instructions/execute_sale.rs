@@ -7,9 +7,10 @@ use indexer_core::{
         models::{ExecuteSaleInstruction, Purchase},
         mutations, select,
         tables::{execute_sale_instructions, listings, offers, purchases},
-        update,
+        update, PooledConnection,
     },
     pubkeys,
+    uuid::Uuid,
 };
 use mpl_auction_house::instruction::ExecuteSale;
 
@@ -58,14 +59,29 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
-    upsert_into_purchases_table(
-        client,
-        Purchase {
+    client
+        .transaction(move |db| write_execute_sale(db, &row, pubkeys::AUCTION_HOUSE))
+        .await?;
+
+    Ok(())
+}
+
+/// Write an execute sale instruction and the purchase it records using the
+/// given connection, attributing the purchase to the given marketplace
+/// program.  Returns the ID of the purchase.
+pub(crate) fn write_execute_sale(
+    db: &PooledConnection,
+    row: &ExecuteSaleInstruction,
+    marketplace_program: Pubkey,
+) -> Result<Uuid> {
+    let purchase_id = write_purchase(
+        db,
+        &Purchase {
             id: None,
             buyer: row.buyer.clone(),
             seller: row.seller.clone(),
             auction_house: row.auction_house.clone(),
-            marketplace_program: Owned(pubkeys::AUCTION_HOUSE.to_string()),
+            marketplace_program: Owned(marketplace_program.to_string()),
             metadata: row.metadata.clone(),
             token_size: row.token_size,
             price: row.buyer_price,
@@ -73,22 +89,17 @@ pub(crate) async fn process(
             slot: row.slot,
             write_version: None,
         },
-        accts[13].clone(),
-        accts[14].clone(),
+        &row.buyer_trade_state,
+        &row.seller_trade_state,
     )
-    .await
     .context("failed to insert purchase!")?;
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(execute_sale_instructions::table)
-                .values(&row)
-                .execute(db)
-        })
-        .await
+    insert_into(execute_sale_instructions::table)
+        .values(row)
+        .execute(db)
         .context("failed to insert execute sale instruction ")?;
-    Ok(())
+
+    Ok(purchase_id)
 }
 
 pub(crate) async fn upsert_into_purchases_table<'a>(
@@ -98,60 +109,64 @@ pub(crate) async fn upsert_into_purchases_table<'a>(
     seller_trade_state: String,
 ) -> Result<()> {
     client
-        .db()
-        .run(move |db| {
-            let purchase_exists = select(exists(
-                purchases::table.filter(
-                    purchases::buyer
-                        .eq(data.buyer.clone())
-                        .and(purchases::seller.eq(data.seller.clone()))
-                        .and(purchases::auction_house.eq(data.auction_house.clone()))
-                        .and(purchases::metadata.eq(data.metadata.clone()))
-                        .and(purchases::price.eq(data.price))
-                        .and(purchases::token_size.eq(data.token_size)),
-                ),
-            ))
-            .get_result::<bool>(db)?;
-
-            let purchase_id = mutations::purchase::insert(db, &data)?;
-
-            update(
-                listings::table.filter(
-                    listings::trade_state
-                        .eq(seller_trade_state.clone())
-                        .and(listings::purchase_id.is_null())
-                        .and(listings::canceled_at.is_null()),
-                ),
-            )
-            .set(listings::purchase_id.eq(Some(purchase_id)))
-            .execute(db)?;
-
-            update(
-                offers::table.filter(
-                    offers::trade_state
-                        .eq(buyer_trade_state.clone())
-                        .and(offers::purchase_id.is_null())
-                        .and(offers::canceled_at.is_null()),
-                ),
-            )
-            .set(offers::purchase_id.eq(Some(purchase_id)))
-            .execute(db)?;
-
-            if purchase_exists {
-                return Ok(());
-            }
-
-            mutations::activity::purchase(
-                db,
-                purchase_id,
-                &data.clone(),
-                ActivityTypeEnum::Purchase,
-            )?;
-
-            Result::<_>::Ok(())
-        })
+        .transaction(move |db| write_purchase(db, &data, &buyer_trade_state, &seller_trade_state))
         .await
         .context("Failed to insert purchase!")?;
 
     Ok(())
 }
+
+/// Insert a purchase and mark the listing and offer it filled as purchased
+/// using the given connection, so callers can apply it alongside their own
+/// writes.  Returns the ID of the purchase.
+pub(crate) fn write_purchase(
+    db: &PooledConnection,
+    data: &Purchase,
+    buyer_trade_state: &str,
+    seller_trade_state: &str,
+) -> Result<Uuid> {
+    let purchase_exists = select(exists(
+        purchases::table.filter(
+            purchases::buyer
+                .eq(data.buyer.clone())
+                .and(purchases::seller.eq(data.seller.clone()))
+                .and(purchases::auction_house.eq(data.auction_house.clone()))
+                .and(purchases::metadata.eq(data.metadata.clone()))
+                .and(purchases::price.eq(data.price))
+                .and(purchases::token_size.eq(data.token_size)),
+        ),
+    ))
+    .get_result::<bool>(db)?;
+
+    let purchase_id = mutations::purchase::insert(db, data)?;
+
+    update(
+        listings::table.filter(
+            listings::trade_state
+                .eq(seller_trade_state)
+                .and(listings::purchase_id.is_null())
+                .and(listings::canceled_at.is_null()),
+        ),
+    )
+    .set(listings::purchase_id.eq(Some(purchase_id)))
+    .execute(db)?;
+
+    update(
+        offers::table.filter(
+            offers::trade_state
+                .eq(buyer_trade_state)
+                .and(offers::purchase_id.is_null())
+                .and(offers::canceled_at.is_null()),
+        ),
+    )
+    .set(offers::purchase_id.eq(Some(purchase_id)))
+    .execute(db)?;
+
+    if purchase_exists {
+        return Ok(purchase_id);
+    }
+
+    mutations::activity::purchase(db, purchase_id, data, ActivityTypeEnum::Purchase)?;
+
+    Ok(purchase_id)
+}
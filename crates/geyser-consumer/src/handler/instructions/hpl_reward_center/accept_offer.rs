@@ -9,7 +9,7 @@ use indexer_core::{
     pubkeys,
 };
 
-use super::{super::Client, buy_listing::upsert_into_purchases_table};
+use super::{super::Client, buy_listing::write_purchase};
 
 #[allow(clippy::pedantic)]
 pub(crate) async fn process(
@@ -60,53 +60,44 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
-    let offer = client
-        .db()
-        .run({
-            let offer = row.clone().offer;
-            move |db| {
-                rewards_offers::table
-                    .select((rewards_offers::token_size, rewards_offers::price))
-                    .filter(rewards_offers::address.eq(offer.to_string()))
-                    .first(db)
-                    .optional()
-            }
-        })
-        .await
-        .context("failed to load reward offer!")?;
+    client
+        .transaction(move |db| {
+            let offer = rewards_offers::table
+                .select((rewards_offers::token_size, rewards_offers::price))
+                .filter(rewards_offers::address.eq(row.offer.to_string()))
+                .first(db)
+                .optional()
+                .context("failed to load reward offer!")?;
 
-    if let Some((token_size, price)) = offer {
-        upsert_into_purchases_table(
-            client,
-            Purchase {
-                id: None,
-                buyer: row.buyer.clone(),
-                seller: row.seller.clone(),
-                auction_house: row.auction_house.clone(),
-                marketplace_program: Owned(pubkeys::REWARD_CENTER.to_string()),
-                metadata: row.metadata.clone(),
-                token_size,
-                price,
-                created_at: timestamp,
-                slot: row.slot,
-                write_version: None,
-            },
-            row.buyer_trade_state.to_string(),
-            row.seller_trade_state.to_string(),
-            row.reward_center.to_string(),
-        )
-        .await
-        .context("failed to insert purchase!")?;
-    }
+            if let Some((token_size, price)) = offer {
+                write_purchase(
+                    db,
+                    &Purchase {
+                        id: None,
+                        buyer: row.buyer.clone(),
+                        seller: row.seller.clone(),
+                        auction_house: row.auction_house.clone(),
+                        marketplace_program: Owned(pubkeys::REWARD_CENTER.to_string()),
+                        metadata: row.metadata.clone(),
+                        token_size,
+                        price,
+                        created_at: timestamp,
+                        slot: row.slot,
+                        write_version: None,
+                    },
+                    &row.buyer_trade_state,
+                    &row.seller_trade_state,
+                    &row.reward_center,
+                )
+                .context("failed to insert purchase!")?;
+            }
 
-    client
-        .db()
-        .run(move |db| {
             insert_into(accept_offer_ins::table)
                 .values(&row)
                 .execute(db)
+                .context("failed to insert reward center accept offer instruction ")?;
+
+            Result::<_>::Ok(())
         })
         .await
-        .context("failed to insert reward center accept offer instruction ")?;
-    Ok(())
 }
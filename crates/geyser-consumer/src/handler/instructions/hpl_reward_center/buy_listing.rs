@@ -14,7 +14,7 @@ use indexer_core::{
             buy_listing_ins, listings, offers, purchases, reward_centers, reward_payouts,
             rewards_listings,
         },
-        update,
+        update, PooledConnection,
     },
     pubkeys,
 };
@@ -71,137 +71,127 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
-    let listing = client
-        .db()
-        .run({
-            let listing = row.clone().listing;
-            move |db| {
-                rewards_listings::table
-                    .select((rewards_listings::token_size, rewards_listings::price))
-                    .filter(rewards_listings::address.eq(listing.to_string()))
-                    .first(db)
-                    .optional()
+    client
+        .transaction(move |db| {
+            let listing = rewards_listings::table
+                .select((rewards_listings::token_size, rewards_listings::price))
+                .filter(rewards_listings::address.eq(row.listing.to_string()))
+                .first(db)
+                .optional()
+                .context("failed to load reward listing!")?;
+
+            if let Some((token_size, price)) = listing {
+                write_purchase(
+                    db,
+                    &Purchase {
+                        id: None,
+                        buyer: row.buyer.clone(),
+                        seller: row.seller.clone(),
+                        auction_house: row.auction_house.clone(),
+                        marketplace_program: Owned(pubkeys::REWARD_CENTER.to_string()),
+                        metadata: row.metadata.clone(),
+                        token_size,
+                        price,
+                        created_at: timestamp,
+                        slot: row.slot,
+                        write_version: None,
+                    },
+                    &row.buyer_trade_state,
+                    &row.seller_trade_state,
+                    &row.reward_center,
+                )
+                .context("failed to insert purchase!")?;
             }
-        })
-        .await
-        .context("failed to load reward listing!")?;
-
-    if let Some((token_size, price)) = listing {
-        upsert_into_purchases_table(
-            client,
-            Purchase {
-                id: None,
-                buyer: row.buyer.clone(),
-                seller: row.seller.clone(),
-                auction_house: row.auction_house.clone(),
-                marketplace_program: Owned(pubkeys::REWARD_CENTER.to_string()),
-                metadata: row.metadata.clone(),
-                token_size,
-                price,
-                created_at: timestamp,
-                slot: row.slot,
-                write_version: None,
-            },
-            row.buyer_trade_state.to_string(),
-            row.seller_trade_state.to_string(),
-            row.reward_center.to_string(),
-        )
-        .await
-        .context("failed to insert purchase!")?;
-    }
 
-    client
-        .db()
-        .run(move |db| insert_into(buy_listing_ins::table).values(&row).execute(db))
+            insert_into(buy_listing_ins::table)
+                .values(&row)
+                .execute(db)
+                .context("failed to insert reward center buy listing instruction ")?;
+
+            Result::<_>::Ok(())
+        })
         .await
-        .context("failed to insert reward center accept offer instruction ")?;
-    Ok(())
 }
 
-#[allow(clippy::too_many_lines)]
-pub(crate) async fn upsert_into_purchases_table<'a>(
-    client: &Client,
-    data: Purchase<'static>,
-    buyer_trade_state: String,
-    seller_trade_state: String,
-    reward_center_address: String,
+/// Insert a reward center purchase, link it to the listing and offer it
+/// filled, and record its reward payout using the given connection
+pub(crate) fn write_purchase(
+    db: &PooledConnection,
+    data: &Purchase,
+    buyer_trade_state: &str,
+    seller_trade_state: &str,
+    reward_center_address: &str,
 ) -> Result<()> {
-    client
-        .db()
-        .run(move |db| {
-            let purchase_exists = select(exists(
-                purchases::table.filter(
-                    purchases::buyer
-                        .eq(data.buyer.clone())
-                        .and(purchases::seller.eq(data.seller.clone()))
-                        .and(purchases::auction_house.eq(data.auction_house.clone()))
-                        .and(purchases::metadata.eq(data.metadata.clone()))
-                        .and(purchases::price.eq(data.price))
-                        .and(purchases::token_size.eq(data.token_size)),
-                ),
-            ))
-            .get_result::<bool>(db)?;
-
-            let purchase_id = mutations::purchase::insert(db, &data)?;
-
-            update(
-                listings::table.filter(
-                    listings::trade_state
-                        .eq(seller_trade_state.clone())
-                        .and(listings::purchase_id.is_null())
-                        .and(listings::canceled_at.is_null()),
-                ),
-            )
-            .set(listings::purchase_id.eq(Some(purchase_id)))
-            .execute(db)?;
+    let purchase_exists = select(exists(
+        purchases::table.filter(
+            purchases::buyer
+                .eq(data.buyer.clone())
+                .and(purchases::seller.eq(data.seller.clone()))
+                .and(purchases::auction_house.eq(data.auction_house.clone()))
+                .and(purchases::metadata.eq(data.metadata.clone()))
+                .and(purchases::price.eq(data.price))
+                .and(purchases::token_size.eq(data.token_size)),
+        ),
+    ))
+    .get_result::<bool>(db)?;
+
+    let purchase_id = mutations::purchase::insert(db, data)?;
+
+    update(
+        listings::table.filter(
+            listings::trade_state
+                .eq(seller_trade_state)
+                .and(listings::purchase_id.is_null())
+                .and(listings::canceled_at.is_null()),
+        ),
+    )
+    .set(listings::purchase_id.eq(Some(purchase_id)))
+    .execute(db)?;
+
+    update(
+        offers::table.filter(
+            offers::trade_state
+                .eq(buyer_trade_state)
+                .and(offers::purchase_id.is_null())
+                .and(offers::canceled_at.is_null()),
+        ),
+    )
+    .set(offers::purchase_id.eq(Some(purchase_id)))
+    .execute(db)?;
+
+    if purchase_exists {
+        return Ok(());
+    }
 
-            update(
-                offers::table.filter(
-                    offers::trade_state
-                        .eq(buyer_trade_state.clone())
-                        .and(offers::purchase_id.is_null())
-                        .and(offers::canceled_at.is_null()),
-                ),
-            )
-            .set(offers::purchase_id.eq(Some(purchase_id)))
+    activity::purchase(db, purchase_id, data, ActivityTypeEnum::Purchase)?;
+
+    let reward_center = reward_centers::table
+        .select(reward_centers::all_columns)
+        .filter(reward_centers::address.eq(reward_center_address))
+        .first::<DbRewardCenter>(db)
+        .optional()?;
+
+    if let Some(r) = reward_center {
+        let (buyer_reward, seller_reward) = calculate_payout(data.price.try_into()?, &r)?;
+
+        let reward_payout = RewardPayout {
+            purchase_id,
+            metadata: Owned(data.metadata.to_string()),
+            reward_center: Owned(reward_center_address.to_owned()),
+            buyer: Owned(data.buyer.to_string()),
+            buyer_reward,
+            seller: Owned(data.seller.to_string()),
+            seller_reward,
+            created_at: data.created_at,
+            slot: data.slot,
+            write_version: -1,
+        };
+
+        insert_into(reward_payouts::table)
+            .values(&reward_payout)
+            .on_conflict_do_nothing()
             .execute(db)?;
-
-            if purchase_exists {
-                return Ok(());
-            }
-
-            activity::purchase(db, purchase_id, &data.clone(), ActivityTypeEnum::Purchase)?;
-
-            let reward_center = reward_centers::table
-                .select(reward_centers::all_columns)
-                .filter(reward_centers::address.eq(reward_center_address.clone()))
-                .first::<DbRewardCenter>(db)
-                .optional()?;
-
-            if let Some(r) = reward_center {
-                let (buyer_reward, seller_reward) = calculate_payout(data.price.try_into()?, &r)?;
-
-                let reward_payout = RewardPayout {
-                    purchase_id,
-                    metadata: Owned(data.metadata.to_string()),
-                    reward_center: Owned(reward_center_address),
-                    buyer: Owned(data.buyer.to_string()),
-                    buyer_reward,
-                    seller: Owned(data.seller.to_string()),
-                    seller_reward,
-                    created_at: data.created_at,
-                    slot: data.slot,
-                    write_version: -1,
-                };
-
-                insert_into(reward_payouts::table)
-                    .values(&reward_payout)
-                    .on_conflict_do_nothing()
-                    .execute(db)?;
-            }
-            Result::<_>::Ok(())
-        })
-        .await?;
+    }
 
     Ok(())
 }
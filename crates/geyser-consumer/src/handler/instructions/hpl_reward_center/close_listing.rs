@@ -23,27 +23,35 @@ pub(crate) async fn process(
     let slot: i64 = slot.try_into()?;
 
     client
-        .db()
-        .run(move |db| {
+        .transaction(move |db| {
+            let token_size = rewards_listings::table
+                .select(rewards_listings::token_size)
+                .filter(rewards_listings::address.eq(listing_address.clone()))
+                .first(db)
+                .optional()
+                .context("failed to load reward listing")?;
+
             delete(
                 rewards_listings::table.filter(
                     rewards_listings::address
-                        .eq(listing_address)
+                        .eq(&listing_address)
                         .and(rewards_listings::slot.lt(slot)),
                 ),
             )
-            .execute(db)?;
+            .execute(db)
+            .context("failed to delete reward listing")?;
 
             let listing = delete(
                 listings::table.filter(
                     listings::trade_state
-                        .eq(trade_state)
+                        .eq(&trade_state)
                         .and(listings::slot.lt(slot)),
                 ),
             )
             .returning(listings::all_columns)
             .get_result::<Listing>(db)
-            .optional()?;
+            .optional()
+            .context("failed to delete listing")?;
 
             if let Some(mut listing) = listing {
                 listing.canceled_at = Some(Some(timestamp));
@@ -56,20 +64,6 @@ pub(crate) async fn process(
                 )?;
             }
 
-            Result::<_>::Ok(())
-        })
-        .await
-        .context("failed to delete reward listing")?;
-
-    client
-        .db()
-        .run(move |db| {
-            let token_size = rewards_listings::table
-                .select(rewards_listings::token_size)
-                .filter(rewards_listings::address.eq(accts[1].clone()))
-                .first(db)
-                .optional()?;
-
             let row = HplRewardCenterCloseListing {
                 wallet: Owned(accts[0].clone()),
                 listing: Owned(accts[1].clone()),
@@ -90,9 +84,9 @@ pub(crate) async fn process(
             insert_into(hpl_reward_center_close_listing_ins::table)
                 .values(&row)
                 .execute(db)
+                .context("failed to insert reward center close listing instruction ")?;
+
+            Result::<_>::Ok(())
         })
         .await
-        .context("failed to insert reward center close listing instruction ")?;
-
-    Ok(())
 }
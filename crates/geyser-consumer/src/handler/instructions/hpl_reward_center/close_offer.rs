@@ -29,86 +29,76 @@ pub(crate) async fn process(
     let escrow_payment_bump = params.escrow_payment_bump.try_into()?;
 
     client
-        .db()
-        .run({
-            let offer_address = offer_address.clone();
-            move |db| {
-                delete(
-                    rewards_offers::table.filter(
-                        rewards_offers::address
-                            .eq(offer_address)
-                            .and(rewards_offers::slot.lt(slot)),
-                    ),
-                )
-                .execute(db)?;
+        .transaction(move |db| {
+            let (token_size, buyer_price) = rewards_offers::table
+                .select((rewards_offers::token_size, rewards_offers::price))
+                .filter(rewards_offers::address.eq(offer_address.clone()))
+                .first::<(i64, i64)>(db)
+                .optional()
+                .context("failed to load reward offer")?
+                .unwrap_or_default();
 
-                let offer = delete(
-                    offers::table.filter(
-                        offers::trade_state
-                            .eq(trade_state)
-                            .and(offers::slot.lt(slot)),
-                    ),
-                )
-                .returning(offers::all_columns)
-                .get_result::<Offer>(db)
-                .optional()?;
+            delete(
+                rewards_offers::table.filter(
+                    rewards_offers::address
+                        .eq(&offer_address)
+                        .and(rewards_offers::slot.lt(slot)),
+                ),
+            )
+            .execute(db)
+            .context("failed to delete reward offer")?;
 
-                if let Some(mut offer) = offer {
-                    offer.canceled_at = Some(Some(timestamp));
+            let offer = delete(
+                offers::table.filter(
+                    offers::trade_state
+                        .eq(&trade_state)
+                        .and(offers::slot.lt(slot)),
+                ),
+            )
+            .returning(offers::all_columns)
+            .get_result::<Offer>(db)
+            .optional()
+            .context("failed to delete offer")?;
 
-                    activity::offer(
-                        db,
-                        offer.id.unwrap(),
-                        &offer.clone(),
-                        ActivityTypeEnum::OfferCanceled,
-                    )?;
-                }
+            if let Some(mut offer) = offer {
+                offer.canceled_at = Some(Some(timestamp));
 
-                Result::<_>::Ok(())
+                activity::offer(
+                    db,
+                    offer.id.unwrap(),
+                    &offer.clone(),
+                    ActivityTypeEnum::OfferCanceled,
+                )?;
             }
-        })
-        .await
-        .context("failed to delete reward offer")?;
 
-    client
-        .db()
-        .run({
-            let offer_address = offer_address.clone();
-            move |db| {
-                let (token_size, buyer_price): (i64, i64) = rewards_offers::table
-                    .select((rewards_offers::token_size, rewards_offers::price))
-                    .filter(rewards_offers::address.eq(offer_address))
-                    .first(db)?;
+            let row = HplRewardCenterCloseoffer {
+                wallet: Owned(accts[0].clone()),
+                offer: Owned(accts[1].clone()),
+                treasury_mint: Owned(accts[2].clone()),
+                token_account: Owned(accts[3].clone()),
+                receipt_account: Owned(accts[4].clone()),
+                escrow_payment_account: Owned(accts[5].clone()),
+                metadata: Owned(accts[6].clone()),
+                token_mint: Owned(accts[7].clone()),
+                authority: Owned(accts[8].clone()),
+                reward_center: Owned(accts[9].clone()),
+                auction_house: Owned(accts[10].clone()),
+                auction_house_fee_account: Owned(accts[11].clone()),
+                trade_state: Owned(accts[12].clone()),
+                ah_auctioneer_pda: Owned(accts[13].clone()),
+                escrow_payment_bump,
+                buyer_price,
+                token_size,
+                created_at: timestamp,
+                slot,
+            };
 
-                let row = HplRewardCenterCloseoffer {
-                    wallet: Owned(accts[0].clone()),
-                    offer: Owned(accts[1].clone()),
-                    treasury_mint: Owned(accts[2].clone()),
-                    token_account: Owned(accts[3].clone()),
-                    receipt_account: Owned(accts[4].clone()),
-                    escrow_payment_account: Owned(accts[5].clone()),
-                    metadata: Owned(accts[6].clone()),
-                    token_mint: Owned(accts[7].clone()),
-                    authority: Owned(accts[8].clone()),
-                    reward_center: Owned(accts[9].clone()),
-                    auction_house: Owned(accts[10].clone()),
-                    auction_house_fee_account: Owned(accts[11].clone()),
-                    trade_state: Owned(accts[12].clone()),
-                    ah_auctioneer_pda: Owned(accts[13].clone()),
-                    escrow_payment_bump,
-                    buyer_price,
-                    token_size,
-                    created_at: timestamp,
-                    slot,
-                };
+            insert_into(hpl_reward_center_close_offer_ins::table)
+                .values(&row)
+                .execute(db)
+                .context("failed to insert reward center close offer instruction ")?;
 
-                insert_into(hpl_reward_center_close_offer_ins::table)
-                    .values(&row)
-                    .execute(db)
-            }
+            Result::<_>::Ok(())
         })
         .await
-        .context("failed to insert reward center close offer instruction ")?;
-
-    Ok(())
 }
@@ -1,3 +1,6 @@
+pub mod anchor_idl;
+pub mod auctioneer;
+pub mod bubblegum;
 pub mod buy;
 pub mod cancel;
 pub mod deposit;
@@ -2,17 +2,15 @@ use borsh::BorshDeserialize;
 use indexer::prelude::*;
 use indexer_core::{
     db::{
-        custom_types::ActivityTypeEnum,
         insert_into,
         models::{Offer, PublicBuyInstruction},
-        mutations,
-        tables::{offers, public_buy_instructions},
+        tables::public_buy_instructions,
     },
     pubkeys,
 };
 use mpl_auction_house::instruction::PublicBuy;
 
-use super::Client;
+use super::{buy, Client};
 
 pub(crate) async fn process(
     client: &Client,
@@ -49,79 +47,35 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
-    upsert_into_offers_table(client, row.clone())
-        .await
-        .context("failed to insert offer")?;
-
-    client
-        .db()
-        .run(move |db| {
-            insert_into(public_buy_instructions::table)
-                .values(&row)
-                .execute(db)
-        })
-        .await
-        .context("failed to insert public buy instruction ")?;
-    Ok(())
-}
-
-async fn upsert_into_offers_table<'a>(
-    client: &Client,
-    data: PublicBuyInstruction<'static>,
-) -> Result<()> {
-    let row = Offer {
+    let offer = Offer {
         id: None,
-        trade_state: data.buyer_trade_state,
-        auction_house: data.auction_house,
+        trade_state: row.buyer_trade_state.clone(),
+        auction_house: row.auction_house.clone(),
         marketplace_program: Owned(pubkeys::AUCTION_HOUSE.to_string()),
-        buyer: data.wallet,
-        metadata: data.metadata,
-        token_account: Some(data.token_account),
+        buyer: row.wallet.clone(),
+        metadata: row.metadata.clone(),
+        token_account: Some(row.token_account.clone()),
         purchase_id: None,
-        price: data.buyer_price,
-        token_size: data.token_size,
-        trade_state_bump: data.trade_state_bump,
-        created_at: data.created_at,
+        price: row.buyer_price,
+        token_size: row.token_size,
+        trade_state_bump: row.trade_state_bump,
+        created_at: row.created_at,
         canceled_at: Some(None),
-        slot: data.slot,
+        slot: row.slot,
         write_version: None,
         expiry: None,
     };
 
     client
-        .db()
-        .run(move |db| {
-            let auction_house: Pubkey = row.auction_house.to_string().parse()?;
-
-            let indexed_offer: Option<Offer> = offers::table
-                .filter(
-                    offers::trade_state
-                        .eq(row.trade_state.clone())
-                        .and(offers::metadata.eq(row.metadata.clone())),
-                )
-                .select(offers::all_columns)
-                .first(db)
-                .optional()?;
-
-            let offer_id = mutations::offer::insert(db, &row)?;
-
-            if let Some(indexed_offer) = indexed_offer {
-                if (indexed_offer.purchase_id.is_none()
-                    && indexed_offer.canceled_at.is_none()
-                    && indexed_offer.price == row.price)
-                    || auction_house == pubkeys::OPENSEA_AUCTION_HOUSE
-                    || row.slot == indexed_offer.slot
-                {
-                    return Ok(());
-                }
-            }
+        .transaction(move |db| {
+            buy::upsert_offer(db, &offer).context("failed to insert offer")?;
 
-            mutations::activity::offer(db, offer_id, &row.clone(), ActivityTypeEnum::OfferCreated)?;
+            insert_into(public_buy_instructions::table)
+                .values(&row)
+                .execute(db)
+                .context("failed to insert public buy instruction ")?;
 
             Result::<_>::Ok(())
         })
         .await
-        .context("Failed to insert offer!")?;
-
-    Ok(())
 }
@@ -7,6 +7,7 @@ use indexer_core::{
         models::{Listing, SellInstruction},
         mutations,
         tables::{listings, purchases, sell_instructions},
+        PooledConnection,
     },
     pubkeys,
     uuid::Uuid,
@@ -50,39 +51,51 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
-    let values = row.clone();
-
-    let purchase_id = client
-        .db()
-        .run({
-            let row = row.clone();
-            move |db| {
-                purchases::table
-                    .filter(
-                        purchases::seller
-                            .eq(row.wallet)
-                            .and(purchases::auction_house.eq(row.auction_house))
-                            .and(purchases::metadata.eq(row.metadata))
-                            .and(purchases::price.eq(row.buyer_price))
-                            .and(
-                                purchases::token_size
-                                    .eq(row.token_size)
-                                    .and(purchases::slot.eq(row.slot)),
-                            ),
-                    )
-                    .select(purchases::id)
-                    .first::<Uuid>(db)
-                    .optional()
-                    .context("failed to get purchase ids")
-            }
-        })
-        .await?;
-
-    upsert_into_listings_table(client, Listing {
+    process_sell(client, row, pubkeys::AUCTION_HOUSE).await
+}
+
+/// Record a sell instruction and upsert the listing it creates, attributed to
+/// the given marketplace program
+pub(crate) async fn process_sell(
+    client: &Client,
+    row: SellInstruction<'static>,
+    marketplace_program: Pubkey,
+) -> Result<()> {
+    client
+        .transaction(move |db| write_sell(db, &row, marketplace_program))
+        .await
+}
+
+/// Write a sell instruction and the listing it creates using the given
+/// connection, so callers can apply it alongside their own writes
+pub(crate) fn write_sell(
+    db: &PooledConnection,
+    row: &SellInstruction,
+    marketplace_program: Pubkey,
+) -> Result<()> {
+    let purchase_id = purchases::table
+        .filter(
+            purchases::seller
+                .eq(row.wallet.clone())
+                .and(purchases::auction_house.eq(row.auction_house.clone()))
+                .and(purchases::metadata.eq(row.metadata.clone()))
+                .and(purchases::price.eq(row.buyer_price))
+                .and(
+                    purchases::token_size
+                        .eq(row.token_size)
+                        .and(purchases::slot.eq(row.slot)),
+                ),
+        )
+        .select(purchases::id)
+        .first::<Uuid>(db)
+        .optional()
+        .context("failed to get purchase ids")?;
+
+    upsert_listing(db, &Listing {
         id: None,
         trade_state: row.seller_trade_state.clone(),
         auction_house: row.auction_house.clone(),
-        marketplace_program: Owned(pubkeys::AUCTION_HOUSE.to_string()),
+        marketplace_program: Owned(marketplace_program.to_string()),
         seller: row.wallet.clone(),
         metadata: row.metadata.clone(),
         purchase_id,
@@ -95,60 +108,55 @@ pub(crate) async fn process(
         write_version: None,
         expiry: None,
     })
-    .await
     .context("failed to insert listing!")?;
 
-    client
-        .db()
-        .run(move |db| {
-            insert_into(sell_instructions::table)
-                .values(&values)
-                .execute(db)
-        })
-        .await
+    insert_into(sell_instructions::table)
+        .values(row)
+        .execute(db)
         .context("failed to insert sell instruction ")?;
+
     Ok(())
 }
 
 pub async fn upsert_into_listings_table<'a>(client: &Client, row: Listing<'static>) -> Result<()> {
     client
-        .db()
-        .run(move |db| {
-            let auction_house: Pubkey = row.auction_house.to_string().parse()?;
-
-            let indexed_listing: Option<Listing> = listings::table
-                .filter(
-                    listings::trade_state
-                        .eq(row.trade_state.clone())
-                        .and(listings::metadata.eq(row.metadata.clone())),
-                )
-                .select(listings::all_columns)
-                .first(db)
-                .optional()?;
-
-            let listing_id = mutations::listing::insert(db, &row)?;
-
-            if let Some(indexed_listing) = indexed_listing {
-                if (indexed_listing.purchase_id.is_none()
-                    && indexed_listing.canceled_at.is_none()
-                    && indexed_listing.price == row.price)
-                    || auction_house == pubkeys::OPENSEA_AUCTION_HOUSE
-                    || row.slot == indexed_listing.slot
-                {
-                    return Ok(());
-                }
-            }
-
-            mutations::activity::listing(
-                db,
-                listing_id,
-                &row.clone(),
-                ActivityTypeEnum::ListingCreated,
-            )?;
-            Result::<_>::Ok(())
-        })
+        .transaction(move |db| upsert_listing(db, &row))
         .await
-        .context("Failed to insert listing!")?;
+        .context("Failed to insert listing!")
+}
+
+fn upsert_listing(db: &PooledConnection, row: &Listing) -> Result<()> {
+    let auction_house: Pubkey = row.auction_house.to_string().parse()?;
+
+    let indexed_listing: Option<Listing> = listings::table
+        .filter(
+            listings::trade_state
+                .eq(row.trade_state.clone())
+                .and(listings::metadata.eq(row.metadata.clone())),
+        )
+        .select(listings::all_columns)
+        .first(db)
+        .optional()?;
+
+    let listing_id = mutations::listing::insert(db, row)?;
+
+    if let Some(indexed_listing) = indexed_listing {
+        if (indexed_listing.purchase_id.is_none()
+            && indexed_listing.canceled_at.is_none()
+            && indexed_listing.price == row.price)
+            || auction_house == pubkeys::OPENSEA_AUCTION_HOUSE
+            || row.slot == indexed_listing.slot
+        {
+            return Ok(());
+        }
+    }
+
+    mutations::activity::listing(
+        db,
+        listing_id,
+        &row.clone(),
+        ActivityTypeEnum::ListingCreated,
+    )?;
 
     Ok(())
 }
@@ -34,6 +34,14 @@ pub(crate) async fn process(
         slot: slot.try_into()?,
     };
 
+    process_withdraw(client, row).await
+}
+
+/// Record a withdraw instruction
+pub(crate) async fn process_withdraw(
+    client: &Client,
+    row: WithdrawInstruction<'static>,
+) -> Result<()> {
     client
         .db()
         .run(move |db| {
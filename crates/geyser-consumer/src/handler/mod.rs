@@ -1,36 +1,29 @@
 //! Support features for the Geyser indexer
 
 mod accounts;
+mod anchor_idl;
 mod client;
 mod instructions;
 mod programs;
+mod registry;
 mod slot_status;
+mod slot_times;
 
-use std::{collections::HashSet, fmt, sync::Arc};
+use std::fmt;
 
 pub use client::{Args as ClientArgs, Client, Queues as ClientQueues};
 use indexer::prelude::*;
 use indexer_core::pubkeys;
 pub(self) use indexer_rabbitmq::geyser::AccountUpdate;
-use indexer_rabbitmq::geyser::Message;
-
-/// A value indicating a specific topic to ignore
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumString, strum::Display)]
-#[strum(serialize_all = "kebab-case")]
-pub enum IgnoreType {
-    /// Ignore the metadata program
-    Metadata,
-    /// Ignore the Metaplex candy machine program
-    CandyMachine,
-    /// Ignore the SPL token program
-    Tokens,
-}
+use indexer_rabbitmq::geyser::{InstructionNotify, Message};
+use registry::Route;
+pub use registry::{ProgramRegistry, ProgramType};
 
 /// Message identifier
 #[derive(Debug, Clone, Copy)]
 pub enum MessageId {
-    /// An update of an account with the given key
-    AccountUpdate(Pubkey),
+    /// An update of an account with the given key, owned by the given program
+    AccountUpdate(Pubkey, Pubkey),
     /// An instruction from the program with the given key
     Instruction(Pubkey),
     /// A status update of the slot with the given ID
@@ -40,142 +33,169 @@ pub enum MessageId {
 impl fmt::Display for MessageId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::AccountUpdate(k) => write!(f, "account update for {k}"),
+            Self::AccountUpdate(k, o) => write!(f, "account update for {k} (owner {o})"),
             Self::Instruction(p) => write!(f, "instruction from program {p}"),
             &Self::SlotStatus(s) => write!(f, "status update for slot {s}"),
         }
     }
 }
 
-/// Process a message from a Geyser RabbitMQ queue
-///
-/// # Errors
-/// This function fails if an error occurs processing the message body.
-#[allow(clippy::too_many_lines)]
-pub async fn process_message<H: std::hash::BuildHasher>(
-    msg: Message,
-    client: &Client,
-    ignore_on_startup: Arc<HashSet<IgnoreType, H>>,
-) -> MessageResult<MessageId> {
-    let check_ignore =
-        |ty, update: &AccountUpdate| !(update.is_startup && ignore_on_startup.contains(&ty));
-
-    let id = match msg {
-        Message::AccountUpdate(ref u) => MessageId::AccountUpdate(u.key),
-        Message::InstructionNotify(ref i) => MessageId::Instruction(i.program),
-        Message::SlotStatusUpdate(ref s) => MessageId::SlotStatus(s.slot),
-    };
-
-    match msg {
-        // Accounts
-        Message::AccountUpdate(update)
-            if update.owner == pubkeys::METADATA && check_ignore(IgnoreType::Metadata, &update) =>
-        {
-            programs::metadata::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::REWARD_CENTER => {
-            programs::reward_center::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::AUCTION => {
-            programs::auction::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::METAPLEX => {
-            programs::metaplex::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::AUCTION_HOUSE => {
-            programs::auction_house::process(client, update).await
-        },
-        Message::AccountUpdate(update)
-            if update.owner == pubkeys::TOKEN && check_ignore(IgnoreType::Tokens, &update) =>
-        {
-            programs::token::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::GRAPH_PROGRAM => {
-            programs::graph::process(client, update).await
-        },
-        Message::AccountUpdate(update)
-            if update.owner == pubkeys::CANDY_MACHINE
-                && check_ignore(IgnoreType::CandyMachine, &update) =>
-        {
-            programs::candy_machine::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::NAME_SERVICE => {
-            programs::name_service::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::CARDINAL_TOKEN_MANAGER => {
+async fn process_account(client: &Client, ty: ProgramType, update: &AccountUpdate) -> Result<()> {
+    match ty {
+        ProgramType::Metadata => programs::metadata::process(client, update).await,
+        ProgramType::CandyMachine if update.owner == pubkeys::CANDY_MACHINE_CORE => {
+            programs::candy_machine_core::process(client, update).await
+        },
+        ProgramType::CandyMachine if update.owner == pubkeys::CANDY_GUARD => {
+            programs::candy_guard::process(client, update).await
+        },
+        ProgramType::CandyMachine => programs::candy_machine::process(client, update).await,
+        ProgramType::Tokens if update.owner == pubkeys::TOKEN_2022 => {
+            programs::token_2022::process(client, update).await
+        },
+        ProgramType::Tokens => programs::token::process(client, update).await,
+        ProgramType::Auction => programs::auction::process(client, update).await,
+        ProgramType::Metaplex => programs::metaplex::process(client, update).await,
+        ProgramType::AuctionHouse => programs::auction_house::process(client, update).await,
+        ProgramType::Auctioneer => programs::auctioneer::process(client, update).await,
+        ProgramType::RewardCenter => programs::reward_center::process(client, update).await,
+        ProgramType::Graph => programs::graph::process(client, update).await,
+        ProgramType::NameService => programs::name_service::process(client, update).await,
+        ProgramType::CardinalTokenManager => {
             programs::cardinal_token_manager::process(client, update).await
         },
-        Message::AccountUpdate(update) if update.owner == pubkeys::CARDINAL_TIME_INVALIDATOR => {
+        ProgramType::CardinalTimeInvalidator => {
             programs::cardinal_time_invalidator::process(client, update).await
         },
-        Message::AccountUpdate(update) if update.owner == pubkeys::CARDINAL_USE_INVALIDATOR => {
+        ProgramType::CardinalUseInvalidator => {
             programs::cardinal_use_invalidator::process(client, update).await
         },
-        Message::AccountUpdate(update) if update.owner == pubkeys::CARDINAL_PAID_CLAIM_APPROVER => {
+        ProgramType::CardinalPaidClaimApprover => {
             programs::cardinal_paid_claim_approver::process(client, update).await
         },
-        Message::AccountUpdate(update) if update.owner == pubkeys::GOKI_SMART_WALLET => {
-            programs::goki_smart_wallet::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::TRIBECA_LOCKED_VOTER => {
+        ProgramType::Namespaces => programs::namespaces::process(client, update).await,
+        ProgramType::GokiSmartWallet => programs::goki_smart_wallet::process(client, update).await,
+        ProgramType::TribecaLockedVoter => {
             programs::tribeca_locked_voter::process(client, update).await
         },
-        Message::AccountUpdate(update) if update.owner == pubkeys::TRIBECA_GOVERN => {
-            programs::tribeca_govern::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::NAMESPACES => {
-            programs::namespaces::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::TOKEN_BONDING => {
-            programs::token_bonding::process(client, update).await
-        },
-        Message::AccountUpdate(update) if pubkeys::SPL_GOVERNANCE.contains(&update.owner) => {
-            programs::spl_governance::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == genostub::ID => {
-            programs::genopets::process(client, update).await
+        ProgramType::TribecaGovern => programs::tribeca_govern::process(client, update).await,
+        ProgramType::TokenBonding => programs::token_bonding::process(client, update).await,
+        ProgramType::SplGovernance => programs::spl_governance::process(client, update).await,
+        ProgramType::Genopets => programs::genopets::process(client, update).await,
+        ProgramType::MagicEden if update.owner == pubkeys::ME_AMM => {
+            programs::magic_eden_amm::process(client, update).await
         },
+        ProgramType::MagicEden | ProgramType::Bubblegum | ProgramType::SplNoop => Ok(()),
+    }
+}
 
-        // Instructions
-        Message::InstructionNotify(ins) if ins.program == pubkeys::AUCTION_HOUSE => {
+async fn process_instruction(
+    client: &Client,
+    ty: ProgramType,
+    ins: &InstructionNotify,
+) -> Result<()> {
+    match ty {
+        ProgramType::AuctionHouse => {
             programs::auction_house::process_instruction(client, &ins.data, &ins.accounts, ins.slot)
                 .await
         },
-        Message::InstructionNotify(ins) if ins.program == pubkeys::REWARD_CENTER => {
+        ProgramType::Auctioneer => {
+            programs::auctioneer::process_instruction(client, &ins.data, &ins.accounts, ins.slot)
+                .await
+        },
+        ProgramType::RewardCenter => {
             programs::reward_center::process_instruction(
                 client,
-                ins.txn_signature,
+                ins.txn_signature.clone(),
                 &ins.data,
                 &ins.accounts,
                 ins.slot,
             )
             .await
         },
-        Message::InstructionNotify(ins) if ins.program == pubkeys::ME_HAUS => {
+        // AMM pool changes are indexed from the pool accounts
+        ProgramType::MagicEden if ins.program == pubkeys::ME_AMM => Ok(()),
+        ProgramType::MagicEden => {
             programs::magic_eden_haus::process_instruction(
                 client,
+                ins.txn_signature.clone(),
                 &ins.data,
                 &ins.accounts,
                 ins.slot,
             )
             .await
         },
-        Message::InstructionNotify(ins) if ins.program == pubkeys::TOKEN => {
+        ProgramType::Tokens if ins.program == pubkeys::TOKEN_2022 => {
+            programs::token_2022::process_instruction(client, &ins.data, &ins.accounts, ins.slot)
+                .await
+        },
+        ProgramType::Tokens => {
             programs::token::process_instruction(client, &ins.data, &ins.accounts, ins.slot).await
         },
+        ProgramType::Bubblegum => {
+            programs::bubblegum::process_instruction(client, &ins.data, &ins.accounts, ins.slot)
+                .await
+        },
+        ProgramType::SplNoop => {
+            programs::spl_noop::process_instruction(client, &ins.data, ins.slot).await
+        },
+        _ => Ok(()),
+    }
+}
 
-        // Other
-        Message::SlotStatusUpdate(slot) => slot_status::process(client, slot).await,
+/// Process a message from a Geyser RabbitMQ queue
+///
+/// # Errors
+/// This function fails if an error occurs processing the message body.
+pub async fn process_message(
+    msg: &Message,
+    client: &Client,
+    registry: &ProgramRegistry,
+) -> MessageResult<MessageId> {
+    let id = match msg {
+        Message::AccountUpdate(u) => MessageId::AccountUpdate(u.key, u.owner),
+        Message::InstructionNotify(i) => MessageId::Instruction(i.program),
+        Message::SlotStatusUpdate(s) => MessageId::SlotStatus(s.slot),
+    };
+
+    let route = match msg {
+        Message::AccountUpdate(u) => registry.route(&u.owner, u.is_startup),
+        Message::InstructionNotify(i) => registry.route(&i.program, false),
+        Message::SlotStatusUpdate(_) => Route::Skip,
+    };
 
-        // Fallbacks
-        Message::AccountUpdate(update) => {
-            debug!(
-                "Unhandled account update for program {}",
-                bs58::encode(update.owner).into_string()
-            );
+    match (route, msg) {
+        (_, Message::SlotStatusUpdate(slot)) => slot_status::process(client, slot).await,
+        (Route::Handle(ty), Message::AccountUpdate(update)) => {
+            process_account(client, ty, update).await
+        },
+        (Route::Handle(ty), Message::InstructionNotify(ins)) => {
+            process_instruction(client, ty, ins).await
+        },
+        // Programs without a dedicated handler may still be indexed from their IDL
+        (Route::Unknown, Message::AccountUpdate(update))
+            if client.anchor_idls().get(&update.owner).is_some() =>
+        {
+            programs::anchor_idl::process(client, update).await
+        },
+        (Route::Unknown, Message::InstructionNotify(ins))
+            if client.anchor_idls().get(&ins.program).is_some() =>
+        {
+            programs::anchor_idl::process_instruction(
+                client,
+                ins.program,
+                ins.txn_signature.clone(),
+                &ins.data,
+                &ins.accounts,
+                ins.slot,
+            )
+            .await
+        },
+        (Route::Unknown, msg) => {
+            indexer::metrics::observe_unhandled(msg);
             Ok(())
         },
-        Message::InstructionNotify { .. } => Ok(()),
+        (Route::Skip, _) => Ok(()),
     }
     .map_err(|e| MessageError::new(e, id))
 }
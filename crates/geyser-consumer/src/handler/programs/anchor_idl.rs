@@ -0,0 +1,50 @@
+//! Accounts and instructions of programs indexed generically from their
+//! Anchor IDL
+
+use indexer::prelude::*;
+
+use super::{
+    accounts::anchor_idl, instructions::anchor_idl as anchor_idl_ins, AccountUpdate, Client,
+};
+
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
+    let Some(idl) = client.anchor_idls().get(&update.owner) else {
+        return Ok(());
+    };
+
+    let Some((account_type, data)) = idl.decode_account(&update.data)? else {
+        return Ok(());
+    };
+
+    anchor_idl::process(
+        client,
+        update.key,
+        update.owner,
+        account_type.to_owned(),
+        data,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+pub(crate) async fn process_instruction(
+    client: &Client,
+    program: Pubkey,
+    tx_signature: Vec<u8>,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let Some(idl) = client.anchor_idls().get(&program) else {
+        return Ok(());
+    };
+
+    let Some(ins) = idl.decode_instruction(data, accounts)? else {
+        return Ok(());
+    };
+
+    let signature = solana_sdk::signature::Signature::new(&tx_signature).to_string();
+
+    anchor_idl_ins::process(client, program, signature, ins, slot).await
+}
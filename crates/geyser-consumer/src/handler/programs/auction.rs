@@ -9,7 +9,7 @@ use super::{
     AccountUpdate, Client,
 };
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let accounts = util::account_data_as_info(
         update.key,
         update.data,
@@ -31,9 +31,16 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
     .await?;
 
     match accounts {
-        (Ok(a), Err(_), Err(_)) => auction_data::process(client, update.key, a).await,
-        (Err(_), Ok(e), Err(_)) => auction_data::process_extended(client, update.key, e).await,
-        (Err(_), Err(_), Ok(b)) => bidder_metadata::process(client, update.key, b).await,
+        (Ok(a), Err(_), Err(_)) => {
+            auction_data::process(client, update.key, a, update.slot, update.write_version).await
+        },
+        (Err(_), Ok(e), Err(_)) => {
+            auction_data::process_extended(client, update.key, e, update.slot, update.write_version)
+                .await
+        },
+        (Err(_), Err(_), Ok(b)) => {
+            bidder_metadata::process(client, update.key, b, update.slot, update.write_version).await
+        },
         (Err(_), Err(_), Err(_)) => {
             debug!(
                 "Account at {} was not AuctionData(Extended) or BidderMetadata",
@@ -29,14 +29,14 @@ const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
 const WITHDRAW_FROM_FEE: [u8; 8] = [179, 208, 190, 154, 32, 179, 19, 59];
 const WITHDRAW_FROM_TREASURY: [u8; 8] = [0, 164, 86, 76, 56, 72, 12, 170];
 
-async fn process_auction_house(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_auction_house(client: &Client, update: &AccountUpdate) -> Result<()> {
     let house: AuctionHouse = AuctionHouse::try_deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize auction house data")?;
 
-    auction_house::process(client, update.key, house).await
+    auction_house::process(client, update.key, house, update.slot, update.write_version).await
 }
 
-async fn process_listing_receipt(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_listing_receipt(client: &Client, update: &AccountUpdate) -> Result<()> {
     let listing_receipt: ListingReceipt =
         ListingReceipt::try_deserialize(&mut update.data.as_slice())
             .context("Failed to deserialize listing receipt data")?;
@@ -51,7 +51,7 @@ async fn process_listing_receipt(client: &Client, update: AccountUpdate) -> Resu
     .await
 }
 
-async fn process_bid_receipt(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_bid_receipt(client: &Client, update: &AccountUpdate) -> Result<()> {
     let bid_receipt: BidReceipt = BidReceipt::try_deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize bid receipt data")?;
 
@@ -65,7 +65,7 @@ async fn process_bid_receipt(client: &Client, update: AccountUpdate) -> Result<(
     .await
 }
 
-async fn process_purchase_receipt(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_purchase_receipt(client: &Client, update: &AccountUpdate) -> Result<()> {
     let purchase_receipt: PurchaseReceipt =
         PurchaseReceipt::try_deserialize(&mut update.data.as_slice())
             .context("Failed to deserialize purchase receipt data")?;
@@ -80,7 +80,7 @@ async fn process_purchase_receipt(client: &Client, update: AccountUpdate) -> Res
     .await
 }
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     match update.data.len() {
         AUCTION_HOUSE_SIZE => process_auction_house(client, update).await,
         LISTING_RECEIPT_SIZE => process_listing_receipt(client, update).await,
@@ -0,0 +1,166 @@
+//! Auctioneer accounts and instructions
+//!
+//! The Auctioneer program runs timed auctions on top of Auction House by
+//! acting as its delegated auctioneer authority.  Listings, bids,
+//! cancellations, sales and escrow deposits and withdrawals are forwarded to
+//! Auction House through CPI, so they are indexed here from the outer
+//! Auctioneer instructions.
+
+use borsh::BorshDeserialize;
+use indexer::prelude::*;
+
+use super::{
+    accounts::auctioneer, instructions::auctioneer as auctioneer_ins, AccountUpdate, Client,
+};
+
+const LISTING_CONFIG: [u8; 8] = [183, 196, 26, 41, 131, 46, 184, 115];
+
+// Anchor Discriminators
+const SELL: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+const BUY: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+const CANCEL: [u8; 8] = [232, 219, 223, 41, 219, 236, 220, 190];
+const EXECUTE_SALE: [u8; 8] = [37, 74, 217, 157, 79, 49, 35, 6];
+const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Bid {
+    pub version: u8,
+    pub amount: u64,
+    pub buyer_trade_state: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct ListingConfig {
+    pub version: u8,
+    pub highest_bid: Bid,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub time_ext_period: u32,
+    pub time_ext_delta: u32,
+    pub allow_high_bid_cancel: bool,
+    pub reserve_price: u64,
+    pub min_bid_increment: u64,
+    pub bump: u8,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Sell {
+    pub trade_state_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub program_as_signer_bump: u8,
+    pub auctioneer_authority_bump: u8,
+    pub token_size: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub reserve_price: Option<u64>,
+    pub min_bid_increment: Option<u64>,
+    pub time_ext_period: Option<u32>,
+    pub time_ext_delta: Option<u32>,
+    pub allow_high_bid_cancel: Option<bool>,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Buy {
+    pub trade_state_bump: u8,
+    pub escrow_payment_bump: u8,
+    pub auctioneer_authority_bump: u8,
+    pub buyer_price: u64,
+    pub token_size: u64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Cancel {
+    pub auctioneer_authority_bump: u8,
+    pub buyer_price: u64,
+    pub token_size: u64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct ExecuteSale {
+    pub escrow_payment_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub program_as_signer_bump: u8,
+    pub auctioneer_authority_bump: u8,
+    pub buyer_price: u64,
+    pub token_size: u64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Deposit {
+    pub escrow_payment_bump: u8,
+    pub auctioneer_authority_bump: u8,
+    pub amount: u64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Withdraw {
+    pub escrow_payment_bump: u8,
+    pub auctioneer_authority_bump: u8,
+    pub amount: u64,
+}
+
+async fn process_listing_config(client: &Client, update: &AccountUpdate) -> Result<()> {
+    let config = ListingConfig::deserialize(&mut &update.data[8..])
+        .context("Failed to deserialize listing config")?;
+
+    auctioneer::process_listing_config(
+        client,
+        update.key,
+        config,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
+    match update.data.get(..8) {
+        Some(d) if d == LISTING_CONFIG => process_listing_config(client, update).await,
+        _ => Ok(()),
+    }
+}
+
+pub(crate) async fn process_instruction(
+    client: &Client,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let discriminator: [u8; 8] = data[..8].try_into()?;
+    let params = &data[8..];
+
+    match discriminator {
+        SELL => {
+            let params = Sell::try_from_slice(params).context("failed to deserialize")?;
+
+            auctioneer_ins::process_sell(client, params, accounts, slot).await
+        },
+        BUY => {
+            let params = Buy::try_from_slice(params).context("failed to deserialize")?;
+
+            auctioneer_ins::process_buy(client, params, accounts, slot).await
+        },
+        CANCEL => {
+            let params = Cancel::try_from_slice(params).context("failed to deserialize")?;
+
+            auctioneer_ins::process_cancel(client, params, accounts, slot).await
+        },
+        EXECUTE_SALE => {
+            let params = ExecuteSale::try_from_slice(params).context("failed to deserialize")?;
+
+            auctioneer_ins::process_execute_sale(client, params, accounts, slot).await
+        },
+        DEPOSIT => {
+            let params = Deposit::try_from_slice(params).context("failed to deserialize")?;
+
+            auctioneer_ins::process_deposit(client, params, accounts, slot).await
+        },
+        WITHDRAW => {
+            let params = Withdraw::try_from_slice(params).context("failed to deserialize")?;
+
+            auctioneer_ins::process_withdraw(client, params, accounts, slot).await
+        },
+        _ => Ok(()),
+    }
+}
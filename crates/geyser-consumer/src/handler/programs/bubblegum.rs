@@ -0,0 +1,207 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use indexer::prelude::*;
+use solana_program::keccak;
+
+use super::{instructions::bubblegum, Client};
+
+const MINT_V1: [u8; 8] = [145, 98, 192, 118, 184, 147, 118, 104];
+const MINT_TO_COLLECTION_V1: [u8; 8] = [153, 18, 178, 47, 197, 158, 86, 15];
+const TRANSFER: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+const BURN: [u8; 8] = [116, 110, 29, 56, 107, 219, 42, 93];
+const REDEEM: [u8; 8] = [184, 12, 86, 149, 70, 196, 97, 225];
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum TokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum UseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Uses {
+    pub use_method: UseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum TokenProgramVersion {
+    Original,
+    Token2022,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// Metadata arguments passed to the Bubblegum mint instructions
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandard>,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+    pub token_program_version: TokenProgramVersion,
+    pub creators: Vec<Creator>,
+}
+
+impl MetadataArgs {
+    /// Compute the data hash stored in the leaves of NFTs minted with this
+    /// metadata
+    pub fn hash(&self) -> Result<keccak::Hash> {
+        let args_hash = keccak::hashv(&[&self.try_to_vec()?]);
+
+        Ok(keccak::hashv(&[
+            args_hash.as_ref(),
+            &self.seller_fee_basis_points.to_le_bytes(),
+        ]))
+    }
+}
+
+/// Arguments shared by Bubblegum instructions that modify an existing leaf
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct LeafArgs {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+async fn process_mint(
+    client: &Client,
+    mut data: &[u8],
+    collection_verified: bool,
+    slot: u64,
+) -> Result<()> {
+    let mut args = MetadataArgs::deserialize(&mut data)
+        .context("Failed to deserialize Bubblegum mint instruction")?;
+
+    // mint_to_collection_v1 verifies the collection before hashing the
+    // metadata into the leaf
+    if collection_verified {
+        if let Some(ref mut collection) = args.collection {
+            collection.verified = true;
+        }
+    }
+
+    bubblegum::process_mint(client, args, slot).await
+}
+
+pub(crate) async fn process_instruction(
+    client: &Client,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let discriminator: [u8; 8] = data
+        .get(..8)
+        .context("Invalid Bubblegum instruction")?
+        .try_into()?;
+    let mut params = &data[8..];
+
+    match discriminator {
+        MINT_V1 => process_mint(client, params, false, slot).await,
+        MINT_TO_COLLECTION_V1 => process_mint(client, params, true, slot).await,
+        TRANSFER => {
+            let args = LeafArgs::deserialize(&mut params)
+                .context("Failed to deserialize Bubblegum transfer instruction")?;
+
+            bubblegum::process_transfer(client, args, accounts, slot).await
+        },
+        BURN => {
+            let args = LeafArgs::deserialize(&mut params)
+                .context("Failed to deserialize Bubblegum burn instruction")?;
+
+            bubblegum::process_burn(client, args, accounts, slot).await
+        },
+        REDEEM => {
+            let args = LeafArgs::deserialize(&mut params)
+                .context("Failed to deserialize Bubblegum redeem instruction")?;
+
+            bubblegum::process_redeem(client, args, accounts, slot).await
+        },
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    #[test]
+    fn test_metadata_hash() {
+        let args = MetadataArgs {
+            name: "Compressed #1".into(),
+            symbol: "CNFT".into(),
+            uri: "https://example.com/1.json".into(),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: Some(254),
+            token_standard: Some(TokenStandard::NonFungible),
+            collection: Some(Collection {
+                verified: true,
+                key: key(7),
+            }),
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![
+                Creator {
+                    address: key(8),
+                    verified: true,
+                    share: 60,
+                },
+                Creator {
+                    address: key(9),
+                    verified: false,
+                    share: 40,
+                },
+            ],
+        };
+
+        // keccak(keccak(<borsh-encoded args>) || 500u16 LE), computed
+        // independently from the on-chain layout
+        assert_eq!(
+            args.hash().unwrap().to_string(),
+            "HwzigKPo6BoxVEqKt7gh8K2SioF3sMdWbWj6W4dAkq3j"
+        );
+
+        let unverified = MetadataArgs {
+            collection: Some(Collection {
+                verified: false,
+                key: key(7),
+            }),
+            ..args.clone()
+        };
+
+        assert_ne!(unverified.hash().unwrap(), args.hash().unwrap());
+    }
+}
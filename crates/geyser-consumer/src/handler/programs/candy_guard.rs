@@ -0,0 +1,396 @@
+//! Candy Guard accounts
+//!
+//! Guard sets are stored after the account header as a `u64` bit mask of
+//! enabled guards, followed by each enabled guard's fixed-size data in bit
+//! order.  The default guard set is followed by a `u32` count of named groups,
+//! each stored as a 6-byte label and a guard set.
+//!
+//! Guards newer than the program gate have no known layout here, so parsing
+//! stops at the first guard set enabling one of them.
+
+use borsh::BorshDeserialize;
+use indexer::prelude::*;
+
+use super::{accounts::candy_guard, AccountUpdate, Client};
+
+const CANDY_GUARD: [u8; 8] = [44, 207, 199, 184, 112, 103, 34, 181];
+const HEADER_SIZE: usize = 8 + 32 + 1 + 32;
+const LABEL_SIZE: usize = 6;
+const PROGRAM_GATE_MAX: usize = 5;
+/// Number of guards with a known layout
+const KNOWN_GUARDS: u32 = 19;
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct CandyGuardHeader {
+    pub base: Pubkey,
+    pub bump: u8,
+    pub authority: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct BotTax {
+    pub lamports: u64,
+    pub last_instruction: bool,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct SolPayment {
+    pub lamports: u64,
+    pub destination: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct TokenPayment {
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub destination_ata: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct TokenGate {
+    pub amount: u64,
+    pub mint: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Gatekeeper {
+    pub gatekeeper_network: Pubkey,
+    pub expire_on_use: bool,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct MintLimit {
+    pub id: u8,
+    pub limit: u16,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct NftPayment {
+    pub required_collection: Pubkey,
+    pub destination: Pubkey,
+}
+
+/// The guards enabled in a guard set
+#[derive(Debug, Clone, Default)]
+pub struct GuardSet {
+    pub bot_tax: Option<BotTax>,
+    pub sol_payment: Option<SolPayment>,
+    pub token_payment: Option<TokenPayment>,
+    pub start_date: Option<i64>,
+    pub third_party_signer: Option<Pubkey>,
+    pub token_gate: Option<TokenGate>,
+    pub gatekeeper: Option<Gatekeeper>,
+    pub end_date: Option<i64>,
+    pub allow_list: Option<[u8; 32]>,
+    pub mint_limit: Option<MintLimit>,
+    pub nft_payment: Option<NftPayment>,
+    pub redeemed_amount: Option<u64>,
+    pub address_gate: Option<Pubkey>,
+    pub nft_gate: Option<Pubkey>,
+    pub nft_burn: Option<Pubkey>,
+    pub token_burn: Option<TokenGate>,
+    pub freeze_sol_payment: Option<SolPayment>,
+    pub freeze_token_payment: Option<TokenPayment>,
+    pub program_gate: Option<Vec<Pubkey>>,
+}
+
+/// A named group of guards
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub label: String,
+    pub guards: GuardSet,
+}
+
+/// The guard sets configured on a candy guard
+#[derive(Debug, Clone)]
+pub struct CandyGuardData {
+    pub default: GuardSet,
+    pub groups: Vec<Group>,
+    /// Bit mask of enabled guards with an unknown layout.  If nonzero, only
+    /// the guard sets up to and including the first one enabling such a guard
+    /// were read.
+    pub unsupported_guards: u64,
+}
+
+fn read<T: BorshDeserialize>(data: &mut &[u8], size: usize) -> Result<T> {
+    let bytes = data
+        .get(..size)
+        .context("Candy guard data ended in the middle of a guard")?;
+    let value = T::deserialize(&mut &*bytes)?;
+
+    *data = &data[size..];
+
+    Ok(value)
+}
+
+/// Read a guard set, returning it along with the bit mask of any enabled
+/// guards which could not be read
+#[allow(clippy::too_many_lines)]
+fn read_guard_set(data: &mut &[u8]) -> Result<(GuardSet, u64)> {
+    let features: u64 = read(data, 8)?;
+    let enabled = |bit: u32| features & (1 << bit) != 0;
+    let mut set = GuardSet::default();
+
+    if enabled(0) {
+        set.bot_tax = Some(read(data, 9)?);
+    }
+
+    if enabled(1) {
+        set.sol_payment = Some(read(data, 40)?);
+    }
+
+    if enabled(2) {
+        set.token_payment = Some(read(data, 72)?);
+    }
+
+    if enabled(3) {
+        set.start_date = Some(read(data, 8)?);
+    }
+
+    if enabled(4) {
+        set.third_party_signer = Some(read(data, 32)?);
+    }
+
+    if enabled(5) {
+        set.token_gate = Some(read(data, 40)?);
+    }
+
+    if enabled(6) {
+        set.gatekeeper = Some(read(data, 33)?);
+    }
+
+    if enabled(7) {
+        set.end_date = Some(read(data, 8)?);
+    }
+
+    if enabled(8) {
+        set.allow_list = Some(read(data, 32)?);
+    }
+
+    if enabled(9) {
+        set.mint_limit = Some(read(data, 3)?);
+    }
+
+    if enabled(10) {
+        set.nft_payment = Some(read(data, 64)?);
+    }
+
+    if enabled(11) {
+        set.redeemed_amount = Some(read(data, 8)?);
+    }
+
+    if enabled(12) {
+        set.address_gate = Some(read(data, 32)?);
+    }
+
+    if enabled(13) {
+        set.nft_gate = Some(read(data, 32)?);
+    }
+
+    if enabled(14) {
+        set.nft_burn = Some(read(data, 32)?);
+    }
+
+    if enabled(15) {
+        set.token_burn = Some(read(data, 40)?);
+    }
+
+    if enabled(16) {
+        set.freeze_sol_payment = Some(read(data, 40)?);
+    }
+
+    if enabled(17) {
+        set.freeze_token_payment = Some(read(data, 72)?);
+    }
+
+    if enabled(18) {
+        // The program gate is stored at its maximum size regardless of how
+        // many programs it lists
+        set.program_gate = Some(read(data, 4 + PROGRAM_GATE_MAX * 32)?);
+    }
+
+    Ok((set, features & !((1 << KNOWN_GUARDS) - 1)))
+}
+
+fn read_candy_guard_data(mut data: &[u8]) -> Result<CandyGuardData> {
+    let (default, mut unsupported_guards) = read_guard_set(&mut data)?;
+    let mut groups = vec![];
+
+    // The data following an unsupported guard cannot be located
+    if unsupported_guards == 0 {
+        let count: u32 = read(&mut data, 4)?;
+
+        for _ in 0..count {
+            let label = data
+                .get(..LABEL_SIZE)
+                .context("Candy guard data ended in the middle of a group label")?;
+            let label = std::str::from_utf8(label)
+                .context("Candy guard group label was not valid UTF-8")?
+                .trim_end_matches('\0')
+                .to_owned();
+            data = &data[LABEL_SIZE..];
+
+            let (guards, unsupported) = read_guard_set(&mut data)?;
+            groups.push(Group { label, guards });
+
+            if unsupported != 0 {
+                unsupported_guards = unsupported;
+                break;
+            }
+        }
+    }
+
+    Ok(CandyGuardData {
+        default,
+        groups,
+        unsupported_guards,
+    })
+}
+
+async fn process_candy_guard(client: &Client, update: &AccountUpdate) -> Result<()> {
+    let header = CandyGuardHeader::deserialize(&mut &update.data[8..HEADER_SIZE])
+        .context("Failed to deserialize candy guard")?;
+    let data = read_candy_guard_data(&update.data[HEADER_SIZE..])
+        .context("Failed to deserialize candy guard data")?;
+
+    if data.unsupported_guards != 0 {
+        warn!(
+            "Candy guard {} enables unsupported guards {:#x}, indexing only the first {} guard \
+             set(s)",
+            update.key,
+            data.unsupported_guards,
+            data.groups.len() + 1,
+        );
+    }
+
+    candy_guard::process(
+        client,
+        update.key,
+        header,
+        data,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
+    match update.data.get(..8) {
+        Some(d) if d == CANDY_GUARD && update.data.len() >= HEADER_SIZE => {
+            process_candy_guard(client, update).await
+        },
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    /// Serialize a candy guard account in the layout written by the program
+    fn account(guard_sets: &[(&str, u64, Vec<u8>)]) -> Vec<u8> {
+        let mut data = CANDY_GUARD.to_vec();
+        data.extend(key(1).to_bytes());
+        data.push(255);
+        data.extend(key(2).to_bytes());
+
+        let (default, groups) = guard_sets.split_first().unwrap();
+        data.extend(default.1.to_le_bytes());
+        data.extend(&default.2);
+        data.extend(u32::try_from(groups.len()).unwrap().to_le_bytes());
+
+        for (label, features, guards) in groups {
+            let mut label = label.as_bytes().to_vec();
+            label.resize(LABEL_SIZE, 0);
+
+            data.extend(label);
+            data.extend(features.to_le_bytes());
+            data.extend(guards);
+        }
+
+        data
+    }
+
+    fn sol_payment(lamports: u64, destination: Pubkey) -> Vec<u8> {
+        let mut data = lamports.to_le_bytes().to_vec();
+        data.extend(destination.to_bytes());
+        data
+    }
+
+    #[test]
+    fn test_read_candy_guard_data() {
+        let default_guards = [
+            [10_000_000_u64.to_le_bytes().to_vec(), vec![1]].concat(),
+            sol_payment(1_000_000_000, key(3)),
+            1_678_000_000_i64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let group_guards = [[7_u8; 32].to_vec(), vec![4], 5_u16.to_le_bytes().to_vec()].concat();
+        let data = account(&[
+            ("", 0b1011, default_guards),
+            ("WL", (1 << 8) | (1 << 9), group_guards),
+        ]);
+
+        let header = CandyGuardHeader::deserialize(&mut &data[8..HEADER_SIZE]).unwrap();
+        assert_eq!(header.base, key(1));
+        assert_eq!(header.bump, 255);
+        assert_eq!(header.authority, key(2));
+
+        let CandyGuardData {
+            default,
+            groups,
+            unsupported_guards,
+        } = read_candy_guard_data(&data[HEADER_SIZE..]).unwrap();
+        assert_eq!(unsupported_guards, 0);
+
+        let bot_tax = default.bot_tax.unwrap();
+        assert_eq!(bot_tax.lamports, 10_000_000);
+        assert!(bot_tax.last_instruction);
+        let sol_payment = default.sol_payment.unwrap();
+        assert_eq!(sol_payment.lamports, 1_000_000_000);
+        assert_eq!(sol_payment.destination, key(3));
+        assert_eq!(default.start_date, Some(1_678_000_000));
+        assert!(default.token_payment.is_none());
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].label, "WL");
+        assert_eq!(groups[0].guards.allow_list, Some([7; 32]));
+        let mint_limit = groups[0].guards.mint_limit.as_ref().unwrap();
+        assert_eq!((mint_limit.id, mint_limit.limit), (4, 5));
+    }
+
+    #[test]
+    fn test_unsupported_guards() {
+        let data = account(&[
+            ("", 1 << 3, 1_678_000_000_i64.to_le_bytes().to_vec()),
+            ("OG", (1 << 7) | (1 << 19), [0_u8; 13].to_vec()),
+            ("WL", 1 << 3, 0_i64.to_le_bytes().to_vec()),
+        ]);
+
+        let data = read_candy_guard_data(&data[HEADER_SIZE..]).unwrap();
+        assert_eq!(data.unsupported_guards, 1 << 19);
+        assert_eq!(data.default.start_date, Some(1_678_000_000));
+        assert_eq!(data.groups.len(), 1);
+        assert_eq!(data.groups[0].label, "OG");
+        assert_eq!(data.groups[0].guards.end_date, Some(0));
+
+        let data = account(&[("", (1 << 3) | (1 << 20), [0_u8; 8].to_vec())]);
+        let data = read_candy_guard_data(&data[HEADER_SIZE..]).unwrap();
+        assert_eq!(data.unsupported_guards, 1 << 20);
+        assert!(data.groups.is_empty());
+    }
+
+    #[test]
+    fn test_truncated() {
+        let mut data = account(&[("", 1 << 1, sol_payment(1, key(3)))]);
+        assert!(read_candy_guard_data(&data[HEADER_SIZE..]).is_ok());
+
+        // Cut off the group count and the last byte of the guard
+        data.truncate(data.len() - 5);
+        assert!(read_candy_guard_data(&data[HEADER_SIZE..]).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+//! Candy Machine Core (v3) accounts
+
+use borsh::BorshDeserialize;
+use indexer::prelude::*;
+
+use super::{accounts::candy_machine_core, AccountUpdate, Client};
+
+const CANDY_MACHINE: [u8; 8] = [51, 173, 177, 113, 25, 241, 109, 189];
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub percentage_share: u8,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct ConfigLineSettings {
+    pub prefix_name: String,
+    pub name_length: u32,
+    pub prefix_uri: String,
+    pub uri_length: u32,
+    pub is_sequential: bool,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct HiddenSettings {
+    pub name: String,
+    pub uri: String,
+    pub hash: [u8; 32],
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct CandyMachineData {
+    pub items_available: u64,
+    pub symbol: String,
+    pub seller_fee_basis_points: u16,
+    pub max_supply: u64,
+    pub is_mutable: bool,
+    pub creators: Vec<Creator>,
+    pub config_line_settings: Option<ConfigLineSettings>,
+    pub hidden_settings: Option<HiddenSettings>,
+}
+
+/// A Candy Machine Core account, excluding the config line data stored after
+/// it
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct CandyMachine {
+    pub features: u64,
+    pub authority: Pubkey,
+    pub mint_authority: Pubkey,
+    pub collection_mint: Pubkey,
+    pub items_redeemed: u64,
+    pub data: CandyMachineData,
+}
+
+async fn process_cm(client: &Client, update: &AccountUpdate) -> Result<()> {
+    let candy_machine = CandyMachine::deserialize(&mut &update.data[8..])
+        .context("Failed to deserialize Candy Machine Core account")?;
+
+    candy_machine_core::process(
+        client,
+        update.key,
+        candy_machine,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
+    match update.data.get(..8) {
+        Some(d) if d == CANDY_MACHINE => process_cm(client, update).await,
+        _ => Ok(()),
+    }
+}
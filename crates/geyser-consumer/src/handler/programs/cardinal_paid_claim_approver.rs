@@ -4,7 +4,7 @@ use indexer::prelude::*;
 
 use super::{accounts::cardinal_paid_claim_approver, AccountUpdate, Client};
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let account_discriminator = &update.data[..8];
 
     if account_discriminator == PaidClaimApprover::discriminator() {
@@ -12,7 +12,14 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
             PaidClaimApprover::try_deserialize(&mut update.data.as_slice())
                 .context("Failed to deserialize claim_approver")?;
 
-        cardinal_paid_claim_approver::process(client, update.key, claim_approver).await?;
+        cardinal_paid_claim_approver::process(
+            client,
+            update.key,
+            claim_approver,
+            update.slot,
+            update.write_version,
+        )
+        .await?;
     }
 
     Ok(())
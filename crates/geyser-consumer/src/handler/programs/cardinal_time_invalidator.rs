@@ -4,7 +4,7 @@ use indexer::prelude::*;
 
 use super::{accounts::cardinal_time_invalidator, AccountUpdate, Client};
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let account_discriminator = &update.data[..8];
 
     if account_discriminator == TimeInvalidator::discriminator() {
@@ -12,7 +12,14 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
             TimeInvalidator::try_deserialize(&mut update.data.as_slice())
                 .context("Failed to deserialize time_invalidator")?;
 
-        cardinal_time_invalidator::process(client, update.key, time_invalidator).await?;
+        cardinal_time_invalidator::process(
+            client,
+            update.key,
+            time_invalidator,
+            update.slot,
+            update.write_version,
+        )
+        .await?;
     }
 
     Ok(())
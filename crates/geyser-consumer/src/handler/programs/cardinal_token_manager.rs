@@ -4,7 +4,7 @@ use indexer::prelude::*;
 
 use super::{accounts::cardinal_token_manager, AccountUpdate, Client};
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let account_discriminator = &update.data[..8];
 
     if account_discriminator == TokenManager::discriminator() {
@@ -12,7 +12,14 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
             TokenManager::try_deserialize(&mut update.data.as_slice())
                 .context("Failed to deserialize token_manager")?;
 
-        cardinal_token_manager::process(client, update.key, token_manager).await?;
+        cardinal_token_manager::process(
+            client,
+            update.key,
+            token_manager,
+            update.slot,
+            update.write_version,
+        )
+        .await?;
     }
 
     Ok(())
@@ -4,7 +4,7 @@ use indexer::prelude::*;
 
 use super::{accounts::cardinal_use_invalidator, AccountUpdate, Client};
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let account_discriminator = &update.data[..8];
 
     if account_discriminator == UseInvalidator::discriminator() {
@@ -12,7 +12,14 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
             UseInvalidator::try_deserialize(&mut update.data.as_slice())
                 .context("Failed to deserialize use_invalidator")?;
 
-        cardinal_use_invalidator::process(client, update.key, use_invalidator).await?;
+        cardinal_use_invalidator::process(
+            client,
+            update.key,
+            use_invalidator,
+            update.slot,
+            update.write_version,
+        )
+        .await?;
     }
 
     Ok(())
@@ -4,7 +4,7 @@ use indexer::prelude::*;
 
 use super::{accounts::geno_habitat_data, AccountUpdate, Client};
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let discrim = &update.data[..8];
 
     if discrim == HabitatData::discriminator() {
@@ -4,7 +4,7 @@ use indexer::prelude::*;
 
 use super::{accounts::graph, AccountUpdate, Client};
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let connection: ConnectionV2 = ConnectionV2::try_deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize graph program Connection data")?;
     graph::process(
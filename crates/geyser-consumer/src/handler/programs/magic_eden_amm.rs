@@ -0,0 +1,55 @@
+//! Magic Eden AMM pools
+//!
+//! Pool accounts hold both sides of an AMM market: the assets listed by the
+//! pool and the escrowed payment backing its bids, which deposit, withdraw
+//! and fulfill instructions update in place.
+
+use borsh::BorshDeserialize;
+use indexer::prelude::*;
+
+use super::{accounts::me_amm_pool, AccountUpdate, Client};
+
+const POOL: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
+
+/// The leading fields of a pool account.  Later program versions append
+/// fields after these, which are not indexed.
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct Pool {
+    pub spot_price: u64,
+    pub curve_type: u8,
+    pub curve_delta: u64,
+    pub reinvest_fulfill_buy: bool,
+    pub reinvest_fulfill_sell: bool,
+    pub expiry: i64,
+    pub lp_fee_bp: u16,
+    pub referral: Pubkey,
+    pub referral_bp: u16,
+    pub buyside_creator_royalty_bp: u16,
+    pub cosigner_annotation: [u8; 32],
+    pub sellside_asset_amount: u64,
+    pub lp_fee_earned: u64,
+    pub owner: Pubkey,
+    pub cosigner: Pubkey,
+    pub uuid: Pubkey,
+    pub payment_mint: Pubkey,
+    pub allowlists: [Allowlist; 6],
+    pub buyside_payment_amount: u64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+pub struct Allowlist {
+    pub kind: u8,
+    pub value: Pubkey,
+}
+
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
+    match update.data.get(..8) {
+        Some(d) if d == POOL => {
+            let pool = Pool::deserialize(&mut &update.data[8..])
+                .context("Failed to deserialize Magic Eden AMM pool")?;
+
+            me_amm_pool::process(client, update.key, pool, update.slot, update.write_version).await
+        },
+        _ => Ok(()),
+    }
+}
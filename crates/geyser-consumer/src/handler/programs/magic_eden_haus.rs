@@ -3,23 +3,20 @@ use indexer::prelude::*;
 use indexer_core::{
     db::{
         custom_types::ActivityTypeEnum,
-        models::{Listing, Offer, Purchase},
+        insert_into,
+        models::{EscrowPayment, Listing, Offer, Purchase},
         mutations::activity,
-        tables::{listings, offers, purchases},
-        update,
+        tables::{escrow_payments, listings, offers, purchases},
+        update, PooledConnection,
     },
-    pubkeys,
-    util::{self, unix_timestamp},
+    pubkeys, util,
     uuid::Uuid,
 };
-use solana_client::{
-    client_error::{ClientError, ClientErrorKind},
-    rpc_request::RpcError,
-};
 
 use super::{
     instructions::{
-        buy::upsert_into_offers_table, execute_sale::upsert_into_purchases_table,
+        buy::upsert_into_offers_table,
+        execute_sale::{upsert_into_purchases_table, write_purchase},
         sell::upsert_into_listings_table,
     },
     Client,
@@ -34,6 +31,9 @@ const CANCEL_BUY: [u8; 8] = [238, 76, 36, 218, 132, 177, 224, 233];
 const MIP1_EXECUTE_SALEV2: [u8; 8] = [236, 163, 204, 173, 71, 144, 235, 118];
 const MIP1_SELL: [u8; 8] = [58, 50, 172, 111, 166, 151, 22, 94];
 const MIP1_CANCEL_SELL: [u8; 8] = [74, 190, 185, 225, 88, 105, 209, 156];
+const BUY_V2: [u8; 8] = [184, 23, 238, 97, 103, 197, 211, 61];
+const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
 
 #[derive(BorshDeserialize, Debug, Clone, Default)]
 struct MEInstructionData {
@@ -55,8 +55,33 @@ struct MIP1ExecuteSaleV2 {
     buyer_price: u64,
 }
 
+#[derive(BorshDeserialize, Debug, Clone)]
+struct BuyV2 {
+    buyer_price: u64,
+    token_size: u64,
+    buyer_state_expiry: i64,
+    _buyer_creator_royalty_bp: u16,
+    _extra_args: Vec<u8>,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+struct EscrowTransfer {
+    _escrow_payment_bump: u8,
+    amount: u64,
+}
+
+/// Account and instruction data identifying a single escrow balance change
+struct EscrowChange<'a> {
+    signature: &'a str,
+    escrow_payment_account: Pubkey,
+    wallet: Pubkey,
+    auction_house: Pubkey,
+    amount: i64,
+}
+
 async fn process_execute_sale(
     client: &Client,
+    signature: &str,
     mut data: &[u8],
     accounts: &[Pubkey],
     slot: u64,
@@ -67,26 +92,42 @@ async fn process_execute_sale(
 
     let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
 
-    upsert_into_purchases_table(
-        client,
-        Purchase {
-            id: None,
-            buyer: Owned(accts[0].clone()),
-            seller: Owned(accts[1].clone()),
-            auction_house: Owned(accts[9].clone()),
-            marketplace_program: Owned(pubkeys::ME_HAUS.to_string()),
-            metadata: Owned(accts[5].clone()),
-            token_size: params.token_size.try_into()?,
-            price: params.buyer_price.try_into()?,
-            created_at: timestamp,
-            slot: slot.try_into()?,
-            write_version: None,
+    // The sale price is paid out of the buyer's escrow
+    let escrow_payment = escrow_payment_row(
+        EscrowChange {
+            signature,
+            escrow_payment_account: accounts[6],
+            wallet: accounts[0],
+            auction_house: accounts[9],
+            amount: -i64::try_from(params.buyer_price)?,
         },
-        accts[11].clone(),
-        accts[13].clone(),
-    )
-    .await
-    .context("failed to insert listing!")?;
+        slot,
+        timestamp,
+    )?;
+
+    let purchase = Purchase {
+        id: None,
+        buyer: Owned(accts[0].clone()),
+        seller: Owned(accts[1].clone()),
+        auction_house: Owned(accts[9].clone()),
+        marketplace_program: Owned(pubkeys::ME_HAUS.to_string()),
+        metadata: Owned(accts[5].clone()),
+        token_size: params.token_size.try_into()?,
+        price: params.buyer_price.try_into()?,
+        created_at: timestamp,
+        slot: slot.try_into()?,
+        write_version: None,
+    };
+    let buyer_trade_state = accts[11].clone();
+    let seller_trade_state = accts[13].clone();
+
+    client
+        .transaction(move |db| {
+            write_escrow_payment(db, &escrow_payment)?;
+            write_purchase(db, &purchase, &buyer_trade_state, &seller_trade_state)
+        })
+        .await
+        .context("failed to insert ME sale!")?;
 
     Ok(())
 }
@@ -280,6 +321,46 @@ async fn process_buy(
         return Ok(());
     }
 
+    upsert_offer(client, params, accounts, slot, timestamp).await
+}
+
+async fn process_buy_v2(
+    client: &Client,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+    timestamp: NaiveDateTime,
+) -> Result<()> {
+    let params =
+        BuyV2::try_from_slice(data).context("failed to deserialize ME BuyV2 instruction")?;
+
+    if accounts.len() != 12 {
+        debug!("invalid accounts for BuyV2Instruction");
+        return Ok(());
+    }
+
+    upsert_offer(
+        client,
+        MEInstructionData {
+            buyer_price: params.buyer_price,
+            token_size: params.token_size,
+            expiry: params.buyer_state_expiry,
+            ..Default::default()
+        },
+        accounts,
+        slot,
+        timestamp,
+    )
+    .await
+}
+
+async fn upsert_offer(
+    client: &Client,
+    params: MEInstructionData,
+    accounts: &[Pubkey],
+    slot: u64,
+    timestamp: NaiveDateTime,
+) -> Result<()> {
     let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
     let buyer = accts[0].clone();
     let auction_house = accts[6].clone();
@@ -452,22 +533,110 @@ async fn process_cancel_buy(
     Ok(())
 }
 
+async fn process_escrow_transfer(
+    client: &Client,
+    signature: &str,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+    timestamp: NaiveDateTime,
+    withdraw: bool,
+) -> Result<()> {
+    let params = EscrowTransfer::try_from_slice(data)
+        .context("failed to deserialize ME escrow transfer instruction")?;
+
+    if accounts.len() != 6 {
+        debug!("invalid accounts for ME escrow transfer instruction");
+        return Ok(());
+    }
+
+    let amount = i64::try_from(params.amount)?;
+
+    insert_escrow_payment(
+        client,
+        EscrowChange {
+            signature,
+            escrow_payment_account: accounts[2],
+            wallet: accounts[0],
+            auction_house: accounts[4],
+            amount: if withdraw { -amount } else { amount },
+        },
+        slot,
+        timestamp,
+    )
+    .await
+}
+
+fn escrow_payment_row(
+    change: EscrowChange<'_>,
+    slot: u64,
+    timestamp: NaiveDateTime,
+) -> Result<EscrowPayment<'static>> {
+    Ok(EscrowPayment {
+        txn_signature: Owned(change.signature.to_owned()),
+        escrow_payment_account: Owned(change.escrow_payment_account.to_string()),
+        amount: change.amount,
+        wallet: Owned(change.wallet.to_string()),
+        auction_house: Owned(change.auction_house.to_string()),
+        marketplace_program: Owned(pubkeys::ME_HAUS.to_string()),
+        created_at: timestamp,
+        slot: slot.try_into()?,
+    })
+}
+
+fn write_escrow_payment(db: &PooledConnection, row: &EscrowPayment) -> Result<()> {
+    insert_into(escrow_payments::table)
+        .values(row)
+        .on_conflict_do_nothing()
+        .execute(db)
+        .context("failed to insert ME escrow payment")?;
+
+    Ok(())
+}
+
+async fn insert_escrow_payment(
+    client: &Client,
+    change: EscrowChange<'_>,
+    slot: u64,
+    timestamp: NaiveDateTime,
+) -> Result<()> {
+    let row = escrow_payment_row(change, slot, timestamp)?;
+
+    client
+        .transaction(move |db| write_escrow_payment(db, &row))
+        .await
+}
+
 pub(crate) async fn process_instruction(
     client: &Client,
+    tx_signature: Vec<u8>,
     data: &[u8],
     accounts: &[Pubkey],
     slot: u64,
 ) -> Result<()> {
     let (discriminator, params) = data.split_at(8);
     let discriminator = <[u8; 8]>::try_from(discriminator)?;
+    let signature = solana_sdk::signature::Signature::new(&tx_signature).to_string();
 
-    let block_time = get_block_time(client, slot)?;
+    let block_time = client.block_time(slot).await?;
 
     match discriminator {
         BUY => process_buy(client, params, accounts, slot, block_time).await,
+        BUY_V2 => process_buy_v2(client, params, accounts, slot, block_time).await,
         SELL => process_sale(client, params, accounts, slot, block_time).await,
-        EXECUTE_SALE => process_execute_sale(client, params, accounts, slot, block_time).await,
-        EXECUTE_SALEV2 => process_execute_sale(client, params, accounts, slot, block_time).await,
+        EXECUTE_SALE | EXECUTE_SALEV2 => {
+            process_execute_sale(client, &signature, params, accounts, slot, block_time).await
+        },
+        DEPOSIT => {
+            process_escrow_transfer(
+                client, &signature, params, accounts, slot, block_time, false,
+            )
+            .await
+        },
+        WITHDRAW => {
+            process_escrow_transfer(client, &signature, params, accounts, slot, block_time, true)
+                .await
+        },
         CANCEL_SELL => process_cancel_sale(client, accounts, slot, block_time).await,
         CANCEL_BUY => process_cancel_buy(client, accounts, slot, block_time).await,
         MIP1_SELL => process_mip_sell(client, params, accounts, slot, block_time).await,
@@ -478,20 +647,3 @@ pub(crate) async fn process_instruction(
         _ => Ok(()),
     }
 }
-
-pub(crate) fn get_block_time(client: &Client, slot: u64) -> Result<NaiveDateTime> {
-    match client.rpc().get_block_time(slot) {
-        Ok(bt) => unix_timestamp(bt),
-        // Catch slot-not-found errors, likely due to race condition between
-        // Geyser and RPC
-        Err(ClientError {
-            request: _,
-            kind:
-                ClientErrorKind::RpcError(RpcError::RpcResponseError {
-                    code: -32009 | -32004,
-                    ..
-                }),
-        }) => Ok(Utc::now().naive_utc()),
-        Err(e) => Err(e).context("Error getting block time"),
-    }
-}
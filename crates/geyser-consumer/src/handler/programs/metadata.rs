@@ -47,15 +47,16 @@ pub enum TokenStandard {
     ProgrammableNonFungible,
 }
 
-async fn process_metadata(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_metadata(client: &Client, update: &AccountUpdate) -> Result<()> {
     let buf = &mut update.data.as_slice();
-    let (metadata, programmable_config, token_standard) =
+    let (metadata, collection_details, programmable_config, token_standard) =
         metadata_deser(buf).context("failed to deserialize metadata")?;
 
     metadata::process(
         client,
         update.key,
         metadata,
+        collection_details,
         programmable_config,
         token_standard,
         update.slot,
@@ -64,14 +65,21 @@ async fn process_metadata(client: &Client, update: AccountUpdate) -> Result<()>
     .await
 }
 
-async fn process_edition(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_edition(client: &Client, update: &AccountUpdate) -> Result<()> {
     let edition: Edition = try_from_slice_checked(&update.data, Key::EditionV1, MAX_EDITION_LEN)
         .context("Failed to parse edition data")?;
 
-    edition::process(client, update.key, edition, update.slot).await
+    edition::process(
+        client,
+        update.key,
+        edition,
+        update.slot,
+        update.write_version,
+    )
+    .await
 }
 
-async fn process_master_edition_v1(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_master_edition_v1(client: &Client, update: &AccountUpdate) -> Result<()> {
     let MasterEditionV1 {
         key,
         supply,
@@ -86,18 +94,32 @@ async fn process_master_edition_v1(client: &Client, update: AccountUpdate) -> Re
         max_supply,
     };
 
-    edition::process_master(client, update.key, master_edition, update.slot).await
+    edition::process_master(
+        client,
+        update.key,
+        master_edition,
+        update.slot,
+        update.write_version,
+    )
+    .await
 }
 
-async fn process_master_edition_v2(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_master_edition_v2(client: &Client, update: &AccountUpdate) -> Result<()> {
     let master_edition: MasterEditionV2 =
         try_from_slice_checked(&update.data, Key::MasterEditionV2, MAX_MASTER_EDITION_LEN)
             .context("Failed to parse master edition v2 data")?;
 
-    edition::process_master(client, update.key, master_edition, update.slot).await
+    edition::process_master(
+        client,
+        update.key,
+        master_edition,
+        update.slot,
+        update.write_version,
+    )
+    .await
 }
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let first_byte = update.data.first().copied();
 
     match first_byte {
@@ -117,7 +139,12 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
 /// https://docs.rs/mpl-token-metadata/1.8.3/src/mpl_token_metadata/utils/metadata.rs.html#192
 fn metadata_deser(
     buf: &mut &[u8],
-) -> Result<(Metadata, Option<ProgrammableConfig>, Option<TokenStandard>)> {
+) -> Result<(
+    Metadata,
+    Option<CollectionDetails>,
+    Option<ProgrammableConfig>,
+    Option<TokenStandard>,
+)> {
     let key: Key = BorshDeserialize::deserialize(buf)?;
     let update_authority: Pubkey = BorshDeserialize::deserialize(buf)?;
     let mint: Pubkey = BorshDeserialize::deserialize(buf)?;
@@ -133,7 +160,7 @@ fn metadata_deser(
     let uses_res: Result<Option<Uses>> = BorshDeserialize::deserialize(buf).map_err(Into::into);
 
     // V1.3
-    let _collection_details_res: Result<Option<CollectionDetails>> =
+    let collection_details_res: Result<Option<CollectionDetails>> =
         BorshDeserialize::deserialize(buf).map_err(Into::into);
 
     // pNFT - Programmable Config
@@ -147,6 +174,8 @@ fn metadata_deser(
         _ => (None, None, None),
     };
 
+    let collection_details = collection_details_res.unwrap_or(None);
+
     // Programmable Config
     let programmable_config = programmable_config_res.unwrap_or(None);
 
@@ -163,6 +192,7 @@ fn metadata_deser(
             collection,
             uses,
         },
+        collection_details,
         programmable_config,
         token_standard,
     ))
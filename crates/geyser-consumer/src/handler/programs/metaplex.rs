@@ -32,16 +32,16 @@ const STORE: u8 = Key::StoreV1 as u8;
 const WHITELISTED_CREATOR: u8 = Key::WhitelistedCreatorV1 as u8;
 const AUCTION_CACHE: u8 = Key::AuctionCacheV1 as u8;
 
-async fn process_store(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_store(client: &Client, update: &AccountUpdate) -> Result<()> {
     assert_eq!(MPL_MAX_STORE_SIZE, MAX_STORE_SIZE);
 
     let store: Store = try_from_slice_checked(&update.data, Key::StoreV1, MAX_STORE_SIZE)
         .context("Failed to parse store data")?;
 
-    mpl_store::process(client, update.key, store).await
+    mpl_store::process(client, update.key, store, update.slot, update.write_version).await
 }
 
-async fn process_whitelisted_creator(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_whitelisted_creator(client: &Client, update: &AccountUpdate) -> Result<()> {
     assert_eq!(
         MPL_MAX_WHITELISTED_CREATOR_SIZE,
         MAX_WHITELISTED_CREATOR_SIZE
@@ -54,10 +54,17 @@ async fn process_whitelisted_creator(client: &Client, update: AccountUpdate) ->
     )
     .context("Failed to parse whitelisted creator data")?;
 
-    mpl_store::process_whitelisted_creator(client, update.key, creator).await
+    mpl_store::process_whitelisted_creator(
+        client,
+        update.key,
+        creator,
+        update.slot,
+        update.write_version,
+    )
+    .await
 }
 
-async fn process_store_config(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_store_config(client: &Client, update: &AccountUpdate) -> Result<()> {
     let config: MplStoreConfig = mpl_try_from_slice_checked(
         &update.data,
         MplKey::StoreConfigV1,
@@ -65,18 +72,25 @@ async fn process_store_config(client: &Client, update: AccountUpdate) -> Result<
     )
     .context("Failed to parse store config data")?;
 
-    mpl_store::process_config(client, update.key, config).await
+    mpl_store::process_config(
+        client,
+        update.key,
+        config,
+        update.slot,
+        update.write_version,
+    )
+    .await
 }
 
-async fn process_auction_cache(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_auction_cache(client: &Client, update: &AccountUpdate) -> Result<()> {
     let cache: AuctionCache =
         try_from_slice_checked(&update.data, Key::AuctionCacheV1, MAX_AUCTION_CACHE_SIZE)
             .context("Failed to parse auction cache data")?;
 
-    auction_cache::process(client, update.key, cache).await
+    auction_cache::process(client, update.key, cache, update.slot, update.write_version).await
 }
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let first_byte = update.data[0];
 
     assert_eq!(MPL_STORE, STORE);
@@ -1,6 +1,11 @@
+pub mod anchor_idl;
 pub mod auction;
 pub mod auction_house;
+pub mod auctioneer;
+pub mod bubblegum;
+pub mod candy_guard;
 pub mod candy_machine;
+pub mod candy_machine_core;
 pub mod cardinal_paid_claim_approver;
 pub mod cardinal_time_invalidator;
 pub mod cardinal_token_manager;
@@ -8,6 +13,7 @@ pub mod cardinal_use_invalidator;
 pub mod genopets;
 pub mod goki_smart_wallet;
 pub mod graph;
+pub mod magic_eden_amm;
 pub mod magic_eden_haus;
 pub mod metadata;
 pub mod metaplex;
@@ -15,7 +21,9 @@ pub mod name_service;
 pub mod namespaces;
 pub mod reward_center;
 pub mod spl_governance;
+pub mod spl_noop;
 pub mod token;
+pub mod token_2022;
 pub mod token_bonding;
 pub mod tribeca_govern;
 pub mod tribeca_locked_voter;
@@ -18,7 +18,7 @@ struct Header {
     class: [u8; 32],
 }
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     if update.data.len() <= HEADER_LENGTH {
         return Ok(());
     }
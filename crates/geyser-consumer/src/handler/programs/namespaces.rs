@@ -4,7 +4,7 @@ use namespaces::state::{Entry, Namespace, ENTRY_SIZE, NAMESPACE_SIZE};
 
 use super::{accounts::namespace, AccountUpdate, Client};
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     match update.data.len() {
         ENTRY_SIZE => {
             let entry: Entry = Entry::try_deserialize(&mut update.data.as_slice())
@@ -5,7 +5,6 @@ use indexer::prelude::*;
 use super::{
     accounts::hpl_reward_center as reward_center,
     instructions::hpl_reward_center::{accept_offer, buy_listing, close_listing, close_offer},
-    magic_eden_haus::get_block_time,
     AccountUpdate, Client,
 };
 
@@ -14,7 +13,7 @@ const BUY_LISTING: [u8; 8] = [115, 149, 42, 108, 44, 49, 140, 153];
 const CLOSE_OFFER: [u8; 8] = [191, 72, 67, 35, 239, 209, 97, 132];
 const CLOSE_LISTING: [u8; 8] = [33, 15, 192, 81, 78, 175, 159, 97];
 
-async fn process_reward_center(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_reward_center(client: &Client, update: &AccountUpdate) -> Result<()> {
     let reward_center: RewardCenter = RewardCenter::try_deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize reward center data")?;
 
@@ -30,7 +29,7 @@ async fn process_reward_center(client: &Client, update: AccountUpdate) -> Result
 
 async fn process_listing(
     client: &Client,
-    update: AccountUpdate,
+    update: &AccountUpdate,
     timestamp: NaiveDateTime,
 ) -> Result<()> {
     let listing: Listing = Listing::try_deserialize(&mut update.data.as_slice())
@@ -49,7 +48,7 @@ async fn process_listing(
 
 async fn process_offer(
     client: &Client,
-    update: AccountUpdate,
+    update: &AccountUpdate,
     timestamp: NaiveDateTime,
 ) -> Result<()> {
     let offer: Offer = Offer::try_deserialize(&mut update.data.as_slice())
@@ -77,7 +76,7 @@ pub(crate) async fn process_instruction(
     let params = data[8..].to_vec();
     let signature = solana_sdk::signature::Signature::new(&tx_signature).to_string();
 
-    let timestamp = get_block_time(client, slot)?;
+    let timestamp = client.block_time(slot).await?;
 
     match discriminator {
         ACCEPT_OFFER => {
@@ -92,10 +91,10 @@ pub(crate) async fn process_instruction(
     }
 }
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let discrim: [u8; 8] = update.data[..8].try_into()?;
 
-    let timestamp = get_block_time(client, update.slot)?;
+    let timestamp = client.block_time(update.slot).await?;
 
     match discrim {
         d if d == RewardCenter::discriminator() => process_reward_center(client, update).await,
@@ -34,7 +34,7 @@ const TOKEN_OWNER_RECORD_V2: u8 = GovernanceAccountType::TokenOwnerRecordV2 as u
 const VOTE_RECORD_V1: u8 = GovernanceAccountType::VoteRecordV1 as u8;
 const VOTE_RECORD_V2: u8 = GovernanceAccountType::VoteRecordV2 as u8;
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     let discrimintator = update.data[0];
     debug!("{:?}", update.owner);
     match discrimintator {
@@ -63,7 +63,7 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
     }
 }
 
-async fn process_governance_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_governance_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: GovernanceV1 = GovernanceV1::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize spl governance  account ")?;
 
@@ -78,7 +78,7 @@ async fn process_governance_account(client: &Client, update: AccountUpdate) -> R
     .await
 }
 
-async fn process_realm_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_realm_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: RealmV1 = RealmV1::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize spl realm account ")?;
 
@@ -93,7 +93,7 @@ async fn process_realm_account(client: &Client, update: AccountUpdate) -> Result
     .await
 }
 
-async fn process_vote_recordv1_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_vote_recordv1_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: VoteRecordV1 = VoteRecordV1::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize vote record v1 account ")?;
 
@@ -108,7 +108,7 @@ async fn process_vote_recordv1_account(client: &Client, update: AccountUpdate) -
     .await
 }
 
-async fn process_vote_recordv2_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_vote_recordv2_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: VoteRecordV2 = VoteRecordV2::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize vote record v2 ")?;
 
@@ -123,7 +123,7 @@ async fn process_vote_recordv2_account(client: &Client, update: AccountUpdate) -
     .await
 }
 
-async fn process_token_owner_record_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_token_owner_record_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: TokenOwnerRecordV1 = TokenOwnerRecordV1::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize token owner record account ")?;
 
@@ -138,7 +138,7 @@ async fn process_token_owner_record_account(client: &Client, update: AccountUpda
     .await
 }
 
-async fn process_proposalv1_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_proposalv1_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: ProposalV1 = ProposalV1::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize proposal v1 account")?;
 
@@ -153,7 +153,7 @@ async fn process_proposalv1_account(client: &Client, update: AccountUpdate) -> R
     .await
 }
 
-async fn process_proposalv2_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_proposalv2_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: ProposalV2 = ProposalV2::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize proposal v2 account")?;
 
@@ -168,7 +168,7 @@ async fn process_proposalv2_account(client: &Client, update: AccountUpdate) -> R
     .await
 }
 
-async fn process_signatory_record_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_signatory_record_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: SignatoryRecordV1 = SignatoryRecordV1::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize signatory record v2 account ")?;
 
@@ -183,7 +183,7 @@ async fn process_signatory_record_account(client: &Client, update: AccountUpdate
     .await
 }
 
-async fn process_realm_config_account(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_realm_config_account(client: &Client, update: &AccountUpdate) -> Result<()> {
     let acc: RealmConfigAccount = RealmConfigAccount::deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize realm config account ")?;
 
@@ -200,7 +200,7 @@ async fn process_realm_config_account(client: &Client, update: AccountUpdate) ->
 
 async fn process_proposal_transaction_account(
     client: &Client,
-    update: AccountUpdate,
+    update: &AccountUpdate,
 ) -> Result<()> {
     let acc: ProposalTransactionV2 =
         ProposalTransactionV2::deserialize(&mut update.data.as_slice())
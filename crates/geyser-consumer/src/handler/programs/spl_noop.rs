@@ -0,0 +1,143 @@
+//! Events logged through the SPL no-op program by account compression and
+//! Bubblegum
+
+use borsh::BorshDeserialize;
+use indexer::prelude::*;
+use solana_program::keccak;
+
+use super::{instructions::bubblegum, Client};
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct PathNode {
+    pub node: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct ChangeLogEventV1 {
+    pub id: Pubkey,
+    pub path: Vec<PathNode>,
+    pub seq: u64,
+    pub index: u32,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub enum ChangeLogEvent {
+    V1(ChangeLogEventV1),
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub enum ApplicationDataEvent {
+    V1 { application_data: Vec<u8> },
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub enum AccountCompressionEvent {
+    ChangeLog(ChangeLogEvent),
+    ApplicationData(ApplicationDataEvent),
+}
+
+#[derive(BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub enum BubblegumEventType {
+    Uninitialized,
+    LeafSchemaEvent,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub enum LeafSchema {
+    V1 {
+        id: Pubkey,
+        owner: Pubkey,
+        delegate: Pubkey,
+        nonce: u64,
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+    },
+}
+
+impl LeafSchema {
+    /// Compute the hash stored in the Merkle tree leaf holding this schema
+    pub fn hash(&self) -> keccak::Hash {
+        let Self::V1 {
+            id,
+            owner,
+            delegate,
+            nonce,
+            data_hash,
+            creator_hash,
+        } = self;
+
+        keccak::hashv(&[
+            &[1],
+            id.as_ref(),
+            owner.as_ref(),
+            delegate.as_ref(),
+            &nonce.to_le_bytes(),
+            data_hash,
+            creator_hash,
+        ])
+    }
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub enum Version {
+    V1,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct LeafSchemaEvent {
+    pub event_type: BubblegumEventType,
+    pub version: Version,
+    pub schema: LeafSchema,
+    pub leaf_hash: [u8; 32],
+}
+
+pub(crate) async fn process_instruction(client: &Client, data: &[u8], slot: u64) -> Result<()> {
+    // The no-op program logs arbitrary data for any program, so anything that
+    // doesn't parse as an account compression event is ignored
+    let Ok(event) = AccountCompressionEvent::try_from_slice(data) else {
+        return Ok(());
+    };
+
+    match event {
+        AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(change_log)) => {
+            bubblegum::process_change_log(client, change_log, slot).await
+        },
+        AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1 { application_data }) => {
+            match LeafSchemaEvent::try_from_slice(&application_data) {
+                Ok(event) if event.event_type == BubblegumEventType::LeafSchemaEvent => {
+                    bubblegum::process_leaf(client, event, slot).await
+                },
+                _ => Ok(()),
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    #[test]
+    fn test_leaf_hash() {
+        let schema = LeafSchema::V1 {
+            id: key(1),
+            owner: key(2),
+            delegate: key(3),
+            nonce: 42,
+            data_hash: [4; 32],
+            creator_hash: [5; 32],
+        };
+
+        // keccak(1u8 || id || owner || delegate || 42u64 LE || data hash ||
+        // creator hash), computed independently from the on-chain layout
+        assert_eq!(
+            schema.hash().to_string(),
+            "Y9wx85rviuK9WaHaPbDodPDowQXpsTe9ZL9dHoa85QX"
+        );
+    }
+}
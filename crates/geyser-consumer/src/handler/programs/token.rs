@@ -5,7 +5,7 @@ use spl_token::state::Account as TokenAccount;
 use super::{accounts::token, instructions::token as token_instruction, AccountUpdate, Client};
 
 const BURN: u8 = 8;
-async fn process_token(client: &Client, update: AccountUpdate) -> Result<()> {
+async fn process_token(client: &Client, update: &AccountUpdate) -> Result<()> {
     let token_account = TokenAccount::unpack_unchecked(&update.data)
         .context("Failed to deserialize token account data!")?;
     token::process(
@@ -18,7 +18,7 @@ async fn process_token(client: &Client, update: AccountUpdate) -> Result<()> {
     .await
 }
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     if update.data.len() != TokenAccount::LEN {
         return Ok(());
     }
@@ -0,0 +1,257 @@
+//! Token-2022 accounts share the SPL token account and mint layouts, which may
+//! be followed by an account type byte and a list of TLV-encoded extensions
+
+use borsh::BorshDeserialize;
+use indexer::prelude::*;
+use solana_program::program_pack::Pack;
+use spl_token::state::{Account as TokenAccount, Mint};
+
+use super::{
+    accounts::{token, token_2022},
+    instructions::token as token_instruction,
+    AccountUpdate, Client,
+};
+
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+const EXTENSION_UNINITIALIZED: u16 = 0;
+const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+const EXTENSION_NON_TRANSFERABLE: u16 = 9;
+const EXTENSION_METADATA_POINTER: u16 = 18;
+
+const BURN: u8 = 8;
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct TransferFee {
+    pub epoch: u64,
+    pub maximum_fee: u64,
+    pub transfer_fee_basis_points: u16,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct TransferFeeConfig {
+    pub transfer_fee_config_authority: Pubkey,
+    pub withdraw_withheld_authority: Pubkey,
+    pub withheld_amount: u64,
+    pub older_transfer_fee: TransferFee,
+    pub newer_transfer_fee: TransferFee,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub struct MetadataPointer {
+    pub authority: Pubkey,
+    pub metadata_address: Pubkey,
+}
+
+/// The mint extensions tracked by the indexer
+#[derive(Debug, Clone, Default)]
+pub struct MintExtensions {
+    pub transfer_fee_config: Option<TransferFeeConfig>,
+    pub non_transferable: bool,
+    pub metadata_pointer: Option<MetadataPointer>,
+}
+
+/// Split the TLV-encoded extension data following the account type byte into
+/// `(extension type, value)` pairs
+fn extensions(mut data: &[u8]) -> Result<Vec<(u16, &[u8])>> {
+    let mut extensions = vec![];
+
+    while data.len() >= 4 {
+        let ty = u16::from_le_bytes([data[0], data[1]]);
+        let len = usize::from(u16::from_le_bytes([data[2], data[3]]));
+
+        // Unused space at the end of an account is zeroed
+        if ty == EXTENSION_UNINITIALIZED {
+            break;
+        }
+
+        let value = data
+            .get(4..4 + len)
+            .context("Token-2022 extension overran the account data")?;
+
+        extensions.push((ty, value));
+        data = &data[4 + len..];
+    }
+
+    Ok(extensions)
+}
+
+fn mint_extensions(data: &[u8]) -> Result<MintExtensions> {
+    let mut mint = MintExtensions::default();
+
+    for (ty, mut value) in extensions(data)? {
+        match ty {
+            EXTENSION_TRANSFER_FEE_CONFIG => {
+                mint.transfer_fee_config = Some(
+                    TransferFeeConfig::deserialize(&mut value)
+                        .context("Failed to deserialize transfer fee config")?,
+                );
+            },
+            EXTENSION_NON_TRANSFERABLE => mint.non_transferable = true,
+            EXTENSION_METADATA_POINTER => {
+                mint.metadata_pointer = Some(
+                    MetadataPointer::deserialize(&mut value)
+                        .context("Failed to deserialize metadata pointer")?,
+                );
+            },
+            _ => (),
+        }
+    }
+
+    Ok(mint)
+}
+
+async fn process_token(client: &Client, update: &AccountUpdate) -> Result<()> {
+    let token_account = TokenAccount::unpack_unchecked(&update.data[..TokenAccount::LEN])
+        .context("Failed to deserialize Token-2022 account data!")?;
+
+    token::process(
+        client,
+        update.key,
+        token_account,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+async fn process_mint(
+    client: &Client,
+    update: &AccountUpdate,
+    extensions: MintExtensions,
+) -> Result<()> {
+    token_2022::process_mint(
+        client,
+        update.key,
+        extensions,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
+    // Mints with extensions are padded to the length of a token account so the
+    // two can be told apart by the account type byte
+    match update.data.len() {
+        Mint::LEN => process_mint(client, update, MintExtensions::default()).await,
+        TokenAccount::LEN => process_token(client, update).await,
+        len if len > TokenAccount::LEN => match update.data[TokenAccount::LEN] {
+            ACCOUNT_TYPE_ACCOUNT => process_token(client, update).await,
+            ACCOUNT_TYPE_MINT => {
+                let extensions = mint_extensions(&update.data[TokenAccount::LEN + 1..])?;
+
+                process_mint(client, update, extensions).await
+            },
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+pub(crate) async fn process_instruction(
+    client: &Client,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let (&discriminator, _) = data
+        .split_first()
+        .context("invalid Token-2022 instruction")?;
+
+    match discriminator {
+        BURN => token_instruction::process_burn_instruction(client, accounts, slot).await,
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    fn tlv(ty: u16, value: &[u8]) -> Vec<u8> {
+        let mut data = ty.to_le_bytes().to_vec();
+        data.extend(u16::try_from(value.len()).unwrap().to_le_bytes());
+        data.extend(value);
+        data
+    }
+
+    fn transfer_fee(epoch: u64, maximum_fee: u64, basis_points: u16) -> Vec<u8> {
+        let mut data = epoch.to_le_bytes().to_vec();
+        data.extend(maximum_fee.to_le_bytes());
+        data.extend(basis_points.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_extensions() {
+        let data = [
+            tlv(EXTENSION_NON_TRANSFERABLE, &[]),
+            tlv(3, &[1, 2, 3]),
+            vec![0; 12],
+        ]
+        .concat();
+
+        assert_eq!(extensions(&data).unwrap(), vec![
+            (EXTENSION_NON_TRANSFERABLE, &[][..]),
+            (3, &[1, 2, 3][..]),
+        ]);
+
+        assert!(extensions(&[]).unwrap().is_empty());
+        assert!(extensions(&[9, 0]).unwrap().is_empty());
+
+        // The value is one byte shorter than its declared length
+        let overrun = tlv(3, &[1, 2, 3]);
+        assert!(extensions(&overrun[..overrun.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_mint_extensions() {
+        let config = [
+            key(1).to_bytes().to_vec(),
+            key(2).to_bytes().to_vec(),
+            1_000_u64.to_le_bytes().to_vec(),
+            transfer_fee(10, 500, 25),
+            transfer_fee(11, 600, 50),
+        ]
+        .concat();
+        let pointer_data = [key(3).to_bytes(), key(4).to_bytes()].concat();
+
+        let data = [
+            tlv(EXTENSION_TRANSFER_FEE_CONFIG, &config),
+            tlv(EXTENSION_NON_TRANSFERABLE, &[]),
+            tlv(EXTENSION_METADATA_POINTER, &pointer_data),
+            tlv(7, &[0; 33]),
+        ]
+        .concat();
+
+        let mint = mint_extensions(&data).unwrap();
+        let fee = mint.transfer_fee_config.unwrap();
+
+        assert_eq!(fee.transfer_fee_config_authority, key(1));
+        assert_eq!(fee.withdraw_withheld_authority, key(2));
+        assert_eq!(fee.withheld_amount, 1_000);
+        assert_eq!(fee.older_transfer_fee.epoch, 10);
+        assert_eq!(fee.newer_transfer_fee.epoch, 11);
+        assert_eq!(fee.newer_transfer_fee.maximum_fee, 600);
+        assert_eq!(fee.newer_transfer_fee.transfer_fee_basis_points, 50);
+        assert!(mint.non_transferable);
+
+        let pointer = mint.metadata_pointer.unwrap();
+        assert_eq!(pointer.authority, key(3));
+        assert_eq!(pointer.metadata_address, key(4));
+
+        let none = mint_extensions(&[]).unwrap();
+        assert!(none.transfer_fee_config.is_none());
+        assert!(!none.non_transferable);
+        assert!(none.metadata_pointer.is_none());
+
+        let truncated = tlv(EXTENSION_METADATA_POINTER, &pointer_data[..40]);
+        assert!(mint_extensions(&truncated).is_err());
+    }
+}
@@ -4,7 +4,7 @@ use spl_token_bonding::state::TokenBondingV0;
 
 use super::{accounts::bonding_change, AccountUpdate, Client};
 
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+pub(crate) async fn process(client: &Client, update: &AccountUpdate) -> Result<()> {
     if let Ok(token_bonding) =
         TokenBondingV0::try_deserialize_unchecked(&mut update.data.as_slice())
     {
@@ -12,6 +12,7 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
             client,
             update.key,
             i64::try_from(update.slot)?,
+            i64::try_from(update.write_version)?,
             token_bonding,
         )
         .await?;
@@ -0,0 +1,246 @@
+//! Lookup of the handler responsible for each program
+
+use std::collections::{HashMap, HashSet};
+
+use indexer::prelude::*;
+use indexer_core::pubkeys;
+use strum::IntoEnumIterator;
+
+/// A program, or group of closely related programs, with a dedicated handler
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumString, strum::Display, strum::EnumIter,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ProgramType {
+    /// The Metaplex token metadata program
+    Metadata,
+    /// The Metaplex candy machine v2, candy machine core and candy guard
+    /// programs
+    CandyMachine,
+    /// The SPL token and token-2022 programs
+    Tokens,
+    /// The Metaplex auction program
+    Auction,
+    /// The Metaplex storefront program
+    Metaplex,
+    /// The Metaplex auction house program
+    AuctionHouse,
+    /// The Metaplex auctioneer program
+    Auctioneer,
+    /// The Holaplex reward center program
+    RewardCenter,
+    /// The Magic Eden v2 marketplace and AMM programs
+    MagicEden,
+    /// The Holaplex graph program
+    Graph,
+    /// The SPL name service program
+    NameService,
+    /// The Cardinal token manager program
+    CardinalTokenManager,
+    /// The Cardinal time invalidator program
+    CardinalTimeInvalidator,
+    /// The Cardinal use invalidator program
+    CardinalUseInvalidator,
+    /// The Cardinal paid claim approver program
+    CardinalPaidClaimApprover,
+    /// The Cardinal namespaces program
+    Namespaces,
+    /// The Goki smart wallet program
+    GokiSmartWallet,
+    /// The Tribeca locked voter program
+    TribecaLockedVoter,
+    /// The Tribeca govern program
+    TribecaGovern,
+    /// The Strata token bonding program
+    TokenBonding,
+    /// All known deployments of the SPL governance program
+    SplGovernance,
+    /// The Genopets habitat program
+    Genopets,
+    /// The Metaplex Bubblegum compressed NFT program
+    Bubblegum,
+    /// The SPL no-op program, used to log compressed NFT changes
+    SplNoop,
+}
+
+impl ProgramType {
+    fn programs(self) -> Vec<Pubkey> {
+        match self {
+            Self::Metadata => vec![pubkeys::METADATA],
+            Self::CandyMachine => vec![
+                pubkeys::CANDY_MACHINE,
+                pubkeys::CANDY_MACHINE_CORE,
+                pubkeys::CANDY_GUARD,
+            ],
+            Self::Tokens => vec![pubkeys::TOKEN, pubkeys::TOKEN_2022],
+            Self::Auction => vec![pubkeys::AUCTION],
+            Self::Metaplex => vec![pubkeys::METAPLEX],
+            Self::AuctionHouse => vec![pubkeys::AUCTION_HOUSE],
+            Self::Auctioneer => vec![pubkeys::AUCTIONEER],
+            Self::RewardCenter => vec![pubkeys::REWARD_CENTER],
+            Self::MagicEden => vec![pubkeys::ME_HAUS, pubkeys::ME_AMM],
+            Self::Graph => vec![pubkeys::GRAPH_PROGRAM],
+            Self::NameService => vec![pubkeys::NAME_SERVICE],
+            Self::CardinalTokenManager => vec![pubkeys::CARDINAL_TOKEN_MANAGER],
+            Self::CardinalTimeInvalidator => vec![pubkeys::CARDINAL_TIME_INVALIDATOR],
+            Self::CardinalUseInvalidator => vec![pubkeys::CARDINAL_USE_INVALIDATOR],
+            Self::CardinalPaidClaimApprover => vec![pubkeys::CARDINAL_PAID_CLAIM_APPROVER],
+            Self::Namespaces => vec![pubkeys::NAMESPACES],
+            Self::GokiSmartWallet => vec![pubkeys::GOKI_SMART_WALLET],
+            Self::TribecaLockedVoter => vec![pubkeys::TRIBECA_LOCKED_VOTER],
+            Self::TribecaGovern => vec![pubkeys::TRIBECA_GOVERN],
+            Self::TokenBonding => vec![pubkeys::TOKEN_BONDING],
+            Self::SplGovernance => pubkeys::SPL_GOVERNANCE.to_vec(),
+            Self::Genopets => vec![genostub::ID],
+            Self::Bubblegum => vec![pubkeys::BUBBLEGUM],
+            Self::SplNoop => vec![pubkeys::SPL_NOOP],
+        }
+    }
+}
+
+/// The outcome of looking up the handler for a message's program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route {
+    /// The message should be processed by the handler for this program type
+    Handle(ProgramType),
+    /// The program has a handler, but it is disabled for this message
+    Skip,
+    /// No handler is registered for the program
+    Unknown,
+}
+
+/// The programs with a dedicated handler, and which of them are enabled
+#[derive(Debug)]
+pub struct ProgramRegistry {
+    programs: HashMap<Pubkey, ProgramType>,
+    enabled: HashSet<ProgramType>,
+    ignore_on_startup: HashSet<ProgramType>,
+}
+
+impl ProgramRegistry {
+    /// Construct a new registry, enabling the given program types (or all of
+    /// them if `None`) except for those disabled
+    #[must_use]
+    pub fn new(
+        enabled: Option<Vec<ProgramType>>,
+        disabled: impl IntoIterator<Item = ProgramType>,
+        ignore_on_startup: impl IntoIterator<Item = ProgramType>,
+    ) -> Self {
+        let programs = ProgramType::iter()
+            .flat_map(|ty| ty.programs().into_iter().map(move |p| (p, ty)))
+            .collect();
+
+        let mut enabled: HashSet<_> = enabled.map_or_else(
+            || ProgramType::iter().collect(),
+            |e| e.into_iter().collect(),
+        );
+
+        for ty in disabled {
+            enabled.remove(&ty);
+        }
+
+        let mut types: Vec<_> = enabled.iter().map(ToString::to_string).collect();
+        types.sort_unstable();
+        info!("Enabled program handlers: {}", types.join(", "));
+
+        Self {
+            programs,
+            enabled,
+            ignore_on_startup: ignore_on_startup.into_iter().collect(),
+        }
+    }
+
+    /// Look up the handler for a message from the given program
+    #[must_use]
+    pub fn route(&self, program: &Pubkey, is_startup: bool) -> Route {
+        match self.programs.get(program) {
+            Some(ty) if !self.enabled.contains(ty) => Route::Skip,
+            Some(ty) if is_startup && self.ignore_on_startup.contains(ty) => Route::Skip,
+            Some(&ty) => Route::Handle(ty),
+            None => Route::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_programs_unique() {
+        let count: usize = ProgramType::iter().map(|ty| ty.programs().len()).sum();
+        let registry = ProgramRegistry::new(None, [], []);
+
+        assert_eq!(registry.programs.len(), count);
+    }
+
+    #[test]
+    fn test_route_all_enabled() {
+        let registry = ProgramRegistry::new(None, [], []);
+
+        assert_eq!(
+            registry.route(&pubkeys::METADATA, false),
+            Route::Handle(ProgramType::Metadata)
+        );
+        assert_eq!(
+            registry.route(&pubkeys::TOKEN_2022, true),
+            Route::Handle(ProgramType::Tokens)
+        );
+        assert_eq!(
+            registry.route(&pubkeys::ME_AMM, false),
+            Route::Handle(ProgramType::MagicEden)
+        );
+        assert_eq!(
+            registry.route(&Pubkey::new_from_array([0xff; 32]), false),
+            Route::Unknown
+        );
+    }
+
+    #[test]
+    fn test_route_enabled() {
+        let registry = ProgramRegistry::new(Some(vec![ProgramType::Tokens]), [], []);
+
+        assert_eq!(
+            registry.route(&pubkeys::TOKEN, false),
+            Route::Handle(ProgramType::Tokens)
+        );
+        assert_eq!(registry.route(&pubkeys::METADATA, false), Route::Skip);
+    }
+
+    #[test]
+    fn test_route_disabled() {
+        let registry = ProgramRegistry::new(
+            Some(vec![ProgramType::Tokens, ProgramType::MagicEden]),
+            [ProgramType::MagicEden, ProgramType::Metadata],
+            [],
+        );
+
+        assert_eq!(
+            registry.route(&pubkeys::TOKEN, false),
+            Route::Handle(ProgramType::Tokens)
+        );
+        assert_eq!(registry.route(&pubkeys::ME_HAUS, false), Route::Skip);
+        assert_eq!(registry.route(&pubkeys::ME_AMM, false), Route::Skip);
+        assert_eq!(registry.route(&pubkeys::METADATA, false), Route::Skip);
+    }
+
+    #[test]
+    fn test_route_ignore_on_startup() {
+        let registry = ProgramRegistry::new(None, [ProgramType::Auction], [
+            ProgramType::Tokens,
+            ProgramType::Auction,
+        ]);
+
+        assert_eq!(registry.route(&pubkeys::TOKEN, true), Route::Skip);
+        assert_eq!(
+            registry.route(&pubkeys::TOKEN, false),
+            Route::Handle(ProgramType::Tokens)
+        );
+        assert_eq!(
+            registry.route(&pubkeys::METADATA, true),
+            Route::Handle(ProgramType::Metadata)
+        );
+        assert_eq!(registry.route(&pubkeys::AUCTION, false), Route::Skip);
+        assert_eq!(registry.route(&pubkeys::AUCTION, true), Route::Skip);
+    }
+}
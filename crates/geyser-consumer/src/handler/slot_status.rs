@@ -1,20 +1,67 @@
 use indexer::prelude::*;
+use indexer_core::db::{custom_types::SlotStatusEnum, mutations::slot as slot_mutations};
 use indexer_rabbitmq::geyser::{SlotStatus, SlotStatusUpdate};
 
 use super::Client;
 
-pub async fn process(client: &Client, slot: SlotStatusUpdate) -> Result<()> {
+pub async fn process(client: &Client, slot: &SlotStatusUpdate) -> Result<()> {
     let SlotStatusUpdate {
         slot,
-        parent: _,
-        status,
-    } = slot;
+        parent,
+        ref status,
+    } = *slot;
+
+    let slot_num: i64 = slot.try_into()?;
+    let parent = parent.map(TryInto::try_into).transpose()?;
+    let status = match status {
+        SlotStatus::Processed => SlotStatusEnum::Processed,
+        SlotStatus::Confirmed => SlotStatusEnum::Confirmed,
+        SlotStatus::Rooted => SlotStatusEnum::Rooted,
+    };
+
+    // Orphaned slots are marked dead and rolled back in one transaction, so
+    // a failed rollback leaves them to be found again by the next root
+    let dead = client
+        .db()
+        .run(move |db| {
+            db.build_transaction().read_write().run(|| {
+                slot_mutations::upsert(db, slot_num, parent, status)?;
+
+                if status != SlotStatusEnum::Rooted {
+                    return Ok(Vec::new());
+                }
+
+                let dead = slot_mutations::root(db, slot_num)?;
+
+                for &dead_slot in &dead {
+                    slot_mutations::rollback(db, dead_slot).with_context(|| {
+                        format!("Failed to roll back orphaned slot {dead_slot}")
+                    })?;
+                }
+
+                Result::<_>::Ok(dead)
+            })
+        })
+        .await
+        .context("Failed to record slot status")?;
+
+    for dead_slot in dead {
+        warn!("Slot {dead_slot} was orphaned by root {slot} and rolled back");
+    }
 
     match status {
-        SlotStatus::Confirmed => client
-            .dispatch_block_reindex(slot)
-            .await
-            .context("Failed to dispatch block reindex request"),
+        SlotStatusEnum::Confirmed => {
+            client
+                .slot_times()
+                .confirm(slot)
+                .await
+                .context("Failed to check slot time")?;
+
+            client
+                .dispatch_block_reindex(slot)
+                .await
+                .context("Failed to dispatch block reindex request")
+        },
         _ => Ok(()),
     }
 }
@@ -0,0 +1,203 @@
+//! Shared lookup of the block times of recent slots
+//!
+//! Block times are not available over RPC until a slot is confirmed, which is
+//! usually later than its accounts and instructions are processed.  Rows
+//! written before then are timestamped with an estimate shared by the whole
+//! slot, and the real block time is fetched in the background once it is
+//! available, correcting any rows written with the estimate.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use indexer::{db::Pool, prelude::*};
+use indexer_core::{
+    db::{models::SlotTime, mutations::slot_time},
+    util::unix_timestamp,
+};
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_client::RpcClient,
+    rpc_request::RpcError,
+};
+
+/// The number of recent slots to remember block times for
+const CACHE_SIZE: usize = 1024;
+/// The number of times to request a block time which is not yet available
+const MAX_ATTEMPTS: u32 = 5;
+/// The delay before retrying a block time which is not yet available,
+/// multiplied by the number of attempts so far
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Slot-to-block-time lookups shared between all handlers
+pub struct SlotTimes {
+    db: Pool,
+    rpc: Arc<RpcClient>,
+    cache: Mutex<BTreeMap<u64, SlotTime>>,
+    pending: Mutex<HashSet<u64>>,
+}
+
+impl std::fmt::Debug for SlotTimes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SlotTimes").finish_non_exhaustive()
+    }
+}
+
+impl SlotTimes {
+    pub(super) fn new(db: Pool, rpc: Arc<RpcClient>) -> Arc<Self> {
+        Arc::new(Self {
+            db,
+            rpc,
+            cache: Mutex::default(),
+            pending: Mutex::default(),
+        })
+    }
+
+    fn cached(&self, slot: u64) -> Option<SlotTime> {
+        self.cache
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&slot)
+            .copied()
+    }
+
+    fn cache(&self, slot: u64, time: SlotTime) {
+        let mut cache = self
+            .cache
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        cache.insert(slot, time);
+
+        while cache.len() > CACHE_SIZE {
+            cache.pop_first();
+        }
+    }
+
+    /// Get the block time of the given slot, or the estimate recorded for it
+    /// if the real block time is not yet known.  Estimates are corrected in
+    /// the background.
+    ///
+    /// # Errors
+    /// This function fails if the slot time cannot be read or recorded.
+    pub async fn get(self: &Arc<Self>, slot: u64) -> Result<NaiveDateTime> {
+        if let Some(time) = self.cached(slot) {
+            return Ok(time.block_time);
+        }
+
+        let slot_num = i64::try_from(slot)?;
+        let time = self
+            .db
+            .run(move |db| match slot_time::get(db, slot_num)? {
+                Some(time) => Ok(time),
+                None => slot_time::estimate(db, slot_num, Utc::now().naive_utc()),
+            })
+            .await
+            .context("Failed to get slot time")?;
+
+        self.cache(slot, time);
+
+        if time.estimated {
+            self.backfill(slot);
+        }
+
+        Ok(time.block_time)
+    }
+
+    /// Fetch the real block time of a newly-confirmed slot if rows were
+    /// written in it with an estimated time
+    ///
+    /// # Errors
+    /// This function fails if the slot time cannot be read.
+    pub async fn confirm(self: &Arc<Self>, slot: u64) -> Result<()> {
+        let estimated = if let Some(time) = self.cached(slot) {
+            time.estimated
+        } else {
+            let slot_num = i64::try_from(slot)?;
+
+            self.db
+                .run(move |db| slot_time::get(db, slot_num))
+                .await
+                .context("Failed to get slot time")?
+                .map_or(false, |t| t.estimated)
+        };
+
+        if estimated {
+            self.backfill(slot);
+        }
+
+        Ok(())
+    }
+
+    fn backfill(self: &Arc<Self>, slot: u64) {
+        if !self
+            .pending
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(slot)
+        {
+            return;
+        }
+
+        let this = Arc::clone(self);
+
+        tokio::spawn(async move {
+            if let Err(e) = this.fetch(slot).await {
+                warn!("Failed to backfill block time for slot {slot}: {e:?}");
+            }
+
+            this.pending
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .remove(&slot);
+        });
+    }
+
+    async fn fetch(&self, slot: u64) -> Result<()> {
+        for attempt in 1..=MAX_ATTEMPTS {
+            let rpc = Arc::clone(&self.rpc);
+            let res = tokio::task::spawn_blocking(move || rpc.get_block_time(slot))
+                .await
+                .context("Blocking task failed")?;
+
+            match res {
+                Ok(block_time) => {
+                    let block_time = unix_timestamp(block_time)?;
+                    let slot_num = i64::try_from(slot)?;
+
+                    self.db
+                        .run(move |db| {
+                            db.build_transaction()
+                                .read_write()
+                                .run(|| slot_time::record(db, slot_num, block_time))
+                        })
+                        .await
+                        .context("Failed to record slot time")?;
+
+                    self.cache(slot, SlotTime {
+                        slot: slot_num,
+                        block_time,
+                        estimated: false,
+                    });
+
+                    return Ok(());
+                },
+                // The block is not available yet, likely due to a race
+                // condition between Geyser and RPC
+                Err(ClientError {
+                    kind:
+                        ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                            code: -32009 | -32004,
+                            ..
+                        }),
+                    ..
+                }) => tokio::time::sleep(RETRY_DELAY * attempt).await,
+                Err(e) => return Err(e).context("Error getting block time"),
+            }
+        }
+
+        bail!("Block time was still unavailable after {MAX_ATTEMPTS} attempts")
+    }
+}
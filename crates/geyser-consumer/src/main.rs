@@ -1,8 +1,8 @@
-use std::{collections::HashSet, sync::Arc};
+use std::sync::Arc;
 
 mod handler;
 
-use handler::{Client, ClientArgs, ClientQueues, IgnoreType};
+use handler::{Client, ClientArgs, ClientQueues, ProgramRegistry, ProgramType};
 use indexer_core::{clap, prelude::*};
 use indexer_rabbitmq::{geyser, http_indexer, job_runner, search_indexer, suffix::Suffix};
 
@@ -23,12 +23,26 @@ struct Args {
     #[arg(long, env, default_value_t = geyser::StartupType::Normal)]
     startup: geyser::StartupType,
 
-    /// List of topics or programs to ignore on startup
+    /// List of programs to ignore on startup
     ///
     /// For example, `metadata,candy-machine` will ignore the Metaplex metadata
     /// and candy machine programs.
     #[arg(long, env, use_value_delimiter(true))]
-    ignore_on_startup: Option<Vec<IgnoreType>>,
+    ignore_on_startup: Option<Vec<ProgramType>>,
+
+    /// List of programs to index, defaulting to all programs with a handler
+    ///
+    /// For example, `auction-house,auctioneer,magic-eden,tokens` will index
+    /// only marketplace activity.
+    #[arg(long, env, use_value_delimiter(true))]
+    enable_programs: Option<Vec<ProgramType>>,
+
+    /// List of programs not to index, applied after `--enable-programs`
+    ///
+    /// For example, `spl-governance,genopets` will skip SPL governance and
+    /// Genopets accounts.
+    #[arg(long, env, use_value_delimiter(true))]
+    disable_programs: Option<Vec<ProgramType>>,
 
     #[command(flatten)]
     queue_suffix: indexer_core::queue_suffix::QueueSuffix,
@@ -44,6 +58,8 @@ fn main() {
              network,
              startup,
              ignore_on_startup,
+             enable_programs,
+             disable_programs,
              queue_suffix,
              client,
          },
@@ -55,7 +71,8 @@ fn main() {
                 _ => network.to_string(),
             };
 
-            let conn = indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
+            let conn = indexer::amqp_connect(&amqp_url, env!("CARGO_BIN_NAME")).await?;
+            let health_conn = indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
             let client = Client::new_rc(
                 db,
                 &conn,
@@ -76,24 +93,32 @@ fn main() {
                 .await
                 .context("Failed to create queue consumer")?;
 
-            let ignore_on_startup = Arc::new(
-                ignore_on_startup
-                    .into_iter()
-                    .flatten()
-                    .collect::<HashSet<_>>(),
+            indexer::metrics::watch_chain_slot(
+                {
+                    let client = client.clone();
+                    move || client.rpc().get_slot().context("Failed to get slot")
+                },
+                StdDuration::from_secs(10),
             );
 
+            let registry = Arc::new(ProgramRegistry::new(
+                enable_programs,
+                disable_programs.into_iter().flatten(),
+                ignore_on_startup.into_iter().flatten(),
+            ));
+
             indexer::amqp_consume(
                 &params,
                 conn,
+                health_conn,
                 consumer,
                 queue_type,
                 StdDuration::from_millis(100),
                 move |m| {
                     let client = client.clone();
-                    let ignore_on_startup = ignore_on_startup.clone();
+                    let registry = registry.clone();
 
-                    async move { handler::process_message(m, &client, ignore_on_startup).await }
+                    async move { handler::process_message(&m, &client, &registry).await }
                 },
             )
             .await
@@ -0,0 +1,679 @@
+//! Optional API key authentication, rate limiting and usage accounting
+//!
+//! Clients may identify themselves with a key sent in the `X-API-Key` header.
+//! Keys are stored hashed in the `api_keys` table and each carries its own
+//! rate limit, while anonymous clients are limited per remote address at a
+//! lower tier.  Rate limits are enforced by token buckets held in memory, so
+//! each server replica enforces them separately.
+//!
+//! Anonymous clients are identified by the address of the connecting peer,
+//! unless the peer is a configured trusted proxy, in which case the nearest
+//! forwarded address not added by a trusted proxy is used instead.
+
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex, PoisonError, RwLock},
+    time::Instant,
+};
+
+use actix_web::{http::header, HttpRequest, HttpResponse};
+use indexer_core::{
+    clap,
+    db::{
+        excluded, insert_into, models,
+        tables::{api_key_usage, api_keys},
+        ConnectionManager, Pool,
+    },
+    prelude::*,
+    uuid::Uuid,
+};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+/// The header clients send their API key in
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// The interval between reloads of the API keys from the database
+const KEY_REFRESH_INTERVAL: StdDuration = StdDuration::from_secs(60);
+/// The interval between writes of accumulated usage to the database
+const USAGE_FLUSH_INTERVAL: StdDuration = StdDuration::from_secs(60);
+/// The time after which the bucket of an idle client is discarded
+const BUCKET_IDLE_TIMEOUT: StdDuration = StdDuration::from_secs(600);
+
+/// Command-line arguments for API keys and rate limits
+#[derive(Debug, Clone, clap::Args)]
+#[group(skip)]
+pub struct Args {
+    /// Sustained number of requests per minute allowed for each anonymous
+    /// client address
+    #[arg(long, env, default_value_t = 60)]
+    anonymous_requests_per_minute: u32,
+
+    /// Maximum number of requests an anonymous client address may make in a
+    /// single burst
+    #[arg(long, env, default_value_t = 20)]
+    anonymous_burst: u32,
+
+    /// Maximum number of anonymous client addresses to track separate rate
+    /// limits for.  Any further clients share a single rate limit until
+    /// idle clients are discarded.
+    #[arg(long, env, default_value_t = 100_000)]
+    max_anonymous_clients: usize,
+
+    /// Addresses of reverse proxies trusted to report the address of the
+    /// client in the `Forwarded` or `X-Forwarded-For` header
+    #[arg(long, env, use_value_delimiter(true))]
+    trusted_proxies: Vec<IpAddr>,
+
+    /// Connection string for a writable database to record API key usage
+    /// in.  Usage is not recorded if this is unset.
+    #[arg(long, env)]
+    api_usage_database_url: Option<String>,
+}
+
+/// A token bucket refill rate and capacity
+#[derive(Debug, Clone, Copy)]
+struct Limit {
+    per_sec: f64,
+    capacity: f64,
+}
+
+impl Limit {
+    fn new(requests_per_minute: u32, burst: u32) -> Self {
+        Self {
+            per_sec: f64::from(requests_per_minute) / 60.0,
+            capacity: f64::from(burst.max(1)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn full(limit: Limit, now: Instant) -> Self {
+        Self {
+            tokens: limit.capacity,
+            updated: now,
+        }
+    }
+
+    /// Take a token from the bucket, or return the time until one is
+    /// available
+    fn take(&mut self, limit: Limit, now: Instant) -> Result<(), StdDuration> {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = elapsed
+            .mul_add(limit.per_sec, self.tokens)
+            .min(limit.capacity);
+        self.updated = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+
+            Ok(())
+        } else if limit.per_sec > 0.0 {
+            Err(StdDuration::from_secs_f64(
+                (1.0 - self.tokens) / limit.per_sec,
+            ))
+        } else {
+            Err(BUCKET_IDLE_TIMEOUT)
+        }
+    }
+}
+
+/// A registered API key
+#[derive(Debug)]
+pub struct Key {
+    id: Uuid,
+    name: String,
+    limit: Limit,
+}
+
+#[cfg(test)]
+impl Key {
+    /// Construct an unregistered key for tests of other modules
+    pub(crate) fn test(name: &str) -> Self {
+        Self {
+            id: Uuid::nil(),
+            name: name.into(),
+            limit: Limit::new(60, 60),
+        }
+    }
+}
+
+/// The identity a request was made with
+#[derive(Debug, Clone)]
+pub enum Client {
+    /// A request authenticated with an API key
+    Key(Arc<Key>),
+    /// A request without an API key, from the given remote address
+    Anonymous(String),
+}
+
+impl Client {
+    /// A short description of this client for logging
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Key(k) => &k.name,
+            Self::Anonymous(_) => "anonymous",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum BucketKey {
+    Key(Uuid),
+    Address(String),
+    /// The bucket shared by anonymous clients once the maximum number of
+    /// addresses are tracked
+    Overflow,
+}
+
+/// A request refused before execution
+#[derive(Debug, Clone, Copy)]
+pub enum Rejection {
+    /// The API key given is not registered or has been revoked
+    InvalidKey,
+    /// The client has exceeded its rate limit, and may retry after the given
+    /// delay
+    RateLimited(StdDuration),
+}
+
+impl Rejection {
+    /// Render this rejection as a GraphQL error
+    #[must_use]
+    pub fn to_error(self) -> serde_json::Value {
+        match self {
+            Self::InvalidKey => json!({
+                "message": "Invalid API key",
+                "extensions": { "code": "INVALID_API_KEY" },
+            }),
+            Self::RateLimited(retry) => json!({
+                "message": "Rate limit exceeded",
+                "extensions": {
+                    "code": "RATE_LIMITED",
+                    "retryAfter": retry_secs(retry),
+                },
+            }),
+        }
+    }
+
+    /// Render this rejection as an HTTP response
+    #[must_use]
+    pub fn to_response(self) -> HttpResponse {
+        let body = json!({ "errors": [self.to_error()] });
+
+        match self {
+            Self::InvalidKey => HttpResponse::Unauthorized().json(body),
+            Self::RateLimited(retry) => HttpResponse::TooManyRequests()
+                .insert_header((header::RETRY_AFTER, retry_secs(retry).to_string()))
+                .json(body),
+        }
+    }
+}
+
+/// Round a retry delay up to whole seconds
+fn retry_secs(retry: StdDuration) -> u64 {
+    retry.as_secs() + u64::from(retry.subsec_nanos() > 0)
+}
+
+/// Hash an API key for lookup in the `api_keys` table
+fn hash_key(key: &str) -> String {
+    format!("{:x}", Sha256::digest(key.as_bytes()))
+}
+
+fn load_keys(db: &Pool) -> Result<HashMap<String, Arc<Key>>> {
+    let conn = db.get().context("Failed to connect to db")?;
+
+    let keys: Vec<models::ApiKey> = api_keys::table
+        .filter(api_keys::revoked_at.is_null())
+        .select(api_keys::all_columns)
+        .load(&conn)
+        .context("Failed to load API keys")?;
+
+    keys.into_iter()
+        .map(|k| {
+            let id = k.id.ok_or_else(|| anyhow!("API key missing ID"))?;
+            let limit = Limit::new(
+                k.requests_per_minute.try_into().unwrap_or(0),
+                k.burst.try_into().unwrap_or(0),
+            );
+
+            Ok((
+                k.key_hash.into_owned(),
+                Arc::new(Key {
+                    id,
+                    name: k.name.into_owned(),
+                    limit,
+                }),
+            ))
+        })
+        .collect()
+}
+
+/// Parse a forwarded node, which may carry a port and brackets around an IPv6
+/// address
+fn parse_node(node: &str) -> Option<IpAddr> {
+    if let Some(rest) = node.strip_prefix('[') {
+        return rest.split_once(']')?.0.parse().ok();
+    }
+
+    node.parse()
+        .ok()
+        .or_else(|| node.parse::<SocketAddr>().ok().map(|a| a.ip()))
+}
+
+/// Walk a forwarding chain from the nearest hop outwards and return the first
+/// address not added by a trusted proxy.  Entries further left were written by
+/// the client itself and cannot be trusted.
+fn client_addr(trusted_proxies: &[IpAddr], peer: IpAddr, chain: &[&str]) -> String {
+    let node = chain
+        .iter()
+        .rev()
+        .find(|n| !matches!(parse_node(n), Some(ip) if trusted_proxies.contains(&ip)))
+        .or_else(|| chain.first());
+
+    match node {
+        Some(n) => parse_node(n).map_or_else(|| (*n).to_owned(), |ip| ip.to_string()),
+        None => peer.to_string(),
+    }
+}
+
+/// Registered API keys and the rate limit and usage state of each client
+#[derive(Debug)]
+pub struct ApiKeys {
+    anonymous: Limit,
+    max_anonymous_clients: usize,
+    trusted_proxies: Vec<IpAddr>,
+    usage_db: Option<Pool>,
+    keys: RwLock<HashMap<String, Arc<Key>>>,
+    buckets: Mutex<HashMap<BucketKey, Bucket>>,
+    usage: Mutex<HashMap<(Uuid, NaiveDate), (i64, i64)>>,
+}
+
+impl ApiKeys {
+    /// Load the registered API keys and connect to the usage database, if
+    /// one is configured
+    ///
+    /// # Errors
+    /// This function fails if the keys cannot be loaded or the usage database
+    /// connection pool cannot be created.
+    pub fn new(args: Args, db: &Pool) -> Result<Self> {
+        let Args {
+            anonymous_requests_per_minute,
+            anonymous_burst,
+            max_anonymous_clients,
+            trusted_proxies,
+            api_usage_database_url,
+        } = args;
+
+        let keys = load_keys(db)?;
+        info!("Loaded {} API key(s)", keys.len());
+
+        let usage_db = api_usage_database_url
+            .map(|url| {
+                Pool::builder()
+                    .max_size(2)
+                    .min_idle(Some(0))
+                    .build(ConnectionManager::new(url))
+                    .context("Failed to create API usage database connection pool")
+            })
+            .transpose()?;
+
+        if usage_db.is_none() {
+            warn!("No API usage database configured, usage will not be recorded");
+        }
+
+        Ok(Self {
+            anonymous: Limit::new(anonymous_requests_per_minute, anonymous_burst),
+            max_anonymous_clients,
+            trusted_proxies,
+            usage_db,
+            keys: RwLock::new(keys),
+            buckets: Mutex::new(HashMap::new()),
+            usage: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Begin periodically reloading keys from the given database and flushing
+    /// usage.  This must be called from within a Tokio runtime.
+    pub fn run(self: &Arc<Self>, db: Arc<Pool>) {
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(KEY_REFRESH_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                let this = Arc::clone(&this);
+                let db = Arc::clone(&db);
+
+                match tokio::task::spawn_blocking(move || this.refresh(&db)).await {
+                    Ok(Ok(())) => (),
+                    Ok(Err(e)) => error!("Failed to refresh API keys: {:?}", e),
+                    Err(e) => error!("API key refresh task failed: {}", e),
+                }
+            }
+        });
+
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(USAGE_FLUSH_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                let this = Arc::clone(&this);
+
+                match tokio::task::spawn_blocking(move || this.flush_usage()).await {
+                    Ok(Ok(())) => (),
+                    Ok(Err(e)) => error!("Failed to record API usage: {:?}", e),
+                    Err(e) => error!("API usage flush task failed: {}", e),
+                }
+            }
+        });
+    }
+
+    /// The address identifying an anonymous client, which is only taken
+    /// from forwarding headers if the request was made through a trusted
+    /// proxy
+    #[must_use]
+    pub fn remote_addr(&self, req: &HttpRequest) -> String {
+        let Some(peer) = req.peer_addr().map(|a| a.ip()) else {
+            return String::new();
+        };
+
+        if !self.trusted_proxies.contains(&peer) {
+            return peer.to_string();
+        }
+
+        let headers = req.headers();
+        let mut chain: Vec<&str> = headers
+            .get_all(header::FORWARDED)
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .filter_map(|e| {
+                e.split(';').find_map(|p| {
+                    let (k, v) = p.trim().split_once('=')?;
+                    k.eq_ignore_ascii_case("for")
+                        .then(|| v.trim().trim_matches('"'))
+                })
+            })
+            .collect();
+
+        if chain.is_empty() {
+            chain = headers
+                .get_all(header::X_FORWARDED_FOR)
+                .filter_map(|v| v.to_str().ok())
+                .flat_map(|v| v.split(','))
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .collect();
+        }
+
+        client_addr(&self.trusted_proxies, peer, &chain)
+    }
+
+    /// Identify the client making a request and take a token from its rate
+    /// limit
+    ///
+    /// # Errors
+    /// This function fails if the key given is invalid or the client has
+    /// exceeded its rate limit.
+    pub fn authorize(&self, key: Option<&str>, remote_addr: &str) -> Result<Client, Rejection> {
+        let client = match key {
+            Some(key) => {
+                let keys = self.keys.read().unwrap_or_else(PoisonError::into_inner);
+
+                Client::Key(Arc::clone(
+                    keys.get(&hash_key(key)).ok_or(Rejection::InvalidKey)?,
+                ))
+            },
+            None => Client::Anonymous(remote_addr.to_owned()),
+        };
+
+        let (mut bucket_key, limit) = match client {
+            Client::Key(ref k) => (BucketKey::Key(k.id), k.limit),
+            Client::Anonymous(ref a) => (BucketKey::Address(a.clone()), self.anonymous),
+        };
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if matches!(bucket_key, BucketKey::Address(_))
+            && buckets.len() >= self.max_anonymous_clients
+            && !buckets.contains_key(&bucket_key)
+        {
+            bucket_key = BucketKey::Overflow;
+        }
+
+        buckets
+            .entry(bucket_key)
+            .or_insert_with(|| Bucket::full(limit, now))
+            .take(limit, now)
+            .map_err(Rejection::RateLimited)?;
+
+        Ok(client)
+    }
+
+    /// Count a request and its estimated cost towards the client's usage
+    pub fn record(&self, client: &Client, cost: u64) {
+        let Client::Key(key) = client else {
+            return;
+        };
+
+        let day = Utc::now().naive_utc().date();
+        let mut usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        let (count, total) = usage.entry((key.id, day)).or_default();
+
+        *count = count.saturating_add(1);
+        *total = total.saturating_add(cost.try_into().unwrap_or(i64::MAX));
+    }
+
+    fn refresh(&self, db: &Pool) -> Result<()> {
+        let keys = load_keys(db)?;
+        *self.keys.write().unwrap_or_else(PoisonError::into_inner) = keys;
+
+        // Discard buckets which would have refilled anyway
+        let now = Instant::now();
+        self.buckets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|_, b| now.saturating_duration_since(b.updated) < BUCKET_IDLE_TIMEOUT);
+
+        Ok(())
+    }
+
+    fn flush_usage(&self) -> Result<()> {
+        let Some(ref db) = self.usage_db else {
+            return Ok(());
+        };
+
+        let usage = std::mem::take(&mut *self.usage.lock().unwrap_or_else(PoisonError::into_inner));
+
+        if usage.is_empty() {
+            return Ok(());
+        }
+
+        let rows: Vec<_> = usage
+            .iter()
+            .map(
+                |(&(api_key_id, day), &(request_count, total_cost))| models::ApiKeyUsage {
+                    api_key_id,
+                    day,
+                    request_count,
+                    total_cost,
+                },
+            )
+            .collect();
+
+        let res = db
+            .get()
+            .context("Failed to connect to API usage database")
+            .and_then(|conn| {
+                insert_into(api_key_usage::table)
+                    .values(&rows)
+                    .on_conflict((api_key_usage::api_key_id, api_key_usage::day))
+                    .do_update()
+                    .set((
+                        api_key_usage::request_count
+                            .eq(api_key_usage::request_count
+                                + excluded(api_key_usage::request_count)),
+                        api_key_usage::total_cost
+                            .eq(api_key_usage::total_cost + excluded(api_key_usage::total_cost)),
+                    ))
+                    .execute(&conn)
+                    .context("Failed to upsert API key usage")
+            });
+
+        if res.is_err() {
+            // Keep the usage to retry with the next flush
+            let mut pending = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+
+            for (k, (count, total)) in usage {
+                let (c, t) = pending.entry(k).or_default();
+                *c = c.saturating_add(count);
+                *t = t.saturating_add(total);
+            }
+        }
+
+        res.map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: Limit = Limit {
+        per_sec: 1.0,
+        capacity: 3.0,
+    };
+
+    fn secs(s: f64) -> StdDuration {
+        StdDuration::from_secs_f64(s)
+    }
+
+    fn api_keys(max_anonymous_clients: usize) -> ApiKeys {
+        ApiKeys {
+            anonymous: LIMIT,
+            max_anonymous_clients,
+            trusted_proxies: vec![],
+            usage_db: None,
+            keys: RwLock::new(HashMap::new()),
+            buckets: Mutex::new(HashMap::new()),
+            usage: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[test]
+    fn test_bucket_burst() {
+        let now = Instant::now();
+        let mut bucket = Bucket::full(LIMIT, now);
+
+        for _ in 0..3 {
+            assert_eq!(bucket.take(LIMIT, now), Ok(()));
+        }
+
+        assert_eq!(bucket.take(LIMIT, now), Err(secs(1.0)));
+    }
+
+    #[test]
+    fn test_bucket_refill() {
+        let now = Instant::now();
+        let mut bucket = Bucket::full(LIMIT, now);
+
+        for _ in 0..3 {
+            assert_eq!(bucket.take(LIMIT, now), Ok(()));
+        }
+
+        assert_eq!(bucket.take(LIMIT, now + secs(0.75)), Err(secs(0.25)));
+        assert_eq!(bucket.take(LIMIT, now + secs(1.0)), Ok(()));
+        assert_eq!(bucket.take(LIMIT, now + secs(1.0)), Err(secs(1.0)));
+
+        // Idle time never fills the bucket past its capacity
+        let later = now + secs(60.0);
+        for _ in 0..3 {
+            assert_eq!(bucket.take(LIMIT, later), Ok(()));
+        }
+        assert!(bucket.take(LIMIT, later).is_err());
+    }
+
+    #[test]
+    fn test_bucket_zero_rate() {
+        let limit = Limit::new(0, 1);
+        let now = Instant::now();
+        let mut bucket = Bucket::full(limit, now);
+
+        assert_eq!(bucket.take(limit, now), Ok(()));
+        assert_eq!(
+            bucket.take(limit, now + secs(3600.0)),
+            Err(BUCKET_IDLE_TIMEOUT)
+        );
+    }
+
+    #[test]
+    fn test_anonymous_overflow() {
+        let keys = api_keys(2);
+
+        assert!(keys.authorize(None, "a").is_ok());
+        assert!(keys.authorize(None, "b").is_ok());
+
+        // Clients past the limit share a single bucket
+        for _ in 0..3 {
+            assert!(keys.authorize(None, "c").is_ok());
+        }
+        assert!(matches!(
+            keys.authorize(None, "d"),
+            Err(Rejection::RateLimited(_))
+        ));
+
+        // Tracked clients keep their own buckets
+        assert!(keys.authorize(None, "a").is_ok());
+
+        let buckets = keys.buckets.lock().unwrap();
+        assert_eq!(buckets.len(), 3);
+        assert!(buckets.contains_key(&BucketKey::Overflow));
+    }
+
+    #[test]
+    fn test_client_addr() {
+        let proxy: IpAddr = "10.0.0.1".parse().unwrap();
+        let peer: IpAddr = "10.0.0.2".parse().unwrap();
+        let trusted = [proxy, peer];
+
+        // A client-supplied entry on the left is ignored
+        assert_eq!(
+            client_addr(&trusted, peer, &["6.6.6.6", "1.2.3.4", "10.0.0.1"]),
+            "1.2.3.4"
+        );
+        assert_eq!(
+            client_addr(&trusted, peer, &["[2001:db8::1]:4711", "1.2.3.4:80"]),
+            "1.2.3.4"
+        );
+        assert_eq!(
+            client_addr(&trusted, peer, &["[2001:db8::1]:4711"]),
+            "2001:db8::1"
+        );
+
+        // Only trusted proxies forwarded the request
+        assert_eq!(
+            client_addr(&trusted, peer, &["10.0.0.1", "10.0.0.2"]),
+            "10.0.0.1"
+        );
+        assert_eq!(client_addr(&trusted, peer, &[]), "10.0.0.2");
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        assert!(matches!(
+            api_keys(1).authorize(Some("key"), "a"),
+            Err(Rejection::InvalidKey)
+        ));
+    }
+}
@@ -0,0 +1,630 @@
+//! Static complexity analysis of GraphQL queries
+//!
+//! Before a query is executed its depth and estimated cost are computed from
+//! the query document alone, so that deeply nested or very wide queries can be
+//! rejected before they reach the database.
+//!
+//! Each field with a selection set costs one point plus the cost of its
+//! selections, multiplied by the page size it requests through a `limit`,
+//! `first` or `last` argument.  List fields without a page size argument are
+//! assumed to return a fixed default number of items, unless they are the
+//! edges of a Relay connection, whose page size is taken from the connection
+//! field instead.  Leaf fields and introspection fields are free.
+
+use std::collections::{HashMap, HashSet};
+
+use graphql_parser::query::{
+    parse_query, Definition, Document, FragmentDefinition, OperationDefinition, Selection,
+    SelectionSet, TypeCondition, Value,
+};
+use indexer_core::{clap, prelude::*};
+use juniper::{
+    meta::{InterfaceMeta, MetaType, ObjectMeta},
+    DefaultScalarValue, GraphQLType, Type,
+};
+use serde_json::json;
+
+use crate::schema::Schema;
+
+/// Arguments which bound the number of items returned by a list field
+const PAGE_SIZE_ARGS: &[&str] = &["limit", "first", "last"];
+
+/// Command-line arguments for query complexity limits
+#[derive(Debug, Clone, Copy, clap::Args)]
+#[group(skip)]
+pub struct Args {
+    /// Maximum nesting depth of a GraphQL query
+    #[arg(long, env, default_value_t = 12)]
+    max_query_depth: u32,
+
+    /// Maximum estimated cost of a GraphQL query, counting each object field
+    /// once per item of its parent list
+    #[arg(long, env, default_value_t = 50_000)]
+    max_query_cost: u64,
+
+    /// Number of items assumed to be returned by list fields which take no
+    /// page size argument
+    #[arg(long, env, default_value_t = 100)]
+    default_list_size: u64,
+}
+
+/// The type returned by a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FieldType {
+    list: bool,
+    /// True if the field takes a page size argument
+    paged: bool,
+    name: String,
+}
+
+impl FieldType {
+    /// True if this field returns a single page object, such as a Relay
+    /// connection, rather than a list
+    fn is_connection(&self) -> bool {
+        self.paged && !self.list
+    }
+}
+
+/// The types of the fields of each object and interface in the schema, used
+/// to find the list fields selected by a query
+#[derive(Debug)]
+pub struct SchemaTypes {
+    query: String,
+    subscription: String,
+    fields: HashMap<String, HashMap<String, FieldType>>,
+}
+
+fn type_name<T: GraphQLType<DefaultScalarValue>>(_: &T, info: &T::TypeInfo) -> String {
+    T::name(info).unwrap_or_default().to_owned()
+}
+
+impl SchemaTypes {
+    /// Collect the field types of a schema
+    #[must_use]
+    pub fn new(schema: &Schema) -> Self {
+        let fields = schema
+            .schema
+            .concrete_type_list()
+            .into_iter()
+            .filter_map(|ty| {
+                let (MetaType::Object(ObjectMeta { name, fields, .. })
+                | MetaType::Interface(InterfaceMeta { name, fields, .. })) = ty else {
+                    return None;
+                };
+
+                let fields = fields
+                    .iter()
+                    .map(|f| {
+                        (f.name.to_string(), FieldType {
+                            list: matches!(f.field_type, Type::List(..) | Type::NonNullList(..)),
+                            paged: f
+                                .arguments
+                                .iter()
+                                .flatten()
+                                .any(|a| PAGE_SIZE_ARGS.contains(&a.name.as_str())),
+                            name: f.field_type.innermost_name().to_owned(),
+                        })
+                    })
+                    .collect();
+
+                Some((name.to_string(), fields))
+            })
+            .collect();
+
+        Self {
+            query: type_name(&schema.query_type, &schema.query_info),
+            subscription: type_name(&schema.subscription_type, &schema.subscription_info),
+            fields,
+        }
+    }
+
+    fn field(&self, parent: Option<&str>, name: &str) -> Option<&FieldType> {
+        self.fields.get(parent?)?.get(name)
+    }
+}
+
+/// The computed complexity of a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Complexity {
+    /// The deepest nesting of selection sets in the query
+    pub depth: u32,
+    /// The estimated cost of resolving the query
+    pub cost: u64,
+}
+
+/// A query rejected for exceeding the complexity limits
+#[derive(Debug, Clone, Copy)]
+pub struct TooComplex {
+    complexity: Complexity,
+    limits: Args,
+}
+
+impl TooComplex {
+    /// Render this rejection as a GraphQL error
+    #[must_use]
+    pub fn to_error(self) -> serde_json::Value {
+        let Self {
+            complexity: Complexity { depth, cost },
+            limits:
+                Args {
+                    max_query_depth,
+                    max_query_cost,
+                    ..
+                },
+        } = self;
+
+        let message = if depth > max_query_depth {
+            format!("Query depth {depth} exceeds the maximum of {max_query_depth}")
+        } else {
+            format!("Query cost {cost} exceeds the maximum of {max_query_cost}")
+        };
+
+        json!({
+            "message": message,
+            "extensions": {
+                "code": "QUERY_TOO_COMPLEX",
+                "depth": depth,
+                "maxDepth": max_query_depth,
+                "cost": cost,
+                "maxCost": max_query_cost,
+            },
+        })
+    }
+
+    /// Render this rejection as a GraphQL response body
+    #[must_use]
+    pub fn to_response(self) -> serde_json::Value {
+        json!({ "errors": [self.to_error()] })
+    }
+}
+
+impl Args {
+    /// Compute the complexity of the operation to be executed from a query
+    /// document, returning `Ok(None)` if the query cannot be parsed so that
+    /// the error can be reported by the executor
+    ///
+    /// # Errors
+    /// This function fails if the operation exceeds the configured maximum
+    /// depth or cost.
+    pub fn check(
+        &self,
+        types: &SchemaTypes,
+        query: &str,
+        operation_name: Option<&str>,
+        variables: &serde_json::Value,
+    ) -> Result<Option<Complexity>, TooComplex> {
+        let Ok(doc) = parse_query::<&str>(query) else {
+            return Ok(None);
+        };
+
+        let complexity = Analyzer::new(&doc, types, variables, *self)
+            .operation(operation_name)
+            .unwrap_or(Complexity { depth: 0, cost: 0 });
+
+        if complexity.depth > self.max_query_depth || complexity.cost > self.max_query_cost {
+            return Err(TooComplex {
+                complexity,
+                limits: *self,
+            });
+        }
+
+        Ok(Some(complexity))
+    }
+}
+
+struct Analyzer<'a, 'b, 't> {
+    operations: Vec<&'b OperationDefinition<'a, &'a str>>,
+    fragments: HashMap<&'a str, &'b FragmentDefinition<'a, &'a str>>,
+    types: &'t SchemaTypes,
+    variables: HashMap<&'a str, i64>,
+    limits: Args,
+    fragment_costs: HashMap<(&'a str, bool), Complexity>,
+    visiting: HashSet<&'a str>,
+}
+
+impl<'a, 'b, 't> Analyzer<'a, 'b, 't> {
+    fn new(
+        doc: &'b Document<'a, &'a str>,
+        types: &'t SchemaTypes,
+        variables: &serde_json::Value,
+        limits: Args,
+    ) -> Self {
+        let mut operations = vec![];
+        let mut fragments = HashMap::new();
+
+        for def in &doc.definitions {
+            match def {
+                Definition::Operation(o) => operations.push(o),
+                Definition::Fragment(f) => {
+                    fragments.insert(f.name, f);
+                },
+            }
+        }
+
+        Self {
+            operations,
+            fragments,
+            types,
+            variables: Self::int_variables(doc, variables),
+            limits,
+            fragment_costs: HashMap::new(),
+            visiting: HashSet::new(),
+        }
+    }
+
+    /// Collect the integer-valued variables of the query, falling back to the
+    /// defaults declared by the operations
+    fn int_variables(
+        doc: &'b Document<'a, &'a str>,
+        variables: &serde_json::Value,
+    ) -> HashMap<&'a str, i64> {
+        doc.definitions
+            .iter()
+            .filter_map(|d| match d {
+                Definition::Operation(OperationDefinition::Query(q)) => {
+                    Some(&q.variable_definitions)
+                },
+                Definition::Operation(OperationDefinition::Subscription(s)) => {
+                    Some(&s.variable_definitions)
+                },
+                Definition::Operation(OperationDefinition::Mutation(m)) => {
+                    Some(&m.variable_definitions)
+                },
+                Definition::Operation(OperationDefinition::SelectionSet(_))
+                | Definition::Fragment(_) => None,
+            })
+            .flatten()
+            .filter_map(|v| {
+                let value = variables
+                    .get(v.name)
+                    .and_then(serde_json::Value::as_i64)
+                    .or(match v.default_value {
+                        Some(Value::Int(ref i)) => i.as_i64(),
+                        _ => None,
+                    })?;
+
+                Some((v.name, value))
+            })
+            .collect()
+    }
+
+    /// Analyze the operation selected by name, or the most complex operation
+    /// if it cannot be determined
+    fn operation(&mut self, name: Option<&str>) -> Option<Complexity> {
+        let operations = std::mem::take(&mut self.operations);
+
+        let selected: Vec<_> = operations
+            .iter()
+            .filter(|o| name.map_or(true, |n| Self::operation_name(o) == Some(n)))
+            .collect();
+        let selected = if selected.is_empty() {
+            operations.iter().collect()
+        } else {
+            selected
+        };
+
+        let types = self.types;
+
+        selected
+            .into_iter()
+            .map(|o| {
+                let (set, root) = match o {
+                    OperationDefinition::SelectionSet(s) => (s, Some(&*types.query)),
+                    OperationDefinition::Query(q) => (&q.selection_set, Some(&*types.query)),
+                    OperationDefinition::Mutation(m) => (&m.selection_set, None),
+                    OperationDefinition::Subscription(s) => {
+                        (&s.selection_set, Some(&*types.subscription))
+                    },
+                };
+
+                self.selection_set(set, root, 1, false)
+            })
+            .max_by_key(|c| (c.cost, c.depth))
+    }
+
+    fn operation_name(op: &OperationDefinition<'a, &'a str>) -> Option<&'a str> {
+        match op {
+            OperationDefinition::SelectionSet(_) => None,
+            OperationDefinition::Query(q) => q.name,
+            OperationDefinition::Mutation(m) => m.name,
+            OperationDefinition::Subscription(s) => s.name,
+        }
+    }
+
+    /// Compute the complexity of a selection set on the given type nested at
+    /// the given level, where the depth returned is relative to the set itself
+    ///
+    /// If `paged` is true the set belongs to a connection field, whose page
+    /// size already counts the items of the lists selected in it.
+    fn selection_set(
+        &mut self,
+        set: &SelectionSet<'a, &'a str>,
+        parent: Option<&str>,
+        level: u32,
+        paged: bool,
+    ) -> Complexity {
+        let mut ret = Complexity { depth: 0, cost: 0 };
+
+        // Stop descending once the query is known to be too deep
+        if level > self.limits.max_query_depth {
+            ret.depth = 1;
+            return ret;
+        }
+
+        let types = self.types;
+
+        for sel in &set.items {
+            let sub = match sel {
+                Selection::Field(f) if f.name.starts_with("__") => continue,
+                Selection::Field(f) if f.selection_set.items.is_empty() => {
+                    Complexity { depth: 1, cost: 0 }
+                },
+                Selection::Field(f) => {
+                    let ty = types.field(parent, f.name);
+                    let connection = ty.map_or(false, FieldType::is_connection);
+                    let children = self.selection_set(
+                        &f.selection_set,
+                        ty.map(|t| &*t.name),
+                        level + 1,
+                        connection,
+                    );
+                    let page_size = self
+                        .page_size(&f.arguments)
+                        .unwrap_or(match ty {
+                            Some(FieldType { list: true, .. }) if paged => 1,
+                            Some(t) if t.list || connection => self.limits.default_list_size,
+                            _ => 1,
+                        })
+                        .max(1);
+
+                    Complexity {
+                        depth: children.depth.saturating_add(1),
+                        cost: children.cost.saturating_mul(page_size).saturating_add(1),
+                    }
+                },
+                Selection::FragmentSpread(s) => self.fragment(s.fragment_name, level, paged),
+                Selection::InlineFragment(f) => {
+                    let parent = match f.type_condition {
+                        Some(TypeCondition::On(ty)) => Some(ty),
+                        None => parent,
+                    };
+
+                    self.selection_set(&f.selection_set, parent, level, paged)
+                },
+            };
+
+            ret.depth = ret.depth.max(sub.depth);
+            ret.cost = ret.cost.saturating_add(sub.cost);
+        }
+
+        ret
+    }
+
+    fn fragment(&mut self, name: &'a str, level: u32, paged: bool) -> Complexity {
+        if let Some(c) = self.fragment_costs.get(&(name, paged)) {
+            return *c;
+        }
+
+        let Some(frag) = self.fragments.get(name).copied() else {
+            return Complexity { depth: 0, cost: 0 };
+        };
+
+        // Fragment cycles are rejected by query validation
+        if !self.visiting.insert(name) {
+            return Complexity { depth: 0, cost: 0 };
+        }
+
+        let TypeCondition::On(ty) = frag.type_condition;
+        let ret = self.selection_set(&frag.selection_set, Some(ty), level, paged);
+
+        self.visiting.remove(name);
+        self.fragment_costs.insert((name, paged), ret);
+
+        ret
+    }
+
+    /// The number of items requested from a field, if a page size argument
+    /// is given
+    fn page_size(&self, args: &[(&'a str, Value<'a, &'a str>)]) -> Option<u64> {
+        args.iter()
+            .filter(|(name, _)| PAGE_SIZE_ARGS.contains(name))
+            .filter_map(|(_, value)| match value {
+                Value::Int(i) => i.as_i64(),
+                Value::Variable(v) => self.variables.get(v).copied(),
+                _ => None,
+            })
+            .filter_map(|i| u64::try_from(i).ok())
+            .max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{Args, Complexity, FieldType, SchemaTypes};
+
+    const ARGS: Args = Args {
+        max_query_depth: 4,
+        max_query_cost: 1_000,
+        default_list_size: 100,
+    };
+
+    fn types() -> SchemaTypes {
+        let ty = |fields: &[(&str, bool, bool, &str)]| {
+            fields
+                .iter()
+                .map(|&(field, list, paged, name)| {
+                    (field.to_owned(), FieldType {
+                        list,
+                        paged,
+                        name: name.to_owned(),
+                    })
+                })
+                .collect()
+        };
+
+        SchemaTypes {
+            query: "QueryRoot".into(),
+            subscription: "SubscriptionRoot".into(),
+            fields: [
+                (
+                    "QueryRoot",
+                    ty(&[
+                        ("nfts", true, true, "Nft"),
+                        ("nftsConnection", false, true, "NftConnection"),
+                        ("wallet", false, false, "Wallet"),
+                    ]),
+                ),
+                (
+                    "Nft",
+                    ty(&[
+                        ("address", false, false, "String"),
+                        ("owner", false, false, "Wallet"),
+                    ]),
+                ),
+                ("NftConnection", ty(&[("edges", true, false, "NftEdge")])),
+                ("NftEdge", ty(&[("node", false, false, "Nft")])),
+                (
+                    "Wallet",
+                    ty(&[
+                        ("address", false, false, "String"),
+                        ("nfts", true, true, "Nft"),
+                    ]),
+                ),
+            ]
+            .into_iter()
+            .map(|(name, fields)| (name.to_owned(), fields))
+            .collect(),
+        }
+    }
+
+    fn check(query: &str, operation: Option<&str>, variables: &serde_json::Value) -> Complexity {
+        ARGS.check(&types(), query, operation, variables)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_page_size() {
+        let c = check(
+            "{ nfts(limit: 10) { owner { address } } }",
+            None,
+            &json!({}),
+        );
+        assert_eq!(c, Complexity { depth: 3, cost: 11 });
+
+        let query = "query($n: Int, $m: Int = 5) { a: nfts(first: $n) { owner { address } } \
+                     b: nfts(last: $m) { owner { address } } }";
+        assert_eq!(check(query, None, &json!({ "n": 20 })).cost, 21 + 6);
+        assert_eq!(check(query, None, &json!({ "n": 20, "m": 2 })).cost, 21 + 3);
+    }
+
+    #[test]
+    fn test_default_list_size() {
+        let c = check(
+            "{ wallet { nfts { owner { address } } } }",
+            None,
+            &json!({}),
+        );
+        assert_eq!(c, Complexity {
+            depth: 4,
+            cost: 102,
+        });
+
+        // Unknown fields are not assumed to be lists
+        let c = check(
+            "{ wallet { other { owner { address } } } }",
+            None,
+            &json!({}),
+        );
+        assert_eq!(c.cost, 3);
+    }
+
+    #[test]
+    fn test_connections() {
+        let c = check(
+            "{ nftsConnection(first: 10) { edges { node { address } } } }",
+            None,
+            &json!({}),
+        );
+        assert_eq!(c, Complexity { depth: 4, cost: 21 });
+
+        // Connections without a page size are assumed to return the default
+        let c = check(
+            "{ nftsConnection { edges { node { address } } } }",
+            None,
+            &json!({}),
+        );
+        assert_eq!(c.cost, 201);
+
+        let spread = check(
+            "query { nftsConnection(first: 10) { ...Edges } } \
+             fragment Edges on NftConnection { edges { node { address } } }",
+            None,
+            &json!({}),
+        );
+        assert_eq!(spread.cost, 21);
+    }
+
+    #[test]
+    fn test_fragments() {
+        let inline = check(
+            "{ nfts(limit: 10) { owner { nfts { address } } } }",
+            None,
+            &json!({}),
+        );
+
+        let spread = check(
+            "query { nfts(limit: 10) { ...Nft } } \
+             fragment Nft on Nft { owner { ...Wallet } } \
+             fragment Wallet on Wallet { nfts { address } }",
+            None,
+            &json!({}),
+        );
+        assert_eq!(spread, inline);
+
+        let typed = check(
+            "{ nfts(limit: 10) { ... on Nft { owner { nfts { address } } } } }",
+            None,
+            &json!({}),
+        );
+        assert_eq!(typed, inline);
+    }
+
+    #[test]
+    fn test_operation_selection() {
+        let query = "query A { wallet { address } } \
+                     query B { nfts(limit: 50) { owner { address } } }";
+
+        assert_eq!(check(query, Some("A"), &json!({})).cost, 1);
+        assert_eq!(check(query, Some("B"), &json!({})).cost, 51);
+
+        // The most complex operation is assumed if none is selected
+        assert_eq!(check(query, None, &json!({})).cost, 51);
+        assert_eq!(check(query, Some("C"), &json!({})).cost, 51);
+    }
+
+    #[test]
+    fn test_limits() {
+        let deep =
+            "{ wallet { nfts(limit: 1) { owner { nfts(limit: 1) { owner { address } } } } } }";
+        let err = ARGS.check(&types(), deep, None, &json!({})).unwrap_err();
+        assert!(err.complexity.depth > ARGS.max_query_depth);
+        assert_eq!(err.to_error()["extensions"]["maxDepth"], 4);
+
+        let costly = "{ nfts(limit: 1000) { owner { address } } }";
+        let err = ARGS.check(&types(), costly, None, &json!({})).unwrap_err();
+        assert_eq!(err.complexity.cost, 1_001);
+        assert_eq!(
+            err.to_error()["message"],
+            "Query cost 1001 exceeds the maximum of 1000"
+        );
+
+        assert!(
+            ARGS.check(&types(), "not a query", None, &json!({}))
+                .unwrap()
+                .is_none()
+        );
+    }
+}
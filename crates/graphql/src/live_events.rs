@@ -0,0 +1,181 @@
+//! Live marketplace and feed events for GraphQL subscriptions
+//!
+//! The indexer database issues a `NOTIFY` whenever a marketplace activity or
+//! feed event is inserted.  A single connection per server listens for these
+//! and fans them out to every subscriber.  The connection uses TLS if the
+//! server supports it, unless disabled with `sslmode=disable`.
+
+use std::str::FromStr;
+
+use futures_util::{stream, Stream, StreamExt};
+use indexer_core::{db::custom_types::ActivityTypeEnum, prelude::*, uuid::Uuid};
+use native_tls::TlsConnector;
+use postgres_native_tls::MakeTlsConnector;
+use tokio::sync::{broadcast, mpsc};
+use tokio_postgres::{AsyncMessage, Notification};
+
+/// The number of events buffered for each subscriber before it starts
+/// missing events
+const CAPACITY: usize = 1024;
+/// The delay before reconnecting after the listening connection fails
+const RECONNECT_DELAY: StdDuration = StdDuration::from_secs(5);
+
+const ACTIVITY_CHANNEL: &str = "marketplace_activities";
+const FEED_EVENT_CHANNEL: &str = "feed_event_wallets";
+
+/// A newly-inserted row relevant to subscribers
+#[derive(Debug, Clone)]
+pub enum LiveEvent {
+    /// A row in the `marketplace_activities` table
+    Activity {
+        /// The kind of activity
+        activity_type: ActivityTypeEnum,
+        /// The ID of the listing, offer or purchase
+        activity_id: Uuid,
+    },
+    /// A row in the `feed_event_wallets` table
+    FeedEvent {
+        /// The ID of the feed event
+        feed_event_id: Uuid,
+        /// The wallet the feed event is shown to
+        wallet_address: String,
+    },
+}
+
+#[derive(serde::Deserialize)]
+struct ActivityPayload {
+    activity_type: String,
+    activity_id: String,
+}
+
+#[derive(serde::Deserialize)]
+struct FeedEventPayload {
+    feed_event_id: String,
+    wallet_address: String,
+}
+
+impl TryFrom<Notification> for LiveEvent {
+    type Error = Error;
+
+    fn try_from(notif: Notification) -> Result<Self> {
+        Ok(match notif.channel() {
+            ACTIVITY_CHANNEL => {
+                let ActivityPayload {
+                    activity_type,
+                    activity_id,
+                } = serde_json::from_str(notif.payload())?;
+
+                Self::Activity {
+                    activity_type: ActivityTypeEnum::from_str(&activity_type)?,
+                    activity_id: activity_id.parse()?,
+                }
+            },
+            FEED_EVENT_CHANNEL => {
+                let FeedEventPayload {
+                    feed_event_id,
+                    wallet_address,
+                } = serde_json::from_str(notif.payload())?;
+
+                Self::FeedEvent {
+                    feed_event_id: feed_event_id.parse()?,
+                    wallet_address,
+                }
+            },
+            c => bail!("Unexpected notification channel {c:?}"),
+        })
+    }
+}
+
+/// Fan-out of live events to GraphQL subscribers
+#[derive(Debug, Clone)]
+pub struct LiveEvents(broadcast::Sender<LiveEvent>);
+
+impl Default for LiveEvents {
+    fn default() -> Self {
+        Self(broadcast::channel(CAPACITY).0)
+    }
+}
+
+impl LiveEvents {
+    /// Begin listening for events on the database with the given connection
+    /// string, reconnecting if the connection fails.  This must be called
+    /// from within a Tokio runtime.
+    pub fn listen(&self, url: String) {
+        let tx = self.0.clone();
+
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = listen(&url, &tx).await {
+                    error!("Live event listener failed: {:?}", e);
+                }
+
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        });
+    }
+
+    /// Subscribe to all events published after this call
+    pub fn stream(&self) -> impl Stream<Item = LiveEvent> + Send + 'static {
+        stream::unfold(self.0.subscribe(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => break Some((event, rx)),
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("Live event subscriber lagged, skipping {} event(s)", n);
+                    },
+                    Err(broadcast::error::RecvError::Closed) => break None,
+                }
+            }
+        })
+    }
+}
+
+async fn listen(url: &str, tx: &broadcast::Sender<LiveEvent>) -> Result<()> {
+    let tls = TlsConnector::new().context("Failed to initialize TLS")?;
+    let (client, mut conn) = tokio_postgres::connect(url, MakeTlsConnector::new(tls))
+        .await
+        .context("Failed to connect to Postgres")?;
+
+    let (notif_tx, mut notif_rx) = mpsc::unbounded_channel();
+
+    // The connection must be polled separately to receive notifications
+    let driver = tokio::spawn(async move {
+        let mut messages = stream::poll_fn(move |cx| conn.poll_message(cx));
+
+        while let Some(msg) = messages.next().await {
+            if let AsyncMessage::Notification(n) = msg? {
+                if notif_tx.send(n).is_err() {
+                    break;
+                }
+            }
+        }
+
+        Result::<_, tokio_postgres::Error>::Ok(())
+    });
+
+    client
+        .batch_execute(&format!(
+            "listen {ACTIVITY_CHANNEL}; listen {FEED_EVENT_CHANNEL};"
+        ))
+        .await
+        .context("Failed to listen for notifications")?;
+
+    info!("Listening for live events");
+
+    while let Some(notif) = notif_rx.recv().await {
+        match LiveEvent::try_from(notif) {
+            // Sending only fails if there are no subscribers
+            Ok(event) => {
+                tx.send(event).ok();
+            },
+            Err(e) => warn!("Failed to parse live event: {:?}", e),
+        }
+    }
+
+    driver
+        .await
+        .context("Live event connection task failed")?
+        .context("Live event connection failed")?;
+
+    bail!("Live event connection closed")
+}
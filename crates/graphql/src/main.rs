@@ -12,7 +12,11 @@
 use std::sync::Arc;
 
 use actix_cors::Cors;
-use actix_web::{dev::ConnectionInfo, http, web, App, Error, HttpResponse, HttpServer};
+use actix_web::{
+    dev::ConnectionInfo, http, web, App, Error, HttpRequest, HttpResponse, HttpServer,
+};
+use api_keys::ApiKeys;
+use futures_util::{SinkExt, StreamExt};
 use indexer_core::{
     assets::AssetProxyArgs,
     chrono::{Duration, Local},
@@ -25,12 +29,21 @@ use indexer_core::{
     util::duration_hhmmssfff,
     ServerOpts,
 };
-use juniper::http::{graphiql::graphiql_source, GraphQLRequest};
+use juniper::{http::graphiql::graphiql_source, DefaultScalarValue};
+use juniper_graphql_ws::{ClientMessage, Connection, ConnectionConfig};
+use live_events::LiveEvents;
+use persisted_queries::PersistedQueries;
+use request::{GetParams, Request};
 // TODO: use nonblocking once we upgrade past 1.9
 use solana_client::rpc_client::RpcClient;
 
 use crate::schema::{AppContext, Schema};
 
+mod api_keys;
+mod complexity;
+mod live_events;
+mod persisted_queries;
+mod request;
 mod schema;
 
 #[derive(Debug, Parser)]
@@ -74,6 +87,20 @@ struct Opts {
 
     #[arg(long, env)]
     pre_query_search_limit: usize,
+
+    /// Connection string for the primary database, used to listen for live
+    /// events.  GraphQL subscriptions produce no events if this is unset.
+    #[arg(long, env)]
+    live_events_database_url: Option<String>,
+
+    #[command(flatten)]
+    query_limits: complexity::Args,
+
+    #[command(flatten)]
+    api_keys: api_keys::Args,
+
+    #[command(flatten)]
+    persisted_queries: persisted_queries::Args,
 }
 
 struct GraphiqlData {
@@ -86,7 +113,11 @@ struct RedirectData {
 }
 
 pub(crate) struct SharedData {
-    schema: Schema,
+    schema: Arc<Schema>,
+    query_limits: complexity::Args,
+    query_types: complexity::SchemaTypes,
+    api_keys: Arc<ApiKeys>,
+    persisted_queries: PersistedQueries,
     pub db: Arc<Pool>,
     pub asset_proxy: AssetProxyArgs,
     pub twitter_bearer_token: String,
@@ -100,6 +131,7 @@ pub(crate) struct SharedData {
     pub pre_query_search_limit: usize,
     pub dolphin_key: String,
     pub coingecko_endpoint: String,
+    pub live_events: LiveEvents,
 }
 
 #[allow(clippy::unused_async)]
@@ -121,51 +153,290 @@ async fn redirect_version(data: web::Data<RedirectData>) -> HttpResponse {
         ))
 }
 
-async fn graphql(
-    data: web::Data<SharedData>,
-    req: web::Json<GraphQLRequest>,
-    conn: ConnectionInfo,
-) -> Result<HttpResponse, Error> {
+fn bad_request(message: impl std::fmt::Display) -> HttpResponse {
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "errors": [{ "message": message.to_string() }],
+    }))
+}
+
+async fn execute(
+    data: &web::Data<SharedData>,
+    mut req: Request,
+    conn: &ConnectionInfo,
+    http_req: &HttpRequest,
+) -> HttpResponse {
+    // A key which isn't valid UTF-8 can't be registered, so it is rejected
+    let key = http_req
+        .headers()
+        .get(api_keys::API_KEY_HEADER)
+        .map(|k| k.to_str().unwrap_or_default());
+
+    let remote_addr = data.api_keys.remote_addr(http_req);
+
+    let client = match data.api_keys.authorize(key, &remote_addr) {
+        Ok(c) => c,
+        Err(e) => return e.to_response(),
+    };
+
+    let query = req.query.take();
+    let resolved =
+        data.persisted_queries
+            .resolve(&data.db, &client, query, req.extensions.as_ref());
+    let query = match resolved {
+        Ok(q) => q,
+        Err(e) => return e.to_response(),
+    };
+
+    let variables = req.variables.as_ref().unwrap_or(&serde_json::Value::Null);
+
+    let complexity = match data.query_limits.check(
+        &data.query_types,
+        &query,
+        req.operation_name.as_deref(),
+        variables,
+    ) {
+        Ok(c) => c,
+        Err(e) => {
+            warn!(
+                "Rejected complex graphql request remote_addr={:?}: {:?}",
+                conn.realip_remote_addr().unwrap_or(""),
+                e
+            );
+
+            return HttpResponse::BadRequest().json(e.to_response());
+        },
+    };
+
+    let graphql_req = match req.to_graphql(query.clone()) {
+        Ok(r) => r,
+        Err(e) => return bad_request(e),
+    };
+
     let ctx = AppContext::new(data.clone().into_inner());
     let start = Local::now();
 
-    let resp = req.execute(&data.schema, &ctx).await;
+    let resp = graphql_req.execute(&data.schema, &ctx).await;
     let end = Local::now();
     let duration = end - start;
+
+    let cost = complexity.map_or(0, |c| c.cost);
+    data.api_keys.record(&client, cost);
+
     info!(
-        "host={:?}, remote_addr={:?}, peer_addr={:?}",
+        "host={:?}, remote_addr={:?}, peer_addr={:?}, client={:?}, depth={:?}, cost={:?}",
         conn.host(),
         conn.realip_remote_addr().unwrap_or(""),
-        conn.peer_addr().unwrap_or("")
+        conn.peer_addr().unwrap_or(""),
+        client.name(),
+        complexity.map(|c| c.depth),
+        complexity.map(|c| c.cost),
     );
     if duration > Duration::milliseconds(5000) {
-        #[derive(serde::Deserialize)]
-        struct Data {
-            #[serde(default)]
-            query: serde_json::Value,
-            #[serde(default)]
-            operation_name: serde_json::Value,
-            #[serde(default)]
-            variables: serde_json::Value,
+        warn!(
+            "Long graphql request query={:?}, operation={:?}, variables={}, duration={}",
+            query,
+            req.operation_name,
+            variables,
+            duration_hhmmssfff(duration),
+        );
+    }
+
+    let mut builder = HttpResponse::Ok();
+
+    // Only successful GET responses are safe for caches to store
+    if http_req.method() == http::Method::GET && resp.is_ok() {
+        builder.insert_header((
+            http::header::CACHE_CONTROL,
+            data.persisted_queries.cache_control(),
+        ));
+    }
+
+    builder.json(&resp)
+}
+
+async fn graphql(
+    data: web::Data<SharedData>,
+    req: web::Json<Request>,
+    conn: ConnectionInfo,
+    http_req: HttpRequest,
+) -> Result<HttpResponse, Error> {
+    Ok(execute(&data, req.into_inner(), &conn, &http_req).await)
+}
+
+async fn graphql_get(
+    data: web::Data<SharedData>,
+    params: web::Query<GetParams>,
+    conn: ConnectionInfo,
+    http_req: HttpRequest,
+) -> Result<HttpResponse, Error> {
+    Ok(match Request::try_from(params.into_inner()) {
+        Ok(req) => execute(&data, req, &conn, &http_req).await,
+        Err(e) => bad_request(format!("Invalid request parameters: {e}")),
+    })
+}
+
+/// The interval between keep-alive messages sent to subscription clients
+const SUBSCRIPTION_KEEP_ALIVE: StdDuration = StdDuration::from_secs(15);
+
+/// Apply the API key, persisted query and complexity checks of HTTP requests
+/// to a message received from a subscription client, returning either the
+/// message to forward to the connection or the message to reply with
+fn check_subscription_message(
+    data: &SharedData,
+    key: Option<&str>,
+    remote_addr: &str,
+    text: &str,
+) -> Result<ClientMessage<DefaultScalarValue>, serde_json::Value> {
+    let connection_error = |e: serde_json::Error| {
+        serde_json::json!({
+            "type": "connection_error",
+            "payload": { "message": format!("Invalid subscription message: {e}") },
+        })
+    };
+
+    let mut msg: serde_json::Value = serde_json::from_str(text).map_err(connection_error)?;
+
+    if msg.get("type").and_then(serde_json::Value::as_str) != Some("start") {
+        return serde_json::from_value(msg).map_err(connection_error);
+    }
+
+    let id = msg.get("id").cloned().unwrap_or_default();
+    let error = |payload: serde_json::Value| {
+        serde_json::json!({
+            "type": "error",
+            "id": id,
+            "payload": payload,
+        })
+    };
+
+    let mut req: Request = msg
+        .get_mut("payload")
+        .map(serde_json::Value::take)
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(connection_error)?
+        .ok_or_else(|| error(serde_json::json!({ "message": "Missing start payload" })))?;
+
+    let client = data
+        .api_keys
+        .authorize(key, remote_addr)
+        .map_err(|e| error(e.to_error()))?;
+
+    let query = data
+        .persisted_queries
+        .resolve(&data.db, &client, req.query.take(), req.extensions.as_ref())
+        .map_err(|e| error(e.to_error()))?;
+
+    let variables = req.variables.unwrap_or_else(|| serde_json::json!({}));
+
+    let complexity = data
+        .query_limits
+        .check(
+            &data.query_types,
+            &query,
+            req.operation_name.as_deref(),
+            &variables,
+        )
+        .map_err(|e| {
+            warn!(
+                "Rejected complex graphql subscription remote_addr={:?}: {:?}",
+                remote_addr, e
+            );
+
+            error(e.to_error())
+        })?;
+
+    data.api_keys
+        .record(&client, complexity.map_or(0, |c| c.cost));
+
+    serde_json::from_value(serde_json::json!({
+        "type": "start",
+        "id": id,
+        "payload": {
+            "query": query,
+            "variables": variables,
+            "operationName": req.operation_name,
+        },
+    }))
+    .map_err(connection_error)
+}
+
+async fn subscriptions(
+    data: web::Data<SharedData>,
+    req: HttpRequest,
+    body: web::Payload,
+) -> Result<HttpResponse, Error> {
+    let key = req
+        .headers()
+        .get(api_keys::API_KEY_HEADER)
+        .map(|k| k.to_str().unwrap_or_default().to_owned());
+    let remote_addr = data.api_keys.remote_addr(&req);
+
+    let (mut resp, session, mut messages) = actix_ws::handle(&req, body)?;
+
+    // Clients of the graphql-ws protocol require it to be acknowledged
+    resp.headers_mut().insert(
+        http::header::SEC_WEBSOCKET_PROTOCOL,
+        http::header::HeaderValue::from_static("graphql-ws"),
+    );
+
+    let ctx = AppContext::new(data.clone().into_inner());
+    let config = ConnectionConfig::new(ctx).with_keep_alive_interval(SUBSCRIPTION_KEEP_ALIVE);
+    let (mut sink, mut stream) = Connection::new(Arc::clone(&data.schema), config).split();
+
+    let mut send_session = session.clone();
+    actix_web::rt::spawn(async move {
+        while let Some(msg) = stream.next().await {
+            let text = match serde_json::to_string(&msg) {
+                Ok(t) => t,
+                Err(e) => {
+                    error!("Failed to serialize subscription message: {}", e);
+                    continue;
+                },
+            };
+
+            if send_session.text(text).await.is_err() {
+                break;
+            }
         }
 
-        match serde_json::to_value(&req).and_then(serde_json::from_value) {
-            Ok(Data {
-                query,
-                operation_name,
-                variables,
-            }) => warn!(
-                "Long graphql request query={}, operation={:?}, variables={}, duration={}",
-                query,
-                operation_name,
-                variables,
-                duration_hhmmssfff(duration),
-            ),
-            Err(e) => error!("Failed to format long query for printing: {}", e),
+        send_session.close(None).await.ok();
+    });
+
+    let mut recv_session = session;
+    actix_web::rt::spawn(async move {
+        while let Some(Ok(msg)) = messages.next().await {
+            let msg = match msg {
+                actix_ws::Message::Text(text) => {
+                    match check_subscription_message(&data, key.as_deref(), &remote_addr, &text) {
+                        Ok(m) => m,
+                        Err(reply) => {
+                            if recv_session.text(reply.to_string()).await.is_err() {
+                                break;
+                            }
+
+                            continue;
+                        },
+                    }
+                },
+                actix_ws::Message::Ping(bytes) => {
+                    if recv_session.pong(&bytes).await.is_err() {
+                        break;
+                    }
+
+                    continue;
+                },
+                actix_ws::Message::Close(_) => ClientMessage::ConnectionTerminate,
+                _ => continue,
+            };
+
+            if sink.send(msg).await.is_err() {
+                break;
+            }
         }
-    }
+    });
 
-    Ok(HttpResponse::Ok().json(&resp))
+    Ok(resp)
 }
 
 fn main() {
@@ -186,6 +457,10 @@ fn main() {
             featured_listings_seller_exclusions,
             marketplaces_store_address_exclusions,
             pre_query_search_limit,
+            live_events_database_url,
+            query_limits,
+            api_keys,
+            persisted_queries,
         } = opts;
 
         let (addr,) = server.into_parts();
@@ -200,12 +475,27 @@ fn main() {
             migrated: _,
         } = db::connect(db, db::ConnectMode::Read).context("Failed to connect to Postgres")?;
         let db = Arc::new(pool);
+        let api_keys = Arc::new(ApiKeys::new(api_keys, &db)?);
+        let persisted_queries = PersistedQueries::new(persisted_queries)?;
         let search = search.into_client();
         let rpc = RpcClient::new(solana_endpoint);
 
+        let live_events = LiveEvents::default();
+
+        if live_events_database_url.is_none() {
+            warn!("No live events database configured, subscriptions will be idle");
+        }
+
+        let schema = schema::create();
+        let query_types = complexity::SchemaTypes::new(&schema);
+
         let shared = web::Data::new(SharedData {
-            schema: schema::create(),
-            db,
+            schema: Arc::new(schema),
+            query_limits,
+            query_types,
+            api_keys: Arc::clone(&api_keys),
+            persisted_queries,
+            db: Arc::clone(&db),
             asset_proxy,
             twitter_bearer_token,
             search,
@@ -218,6 +508,7 @@ fn main() {
             pre_query_search_limit,
             dolphin_key,
             coingecko_endpoint,
+            live_events: live_events.clone(),
         });
 
         let version_extension = "/v1";
@@ -234,7 +525,13 @@ fn main() {
         assert!(graphiql_data.uri.starts_with('/'));
 
         actix_web::rt::System::new()
-            .block_on(
+            .block_on(async move {
+                if let Some(url) = live_events_database_url {
+                    live_events.listen(url);
+                }
+
+                api_keys.run(db);
+
                 HttpServer::new(move || {
                     App::new()
                         .wrap(
@@ -246,12 +543,21 @@ fn main() {
                                     http::header::ACCEPT,
                                 ])
                                 .allowed_header(http::header::CONTENT_TYPE)
+                                .allowed_header(http::header::HeaderName::from_static(
+                                    api_keys::API_KEY_HEADER,
+                                ))
                                 .max_age(3600),
                         )
                         .service(
                             web::resource(version_extension)
                                 .app_data(shared.clone())
-                                .route(web::post().to(graphql)),
+                                .route(web::post().to(graphql))
+                                .route(web::get().to(graphql_get)),
+                        )
+                        .service(
+                            web::resource(format!("{version_extension}/subscriptions"))
+                                .app_data(shared.clone())
+                                .route(web::get().to(subscriptions)),
                         )
                         .service(
                             web::resource(redirect_data.route)
@@ -265,8 +571,9 @@ fn main() {
                         )
                 })
                 .bind(addr)?
-                .run(),
-            )
+                .run()
+                .await
+            })
             .context("Actix server failed to run")
     });
 }
@@ -0,0 +1,603 @@
+//! Automatic persisted queries
+//!
+//! Clients may send the SHA-256 hash of a query in place of its text, following
+//! Apollo's automatic persisted queries protocol.  If the hash is unknown the
+//! client retries with the full query, which is then registered under its hash
+//! if the client authenticated with an API key.
+//! Combined with GET requests this keeps request URLs short and stable, so
+//! responses can be cached by a CDN.
+//!
+//! In allow-list mode only queries registered ahead of time with
+//! `allow_listed` set may run, and clients cannot register new queries.
+
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+
+use actix_web::HttpResponse;
+use indexer_core::{
+    clap,
+    db::{insert_into, models, tables::persisted_queries, ConnectionManager, Pool},
+    prelude::*,
+};
+use lru::LruCache;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use crate::api_keys::Client;
+
+/// The only version of the persisted query protocol
+const PROTOCOL_VERSION: u32 = 1;
+/// The time for which a hash not found in the database is remembered as
+/// missing
+const MISSING_QUERY_TTL: StdDuration = StdDuration::from_secs(60);
+
+/// Command-line arguments for persisted queries
+#[derive(Debug, Clone, clap::Args)]
+#[group(skip)]
+pub struct Args {
+    /// Only run queries registered in the persisted query allow-list
+    #[arg(long, env, default_value_t = false)]
+    persisted_queries_only: bool,
+
+    /// Maximum number of persisted query hashes to cache in memory
+    ///
+    /// The least recently used entries are evicted first, and hashes found
+    /// to be missing from the database count towards this limit.
+    #[arg(long, env, default_value_t = 10_000)]
+    persisted_query_cache_size: usize,
+
+    /// Connection string for a writable database to register automatically
+    /// persisted queries in.  Queries are only cached in memory if this is
+    /// unset.
+    #[arg(long, env)]
+    persisted_queries_database_url: Option<String>,
+
+    /// Number of seconds caches may store successful responses to GET
+    /// requests
+    #[arg(long, env, default_value_t = 10)]
+    get_cache_max_age: u32,
+}
+
+/// The `persistedQuery` request extension
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedQuery {
+    version: u32,
+    sha256_hash: String,
+}
+
+/// Protocol extensions of a GraphQL request
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Extensions {
+    #[serde(default)]
+    persisted_query: Option<PersistedQuery>,
+}
+
+/// A request whose query could not be resolved
+#[derive(Debug, Clone, Copy)]
+pub enum Rejection {
+    /// No query was registered under the hash given
+    NotFound,
+    /// The query is not in the allow-list
+    NotAllowed,
+    /// The hash given does not match the query
+    HashMismatch,
+    /// The persisted query protocol version is not supported
+    UnsupportedVersion,
+    /// Neither a query nor a hash was given
+    MissingQuery,
+}
+
+impl Rejection {
+    /// Render this rejection as a GraphQL error
+    #[must_use]
+    pub fn to_error(self) -> serde_json::Value {
+        let (message, code) = match self {
+            Self::NotFound => ("PersistedQueryNotFound", "PERSISTED_QUERY_NOT_FOUND"),
+            Self::NotAllowed => (
+                "Query is not in the persisted query allow-list",
+                "PERSISTED_QUERY_NOT_ALLOWED",
+            ),
+            Self::HashMismatch => (
+                "Provided sha256Hash does not match query",
+                "PERSISTED_QUERY_HASH_MISMATCH",
+            ),
+            Self::UnsupportedVersion => (
+                "Unsupported persisted query version",
+                "PERSISTED_QUERY_UNSUPPORTED_VERSION",
+            ),
+            Self::MissingQuery => (
+                "Must provide a query or persisted query hash",
+                "BAD_REQUEST",
+            ),
+        };
+
+        json!({
+            "message": message,
+            "extensions": { "code": code },
+        })
+    }
+
+    /// Render this rejection as an HTTP response
+    #[must_use]
+    pub fn to_response(self) -> HttpResponse {
+        let mut resp = match self {
+            // Clients retry with the full query upon receiving this error,
+            // which Apollo clients only recognize in a successful response
+            Self::NotFound => HttpResponse::Ok(),
+            Self::NotAllowed
+            | Self::HashMismatch
+            | Self::UnsupportedVersion
+            | Self::MissingQuery => HttpResponse::BadRequest(),
+        };
+
+        resp.json(json!({ "errors": [self.to_error()] }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedQuery {
+    query: String,
+    allow_listed: bool,
+}
+
+#[derive(Debug, Clone)]
+enum CacheEntry {
+    Found(CachedQuery),
+    /// The hash was not registered when looked up at the given time
+    Missing(Instant),
+}
+
+/// Hash a query for lookup in the `persisted_queries` table
+fn hash_query(query: &str) -> String {
+    format!("{:x}", Sha256::digest(query.as_bytes()))
+}
+
+/// Lookup and registration of persisted queries
+#[derive(Debug)]
+pub struct PersistedQueries {
+    allow_list_only: bool,
+    get_cache_max_age: u32,
+    write_db: Option<Pool>,
+    cache: Mutex<LruCache<String, CacheEntry>>,
+}
+
+impl PersistedQueries {
+    /// Connect to the database for registering queries, if one is configured
+    ///
+    /// # Errors
+    /// This function fails if the database connection pool cannot be created.
+    pub fn new(args: Args) -> Result<Self> {
+        let Args {
+            persisted_queries_only,
+            persisted_query_cache_size,
+            persisted_queries_database_url,
+            get_cache_max_age,
+        } = args;
+
+        let write_db = persisted_queries_database_url
+            .map(|url| {
+                Pool::builder()
+                    .max_size(2)
+                    .min_idle(Some(0))
+                    .build(ConnectionManager::new(url))
+                    .context("Failed to create persisted query database connection pool")
+            })
+            .transpose()?;
+
+        if persisted_queries_only {
+            info!("Only allow-listed persisted queries will be run");
+        } else if write_db.is_none() {
+            warn!("No persisted query database configured, queries will only be cached in memory");
+        }
+
+        Ok(Self {
+            allow_list_only: persisted_queries_only,
+            get_cache_max_age,
+            write_db,
+            cache: Mutex::new(LruCache::new(persisted_query_cache_size.max(1))),
+        })
+    }
+
+    /// The value of the `Cache-Control` header for successful responses to
+    /// GET requests
+    #[must_use]
+    pub fn cache_control(&self) -> String {
+        format!("public, max-age={}", self.get_cache_max_age)
+    }
+
+    /// Determine the query to execute for a request, registering it if the
+    /// request carries both a query and its hash and was made with an API key
+    ///
+    /// # Errors
+    /// This function fails if no query is given and the hash given is not
+    /// registered, or if the query is not allowed to run.
+    pub fn resolve(
+        &self,
+        db: &Pool,
+        client: &Client,
+        query: Option<String>,
+        extensions: Option<&Extensions>,
+    ) -> Result<String, Rejection> {
+        let Some(persisted) = extensions.and_then(|e| e.persisted_query.as_ref()) else {
+            let query = query.ok_or(Rejection::MissingQuery)?;
+
+            if self.allow_list_only {
+                self.check_allowed(db, &hash_query(&query))?;
+            }
+
+            return Ok(query);
+        };
+
+        if persisted.version != PROTOCOL_VERSION {
+            return Err(Rejection::UnsupportedVersion);
+        }
+
+        let hash = persisted.sha256_hash.to_ascii_lowercase();
+
+        let Some(query) = query else {
+            let cached = self.lookup(db, &hash).ok_or(Rejection::NotFound)?;
+
+            if self.allow_list_only && !cached.allow_listed {
+                return Err(Rejection::NotAllowed);
+            }
+
+            return Ok(cached.query);
+        };
+
+        if hash_query(&query) != hash {
+            return Err(Rejection::HashMismatch);
+        }
+
+        if self.allow_list_only {
+            self.check_allowed(db, &hash)?;
+        } else if let Client::Key(_) = client {
+            self.register(db, hash, &query);
+        }
+
+        Ok(query)
+    }
+
+    fn check_allowed(&self, db: &Pool, hash: &str) -> Result<(), Rejection> {
+        match self.lookup(db, hash) {
+            Some(CachedQuery {
+                allow_listed: true, ..
+            }) => Ok(()),
+            _ => Err(Rejection::NotAllowed),
+        }
+    }
+
+    fn cache(&self, hash: String, entry: CacheEntry) {
+        self.cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .put(hash, entry);
+    }
+
+    fn lookup(&self, db: &Pool, hash: &str) -> Option<CachedQuery> {
+        match self
+            .cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(hash)
+        {
+            Some(CacheEntry::Found(q)) => return Some(q.clone()),
+            Some(CacheEntry::Missing(at)) if at.elapsed() < MISSING_QUERY_TTL => return None,
+            Some(CacheEntry::Missing(_)) | None => (),
+        }
+
+        let res = db
+            .get()
+            .context("Failed to connect to db")
+            .and_then(|conn| {
+                persisted_queries::table
+                    .filter(persisted_queries::hash.eq(hash))
+                    .select(persisted_queries::all_columns)
+                    .first::<models::PersistedQuery>(&conn)
+                    .optional()
+                    .context("Failed to load persisted query")
+            });
+
+        let row = match res {
+            Ok(Some(r)) => r,
+            Ok(None) => {
+                self.cache(hash.to_owned(), CacheEntry::Missing(Instant::now()));
+                return None;
+            },
+            Err(e) => {
+                error!("Persisted query lookup failed: {:?}", e);
+                return None;
+            },
+        };
+
+        let query = CachedQuery {
+            query: row.query.into_owned(),
+            allow_listed: row.allow_listed,
+        };
+        self.cache(row.hash.into_owned(), CacheEntry::Found(query.clone()));
+
+        Some(query)
+    }
+
+    fn register(&self, db: &Pool, hash: String, query: &str) {
+        if self.lookup(db, &hash).is_some() {
+            return;
+        }
+
+        if let Some(ref write_db) = self.write_db {
+            let res = write_db
+                .get()
+                .context("Failed to connect to persisted query database")
+                .and_then(|conn| {
+                    insert_into(persisted_queries::table)
+                        .values(&models::PersistedQuery {
+                            hash: Cow::Borrowed(&hash),
+                            query: Cow::Borrowed(query),
+                            allow_listed: false,
+                            created_at: Utc::now().naive_utc(),
+                        })
+                        .on_conflict_do_nothing()
+                        .execute(&conn)
+                        .context("Failed to insert persisted query")
+                });
+
+            if let Err(e) = res {
+                error!("Failed to register persisted query: {:?}", e);
+            }
+        }
+
+        self.cache(
+            hash,
+            CacheEntry::Found(CachedQuery {
+                query: query.to_owned(),
+                allow_listed: false,
+            }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use indexer_core::db::sql_query;
+
+    use super::*;
+    use crate::api_keys::Key;
+
+    const QUERY: &str = "{ stats { mintCount } }";
+
+    fn persisted_queries(allow_list_only: bool, cache_size: usize) -> PersistedQueries {
+        PersistedQueries {
+            allow_list_only,
+            get_cache_max_age: 10,
+            write_db: None,
+            cache: Mutex::new(LruCache::new(cache_size)),
+        }
+    }
+
+    /// A pool whose connections always fail, so every lookup missing the
+    /// cache finds nothing
+    fn db() -> Pool {
+        Pool::builder()
+            .connection_timeout(StdDuration::from_millis(10))
+            .build_unchecked(ConnectionManager::new("postgres://127.0.0.1:1/none"))
+    }
+
+    fn key() -> Client {
+        Client::Key(Arc::new(Key::test("test")))
+    }
+
+    fn anonymous() -> Client {
+        Client::Anonymous("127.0.0.1".into())
+    }
+
+    fn extensions(hash: &str) -> Extensions {
+        Extensions {
+            persisted_query: Some(PersistedQuery {
+                version: PROTOCOL_VERSION,
+                sha256_hash: hash.into(),
+            }),
+        }
+    }
+
+    fn cached(pq: &PersistedQueries, hash: &str) -> Option<CacheEntry> {
+        pq.cache.lock().unwrap().peek(hash).cloned()
+    }
+
+    #[test]
+    fn test_plain_query() {
+        let pq = persisted_queries(false, 4);
+        let db = db();
+
+        assert_eq!(
+            pq.resolve(&db, &anonymous(), Some(QUERY.into()), None)
+                .unwrap(),
+            QUERY
+        );
+        assert!(matches!(
+            pq.resolve(&db, &anonymous(), None, None),
+            Err(Rejection::MissingQuery)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_persisted_query() {
+        let pq = persisted_queries(false, 4);
+        let db = db();
+
+        let mut ext = extensions(&hash_query(QUERY));
+        ext.persisted_query.as_mut().unwrap().version = 2;
+        assert!(matches!(
+            pq.resolve(&db, &key(), Some(QUERY.into()), Some(&ext)),
+            Err(Rejection::UnsupportedVersion)
+        ));
+
+        let ext = extensions(&hash_query("{ other }"));
+        assert!(matches!(
+            pq.resolve(&db, &key(), Some(QUERY.into()), Some(&ext)),
+            Err(Rejection::HashMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_register_with_key() {
+        let pq = persisted_queries(false, 4);
+        let db = db();
+        let hash = hash_query(QUERY);
+        let ext = extensions(&hash.to_ascii_uppercase());
+
+        assert!(matches!(
+            pq.resolve(&db, &key(), None, Some(&ext)),
+            Err(Rejection::NotFound)
+        ));
+        assert_eq!(
+            pq.resolve(&db, &key(), Some(QUERY.into()), Some(&ext))
+                .unwrap(),
+            QUERY
+        );
+        assert_eq!(
+            pq.resolve(&db, &anonymous(), None, Some(&ext)).unwrap(),
+            QUERY
+        );
+    }
+
+    #[test]
+    fn test_anonymous_does_not_register() {
+        let pq = persisted_queries(false, 4);
+        let db = db();
+        let hash = hash_query(QUERY);
+        let ext = extensions(&hash);
+
+        assert_eq!(
+            pq.resolve(&db, &anonymous(), Some(QUERY.into()), Some(&ext))
+                .unwrap(),
+            QUERY
+        );
+        assert!(matches!(
+            pq.resolve(&db, &anonymous(), None, Some(&ext)),
+            Err(Rejection::NotFound)
+        ));
+        assert!(cached(&pq, &hash).is_none());
+    }
+
+    /// Connect to the database named by `TEST_DATABASE_URL`, or return `None`
+    /// to skip tests requiring a database
+    ///
+    /// The pool holds a single connection, in which a temporary
+    /// `persisted_queries` table shadows any existing one.
+    fn connect() -> Option<Pool> {
+        let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return None;
+        };
+
+        let pool = Pool::builder()
+            .max_size(1)
+            .build(ConnectionManager::new(url))
+            .unwrap();
+
+        sql_query(
+            "CREATE TEMPORARY TABLE persisted_queries (
+                hash TEXT PRIMARY KEY,
+                query TEXT NOT NULL,
+                allow_listed BOOL NOT NULL,
+                created_at TIMESTAMP NOT NULL
+            )",
+        )
+        .execute(&pool.get().unwrap())
+        .unwrap();
+
+        Some(pool)
+    }
+
+    #[test]
+    fn test_missing_hash_cached() {
+        let Some(db) = connect() else { return };
+        let pq = persisted_queries(false, 4);
+        let hash = hash_query(QUERY);
+
+        assert!(pq.lookup(&db, &hash).is_none());
+        assert!(matches!(cached(&pq, &hash), Some(CacheEntry::Missing(_))));
+
+        insert_into(persisted_queries::table)
+            .values(&models::PersistedQuery {
+                hash: Cow::Borrowed(&hash),
+                query: Cow::Borrowed(QUERY),
+                allow_listed: false,
+                created_at: Utc::now().naive_utc(),
+            })
+            .execute(&db.get().unwrap())
+            .unwrap();
+
+        assert!(
+            pq.lookup(&db, &hash).is_none(),
+            "missing hash should not be looked up again"
+        );
+
+        let expired = Instant::now().checked_sub(MISSING_QUERY_TTL).unwrap();
+        pq.cache(hash.clone(), CacheEntry::Missing(expired));
+
+        assert_eq!(pq.lookup(&db, &hash).unwrap().query, QUERY);
+        assert!(matches!(cached(&pq, &hash), Some(CacheEntry::Found(_))));
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recent() {
+        let pq = persisted_queries(false, 2);
+        let db = db();
+        let queries = ["{ a }", "{ b }", "{ c }"];
+
+        for query in &queries[..2] {
+            let ext = extensions(&hash_query(query));
+            pq.resolve(&db, &key(), Some((*query).into()), Some(&ext))
+                .unwrap();
+        }
+
+        // Touch the first query so the second is evicted instead
+        let ext = extensions(&hash_query(queries[0]));
+        pq.resolve(&db, &anonymous(), None, Some(&ext)).unwrap();
+
+        let ext = extensions(&hash_query(queries[2]));
+        pq.resolve(&db, &key(), Some(queries[2].into()), Some(&ext))
+            .unwrap();
+
+        assert!(cached(&pq, &hash_query(queries[0])).is_some());
+        assert!(cached(&pq, &hash_query(queries[1])).is_none());
+        assert!(cached(&pq, &hash_query(queries[2])).is_some());
+    }
+
+    #[test]
+    fn test_allow_list() {
+        let pq = persisted_queries(true, 4);
+        let db = db();
+        let hash = hash_query(QUERY);
+        let ext = extensions(&hash);
+
+        assert!(matches!(
+            pq.resolve(&db, &key(), Some(QUERY.into()), Some(&ext)),
+            Err(Rejection::NotAllowed)
+        ));
+
+        pq.cache(
+            hash,
+            CacheEntry::Found(CachedQuery {
+                query: QUERY.into(),
+                allow_listed: true,
+            }),
+        );
+
+        assert_eq!(
+            pq.resolve(&db, &anonymous(), Some(QUERY.into()), None)
+                .unwrap(),
+            QUERY
+        );
+        assert_eq!(
+            pq.resolve(&db, &anonymous(), None, Some(&ext)).unwrap(),
+            QUERY
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! GraphQL requests received over HTTP
+
+use indexer_core::prelude::*;
+use juniper::{http::GraphQLRequest, InputValue};
+
+use crate::persisted_queries::Extensions;
+
+/// A GraphQL request, whose query may be omitted in favor of a persisted
+/// query hash
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    /// The query document
+    #[serde(default)]
+    pub query: Option<String>,
+    /// The name of the operation to execute
+    #[serde(default)]
+    pub operation_name: Option<String>,
+    /// The operation variables, as a JSON object
+    #[serde(default)]
+    pub variables: Option<serde_json::Value>,
+    /// Protocol extensions, such as persisted queries
+    #[serde(default)]
+    pub extensions: Option<Extensions>,
+}
+
+/// The query string of a GraphQL GET request, in which variables and
+/// extensions are encoded as JSON
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetParams {
+    query: Option<String>,
+    operation_name: Option<String>,
+    variables: Option<String>,
+    extensions: Option<String>,
+}
+
+impl TryFrom<GetParams> for Request {
+    type Error = serde_json::Error;
+
+    fn try_from(params: GetParams) -> Result<Self, Self::Error> {
+        let GetParams {
+            query,
+            operation_name,
+            variables,
+            extensions,
+        } = params;
+
+        Ok(Self {
+            query: query.filter(|q| !q.is_empty()),
+            operation_name: operation_name.filter(|o| !o.is_empty()),
+            variables: variables.as_deref().map(serde_json::from_str).transpose()?,
+            extensions: extensions
+                .as_deref()
+                .map(serde_json::from_str)
+                .transpose()?,
+        })
+    }
+}
+
+impl Request {
+    /// Construct the request to execute with the given resolved query
+    ///
+    /// # Errors
+    /// This function fails if the variables are not valid input values.
+    pub fn to_graphql(&self, query: String) -> Result<GraphQLRequest> {
+        let variables = self
+            .variables
+            .clone()
+            .map(serde_json::from_value::<InputValue>)
+            .transpose()
+            .context("Invalid query variables")?;
+
+        Ok(GraphQLRequest::new(
+            query,
+            self.operation_name.clone(),
+            variables,
+        ))
+    }
+}
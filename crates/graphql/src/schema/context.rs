@@ -5,22 +5,26 @@ use dataloaders::{
 };
 use indexer_core::uuid::Uuid;
 use objects::{
-    ah_listing::AhListing,
+    ah_listing::{AhListing, ListingAuction},
     ah_offer::Offer as AhOffer,
     ah_purchase::Purchase as AhPurchase,
     auction_house::AuctionHouse,
     bid_receipt::BidReceipt,
+    candy_guard::{CandyGuard, CandyGuardGroup},
     candy_machine::{
-        CandyMachine, CandyMachineCollectionPda, CandyMachineConfigLine, CandyMachineCreator,
-        CandyMachineEndSetting, CandyMachineGateKeeperConfig, CandyMachineHiddenSetting,
-        CandyMachineWhitelistMintSetting,
+        CandyMachine, CandyMachineCollectionPda, CandyMachineConfigLine,
+        CandyMachineConfigLineSetting, CandyMachineCreator, CandyMachineEndSetting,
+        CandyMachineGateKeeperConfig, CandyMachineHiddenSetting, CandyMachineWhitelistMintSetting,
     },
     collection::{Collection, CollectionTrend},
     genopets::{GenoHabitat, GenoRentalAgreement},
     graph_connection::GraphConnection,
     listing::{Bid, Listing},
     listing_receipt::ListingReceipt,
-    nft::{CollectionNFT, Nft, NftActivity, NftAttribute, NftCreator, NftFile, NftOwner},
+    nft::{
+        CollectionDetails, CollectionNFT, Nft, NftActivity, NftAttribute, NftCreator, NftFile,
+        NftOwner, NftUses,
+    },
     profile::TwitterProfile,
     purchase_receipt::PurchaseReceipt,
     reward_center::RewardCenter,
@@ -51,10 +55,14 @@ pub struct AppContext {
     pub reward_center_loader: Loader<PublicKey<AuctionHouse>, Option<RewardCenter>>,
     pub bid_receipt_loader: Loader<PublicKey<BidReceipt>, Option<BidReceipt>>,
     pub bid_receipts_loader: Loader<PublicKey<Nft>, Vec<BidReceipt>>,
+    pub candy_guard_loader: Loader<PublicKey<CandyGuard>, Option<CandyGuard>>,
+    pub candy_guard_groups_loader: Loader<PublicKey<CandyGuard>, Vec<CandyGuardGroup>>,
     pub candy_machine_collection_pda_loader:
         Loader<PublicKey<CandyMachine>, Option<CandyMachineCollectionPda>>,
     pub candy_machine_config_line_loader:
         Loader<PublicKey<CandyMachine>, Vec<CandyMachineConfigLine>>,
+    pub candy_machine_config_line_settings_loader:
+        Loader<PublicKey<CandyMachine>, Option<CandyMachineConfigLineSetting>>,
     pub candy_machine_creator_loader: Loader<PublicKey<CandyMachine>, Vec<CandyMachineCreator>>,
     pub candy_machine_end_settings_loader:
         Loader<PublicKey<CandyMachine>, Option<CandyMachineEndSetting>>,
@@ -87,17 +95,20 @@ pub struct AppContext {
     pub mr_collection_trends_loader: Loader<String, Option<CollectionTrend>>,
     pub nft_activities_loader: Loader<PublicKey<Nft>, Vec<NftActivity>>,
     pub nft_attributes_loader: Loader<PublicKey<Nft>, Vec<NftAttribute>>,
+    pub nft_auctions_loader: Loader<PublicKey<Nft>, Vec<ListingAuction>>,
     pub nft_by_mint_loader: Loader<PublicKey<TokenMint>, Option<Nft>>,
     pub nft_moonrank_collection_loader: Loader<PublicKey<TokenMint>, Option<Collection>>,
     pub nft_moonrank_rank_loader: Loader<PublicKey<TokenMint>, Option<MoonrankRank>>,
     pub nft_last_sale_loader: Loader<PublicKey<Nft>, Option<LastSale>>,
     pub metaplex_certified_collection_loader: Loader<PublicKey<Nft>, Option<CollectionNFT>>,
+    pub collection_details_loader: Loader<PublicKey<Nft>, Option<CollectionDetails>>,
     pub generic_collection_loader:
         Loader<objects::collection::CollectionId, Option<objects::collection::Collection>>,
     pub nft_creators_loader: Loader<PublicKey<Nft>, Vec<NftCreator>>,
     pub nft_files_loader: Loader<PublicKey<Nft>, Vec<NftFile>>,
     pub nft_loader: Loader<PublicKey<Nft>, Option<Nft>>,
     pub nft_owner_loader: Loader<PublicKey<Nft>, Option<NftOwner>>,
+    pub nft_uses_loader: Loader<PublicKey<Nft>, Option<NftUses>>,
     pub offer_loader: Loader<Uuid, Option<AhOffer>>,
     pub offers_loader: Loader<PublicKey<Nft>, Vec<AhOffer>>,
     pub purchase_loader: Loader<Uuid, Option<AhPurchase>>,
@@ -143,8 +154,11 @@ impl AppContext {
             reward_center_loader: Loader::new(batcher.clone()),
             bid_receipt_loader: Loader::new(batcher.clone()),
             bid_receipts_loader: Loader::new(batcher.clone()),
+            candy_guard_loader: Loader::new(batcher.clone()),
+            candy_guard_groups_loader: Loader::new(batcher.clone()),
             candy_machine_collection_pda_loader: Loader::new(batcher.clone()),
             candy_machine_config_line_loader: Loader::new(batcher.clone()),
+            candy_machine_config_line_settings_loader: Loader::new(batcher.clone()),
             candy_machine_creator_loader: Loader::new(batcher.clone()),
             candy_machine_end_settings_loader: Loader::new(batcher.clone()),
             candy_machine_gatekeeper_configs_loader: Loader::new(batcher.clone()),
@@ -170,16 +184,19 @@ impl AppContext {
             mr_collection_trends_loader: Loader::new(batcher.clone()),
             nft_activities_loader: Loader::new(batcher.clone()),
             nft_attributes_loader: Loader::new(batcher.clone()),
+            nft_auctions_loader: Loader::new(batcher.clone()),
             nft_by_mint_loader: Loader::new(batcher.clone()),
             nft_moonrank_collection_loader: Loader::new(batcher.clone()),
             nft_moonrank_rank_loader: Loader::new(batcher.clone()),
             nft_last_sale_loader: Loader::new(batcher.clone()),
             generic_collection_loader: Loader::new(batcher.clone()),
             metaplex_certified_collection_loader: Loader::new(batcher.clone()),
+            collection_details_loader: Loader::new(batcher.clone()),
             nft_creators_loader: Loader::new(batcher.clone()),
             nft_files_loader: Loader::new(batcher.clone()),
             nft_loader: Loader::new(batcher.clone()),
             nft_owner_loader: Loader::new(batcher.clone()),
+            nft_uses_loader: Loader::new(batcher.clone()),
             offer_loader: Loader::new(batcher.clone()),
             offers_loader: Loader::new(batcher.clone()),
             purchase_loader: Loader::new(batcher.clone()),
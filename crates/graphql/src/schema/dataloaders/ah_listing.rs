@@ -1,7 +1,12 @@
 use indexer_core::{pubkeys, uuid::Uuid};
-use objects::{ah_listing::AhListing, nft::Nft};
+use objects::{
+    ah_listing::{AhListing, ListingAuction},
+    nft::Nft,
+};
 use scalars::PublicKey;
-use tables::{current_metadata_owners, listings, metadatas};
+use tables::{
+    auctioneer_listing_configs, auctioneer_listings, current_metadata_owners, listings, metadatas,
+};
 
 use super::prelude::*;
 
@@ -57,3 +62,33 @@ impl TryBatchFn<PublicKey<Nft>, Vec<AhListing>> for Batcher {
             .batch(addresses))
     }
 }
+
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Vec<ListingAuction>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Vec<ListingAuction>> {
+        let conn = self.db()?;
+
+        let rows: Vec<(models::AuctioneerListing, models::AuctioneerListingConfig)> =
+            auctioneer_listings::table
+                .inner_join(auctioneer_listing_configs::table.on(
+                    auctioneer_listing_configs::address.eq(auctioneer_listings::listing_config),
+                ))
+                .select((
+                    auctioneer_listings::all_columns,
+                    auctioneer_listing_configs::all_columns,
+                ))
+                .filter(auctioneer_listings::canceled_at.is_null())
+                .filter(auctioneer_listings::purchase_id.is_null())
+                .filter(auctioneer_listings::metadata.eq(any(addresses)))
+                .load(&conn)
+                .context("Failed to load listing auctions")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(listing, config)| (listing.metadata.clone(), (listing, config).try_into()))
+            .batch(addresses))
+    }
+}
@@ -2,13 +2,16 @@ use indexer_core::db::{queries, tables::last_sold_metadatas};
 use objects::{
     collection::Collection,
     listing_receipt::ListingReceipt,
-    nft::{Nft, NftActivity, NftAttribute, NftCreator, NftFile, NftOwner},
+    nft::{
+        CollectionDetails, Nft, NftActivity, NftAttribute, NftCreator, NftFile, NftOwner, NftUses,
+    },
     purchase_receipt::PurchaseReceipt,
 };
 use scalars::{markers::TokenMint, PublicKey};
 use tables::{
-    attributes, collection_mints, collections, current_metadata_owners, files, listing_receipts,
-    metadata_creators, metadata_jsons, metadatas, purchase_receipts, twitter_handle_name_services,
+    attributes, collection_mints, collections, compressed_assets, current_metadata_owners, files,
+    listing_receipts, metadata_collection_details, metadata_creators, metadata_jsons,
+    metadata_uses, metadatas, purchase_receipts, twitter_handle_name_services,
 };
 
 use super::prelude::*;
@@ -110,6 +113,48 @@ impl TryBatchFn<PublicKey<Nft>, Option<LastSale>> for Batcher {
     }
 }
 
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Option<NftUses>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Option<NftUses>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::MetadataUse> = metadata_uses::table
+            .filter(metadata_uses::metadata_address.eq(any(addresses)))
+            .select(metadata_uses::all_columns)
+            .load(&conn)
+            .context("Failed to load NFT uses")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|uses| (uses.metadata_address.clone(), uses.try_into()))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Option<CollectionDetails>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Option<CollectionDetails>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::MetadataCollectionDetails> = metadata_collection_details::table
+            .filter(metadata_collection_details::metadata_address.eq(any(addresses)))
+            .select(metadata_collection_details::all_columns)
+            .load(&conn)
+            .context("Failed to load collection details")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|details| (details.metadata_address.clone(), details.try_into()))
+            .batch(addresses))
+    }
+}
+
 #[async_trait]
 impl TryBatchFn<PublicKey<Nft>, Vec<NftCreator>> for Batcher {
     async fn load(
@@ -161,11 +206,29 @@ impl TryBatchFn<PublicKey<Nft>, Option<NftOwner>> for Batcher {
                         current_metadata_owners::owner_address,
                         current_metadata_owners::token_account_address,
                         current_metadata_owners::slot,
+                        current_metadata_owners::write_version,
                     ),
                 ))
                 .load(&conn)
                 .context("Failed to load NFT owners")?;
 
+        // Compressed NFTs have no token account, and use their asset ID as a
+        // mint address
+        let compressed: Vec<(Option<String>, String, String)> = compressed_assets::table
+            .left_join(
+                twitter_handle_name_services::table
+                    .on(twitter_handle_name_services::wallet_address.eq(compressed_assets::owner)),
+            )
+            .filter(compressed_assets::asset_id.eq(any(mint_addresses)))
+            .filter(compressed_assets::burned_at.is_null())
+            .select((
+                twitter_handle_name_services::twitter_handle.nullable(),
+                compressed_assets::asset_id,
+                compressed_assets::owner,
+            ))
+            .load(&conn)
+            .context("Failed to load compressed NFT owners")?;
+
         Ok(rows
             .into_iter()
             .map(|(h, t)| {
@@ -175,6 +238,13 @@ impl TryBatchFn<PublicKey<Nft>, Option<NftOwner>> for Batcher {
                     twitter_handle: h,
                 })
             })
+            .chain(compressed.into_iter().map(|(h, asset_id, owner)| {
+                (asset_id, NftOwner {
+                    address: owner,
+                    associated_token_account_address: String::new(),
+                    twitter_handle: h,
+                })
+            }))
             .batch(mint_addresses))
     }
 }
@@ -288,9 +358,20 @@ impl TryBatchFn<PublicKey<Nft>, Option<Nft>> for Batcher {
             .load(&conn)
             .context("Failed to load NFTs")?;
 
+        let compressed = queries::compressed_assets::nfts(&conn, addresses)?;
+
         Ok(rows
             .into_iter()
             .map(|nft| (nft.address.clone(), nft.try_into()))
+            .chain(compressed.into_iter().map(|nft| {
+                (
+                    nft.address.clone(),
+                    Nft::try_from(nft).map(|nft| Nft {
+                        compressed: true,
+                        ..nft
+                    }),
+                )
+            }))
             .batch(addresses))
     }
 }
@@ -1,6 +1,6 @@
 #![allow(clippy::module_name_repetitions)]
 
-use juniper::{EmptyMutation, EmptySubscription, RootNode};
+use juniper::{EmptyMutation, RootNode};
 
 mod context;
 pub(self) mod dataloaders;
@@ -9,6 +9,7 @@ pub(self) mod objects;
 mod query_root;
 pub(self) mod scalars;
 pub(self) mod services;
+mod subscription_root;
 
 pub(self) mod prelude {
     pub use std::{collections::HashMap, sync::Arc};
@@ -32,13 +33,13 @@ pub type Schema = RootNode<
     'static,
     query_root::QueryRoot,
     EmptyMutation<AppContext>,
-    EmptySubscription<AppContext>,
+    subscription_root::SubscriptionRoot,
 >;
 
 pub fn create() -> Schema {
     Schema::new(
         query_root::QueryRoot,
         EmptyMutation::new(),
-        EmptySubscription::new(),
+        subscription_root::SubscriptionRoot,
     )
 }
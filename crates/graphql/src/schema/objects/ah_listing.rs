@@ -118,3 +118,78 @@ impl<'a> TryFrom<models::Listing<'a>> for AhListing {
         })
     }
 }
+
+#[derive(Debug, Clone, GraphQLObject)]
+#[graphql(
+    description = "A timed auction run by the Auctioneer program, kept apart from fixed-price \
+                   listings"
+)]
+pub struct ListingAuction {
+    pub trade_state: String,
+    pub auction_house: PublicKey<AuctionHouse>,
+    pub seller: PublicKey<Wallet>,
+    pub listing_config: String,
+    pub start_time: DateTime<Utc>,
+    #[graphql(description = "The end of the auction, including any extensions from late bids")]
+    pub end_time: DateTime<Utc>,
+    pub reserve_price: U64,
+    pub min_bid_increment: U64,
+    #[graphql(description = "Seconds before the end of the auction in which a bid extends it")]
+    pub time_ext_period: i32,
+    #[graphql(description = "Seconds a late bid extends the auction by")]
+    pub time_ext_delta: i32,
+    pub allow_high_bid_cancel: bool,
+    pub highest_bid: Option<U64>,
+    pub highest_bid_trade_state: Option<String>,
+}
+
+impl<'a>
+    TryFrom<(
+        models::AuctioneerListing<'a>,
+        models::AuctioneerListingConfig<'a>,
+    )> for ListingAuction
+{
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        (
+            models::AuctioneerListing {
+                trade_state,
+                auction_house,
+                seller,
+                ..
+            },
+            models::AuctioneerListingConfig {
+                address,
+                highest_bid_amount,
+                highest_bid_trade_state,
+                start_time,
+                end_time,
+                time_ext_period,
+                time_ext_delta,
+                allow_high_bid_cancel,
+                reserve_price,
+                min_bid_increment,
+                ..
+            },
+        ): (models::AuctioneerListing, models::AuctioneerListingConfig),
+    ) -> Result<Self, Self::Error> {
+        let has_bid = highest_bid_amount > 0;
+
+        Ok(Self {
+            trade_state: trade_state.into_owned(),
+            auction_house: auction_house.into_owned().into(),
+            seller: seller.into_owned().into(),
+            listing_config: address.into_owned(),
+            start_time: DateTime::from_utc(start_time, Utc),
+            end_time: DateTime::from_utc(end_time, Utc),
+            reserve_price: reserve_price.try_into()?,
+            min_bid_increment: min_bid_increment.try_into()?,
+            time_ext_period,
+            time_ext_delta,
+            allow_high_bid_cancel,
+            highest_bid: has_bid.then(|| highest_bid_amount.try_into()).transpose()?,
+            highest_bid_trade_state: has_bid.then(|| highest_bid_trade_state.into_owned()),
+        })
+    }
+}
@@ -1,6 +1,12 @@
-use indexer_core::uuid::Uuid;
+use indexer_core::{
+    bigdecimal::{BigDecimal, ToPrimitive},
+    db::expression::dsl::sum,
+    pubkeys,
+    uuid::Uuid,
+};
 use objects::{auction_house::AuctionHouse, nft::Nft, wallet::Wallet};
 use scalars::{PublicKey, U64};
+use tables::escrow_payments;
 
 use super::prelude::*;
 
@@ -19,6 +25,7 @@ pub struct Offer {
     pub created_at: DateTime<Utc>,
     pub canceled_at: Option<DateTime<Utc>>,
     pub token_size: i32,
+    pub expiry: Option<DateTime<Utc>>,
 }
 
 #[graphql_object(Context = AppContext)]
@@ -81,6 +88,35 @@ impl Offer {
         &self.marketplace_program_address
     }
 
+    fn expiry(&self) -> Option<DateTime<Utc>> {
+        self.expiry
+    }
+
+    #[graphql(
+        description = "The buyer's escrow balance summed from indexed escrow payments, or null if \
+                       the marketplace's escrow payments are not indexed"
+    )]
+    fn escrow_balance(&self, ctx: &AppContext) -> FieldResult<Option<U64>> {
+        if self.marketplace_program_address != pubkeys::ME_HAUS.to_string() {
+            return Ok(None);
+        }
+
+        let db_conn = ctx.shared.db.get()?;
+
+        let balance = escrow_payments::table
+            .select(sum(escrow_payments::amount))
+            .filter(escrow_payments::wallet.eq(&self.buyer))
+            .filter(escrow_payments::auction_house.eq(&self.auction_house))
+            .filter(escrow_payments::marketplace_program.eq(&self.marketplace_program_address))
+            .first::<Option<BigDecimal>>(&db_conn)
+            .context("Failed to load escrow balance")?
+            .unwrap_or_default();
+
+        // Withdrawals and sales predating the indexed deposits can leave a
+        // negative sum, which is clamped to zero
+        Ok(Some(balance.to_u64().unwrap_or_default().into()))
+    }
+
     pub async fn nft(&self, ctx: &AppContext) -> FieldResult<Option<Nft>> {
         ctx.nft_loader
             .load(self.metadata.clone())
@@ -114,6 +150,7 @@ impl<'a> TryFrom<models::Offer<'a>> for Offer {
             trade_state_bump,
             created_at,
             canceled_at,
+            expiry,
             ..
         }: models::Offer,
     ) -> Result<Self, Self::Error> {
@@ -131,6 +168,7 @@ impl<'a> TryFrom<models::Offer<'a>> for Offer {
             created_at: DateTime::from_utc(created_at, Utc),
             canceled_at: canceled_at.flatten().map(|c| DateTime::from_utc(c, Utc)),
             token_size: token_size.try_into()?,
+            expiry: expiry.map(|e| DateTime::from_utc(e, Utc)),
         })
     }
 }
@@ -0,0 +1,83 @@
+use objects::wallet::Wallet;
+use scalars::{markers::TokenMint, PublicKey, U64};
+
+use super::prelude::*;
+
+#[derive(Debug, Clone, GraphQLObject)]
+#[graphql(name = "MagicEdenAmmPool")]
+/// A Magic Eden AMM pool, which buys and sells NFTs along a bonding curve
+pub struct AmmPool {
+    pub address: PublicKey<AmmPool>,
+    /// The wallet which created the pool
+    pub owner: PublicKey<Wallet>,
+    /// The cosigner required to fulfill orders against the pool
+    pub cosigner: PublicKey<Wallet>,
+    /// The mint of the pool's payment token, or the default pubkey for SOL
+    pub payment_mint: PublicKey<TokenMint>,
+    /// The current price the pool buys or sells at, in lamports
+    pub spot_price: U64,
+    /// The bonding curve of the pool, 0 for linear or 1 for exponential
+    pub curve_type: i32,
+    /// The change in spot price after each fill, in lamports for linear
+    /// curves or basis points for exponential curves
+    pub curve_delta: U64,
+    /// True if assets bought by the pool are relisted
+    pub reinvest_fulfill_buy: bool,
+    /// True if payments received by the pool fund further buys
+    pub reinvest_fulfill_sell: bool,
+    /// The time after which the pool can no longer be filled, if any
+    pub expiry: Option<DateTime<Utc>>,
+    /// The fee paid to the pool owner on two-sided pools, in basis points
+    pub lp_fee_bp: i32,
+    /// The creator royalty paid by the pool when buying, in basis points
+    pub buyside_creator_royalty_bp: i32,
+    /// The number of assets the pool has listed for sale
+    pub sellside_asset_amount: U64,
+    /// The escrowed balance available for the pool's bids, in lamports
+    pub buyside_payment_amount: U64,
+    /// The total fees earned by the pool owner, in lamports
+    pub lp_fee_earned: U64,
+}
+
+impl<'a> TryFrom<models::MeAmmPool<'a>> for AmmPool {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::MeAmmPool {
+            address,
+            owner,
+            cosigner,
+            payment_mint,
+            spot_price,
+            curve_type,
+            curve_delta,
+            reinvest_fulfill_buy,
+            reinvest_fulfill_sell,
+            expiry,
+            lp_fee_bp,
+            buyside_creator_royalty_bp,
+            sellside_asset_amount,
+            buyside_payment_amount,
+            lp_fee_earned,
+            ..
+        }: models::MeAmmPool,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into(),
+            owner: owner.into(),
+            cosigner: cosigner.into(),
+            payment_mint: payment_mint.into(),
+            spot_price: spot_price.try_into()?,
+            curve_type: curve_type.into(),
+            curve_delta: curve_delta.try_into()?,
+            reinvest_fulfill_buy,
+            reinvest_fulfill_sell,
+            expiry: expiry.map(|e| DateTime::from_utc(e, Utc)),
+            lp_fee_bp,
+            buyside_creator_royalty_bp,
+            sellside_asset_amount: sellside_asset_amount.try_into()?,
+            buyside_payment_amount: buyside_payment_amount.try_into()?,
+            lp_fee_earned: lp_fee_earned.try_into()?,
+        })
+    }
+}
@@ -45,6 +45,7 @@ impl<'a> From<models::AuctionHouse<'a>> for AuctionHouse {
             requires_sign_off,
             can_change_sale_price,
             auction_house_fee_account,
+            ..
         }: models::AuctionHouse,
     ) -> Self {
         Self {
@@ -0,0 +1,296 @@
+use indexer_core::base64;
+use objects::wallet::Wallet;
+use scalars::{PublicKey, U64};
+
+use super::prelude::*;
+use crate::schema::scalars::markers::TokenMint;
+
+#[derive(Debug, Clone)]
+/// A Candy Guard account, which wraps minting from a Candy Machine v3 with
+/// configurable guards
+pub struct CandyGuard {
+    pub address: PublicKey<CandyGuard>,
+    pub base: PublicKey<Wallet>,
+    pub bump: i32,
+    pub authority: PublicKey<Wallet>,
+}
+
+#[graphql_object(Context = AppContext)]
+impl CandyGuard {
+    pub fn address(&self) -> &PublicKey<CandyGuard> {
+        &self.address
+    }
+
+    pub fn base(&self) -> &PublicKey<Wallet> {
+        &self.base
+    }
+
+    pub fn bump(&self) -> i32 {
+        self.bump
+    }
+
+    pub fn authority(&self) -> &PublicKey<Wallet> {
+        &self.authority
+    }
+
+    #[graphql(description = "Guards applied to every mint, unless overridden by a group")]
+    pub async fn default_guards(&self, ctx: &AppContext) -> FieldResult<Option<CandyGuardGroup>> {
+        Ok(ctx
+            .candy_guard_groups_loader
+            .load(self.address.clone())
+            .await?
+            .into_iter()
+            .find(|g| g.label.is_none()))
+    }
+
+    #[graphql(description = "Named groups of guards, one of which must be selected when minting")]
+    pub async fn groups(&self, ctx: &AppContext) -> FieldResult<Vec<CandyGuardGroup>> {
+        Ok(ctx
+            .candy_guard_groups_loader
+            .load(self.address.clone())
+            .await?
+            .into_iter()
+            .filter(|g| g.label.is_some())
+            .collect())
+    }
+}
+
+impl<'a> From<models::CandyGuard<'a>> for CandyGuard {
+    fn from(
+        models::CandyGuard {
+            address,
+            base,
+            bump,
+            authority,
+            ..
+        }: models::CandyGuard,
+    ) -> Self {
+        Self {
+            address: address.into(),
+            base: base.into(),
+            bump: bump.into(),
+            authority: authority.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// Charge a penalty for invalid mint transactions instead of failing them
+pub struct BotTaxGuard {
+    pub lamports: U64,
+    pub last_instruction: bool,
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// Charge an amount of SOL per mint
+pub struct SolPaymentGuard {
+    pub lamports: U64,
+    pub destination: PublicKey<Wallet>,
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// Charge an amount of tokens per mint
+pub struct TokenPaymentGuard {
+    pub amount: U64,
+    pub mint: PublicKey<TokenMint>,
+    pub destination_ata: PublicKey<Wallet>,
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// Require minters to hold, or burn, an amount of tokens
+pub struct TokenGateGuard {
+    pub amount: U64,
+    pub mint: PublicKey<TokenMint>,
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// Require minters to hold a valid gateway token
+pub struct GatekeeperGuard {
+    pub gatekeeper_network: PublicKey<Wallet>,
+    pub expire_on_use: bool,
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// Limit the number of mints per wallet
+pub struct MintLimitGuard {
+    pub id: i32,
+    pub limit: i32,
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// Charge an NFT from a collection per mint
+pub struct NftPaymentGuard {
+    pub required_collection: PublicKey<TokenMint>,
+    pub destination: PublicKey<Wallet>,
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// A set of guards, either the default guard set of a candy guard or one of
+/// its named groups.  Guards which are not enabled are null.
+pub struct CandyGuardGroup {
+    pub candy_guard_address: PublicKey<CandyGuard>,
+    #[graphql(description = "The label of the group, or null for the default guard set")]
+    pub label: Option<String>,
+    pub bot_tax: Option<BotTaxGuard>,
+    pub sol_payment: Option<SolPaymentGuard>,
+    pub token_payment: Option<TokenPaymentGuard>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub third_party_signer: Option<PublicKey<Wallet>>,
+    pub token_gate: Option<TokenGateGuard>,
+    pub gatekeeper: Option<GatekeeperGuard>,
+    pub end_date: Option<DateTime<Utc>>,
+    #[graphql(description = "base64-encoded root of the Merkle tree of wallets allowed to mint")]
+    pub allow_list_merkle_root: Option<String>,
+    pub mint_limit: Option<MintLimitGuard>,
+    pub nft_payment: Option<NftPaymentGuard>,
+    pub redeemed_amount_maximum: Option<U64>,
+    pub address_gate: Option<PublicKey<Wallet>>,
+    pub nft_gate_required_collection: Option<PublicKey<TokenMint>>,
+    pub nft_burn_required_collection: Option<PublicKey<TokenMint>>,
+    pub token_burn: Option<TokenGateGuard>,
+    pub freeze_sol_payment: Option<SolPaymentGuard>,
+    pub freeze_token_payment: Option<TokenPaymentGuard>,
+    pub program_gate_additional: Option<Vec<String>>,
+}
+
+impl<'a> TryFrom<models::CandyGuardGroup<'a>> for CandyGuardGroup {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::CandyGuardGroup {
+            candy_guard_address,
+            label,
+            bot_tax_lamports,
+            bot_tax_last_instruction,
+            sol_payment_lamports,
+            sol_payment_destination,
+            token_payment_amount,
+            token_payment_mint,
+            token_payment_destination_ata,
+            start_date,
+            third_party_signer_key,
+            token_gate_amount,
+            token_gate_mint,
+            gatekeeper_network,
+            gatekeeper_expire_on_use,
+            end_date,
+            allow_list_merkle_root,
+            mint_limit_id,
+            mint_limit_limit,
+            nft_payment_required_collection,
+            nft_payment_destination,
+            redeemed_amount_maximum,
+            address_gate_address,
+            nft_gate_required_collection,
+            nft_burn_required_collection,
+            token_burn_amount,
+            token_burn_mint,
+            freeze_sol_payment_lamports,
+            freeze_sol_payment_destination,
+            freeze_token_payment_amount,
+            freeze_token_payment_mint,
+            freeze_token_payment_destination_ata,
+            program_gate_additional,
+        }: models::CandyGuardGroup,
+    ) -> Result<Self, Self::Error> {
+        use base64::engine::fast_portable::FastPortable;
+
+        const ENGINE: FastPortable = FastPortable::from(
+            &base64::alphabet::STANDARD,
+            base64::engine::fast_portable::NO_PAD,
+        );
+
+        Ok(Self {
+            candy_guard_address: candy_guard_address.into(),
+            label: Some(label.into_owned()).filter(|l| !l.is_empty()),
+            bot_tax: bot_tax_lamports
+                .map(U64::try_from)
+                .transpose()?
+                .zip(bot_tax_last_instruction)
+                .map(|(lamports, last_instruction)| BotTaxGuard {
+                    lamports,
+                    last_instruction,
+                }),
+            sol_payment: sol_payment_lamports
+                .map(U64::try_from)
+                .transpose()?
+                .zip(sol_payment_destination)
+                .map(|(lamports, destination)| SolPaymentGuard {
+                    lamports,
+                    destination: destination.into(),
+                }),
+            token_payment: token_payment_amount
+                .map(U64::try_from)
+                .transpose()?
+                .zip(token_payment_mint)
+                .zip(token_payment_destination_ata)
+                .map(|((amount, mint), destination_ata)| TokenPaymentGuard {
+                    amount,
+                    mint: mint.into(),
+                    destination_ata: destination_ata.into(),
+                }),
+            start_date: start_date.map(|d| DateTime::from_utc(d, Utc)),
+            third_party_signer: third_party_signer_key.map(Into::into),
+            token_gate: token_gate_amount
+                .map(U64::try_from)
+                .transpose()?
+                .zip(token_gate_mint)
+                .map(|(amount, mint)| TokenGateGuard {
+                    amount,
+                    mint: mint.into(),
+                }),
+            gatekeeper: gatekeeper_network.zip(gatekeeper_expire_on_use).map(
+                |(gatekeeper_network, expire_on_use)| GatekeeperGuard {
+                    gatekeeper_network: gatekeeper_network.into(),
+                    expire_on_use,
+                },
+            ),
+            end_date: end_date.map(|d| DateTime::from_utc(d, Utc)),
+            allow_list_merkle_root: allow_list_merkle_root
+                .map(|r| base64::encode_engine(r, &ENGINE)),
+            mint_limit: mint_limit_id
+                .zip(mint_limit_limit)
+                .map(|(id, limit)| MintLimitGuard {
+                    id: id.into(),
+                    limit,
+                }),
+            nft_payment: nft_payment_required_collection
+                .zip(nft_payment_destination)
+                .map(|(required_collection, destination)| NftPaymentGuard {
+                    required_collection: required_collection.into(),
+                    destination: destination.into(),
+                }),
+            redeemed_amount_maximum: redeemed_amount_maximum.map(TryInto::try_into).transpose()?,
+            address_gate: address_gate_address.map(Into::into),
+            nft_gate_required_collection: nft_gate_required_collection.map(Into::into),
+            nft_burn_required_collection: nft_burn_required_collection.map(Into::into),
+            token_burn: token_burn_amount
+                .map(U64::try_from)
+                .transpose()?
+                .zip(token_burn_mint)
+                .map(|(amount, mint)| TokenGateGuard {
+                    amount,
+                    mint: mint.into(),
+                }),
+            freeze_sol_payment: freeze_sol_payment_lamports
+                .map(U64::try_from)
+                .transpose()?
+                .zip(freeze_sol_payment_destination)
+                .map(|(lamports, destination)| SolPaymentGuard {
+                    lamports,
+                    destination: destination.into(),
+                }),
+            freeze_token_payment: freeze_token_payment_amount
+                .map(U64::try_from)
+                .transpose()?
+                .zip(freeze_token_payment_mint)
+                .zip(freeze_token_payment_destination_ata)
+                .map(|((amount, mint), destination_ata)| TokenPaymentGuard {
+                    amount,
+                    mint: mint.into(),
+                    destination_ata: destination_ata.into(),
+                }),
+            program_gate_additional,
+        })
+    }
+}
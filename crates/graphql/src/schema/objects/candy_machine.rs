@@ -2,7 +2,7 @@ use indexer_core::{
     base64,
     db::custom_types::{EndSettingType, WhitelistMintMode},
 };
-use objects::wallet::Wallet;
+use objects::{candy_guard::CandyGuard, wallet::Wallet};
 use scalars::{PublicKey, U64};
 
 use super::prelude::*;
@@ -12,19 +12,23 @@ use crate::schema::scalars::markers::TokenMint;
 pub struct CandyMachine {
     pub address: PublicKey<CandyMachine>,
     pub authority: PublicKey<Wallet>,
-    pub wallet: PublicKey<Wallet>,
+    pub wallet: Option<PublicKey<Wallet>>,
     pub token_mint: Option<PublicKey<TokenMint>>,
     pub items_redeemed: U64,
 
-    pub uuid: String,
-    pub price: U64,
+    pub uuid: Option<String>,
+    pub price: Option<U64>,
     pub symbol: String,
     pub seller_fee_basis_points: i32,
     pub max_supply: U64,
     pub is_mutable: bool,
-    pub retain_authority: bool,
+    pub retain_authority: Option<bool>,
     pub go_live_date: Option<U64>,
     pub items_available: U64,
+
+    pub version: i32,
+    pub mint_authority: Option<PublicKey<Wallet>>,
+    pub collection_mint: Option<PublicKey<TokenMint>>,
 }
 
 #[graphql_object(Context = AppContext)]
@@ -37,7 +41,11 @@ impl CandyMachine {
         &self.authority
     }
 
-    pub fn wallet(&self) -> &PublicKey<Wallet> {
+    #[graphql(
+        description = "The wallet receiving mint payments, or null for v3 candy machines, whose \
+                       payments are configured by their candyGuard"
+    )]
+    pub fn wallet(&self) -> &Option<PublicKey<Wallet>> {
         &self.wallet
     }
 
@@ -49,11 +57,16 @@ impl CandyMachine {
         &self.items_redeemed
     }
 
-    pub fn uuid(&self) -> &String {
+    #[graphql(description = "The candy machine's UUID, or null for v3 candy machines")]
+    pub fn uuid(&self) -> &Option<String> {
         &self.uuid
     }
 
-    pub fn price(&self) -> &U64 {
+    #[graphql(
+        description = "The mint price, or null for v3 candy machines, whose price is configured by \
+                       their candyGuard"
+    )]
+    pub fn price(&self) -> &Option<U64> {
         &self.price
     }
 
@@ -73,7 +86,11 @@ impl CandyMachine {
         &self.is_mutable
     }
 
-    pub fn retain_authority(&self) -> &bool {
+    #[graphql(
+        description = "True if the candy machine retains update authority over minted NFTs, or \
+                       null for v3 candy machines"
+    )]
+    pub fn retain_authority(&self) -> &Option<bool> {
         &self.retain_authority
     }
 
@@ -85,6 +102,45 @@ impl CandyMachine {
         &self.items_available
     }
 
+    #[graphql(description = "The Candy Machine program version, either 2 or 3")]
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    #[graphql(
+        description = "The authority permitted to mint from a v3 candy machine, usually its candy guard"
+    )]
+    pub fn mint_authority(&self) -> &Option<PublicKey<Wallet>> {
+        &self.mint_authority
+    }
+
+    #[graphql(description = "The collection NFT minted items of a v3 candy machine belong to")]
+    pub fn collection_mint(&self) -> &Option<PublicKey<TokenMint>> {
+        &self.collection_mint
+    }
+
+    #[graphql(description = "The candy guard wrapping a v3 candy machine, if any")]
+    pub async fn candy_guard(&self, ctx: &AppContext) -> FieldResult<Option<CandyGuard>> {
+        let Some(ref mint_authority) = self.mint_authority else {
+            return Ok(None);
+        };
+
+        ctx.candy_guard_loader
+            .load(mint_authority.to_string().into())
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn config_line_setting(
+        &self,
+        ctx: &AppContext,
+    ) -> FieldResult<Option<CandyMachineConfigLineSetting>> {
+        ctx.candy_machine_config_line_settings_loader
+            .load(self.address.clone())
+            .await
+            .map_err(Into::into)
+    }
+
     #[graphql(description = "NOTE - this is currently bugged and will only return one creator")]
     pub async fn creators(&self, ctx: &AppContext) -> FieldResult<Vec<CandyMachineCreator>> {
         ctx.candy_machine_creator_loader
@@ -163,6 +219,7 @@ impl<'a, 'b> TryFrom<(models::CandyMachine<'a>, models::CandyMachineData<'b>)> f
                 wallet,
                 token_mint,
                 items_redeemed,
+                ..
             },
             models::CandyMachineData {
                 uuid,
@@ -181,18 +238,64 @@ impl<'a, 'b> TryFrom<(models::CandyMachine<'a>, models::CandyMachineData<'b>)> f
         Ok(Self {
             address: address.into(),
             authority: authority.into(),
-            wallet: wallet.into(),
+            wallet: Some(wallet.into()),
             token_mint: token_mint.map(Into::into),
             items_redeemed: items_redeemed.try_into()?,
-            uuid: uuid.into_owned(),
-            price: price.try_into()?,
+            uuid: Some(uuid.into_owned()),
+            price: Some(price.try_into()?),
             symbol: symbol.into_owned(),
             seller_fee_basis_points: seller_fee_basis_points.try_into()?,
             max_supply: max_supply.try_into()?,
             is_mutable,
-            retain_authority,
+            retain_authority: Some(retain_authority),
             go_live_date: go_live_date.map(U64::try_from).transpose()?,
             items_available: items_available.try_into()?,
+            version: 2,
+            mint_authority: None,
+            collection_mint: None,
+        })
+    }
+}
+
+/// Candy Machine v3 has no payment, go-live or UUID settings of its own, as
+/// payment and start dates are configured by its candy guard, so those fields
+/// are left null
+impl<'a> TryFrom<models::CoreCandyMachine<'a>> for CandyMachine {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::CoreCandyMachine {
+            address,
+            authority,
+            mint_authority,
+            collection_mint,
+            items_redeemed,
+            items_available,
+            symbol,
+            seller_fee_basis_points,
+            max_supply,
+            is_mutable,
+            ..
+        }: models::CoreCandyMachine,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into(),
+            authority: authority.into(),
+            wallet: None,
+            token_mint: None,
+            items_redeemed: items_redeemed.try_into()?,
+            uuid: None,
+            price: None,
+            symbol: symbol.into_owned(),
+            seller_fee_basis_points,
+            max_supply: max_supply.try_into()?,
+            is_mutable,
+            retain_authority: None,
+            go_live_date: None,
+            items_available: items_available.try_into()?,
+            version: 3,
+            mint_authority: Some(mint_authority.into()),
+            collection_mint: Some(collection_mint.into()),
         })
     }
 }
@@ -238,6 +341,7 @@ impl<'a> From<models::CMCollectionPDA<'a>> for CandyMachineCollectionPda {
             address,
             mint,
             candy_machine,
+            ..
         }: models::CMCollectionPDA,
     ) -> Self {
         Self {
@@ -277,6 +381,38 @@ impl<'a> From<models::CMConfigLine<'a>> for CandyMachineConfigLine {
     }
 }
 
+#[derive(Debug, Clone, GraphQLObject)]
+pub struct CandyMachineConfigLineSetting {
+    pub candy_machine_address: PublicKey<CandyMachine>,
+    pub prefix_name: String,
+    pub name_length: i32,
+    pub prefix_uri: String,
+    pub uri_length: i32,
+    pub is_sequential: bool,
+}
+
+impl<'a> From<models::CMConfigLineSetting<'a>> for CandyMachineConfigLineSetting {
+    fn from(
+        models::CMConfigLineSetting {
+            candy_machine_address,
+            prefix_name,
+            name_length,
+            prefix_uri,
+            uri_length,
+            is_sequential,
+        }: models::CMConfigLineSetting,
+    ) -> Self {
+        Self {
+            candy_machine_address: candy_machine_address.into(),
+            prefix_name: prefix_name.into_owned(),
+            name_length,
+            prefix_uri: prefix_uri.into_owned(),
+            uri_length,
+            is_sequential,
+        }
+    }
+}
+
 #[derive(Debug, Clone, GraphQLObject)]
 pub struct CandyMachineEndSetting {
     pub candy_machine_address: PublicKey<CandyMachine>,
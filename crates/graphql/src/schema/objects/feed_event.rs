@@ -0,0 +1,270 @@
+use indexer_core::{
+    db::{
+        custom_types::{ListingEventLifecycleEnum, OfferEventLifecycleEnum},
+        queries::feed_event,
+        Connection,
+    },
+    uuid::Uuid,
+};
+use objects::{
+    ah_listing::AhListing, ah_offer::Offer, ah_purchase::Purchase, nft::Nft, wallet::Wallet,
+};
+use scalars::PublicKey;
+use tables::{
+    feed_events, follow_events, listing_events, mint_events, offer_events, purchase_events,
+};
+
+use super::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, GraphQLEnum)]
+#[graphql(description = "The kind of activity a feed event describes")]
+pub enum FeedEventKind {
+    #[graphql(name = "MINT")]
+    Mint,
+    #[graphql(name = "LISTING")]
+    Listing,
+    #[graphql(name = "OFFER")]
+    Offer,
+    #[graphql(name = "PURCHASE")]
+    Purchase,
+    #[graphql(name = "FOLLOW")]
+    Follow,
+}
+
+impl FeedEventKind {
+    fn of(
+        listing_id: Option<Uuid>,
+        offer_id: Option<Uuid>,
+        purchase_id: Option<Uuid>,
+        graph_connection_address: Option<&str>,
+        metadata_address: Option<&str>,
+    ) -> Option<Self> {
+        if listing_id.is_some() {
+            Some(Self::Listing)
+        } else if offer_id.is_some() {
+            Some(Self::Offer)
+        } else if purchase_id.is_some() {
+            Some(Self::Purchase)
+        } else if graph_connection_address.is_some() {
+            Some(Self::Follow)
+        } else if metadata_address.is_some() {
+            Some(Self::Mint)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<FeedEventKind> for feed_event::EventType {
+    fn from(kind: FeedEventKind) -> Self {
+        match kind {
+            FeedEventKind::Mint => Self::Mint,
+            FeedEventKind::Listing => Self::Listing,
+            FeedEventKind::Offer => Self::Offer,
+            FeedEventKind::Purchase => Self::Purchase,
+            FeedEventKind::Follow => Self::Follow,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedEvent {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub wallet_address: PublicKey<Wallet>,
+    pub kind: FeedEventKind,
+    pub lifecycle: Option<String>,
+    pub metadata_address: Option<PublicKey<Nft>>,
+    pub listing_id: Option<Uuid>,
+    pub offer_id: Option<Uuid>,
+    pub purchase_id: Option<Uuid>,
+    pub graph_connection_address: Option<String>,
+}
+
+type FeedEventRow = (
+    Uuid,
+    NaiveDateTime,
+    Option<String>,
+    Option<Uuid>,
+    Option<ListingEventLifecycleEnum>,
+    Option<Uuid>,
+    Option<OfferEventLifecycleEnum>,
+    Option<Uuid>,
+    Option<String>,
+);
+
+impl FeedEvent {
+    /// Load the feed event with the given ID, as shown to the given wallet
+    pub fn load(
+        conn: &Connection,
+        id: Uuid,
+        wallet_address: PublicKey<Wallet>,
+    ) -> Result<Option<Self>> {
+        let row: Option<FeedEventRow> = feed_events::table
+            .left_join(mint_events::table)
+            .left_join(listing_events::table)
+            .left_join(offer_events::table)
+            .left_join(purchase_events::table)
+            .left_join(follow_events::table)
+            .filter(feed_events::id.eq(id))
+            .select((
+                feed_events::id,
+                feed_events::created_at,
+                mint_events::metadata_address.nullable(),
+                listing_events::listing_id.nullable(),
+                listing_events::lifecycle.nullable(),
+                offer_events::offer_id.nullable(),
+                offer_events::lifecycle.nullable(),
+                purchase_events::purchase_id.nullable(),
+                follow_events::graph_connection_address.nullable(),
+            ))
+            .first(conn)
+            .optional()
+            .context("Failed to load feed event")?;
+
+        let Some((
+            id,
+            created_at,
+            metadata_address,
+            listing_id,
+            listing_lifecycle,
+            offer_id,
+            offer_lifecycle,
+            purchase_id,
+            graph_connection_address,
+        )) = row else {
+            return Ok(None);
+        };
+
+        let Some(kind) = FeedEventKind::of(
+            listing_id,
+            offer_id,
+            purchase_id,
+            graph_connection_address.as_deref(),
+            metadata_address.as_deref(),
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            id,
+            created_at: DateTime::from_utc(created_at, Utc),
+            wallet_address,
+            kind,
+            lifecycle: listing_lifecycle
+                .map(|l| l.to_string())
+                .or_else(|| offer_lifecycle.map(|l| l.to_string())),
+            metadata_address: metadata_address.map(Into::into),
+            listing_id,
+            offer_id,
+            purchase_id,
+            graph_connection_address,
+        }))
+    }
+}
+
+impl TryFrom<models::CompleteFeedEvent> for FeedEvent {
+    type Error = Error;
+
+    fn try_from(
+        models::CompleteFeedEvent {
+            id,
+            created_at,
+            wallet_address,
+            twitter_handle: _,
+            metadata_address,
+            purchase_id,
+            offer_id,
+            offer_lifecycle,
+            listing_id,
+            listing_lifecycle,
+            graph_connection_address,
+        }: models::CompleteFeedEvent,
+    ) -> Result<Self> {
+        let kind = FeedEventKind::of(
+            listing_id,
+            offer_id,
+            purchase_id,
+            graph_connection_address.as_deref(),
+            metadata_address.as_deref(),
+        )
+        .with_context(|| format!("Feed event {id} has no associated event"))?;
+
+        Ok(Self {
+            id,
+            created_at: DateTime::from_utc(created_at, Utc),
+            wallet_address: wallet_address.into(),
+            kind,
+            lifecycle: listing_lifecycle
+                .map(|l| l.to_string())
+                .or_else(|| offer_lifecycle.map(|l| l.to_string())),
+            metadata_address: metadata_address.map(Into::into),
+            listing_id,
+            offer_id,
+            purchase_id,
+            graph_connection_address,
+        })
+    }
+}
+
+#[graphql_object(Context = AppContext)]
+impl FeedEvent {
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn wallet_address(&self) -> &PublicKey<Wallet> {
+        &self.wallet_address
+    }
+
+    pub fn kind(&self) -> FeedEventKind {
+        self.kind
+    }
+
+    #[graphql(description = "Whether a listing or offer was created or canceled")]
+    pub fn lifecycle(&self) -> Option<&str> {
+        self.lifecycle.as_deref()
+    }
+
+    #[graphql(description = "The address of the connection for follow events")]
+    pub fn graph_connection_address(&self) -> Option<&str> {
+        self.graph_connection_address.as_deref()
+    }
+
+    #[graphql(description = "The NFT minted, for mint events")]
+    pub async fn nft(&self, context: &AppContext) -> FieldResult<Option<Nft>> {
+        let Some(address) = self.metadata_address.clone() else {
+            return Ok(None);
+        };
+
+        context.nft_loader.load(address).await.map_err(Into::into)
+    }
+
+    pub async fn listing(&self, context: &AppContext) -> FieldResult<Option<AhListing>> {
+        let Some(id) = self.listing_id else {
+            return Ok(None);
+        };
+
+        context.ah_listing_loader.load(id).await.map_err(Into::into)
+    }
+
+    pub async fn offer(&self, context: &AppContext) -> FieldResult<Option<Offer>> {
+        let Some(id) = self.offer_id else {
+            return Ok(None);
+        };
+
+        context.offer_loader.load(id).await.map_err(Into::into)
+    }
+
+    pub async fn purchase(&self, context: &AppContext) -> FieldResult<Option<Purchase>> {
+        let Some(id) = self.purchase_id else {
+            return Ok(None);
+        };
+
+        context.purchase_loader.load(id).await.map_err(Into::into)
+    }
+}
@@ -1,5 +1,8 @@
 use indexer_core::{
-    db::{models, queries::genopets},
+    db::{
+        models,
+        queries::{genopets, Pagination},
+    },
     meilisearch::IndirectMetadataDocument,
 };
 use objects::{nft::Nft, wallet::Wallet};
@@ -170,8 +173,10 @@ impl GenoHabitatsParams {
             is_activated,
             sort_field: sort_field.unwrap_or(GenoHabitatSortField::Address).into(),
             sort_desc: sort_desc.unwrap_or(false),
-            limit: limit.into(),
-            offset: offset.into(),
+            page: Pagination::Offset {
+                limit: limit.try_into()?,
+                offset: offset.try_into()?,
+            },
         })
     }
 }
@@ -2,15 +2,18 @@ pub mod activity;
 pub mod ah_listing;
 pub mod ah_offer;
 pub mod ah_purchase;
+pub mod amm_pool;
 pub mod attributes;
 pub mod auction_house;
 pub mod bid_receipt;
 pub mod bonding_change;
+pub mod candy_guard;
 pub mod candy_machine;
 pub mod chart;
 pub mod collection;
 pub mod creator;
 pub mod denylist;
+pub mod feed_event;
 pub mod genopets;
 pub mod graph_connection;
 pub mod listing;
@@ -18,7 +21,9 @@ pub mod listing_receipt;
 pub mod marketplace;
 pub mod nft;
 pub mod profile;
+pub mod program;
 pub mod purchase_receipt;
+pub mod relay;
 pub mod reward_center;
 pub mod reward_payout;
 pub mod solana_network;
@@ -2,7 +2,7 @@ use indexer_core::{
     assets::{proxy_url, AssetIdentifier, ImageSize},
     bigdecimal::ToPrimitive,
     db::{
-        custom_types::TokenStandardEnum,
+        custom_types::{TokenStandardEnum, UseMethodEnum},
         queries::{self, metadatas::CollectionNftOptions},
         sql_query,
         sql_types::Text,
@@ -17,8 +17,13 @@ use indexer_core::{
     uuid::Uuid,
 };
 use objects::{
-    ah_listing::AhListing, ah_offer::Offer, ah_purchase::Purchase, auction_house::AuctionHouse,
-    collection::Collection, profile::TwitterProfile, wallet::Wallet,
+    ah_listing::{AhListing, ListingAuction},
+    ah_offer::Offer,
+    ah_purchase::Purchase,
+    auction_house::AuctionHouse,
+    collection::Collection,
+    profile::TwitterProfile,
+    wallet::Wallet,
 };
 use scalars::{PublicKey, U64};
 use serde_json::Value;
@@ -386,6 +391,117 @@ impl From<TokenStandardEnum> for TokenStandard {
     }
 }
 
+#[derive(Debug, Clone, juniper::GraphQLEnum)]
+pub enum UseMethod {
+    /// The NFT is burned once its uses are exhausted
+    Burn,
+    /// The NFT can be used multiple times
+    Multiple,
+    /// The NFT can be used once
+    Single,
+}
+
+impl From<UseMethodEnum> for UseMethod {
+    fn from(v: UseMethodEnum) -> Self {
+        match v {
+            UseMethodEnum::Burn => UseMethod::Burn,
+            UseMethodEnum::Multiple => UseMethod::Multiple,
+            UseMethodEnum::Single => UseMethod::Single,
+        }
+    }
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// Usage of a consumable NFT, as recorded on-chain
+pub struct NftUses {
+    pub metadata_address: PublicKey<Nft>,
+    pub use_method: UseMethod,
+    pub remaining: U64,
+    pub total: U64,
+}
+
+impl<'a> TryFrom<models::MetadataUse<'a>> for NftUses {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::MetadataUse {
+            metadata_address,
+            use_method,
+            remaining,
+            total,
+        }: models::MetadataUse,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            metadata_address: metadata_address.into(),
+            use_method: use_method.into(),
+            remaining: remaining.try_into()?,
+            total: total.try_into()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// On-chain details of a sized collection parent NFT
+pub struct CollectionDetails {
+    pub metadata_address: PublicKey<Nft>,
+    /// The number of NFTs verified as members of the collection
+    pub size: U64,
+}
+
+impl<'a> TryFrom<models::MetadataCollectionDetails<'a>> for CollectionDetails {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::MetadataCollectionDetails {
+            metadata_address,
+            size,
+        }: models::MetadataCollectionDetails,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            metadata_address: metadata_address.into(),
+            size: size.try_into()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// A proof that a compressed NFT's leaf is included in its Merkle tree
+pub struct MerkleProof {
+    /// The address of the Merkle tree account
+    pub tree: String,
+    /// The index of the NFT's leaf in the tree
+    pub leaf_index: U64,
+    /// The hash stored in the NFT's leaf
+    pub leaf: String,
+    /// Sibling hashes along the path from the leaf to the root, ordered from
+    /// the leaf upwards
+    pub proof: Vec<String>,
+    /// The current root hash of the tree
+    pub root: String,
+}
+
+impl TryFrom<queries::compressed_assets::MerkleProof> for MerkleProof {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        queries::compressed_assets::MerkleProof {
+            tree,
+            leaf_index,
+            leaf,
+            proof,
+            root,
+        }: queries::compressed_assets::MerkleProof,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tree,
+            leaf_index: leaf_index.try_into()?,
+            leaf,
+            proof,
+            root,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 /// An NFT
 pub struct Nft {
@@ -405,6 +521,7 @@ pub struct Nft {
     pub model: Option<String>,
     pub slot: Option<i32>,
     pub token_standard: Option<TokenStandard>,
+    pub compressed: bool,
 }
 
 impl TryFrom<models::Nft> for Nft {
@@ -447,6 +564,7 @@ impl TryFrom<models::Nft> for Nft {
             model,
             slot: slot.map(TryInto::try_into).transpose()?,
             token_standard: token_standard.map(Into::into),
+            compressed: false,
         })
     }
 }
@@ -477,6 +595,27 @@ impl Nft {
         &self.token_standard
     }
 
+    #[graphql(description = "Whether the NFT is a compressed NFT stored in a Merkle tree")]
+    pub fn compressed(&self) -> bool {
+        self.compressed
+    }
+
+    #[graphql(
+        description = "A proof of the NFT's inclusion in its Merkle tree, if it is a compressed NFT"
+    )]
+    pub async fn merkle_proof(&self, ctx: &AppContext) -> FieldResult<Option<MerkleProof>> {
+        if !self.compressed {
+            return Ok(None);
+        }
+
+        let conn = ctx.shared.db.get()?;
+
+        queries::compressed_assets::proof(&conn, &self.address)?
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(Into::into)
+    }
+
     pub fn primary_sale_happened(&self) -> bool {
         self.primary_sale_happened
     }
@@ -580,6 +719,14 @@ If no value is provided, it will return XSmall")))]
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Open Auctioneer timed auctions of the NFT")]
+    pub async fn auctions(&self, ctx: &AppContext) -> FieldResult<Vec<ListingAuction>> {
+        ctx.nft_auctions_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
     pub async fn purchases(&self, ctx: &AppContext) -> FieldResult<Vec<Purchase>> {
         ctx.purchases_loader
             .load(self.address.clone().into())
@@ -594,6 +741,14 @@ If no value is provided, it will return XSmall")))]
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Usage of the NFT, if it is a consumable NFT.")]
+    pub async fn uses(&self, ctx: &AppContext) -> FieldResult<Option<NftUses>> {
+        ctx.nft_uses_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
     pub async fn files(&self, ctx: &AppContext) -> FieldResult<Vec<NftFile>> {
         ctx.nft_files_loader
             .load(self.address.clone().into())
@@ -666,6 +821,19 @@ impl CollectionNFT {
         &self.0
     }
 
+    #[graphql(
+        description = "On-chain details of the collection, if it is a sized collection.  The size is maintained by the token metadata program as members are verified."
+    )]
+    pub async fn collection_details(
+        &self,
+        ctx: &AppContext,
+    ) -> FieldResult<Option<CollectionDetails>> {
+        ctx.collection_details_loader
+            .load(self.0.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
     pub async fn nfts(
         &self,
         ctx: &AppContext,
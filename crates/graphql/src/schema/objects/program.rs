@@ -0,0 +1,81 @@
+use indexer_core::db::models;
+use scalars::{markers::ProgramId, PublicKey, U64};
+
+use super::prelude::*;
+
+#[derive(Debug, Clone, GraphQLObject)]
+#[graphql(description = "An account of a program indexed from its Anchor IDL")]
+pub struct ProgramAccount {
+    pub address: PublicKey<ProgramAccount>,
+    pub program_id: PublicKey<ProgramId>,
+    #[graphql(description = "The name of the account type in the program's IDL")]
+    pub account_type: String,
+    #[graphql(description = "The decoded account fields, encoded as JSON")]
+    pub data: String,
+    pub slot: U64,
+}
+
+impl<'a> TryFrom<models::ProgramAccount<'a>> for ProgramAccount {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::ProgramAccount {
+            address,
+            program_id,
+            account_type,
+            data,
+            slot,
+            ..
+        }: models::ProgramAccount,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into(),
+            program_id: program_id.into(),
+            account_type: account_type.into_owned(),
+            data: data.to_string(),
+            slot: slot.try_into()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+#[graphql(description = "An instruction of a program indexed from its Anchor IDL")]
+pub struct ProgramInstruction {
+    pub program_id: PublicKey<ProgramId>,
+    #[graphql(description = "The name of the instruction in the program's IDL")]
+    pub instruction_name: String,
+    pub txn_signature: String,
+    #[graphql(description = "The instruction accounts keyed by their IDL names, encoded as JSON")]
+    pub accounts: String,
+    #[graphql(description = "The decoded instruction arguments, encoded as JSON")]
+    pub data: String,
+    pub created_at: DateTime<Utc>,
+    pub slot: U64,
+}
+
+impl<'a> TryFrom<models::ProgramInstruction<'a>> for ProgramInstruction {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::ProgramInstruction {
+            program_id,
+            instruction_name,
+            txn_signature,
+            accounts,
+            data,
+            created_at,
+            slot,
+            ..
+        }: models::ProgramInstruction,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            program_id: program_id.into(),
+            instruction_name: instruction_name.into_owned(),
+            txn_signature: txn_signature.into_owned(),
+            accounts: accounts.to_string(),
+            data: data.to_string(),
+            created_at: DateTime::from_utc(created_at, Utc),
+            slot: slot.try_into()?,
+        })
+    }
+}
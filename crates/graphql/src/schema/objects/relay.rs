@@ -0,0 +1,265 @@
+//! Relay-style cursor pagination
+//!
+//! List queries page through their rows by keyset rather than by offset.
+//! Each edge's cursor is an opaque encoding of the sort key of its node, so
+//! a page can be resumed without rescanning the rows before it and without
+//! skipping or repeating rows inserted concurrently.
+
+use objects::{
+    collection::CollectionTrend,
+    feed_event::FeedEvent,
+    genopets::GenoHabitat,
+    graph_connection::GraphConnection,
+    nft::Nft,
+    program::{ProgramAccount, ProgramInstruction},
+    spl_governance::{Proposal, Realm, VoteRecord},
+    wallet::{AssociatedTokenAccount, Wallet},
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::prelude::*;
+
+/// The page size used if neither `first` nor `last` is given
+const DEFAULT_PAGE_SIZE: i32 = 25;
+/// The largest page size a client may request
+const MAX_PAGE_SIZE: i32 = 1000;
+
+fn invalid_cursor() -> FieldError {
+    FieldError::new(
+        "Invalid pagination cursor",
+        graphql_value!({ "Cursor": "Cursors must be taken from a previous page of the same query" }),
+    )
+}
+
+fn encode_cursor<K: Serialize>(key: &K) -> Result<String> {
+    let json = serde_json::to_vec(key).context("Failed to serialize cursor")?;
+
+    Ok(base64::encode_config(json, base64::URL_SAFE_NO_PAD))
+}
+
+fn decode_cursor<K: DeserializeOwned>(cursor: &str) -> FieldResult<K> {
+    let json =
+        base64::decode_config(cursor, base64::URL_SAFE_NO_PAD).map_err(|_| invalid_cursor())?;
+
+    serde_json::from_slice(&json).map_err(|_| invalid_cursor())
+}
+
+/// The end of a query's ordering a page is read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Read the rows after the cursor, in the query's natural order
+    Forward,
+    /// Read the rows before the cursor, in the reverse of the query's natural
+    /// order
+    Backward,
+}
+
+/// A validated page request for a query keyed by `K`
+#[derive(Debug)]
+pub struct Page<K> {
+    direction: Direction,
+    cursor: Option<K>,
+    size: i32,
+}
+
+impl<K: DeserializeOwned> Page<K> {
+    /// Validate the Relay pagination arguments of a connection field
+    ///
+    /// # Errors
+    /// This function fails if both forward and backward arguments are given,
+    /// if the page size is out of range, or if the cursor is malformed.
+    pub fn new(
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> FieldResult<Self> {
+        let (direction, size, cursor) = match (first, after, last, before) {
+            (first, after, None, None) => (Direction::Forward, first, after),
+            (None, None, last, before) => (Direction::Backward, last, before),
+            _ => {
+                return Err(FieldError::new(
+                    "Cannot paginate forwards and backwards at once",
+                    graphql_value!({ "Arguments": "first/after or last/before" }),
+                ));
+            },
+        };
+
+        let size = size.unwrap_or(DEFAULT_PAGE_SIZE);
+
+        if !(0..=MAX_PAGE_SIZE).contains(&size) {
+            return Err(FieldError::new(
+                "Page size out of range",
+                graphql_value!({ "Range": "0..=1000" }),
+            ));
+        }
+
+        let cursor = cursor.as_deref().map(decode_cursor).transpose()?;
+
+        Ok(Self {
+            direction,
+            cursor,
+            size,
+        })
+    }
+}
+
+impl<K> Page<K> {
+    /// True if this page is read in the query's natural order
+    #[must_use]
+    pub fn is_forward(&self) -> bool {
+        self.direction == Direction::Forward
+    }
+
+    /// The key of the row this page starts after (or ends before, if
+    /// reading backward)
+    #[must_use]
+    pub fn cursor(&self) -> Option<&K> {
+        self.cursor.as_ref()
+    }
+
+    /// The number of rows to load for this page, including one extra row
+    /// used to detect whether another page follows it
+    #[must_use]
+    pub fn limit(&self) -> i64 {
+        i64::from(self.size) + 1
+    }
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+#[graphql(description = "Information about a page of a connection")]
+pub struct PageInfo {
+    #[graphql(description = "True if more edges follow the end cursor")]
+    pub has_next_page: bool,
+    #[graphql(description = "True if more edges precede the start cursor")]
+    pub has_previous_page: bool,
+    #[graphql(description = "The cursor of the first edge of this page")]
+    pub start_cursor: Option<String>,
+    #[graphql(description = "The cursor of the last edge of this page")]
+    pub end_cursor: Option<String>,
+}
+
+/// A node along with the cursor pointing to it
+#[derive(Debug, Clone)]
+pub struct Edge<T> {
+    cursor: String,
+    node: T,
+}
+
+/// A page of nodes returned by a connection field
+#[derive(Debug, Clone)]
+pub struct Connection<T> {
+    edges: Vec<Edge<T>>,
+    page_info: PageInfo,
+}
+
+impl<T> Connection<T> {
+    /// Construct a page from rows loaded in the page's direction, each paired
+    /// with the key its cursor is encoded from
+    ///
+    /// # Errors
+    /// This function fails if a cursor cannot be encoded.
+    pub fn new<K: Serialize>(page: &Page<K>, rows: Vec<(K, T)>) -> FieldResult<Self> {
+        let size = usize::try_from(page.size)?;
+        let has_more = rows.len() > size;
+
+        let mut edges = rows
+            .into_iter()
+            .take(size)
+            .map(|(key, node)| {
+                Ok(Edge {
+                    cursor: encode_cursor(&key)?,
+                    node,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if page.direction == Direction::Backward {
+            edges.reverse();
+        }
+
+        // Whether rows exist on the far side of the given cursor isn't
+        // checked, so this is reported conservatively as the Relay spec allows
+        let has_cursor = page.cursor.is_some();
+        let (has_next_page, has_previous_page) = match page.direction {
+            Direction::Forward => (has_more, has_cursor),
+            Direction::Backward => (has_cursor, has_more),
+        };
+
+        let page_info = PageInfo {
+            has_next_page,
+            has_previous_page,
+            start_cursor: edges.first().map(|e| e.cursor.clone()),
+            end_cursor: edges.last().map(|e| e.cursor.clone()),
+        };
+
+        Ok(Self { edges, page_info })
+    }
+}
+
+/// Implement the GraphQL types of the connection and edge for a node type,
+/// as each instantiation of the generic types needs its own name
+macro_rules! connection {
+    ($node:ident, $connection:tt, $edge:tt) => {
+        #[graphql_object(Context = AppContext, name = $connection)]
+        impl Connection<$node> {
+            fn edges(&self) -> &[Edge<$node>] {
+                &self.edges
+            }
+
+            #[graphql(description = "The nodes of each edge in this page")]
+            fn nodes(&self) -> Vec<&$node> {
+                self.edges.iter().map(|e| &e.node).collect()
+            }
+
+            fn page_info(&self) -> &PageInfo {
+                &self.page_info
+            }
+        }
+
+        #[graphql_object(Context = AppContext, name = $edge)]
+        impl Edge<$node> {
+            #[graphql(description = "An opaque cursor for paginating from this edge")]
+            fn cursor(&self) -> &str {
+                &self.cursor
+            }
+
+            fn node(&self) -> &$node {
+                &self.node
+            }
+        }
+    };
+}
+
+connection!(
+    AssociatedTokenAccount,
+    "AssociatedTokenAccountConnection",
+    "AssociatedTokenAccountEdge"
+);
+connection!(
+    CollectionTrend,
+    "CollectionTrendConnection",
+    "CollectionTrendEdge"
+);
+connection!(FeedEvent, "FeedEventConnection", "FeedEventEdge");
+connection!(GenoHabitat, "GenoHabitatConnection", "GenoHabitatEdge");
+connection!(
+    GraphConnection,
+    "GraphConnectionConnection",
+    "GraphConnectionEdge"
+);
+connection!(Nft, "NftConnection", "NftEdge");
+connection!(
+    ProgramAccount,
+    "ProgramAccountConnection",
+    "ProgramAccountEdge"
+);
+connection!(
+    ProgramInstruction,
+    "ProgramInstructionConnection",
+    "ProgramInstructionEdge"
+);
+connection!(Proposal, "ProposalConnection", "ProposalEdge");
+connection!(Realm, "RealmConnection", "RealmEdge");
+connection!(VoteRecord, "VoteRecordConnection", "VoteRecordEdge");
+connection!(Wallet, "WalletConnection", "WalletEdge");
@@ -516,3 +516,12 @@ impl<'a> TryFrom<models::AssociatedTokenAccount<'a>> for AssociatedTokenAccount
         })
     }
 }
+
+#[derive(Debug, Clone, GraphQLObject)]
+/// The total balance a wallet holds of a token mint, across all of its token
+/// accounts for that mint
+pub struct TokenBalance {
+    pub owner: PublicKey<Wallet>,
+    pub mint: PublicKey<TokenMint>,
+    pub amount: U64,
+}
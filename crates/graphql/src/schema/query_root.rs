@@ -1,14 +1,17 @@
 use enums::{CollectionInterval, CollectionSort, OrderDirection};
 use indexer_core::{
+    bigdecimal::BigDecimal,
     db::{
         self,
         expression::dsl::all,
-        queries::{self, collections::TrendingQueryOptions},
+        queries::{self, collections::TrendingQueryOptions, Pagination},
     },
     pubkeys,
+    uuid::Uuid,
 };
 use objects::{
     ah_listing::AhListing,
+    amm_pool::AmmPool,
     auction_house::AuctionHouse,
     bid_receipt::BidReceipt,
     bonding_change::EnrichedBondingChange,
@@ -17,25 +20,32 @@ use objects::{
     collection::{CollectionDocument, CollectionTrend},
     creator::Creator,
     denylist::Denylist,
+    feed_event::{FeedEvent, FeedEventKind},
     genopets::{GenoHabitat, GenoHabitatList, GenoHabitatsParams},
     graph_connection::GraphConnection,
     listing::{Listing, ListingColumns, ListingRow},
     marketplace::Marketplace,
     nft::{CollectionNFT, MetadataJson, Nft, NftActivity, NftCount, NftCreator, NftsStats},
     profile::{ProfilesStats, TwitterProfile},
+    program::{ProgramAccount, ProgramInstruction},
+    relay::{Connection, Page},
     solana_network::SolanaNetwork,
     spl_governance::{
         Governance, Proposal, ProposalV2, Realm, SignatoryRecord, TokenOwnerRecord, VoteRecord,
     },
     storefront::{Storefront, StorefrontColumns},
-    wallet::{AssociatedTokenAccount, Wallet},
+    wallet::{AssociatedTokenAccount, TokenBalance, Wallet},
+};
+use scalars::{
+    markers::{ProgramId, TokenMint},
+    PublicKey,
 };
-use scalars::{markers::TokenMint, PublicKey};
 use serde_json::Value;
 use tables::{
     associated_token_accounts, auction_caches, auction_datas, auction_datas_ext, auction_houses,
-    bid_receipts, candy_machine_datas, candy_machines, current_metadata_owners, geno_habitat_datas,
-    governances, graph_connections, metadata_jsons, metadatas, realms, signatory_records,
+    bid_receipts, candy_machine_datas, candy_machines, core_candy_machines,
+    current_metadata_owners, geno_habitat_datas, governances, graph_connections, me_amm_pools,
+    metadata_jsons, metadatas, program_accounts, program_instructions, realms, signatory_records,
     store_config_jsons, storefronts, token_owner_records, twitter_handle_name_services,
     wallet_totals,
 };
@@ -57,27 +67,181 @@ impl From<AttributeFilter> for queries::metadatas::AttributeFilter {
     }
 }
 
+/// Filter arguments shared by the NFT list queries
+struct NftFilters {
+    owners: Option<Vec<PublicKey<Wallet>>>,
+    creators: Option<Vec<PublicKey<Wallet>>>,
+    update_authorities: Option<Vec<PublicKey<Wallet>>>,
+    offerers: Option<Vec<PublicKey<Wallet>>>,
+    attributes: Option<Vec<AttributeFilter>>,
+    listed: Option<bool>,
+    allow_unverified: Option<bool>,
+    with_offers: Option<bool>,
+    auction_houses: Option<Vec<PublicKey<AuctionHouse>>>,
+    collections: Option<Vec<PublicKey<Nft>>>,
+    term: Option<String>,
+}
+
 impl QueryRoot {
     fn candy_machine(context: &AppContext, address: String) -> FieldResult<Option<CandyMachine>> {
         let conn = context.shared.db.get()?;
 
-        candy_machines::table
+        let candy_machine = candy_machines::table
             .inner_join(
                 candy_machine_datas::table
                     .on(candy_machines::address.eq(candy_machine_datas::candy_machine_address)),
             )
-            .filter(candy_machines::address.eq(address))
+            .filter(candy_machines::address.eq(&address))
             .select((
                 candy_machines::all_columns,
                 candy_machine_datas::all_columns,
             ))
             .first::<(models::CandyMachine, models::CandyMachineData)>(&conn)
             .optional()
-            .context("Failed to load candy machine by address.")?
+            .context("Failed to load candy machine by address.")?;
+
+        if let Some(candy_machine) = candy_machine {
+            return Ok(Some(candy_machine.try_into()?));
+        }
+
+        core_candy_machines::table
+            .filter(core_candy_machines::address.eq(address))
+            .first::<models::CoreCandyMachine>(&conn)
+            .optional()
+            .context("Failed to load v3 candy machine by address.")?
             .map(TryInto::try_into)
             .transpose()
             .map_err(Into::into)
     }
+
+    fn collection_sort(
+        sort_by: CollectionSort,
+        time_frame: CollectionInterval,
+    ) -> db::custom_types::CollectionSort {
+        match (time_frame, sort_by) {
+            (CollectionInterval::One, CollectionSort::Volume) => {
+                db::custom_types::CollectionSort::OneDayVolume
+            },
+            (CollectionInterval::Seven, CollectionSort::Volume) => {
+                db::custom_types::CollectionSort::SevenDayVolume
+            },
+            (CollectionInterval::Thirty, CollectionSort::Volume) => {
+                db::custom_types::CollectionSort::ThirtyDayVolume
+            },
+            (CollectionInterval::One, CollectionSort::NumberListed) => {
+                db::custom_types::CollectionSort::OneDayListedCount
+            },
+            (CollectionInterval::Seven, CollectionSort::NumberListed) => {
+                db::custom_types::CollectionSort::SevenDayListedCount
+            },
+            (CollectionInterval::Thirty, CollectionSort::NumberListed) => {
+                db::custom_types::CollectionSort::ThirtyDayListedCount
+            },
+            (CollectionInterval::One, CollectionSort::Floor) => {
+                db::custom_types::CollectionSort::OneDayFloorPrice
+            },
+            (CollectionInterval::Seven, CollectionSort::Floor) => {
+                db::custom_types::CollectionSort::SevenDayFloorPrice
+            },
+            (CollectionInterval::Thirty, CollectionSort::Floor) => {
+                db::custom_types::CollectionSort::ThirtyDayFloorPrice
+            },
+        }
+    }
+
+    async fn nfts(
+        context: &AppContext,
+        NftFilters {
+            owners,
+            creators,
+            update_authorities,
+            offerers,
+            attributes,
+            listed,
+            allow_unverified,
+            with_offers,
+            auction_houses,
+            collections,
+            term,
+        }: NftFilters,
+        page: Pagination<(Option<i64>, String)>,
+    ) -> FieldResult<Vec<models::ListedNft>> {
+        if collections.is_none()
+            && owners.is_none()
+            && creators.is_none()
+            && auction_houses.is_none()
+            && offerers.is_none()
+            && term.is_none()
+            && update_authorities.is_none()
+        {
+            return Err(FieldError::new(
+                "No filter provided! Please provide at least one of the following arguments",
+                graphql_value!([
+                    "collections",
+                    "owners",
+                    "creators",
+                    "auction_houses",
+                    "offerers",
+                    "term",
+                    "update_authorities"
+                ]),
+            ));
+        }
+
+        if let Some(false) = with_offers {
+            return Err(FieldError::new(
+                "with_offers == false is not currently supported",
+                graphql_value!({ "invalid_parameter": "with_offers" }),
+            ));
+        }
+
+        let conn = context.shared.db.get().context("failed to connect to db")?;
+
+        let addresses = match term {
+            Some(term) => {
+                let search = &context.shared.search;
+                let search_result = search
+                    .index("metadatas")
+                    .search()
+                    .with_query(&term)
+                    .with_limit(context.shared.pre_query_search_limit)
+                    .execute::<Value>()
+                    .await
+                    .context("failed to load search result for metadata json")?
+                    .hits;
+
+                Some(
+                    search_result
+                        .into_iter()
+                        .map(|r| MetadataJson::from(r.result).address)
+                        .collect(),
+                )
+            },
+            None => None,
+        };
+
+        let query_options = queries::metadatas::ListQueryOptions {
+            addresses,
+            owners: owners.map(|o| o.into_iter().map(Into::into).collect()),
+            creators: creators.map(|c| c.into_iter().map(Into::into).collect()),
+            update_authorities: update_authorities.map(|a| a.into_iter().map(Into::into).collect()),
+            offerers: offerers.map(|o| o.into_iter().map(Into::into).collect()),
+            attributes: attributes.map(|a| a.into_iter().map(Into::into).collect()),
+            listed,
+            allow_unverified,
+            with_offers,
+            auction_houses: auction_houses.map(|h| h.into_iter().map(Into::into).collect()),
+            collections: collections.map(|c| c.into_iter().map(Into::into).collect()),
+            page,
+        };
+
+        queries::metadatas::list(
+            &conn,
+            query_options,
+            pubkeys::OPENSEA_AUCTION_HOUSE.to_string(),
+        )
+        .map_err(Into::into)
+    }
 }
 
 #[graphql_object(Context = AppContext)]
@@ -228,6 +392,7 @@ impl QueryRoot {
         row.map(TryInto::try_into).transpose().map_err(Into::into)
     }
 
+    #[graphql(deprecated = "Use connectionsConnection instead")]
     fn connections(
         &self,
         context: &AppContext,
@@ -262,6 +427,53 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Get a page of graph connections, ordered by address")]
+    fn connections_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Connections from a list of wallets")] from: Option<
+            Vec<PublicKey<Wallet>>,
+        >,
+        #[graphql(description = "Connections to a list of wallets")] to: Option<
+            Vec<PublicKey<Wallet>>,
+        >,
+        #[graphql(description = "Number of connections after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of connections before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<GraphConnection>> {
+        if from.is_none() && to.is_none() {
+            return Err(FieldError::new(
+                "No filter provided! Please provide at least one of the filters",
+                graphql_value!({ "Filters": "from: Vec<PublicKey>, to: Vec<PublicKey>" }),
+            ));
+        }
+        let page = Page::<String>::new(first, after, last, before)?;
+        let conn = context.shared.db.get().context("failed to connect to db")?;
+        let from: Vec<String> = from
+            .unwrap_or_default()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        let to: Vec<String> = to.unwrap_or_default().into_iter().map(Into::into).collect();
+
+        let rows = queries::graph_connection::connections_page(
+            &conn,
+            from,
+            to,
+            page.cursor(),
+            page.is_forward(),
+            page.limit(),
+        )?;
+
+        let rows = rows
+            .into_iter()
+            .map(|r| (r.connection_address.clone(), r.into()))
+            .collect();
+
+        Connection::new(&page, rows)
+    }
+
     fn creator(
         &self,
         context: &AppContext,
@@ -277,6 +489,7 @@ impl QueryRoot {
         })
     }
 
+    #[graphql(deprecated = "Use nftsConnection instead")]
     async fn nfts(
         &self,
         context: &AppContext,
@@ -324,87 +537,100 @@ impl QueryRoot {
             },
         };
 
-        if collections.is_none()
-            && owners.is_none()
-            && creators.is_none()
-            && auction_houses.is_none()
-            && offerers.is_none()
-            && term.is_none()
-            && update_authorities.is_none()
-        {
-            return Err(FieldError::new(
-                "No filter provided! Please provide at least one of the following arguments",
-                graphql_value!([
-                    "collections",
-                    "owners",
-                    "creators",
-                    "auction_houses",
-                    "offerers",
-                    "term",
-                    "update_authorities"
-                ]),
-            ));
-        }
-
-        if let Some(false) = with_offers {
-            return Err(FieldError::new(
-                "with_offers == false is not currently supported",
-                graphql_value!({ "invalid_parameter": "with_offers" }),
-            ));
-        }
-
-        let conn = context.shared.db.get().context("failed to connect to db")?;
-
-        let addresses = match term {
-            Some(term) => {
-                let search = &context.shared.search;
-                let search_result = search
-                    .index("metadatas")
-                    .search()
-                    .with_query(&term)
-                    .with_limit(context.shared.pre_query_search_limit)
-                    .execute::<Value>()
-                    .await
-                    .context("failed to load search result for metadata json")?
-                    .hits;
-
-                Some(
-                    search_result
-                        .into_iter()
-                        .map(|r| MetadataJson::from(r.result).address)
-                        .collect(),
-                )
-            },
-            None => None,
-        };
-
-        let query_options = queries::metadatas::ListQueryOptions {
-            addresses,
-            owners: owners.map(|o| o.into_iter().map(Into::into).collect()),
-            creators: creators.map(|c| c.into_iter().map(Into::into).collect()),
-            update_authorities: update_authorities.map(|a| a.into_iter().map(Into::into).collect()),
-            offerers: offerers.map(|o| o.into_iter().map(Into::into).collect()),
-            attributes: attributes.map(|a| a.into_iter().map(Into::into).collect()),
+        let filters = NftFilters {
+            owners,
+            creators,
+            update_authorities,
+            offerers,
+            attributes,
             listed,
             allow_unverified,
             with_offers,
-            auction_houses: auction_houses.map(|h| h.into_iter().map(Into::into).collect()),
-            collections: collections.map(|c| c.into_iter().map(Into::into).collect()),
+            auction_houses,
+            collections,
+            term,
+        };
+        let page = Pagination::Offset {
             limit: limit.try_into()?,
             offset: offset.try_into()?,
         };
-        let nfts = queries::metadatas::list(
-            &conn,
-            query_options,
-            pubkeys::OPENSEA_AUCTION_HOUSE.to_string(),
-        )?;
 
-        nfts.into_iter()
-            .map(TryInto::try_into)
+        Self::nfts(context, filters, page)
+            .await?
+            .into_iter()
+            .map(|n| n.nft.try_into())
             .collect::<Result<_, _>>()
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Get a page of NFTs, ordered by listed price with unlisted NFTs last")]
+    async fn nfts_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Filter on owner address")] owners: Option<Vec<PublicKey<Wallet>>>,
+        #[graphql(description = "Filter on creator address")] creators: Option<
+            Vec<PublicKey<Wallet>>,
+        >,
+        #[graphql(description = "Filter on update authorities")] update_authorities: Option<
+            Vec<PublicKey<Wallet>>,
+        >,
+        #[graphql(description = "Filter on offerers address")] offerers: Option<
+            Vec<PublicKey<Wallet>>,
+        >,
+        #[graphql(description = "Filter on attributes")] attributes: Option<Vec<AttributeFilter>>,
+        #[graphql(description = "Filter only listed NFTs")] listed: Option<bool>,
+        #[graphql(description = "Allow unverified NFTs")] allow_unverified: Option<bool>,
+        #[graphql(
+            description = "Filter only NFTs with active offers; rejected if flag is 'false'"
+        )]
+        with_offers: Option<bool>,
+        #[graphql(description = "Filter NFTs associated to the list of auction houses")]
+        auction_houses: Option<Vec<PublicKey<AuctionHouse>>>,
+        #[graphql(description = "Filter on one or more collections")] collections: Option<
+            Vec<PublicKey<Nft>>,
+        >,
+        #[graphql(
+            description = "Return NFTs whose metadata contain this search term (case-insensitive)"
+        )]
+        term: Option<String>,
+        #[graphql(description = "Number of NFTs after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of NFTs before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<Nft>> {
+        let page = Page::<(Option<i64>, String)>::new(first, after, last, before)?;
+        let filters = NftFilters {
+            owners,
+            creators,
+            update_authorities,
+            offerers,
+            attributes,
+            listed,
+            allow_unverified,
+            with_offers,
+            auction_houses,
+            collections,
+            term,
+        };
+        let pagination = Pagination::Keyset {
+            cursor: page.cursor().cloned(),
+            forward: page.is_forward(),
+            limit: page.limit().try_into()?,
+        };
+
+        let rows = Self::nfts(context, filters, pagination)
+            .await?
+            .into_iter()
+            .map(|n| {
+                let key = (n.listing_price, n.nft.address.clone());
+
+                Ok((key, n.nft.try_into()?))
+            })
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
     #[graphql(description = "Stats aggregated across all indexed NFTs")]
     fn nfts_stats(&self) -> NftsStats {
         NftsStats
@@ -489,6 +715,108 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Get a page of the given wallets, ordered by address")]
+    async fn wallets_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Addresses of the wallets")] addresses: Vec<PublicKey<Wallet>>,
+        #[graphql(description = "Number of wallets after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of wallets before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<Wallet>> {
+        if addresses.is_empty() {
+            return Err(FieldError::new(
+                "You must supply at least one address to query.",
+                graphql_value!({ "addresses": "Vec<String>"}),
+            ));
+        }
+
+        let page = Page::<String>::new(first, after, last, before)?;
+
+        let mut addresses: Vec<_> = addresses
+            .into_iter()
+            .filter(|a| match (page.is_forward(), page.cursor()) {
+                (true, Some(cursor)) => a.as_ref() > cursor.as_str(),
+                (false, Some(cursor)) => a.as_ref() < cursor.as_str(),
+                (_, None) => true,
+            })
+            .collect();
+
+        addresses.sort_unstable();
+        addresses.dedup();
+
+        if !page.is_forward() {
+            addresses.reverse();
+        }
+
+        addresses.truncate(page.limit().try_into()?);
+
+        let wallets =
+            futures_util::future::try_join_all(addresses.iter().map(|a| context.wallet(a.clone())))
+                .await?;
+
+        let rows = addresses
+            .into_iter()
+            .map(String::from)
+            .zip(wallets)
+            .collect();
+
+        Connection::new(&page, rows)
+    }
+
+    #[graphql(description = "Get a page of feed events, most recent first")]
+    fn feed_events_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Only events of wallets this wallet follows")] wallet: Option<
+            PublicKey<Wallet>,
+        >,
+        #[graphql(description = "Only events of these kinds")] include_types: Option<
+            Vec<FeedEventKind>,
+        >,
+        #[graphql(description = "Number of events after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of events before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<FeedEvent>> {
+        let page = Page::<(i64, String)>::new(first, after, last, before)?;
+        let cursor = page
+            .cursor()
+            .map(|(nanos, id)| {
+                let created_at = NaiveDateTime::from_timestamp_opt(
+                    nanos.div_euclid(1_000_000_000),
+                    nanos.rem_euclid(1_000_000_000).try_into()?,
+                )
+                .context("Invalid feed event cursor timestamp")?;
+
+                Ok::<_, FieldError>((created_at, Uuid::parse_str(id)?))
+            })
+            .transpose()?;
+        let conn = context.shared.db.get()?;
+
+        let events = queries::feed_event::list_relay(
+            &conn,
+            wallet.map(Into::into),
+            include_types.map(|t| t.into_iter().map(Into::into).collect()),
+            cursor,
+            page.is_forward(),
+            page.limit().try_into()?,
+        )?;
+
+        let rows = events
+            .into_iter()
+            .map(|e| {
+                let key = (e.created_at.timestamp_nanos(), e.id.to_string());
+
+                Ok((key, e.try_into()?))
+            })
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
+    #[graphql(deprecated = "Use associatedTokenAccountsConnection instead")]
     async fn associated_token_accounts(
         &self,
         context: &AppContext,
@@ -506,11 +834,313 @@ impl QueryRoot {
             .load::<models::AssociatedTokenAccount>(&conn)
             .context("Failed to load token accounts")?
             .into_iter()
-            .map(AssociatedTokenAccount::try_from)
+            .map(AssociatedTokenAccount::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    #[graphql(description = "Get a page of token accounts, ordered by address")]
+    fn associated_token_accounts_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Token mint addresses")] mints: Vec<PublicKey<TokenMint>>,
+        #[graphql(description = "Number of accounts after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of accounts before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<AssociatedTokenAccount>> {
+        let page = Page::<String>::new(first, after, last, before)?;
+        let conn = context.shared.db.get()?;
+
+        let mut query = associated_token_accounts::table
+            .select(associated_token_accounts::all_columns)
+            .filter(associated_token_accounts::mint.eq(any(mints)))
+            .into_boxed();
+
+        query = match (page.is_forward(), page.cursor()) {
+            (true, Some(address)) => query.filter(associated_token_accounts::address.gt(address)),
+            (false, Some(address)) => query.filter(associated_token_accounts::address.lt(address)),
+            (_, None) => query,
+        };
+
+        query = if page.is_forward() {
+            query.order(associated_token_accounts::address.asc())
+        } else {
+            query.order(associated_token_accounts::address.desc())
+        };
+
+        let rows = query
+            .limit(page.limit())
+            .load::<models::AssociatedTokenAccount>(&conn)
+            .context("Failed to load token accounts")?
+            .into_iter()
+            .map(|a| Ok((a.address.to_string(), a.try_into()?)))
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
+    #[graphql(description = "Get a wallet's total balance of each of the given token mints")]
+    fn token_balances(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Address of the wallet")] wallet: PublicKey<Wallet>,
+        #[graphql(description = "Token mint addresses")] mints: Vec<PublicKey<TokenMint>>,
+    ) -> FieldResult<Vec<TokenBalance>> {
+        let conn = context.shared.db.get()?;
+
+        let balances = queries::wallet::token_balances(&conn, wallet.as_ref(), &mints)?;
+
+        Ok(mints
+            .into_iter()
+            .zip(balances)
+            .map(|(mint, amount)| TokenBalance {
+                owner: wallet.clone(),
+                mint,
+                amount: amount.into(),
+            })
+            .collect())
+    }
+
+    #[graphql(description = "Get Magic Eden AMM pools by address or owner")]
+    fn magic_eden_amm_pools(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Filter on pool addresses")] addresses: Option<
+            Vec<PublicKey<AmmPool>>,
+        >,
+        #[graphql(description = "Filter on pool owners")] owners: Option<Vec<PublicKey<Wallet>>>,
+    ) -> FieldResult<Vec<AmmPool>> {
+        if addresses.is_none() && owners.is_none() {
+            return Err(FieldError::new(
+                "You must supply atleast one filter",
+                graphql_value!({ "Filters": "addresses: Vec<PublicKey<AmmPool>>, owners: Vec<PublicKey<Wallet>>" }),
+            ));
+        }
+
+        let conn = context.shared.db.get()?;
+        let mut query = me_amm_pools::table
+            .select(me_amm_pools::all_columns)
+            .into_boxed();
+
+        if let Some(addresses) = addresses {
+            query = query.filter(me_amm_pools::address.eq(any(addresses)));
+        }
+        if let Some(owners) = owners {
+            query = query.filter(me_amm_pools::owner.eq(any(owners)));
+        }
+
+        query
+            .load::<models::MeAmmPool>(&conn)
+            .context("Failed to load Magic Eden AMM pools")?
+            .into_iter()
+            .map(AmmPool::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    #[graphql(
+        description = "Get accounts of a program indexed from its Anchor IDL",
+        deprecated = "Use programAccountsConnection instead"
+    )]
+    fn program_accounts(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Address of the program")] program_id: PublicKey<ProgramId>,
+        #[graphql(description = "IDL account type name")] account_type: Option<String>,
+        #[graphql(description = "Query limit")] limit: i32,
+        #[graphql(description = "Query offset")] offset: i32,
+    ) -> FieldResult<Vec<ProgramAccount>> {
+        let conn = context.shared.db.get()?;
+
+        let mut query = program_accounts::table
+            .select(program_accounts::all_columns)
+            .filter(program_accounts::program_id.eq(&program_id))
+            .into_boxed();
+
+        if let Some(account_type) = account_type {
+            query = query.filter(program_accounts::account_type.eq(account_type));
+        }
+
+        query
+            .order(program_accounts::slot.desc())
+            .offset(offset.into())
+            .limit(limit.into())
+            .load::<models::ProgramAccount>(&conn)
+            .context("Failed to load program accounts")?
+            .into_iter()
+            .map(ProgramAccount::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    #[graphql(description = "Get a page of accounts of a program, most recently updated first")]
+    fn program_accounts_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Address of the program")] program_id: PublicKey<ProgramId>,
+        #[graphql(description = "IDL account type name")] account_type: Option<String>,
+        #[graphql(description = "Number of accounts after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of accounts before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<ProgramAccount>> {
+        let page = Page::<(i64, String)>::new(first, after, last, before)?;
+        let conn = context.shared.db.get()?;
+
+        let mut query = program_accounts::table
+            .select(program_accounts::all_columns)
+            .filter(program_accounts::program_id.eq(&program_id))
+            .into_boxed();
+
+        if let Some(account_type) = account_type {
+            query = query.filter(program_accounts::account_type.eq(account_type));
+        }
+
+        query = match (page.is_forward(), page.cursor()) {
+            (true, Some((slot, address))) => query.filter(
+                program_accounts::slot.lt(slot).or(program_accounts::slot
+                    .eq(slot)
+                    .and(program_accounts::address.lt(address))),
+            ),
+            (false, Some((slot, address))) => query.filter(
+                program_accounts::slot.gt(slot).or(program_accounts::slot
+                    .eq(slot)
+                    .and(program_accounts::address.gt(address))),
+            ),
+            (_, None) => query,
+        };
+
+        query = if page.is_forward() {
+            query.order((
+                program_accounts::slot.desc(),
+                program_accounts::address.desc(),
+            ))
+        } else {
+            query.order((
+                program_accounts::slot.asc(),
+                program_accounts::address.asc(),
+            ))
+        };
+
+        let rows = query
+            .limit(page.limit())
+            .load::<models::ProgramAccount>(&conn)
+            .context("Failed to load program accounts")?
+            .into_iter()
+            .map(|a| Ok(((a.slot, a.address.to_string()), a.try_into()?)))
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
+    #[graphql(
+        description = "Get instructions of a program indexed from its Anchor IDL",
+        deprecated = "Use programInstructionsConnection instead"
+    )]
+    fn program_instructions(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Address of the program")] program_id: PublicKey<ProgramId>,
+        #[graphql(description = "IDL instruction name")] instruction_name: Option<String>,
+        #[graphql(description = "Query limit")] limit: i32,
+        #[graphql(description = "Query offset")] offset: i32,
+    ) -> FieldResult<Vec<ProgramInstruction>> {
+        let conn = context.shared.db.get()?;
+
+        let mut query = program_instructions::table
+            .select(program_instructions::all_columns)
+            .filter(program_instructions::program_id.eq(&program_id))
+            .into_boxed();
+
+        if let Some(instruction_name) = instruction_name {
+            query = query.filter(program_instructions::instruction_name.eq(instruction_name));
+        }
+
+        query
+            .order(program_instructions::slot.desc())
+            .offset(offset.into())
+            .limit(limit.into())
+            .load::<models::ProgramInstruction>(&conn)
+            .context("Failed to load program instructions")?
+            .into_iter()
+            .map(ProgramInstruction::try_from)
             .collect::<Result<_, _>>()
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Get a page of instructions of a program, most recent first")]
+    fn program_instructions_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Address of the program")] program_id: PublicKey<ProgramId>,
+        #[graphql(description = "IDL instruction name")] instruction_name: Option<String>,
+        #[graphql(description = "Number of instructions after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of instructions before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<ProgramInstruction>> {
+        let page = Page::<(i64, String)>::new(first, after, last, before)?;
+        let cursor = page
+            .cursor()
+            .map(|(slot, id)| Ok::<_, FieldError>((*slot, Uuid::parse_str(id)?)))
+            .transpose()?;
+        let conn = context.shared.db.get()?;
+
+        let mut query = program_instructions::table
+            .select(program_instructions::all_columns)
+            .filter(program_instructions::program_id.eq(&program_id))
+            .into_boxed();
+
+        if let Some(instruction_name) = instruction_name {
+            query = query.filter(program_instructions::instruction_name.eq(instruction_name));
+        }
+
+        query = match (page.is_forward(), cursor) {
+            (true, Some((slot, id))) => query.filter(
+                program_instructions::slot
+                    .lt(slot)
+                    .or(program_instructions::slot
+                        .eq(slot)
+                        .and(program_instructions::id.lt(id))),
+            ),
+            (false, Some((slot, id))) => query.filter(
+                program_instructions::slot
+                    .gt(slot)
+                    .or(program_instructions::slot
+                        .eq(slot)
+                        .and(program_instructions::id.gt(id))),
+            ),
+            (_, None) => query,
+        };
+
+        query = if page.is_forward() {
+            query.order((
+                program_instructions::slot.desc(),
+                program_instructions::id.desc(),
+            ))
+        } else {
+            query.order((
+                program_instructions::slot.asc(),
+                program_instructions::id.asc(),
+            ))
+        };
+
+        let rows = query
+            .limit(page.limit())
+            .load::<models::ProgramInstruction>(&conn)
+            .context("Failed to load program instructions")?
+            .into_iter()
+            .map(|i| {
+                let id = i.id.context("Program instruction missing ID")?;
+
+                Ok(((i.slot, id.to_string()), i.try_into()?))
+            })
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
     fn listings(&self, context: &AppContext) -> FieldResult<Vec<Listing>> {
         let now = Local::now().naive_utc();
         let conn = context.shared.db.get()?;
@@ -735,7 +1365,8 @@ impl QueryRoot {
             ),
             limit(description = "Return at most this many results"),
             offset(description = "Return results starting from this index"),
-        )
+        ),
+        deprecated = "Use collectionTrendsConnection instead"
     )]
     async fn collection_trends(
         &self,
@@ -748,41 +1379,13 @@ impl QueryRoot {
     ) -> FieldResult<Vec<CollectionTrend>> {
         let conn = context.shared.db.get().context("failed to connect to db")?;
 
-        let sort = match (time_frame, sort_by) {
-            (CollectionInterval::One, CollectionSort::Volume) => {
-                db::custom_types::CollectionSort::OneDayVolume
-            },
-            (CollectionInterval::Seven, CollectionSort::Volume) => {
-                db::custom_types::CollectionSort::SevenDayVolume
-            },
-            (CollectionInterval::Thirty, CollectionSort::Volume) => {
-                db::custom_types::CollectionSort::ThirtyDayVolume
-            },
-            (CollectionInterval::One, CollectionSort::NumberListed) => {
-                db::custom_types::CollectionSort::OneDayListedCount
-            },
-            (CollectionInterval::Seven, CollectionSort::NumberListed) => {
-                db::custom_types::CollectionSort::SevenDayListedCount
-            },
-            (CollectionInterval::Thirty, CollectionSort::NumberListed) => {
-                db::custom_types::CollectionSort::ThirtyDayListedCount
-            },
-            (CollectionInterval::One, CollectionSort::Floor) => {
-                db::custom_types::CollectionSort::OneDayFloorPrice
-            },
-            (CollectionInterval::Seven, CollectionSort::Floor) => {
-                db::custom_types::CollectionSort::SevenDayFloorPrice
-            },
-            (CollectionInterval::Thirty, CollectionSort::Floor) => {
-                db::custom_types::CollectionSort::ThirtyDayFloorPrice
-            },
-        };
-
         let collections = queries::collections::trends(&conn, TrendingQueryOptions {
-            sort_by: sort,
+            sort_by: Self::collection_sort(sort_by, time_frame),
             order: order_direction.map(Into::into),
-            limit: limit.try_into()?,
-            offset: offset.try_into()?,
+            page: Pagination::Offset {
+                limit: limit.try_into()?,
+                offset: offset.try_into()?,
+            },
         })?;
 
         collections
@@ -792,6 +1395,61 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(
+        description = "Get a page of trending collections, ordered by the selected statistic",
+        arguments(
+            sort_by(description = "Choose sort for trending collections"),
+            time_frame(description = "The desired timeframe to evaluate the trending collection"),
+            order_direction(
+                description = "Arrange result in ascending or descending order by selected sort_by"
+            ),
+            first(description = "Number of collections after the cursor"),
+            after(description = "Cursor to start after"),
+            last(description = "Number of collections before the cursor"),
+            before(description = "Cursor to end before"),
+        )
+    )]
+    fn collection_trends_connection(
+        &self,
+        context: &AppContext,
+        sort_by: CollectionSort,
+        time_frame: CollectionInterval,
+        order_direction: Option<OrderDirection>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> FieldResult<Connection<CollectionTrend>> {
+        let page = Page::<(String, String)>::new(first, after, last, before)?;
+        let cursor = page
+            .cursor()
+            .map(|(value, id)| Ok::<_, FieldError>((value.parse::<BigDecimal>()?, id.clone())))
+            .transpose()?;
+        let sort = Self::collection_sort(sort_by, time_frame);
+        let conn = context.shared.db.get().context("failed to connect to db")?;
+
+        let collections = queries::collections::trends(&conn, TrendingQueryOptions {
+            sort_by: sort,
+            order: order_direction.map(Into::into),
+            page: Pagination::Keyset {
+                cursor,
+                forward: page.is_forward(),
+                limit: page.limit().try_into()?,
+            },
+        })?;
+
+        let rows = collections
+            .into_iter()
+            .map(|c| {
+                let (value, id) = queries::collections::trend_sort_key(sort, &c);
+
+                Ok(((value.to_string(), id), c.try_into()?))
+            })
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
     #[graphql(
         description = "Returns featured collection NFTs ordered by market cap (floor price * number of NFTs in collection)",
         arguments(
@@ -959,7 +1617,10 @@ impl QueryRoot {
             .collect::<Vec<CollectionDocument>>())
     }
 
-    #[graphql(description = "returns profiles matching the search term")]
+    #[graphql(
+        description = "returns profiles matching the search term",
+        deprecated = "Use profilesConnection instead"
+    )]
     async fn profiles(
         &self,
         context: &AppContext,
@@ -986,6 +1647,57 @@ impl QueryRoot {
             .collect::<Vec<Wallet>>())
     }
 
+    #[graphql(description = "Get a page of profiles matching the search term, by relevance")]
+    async fn profiles_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Search term")] term: String,
+        #[graphql(description = "Number of profiles after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of profiles before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<Wallet>> {
+        // Search hits have no sort key of their own, so each cursor is the
+        // rank of its hit in the search results
+        let page = Page::<usize>::new(first, after, last, before)?;
+        let limit = usize::try_from(page.limit())?;
+
+        let (offset, limit) = match (page.is_forward(), page.cursor().copied()) {
+            (true, rank) => (rank.map_or(0, |r| r.saturating_add(1)), limit),
+            (false, Some(rank)) => (rank.saturating_sub(limit), limit.min(rank)),
+            (false, None) => {
+                return Err(FieldError::new(
+                    "Paginating backwards requires a cursor",
+                    graphql_value!({ "Arguments": "last/before" }),
+                ));
+            },
+        };
+
+        let hits = context
+            .shared
+            .search
+            .index("name_service")
+            .search()
+            .with_query(&term)
+            .with_offset(offset)
+            .with_limit(limit)
+            .execute::<Value>()
+            .await
+            .context("failed to load search result for twitter handle")?
+            .hits;
+
+        let mut rows: Vec<_> = (offset..)
+            .zip(hits)
+            .map(|(rank, hit)| (rank, hit.result.into()))
+            .collect();
+
+        if !page.is_forward() {
+            rows.reverse();
+        }
+
+        Connection::new(&page, rows)
+    }
+
     #[graphql(description = "returns stats about profiles")]
     async fn profiles_stats(&self) -> ProfilesStats {
         ProfilesStats
@@ -1089,7 +1801,7 @@ impl QueryRoot {
         Ok(row.map(Into::into))
     }
 
-    #[graphql(deprecated = "Use genoHabitatsCounted instead")]
+    #[graphql(deprecated = "Use genoHabitatsCounted or genoHabitatsConnection instead")]
     async fn geno_habitats(
         &self,
         ctx: &AppContext,
@@ -1156,6 +1868,87 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Get a page of Genopets habitats, ordered by address")]
+    async fn geno_habitats_connection(
+        &self,
+        ctx: &AppContext,
+        mints: Option<Vec<PublicKey<TokenMint>>>,
+        owners: Option<Vec<PublicKey<Wallet>>>,
+        renters: Option<Vec<PublicKey<Wallet>>>,
+        harvesters: Option<Vec<String>>,
+        genesis: Option<bool>,
+        elements: Option<Vec<i32>>,
+        min_level: Option<i32>,
+        max_level: Option<i32>,
+        min_sequence: Option<i32>,
+        max_sequence: Option<i32>,
+        guilds: Option<Vec<i32>>,
+        min_durability: Option<i32>,
+        max_durability: Option<i32>,
+        min_expiry: Option<DateTime<Utc>>,
+        max_expiry: Option<DateTime<Utc>>,
+        harvester_open_market: Option<bool>,
+        rental_open_market: Option<bool>,
+        has_alchemist: Option<bool>,
+        has_harvester: Option<bool>,
+        has_max_ki: Option<bool>,
+        is_activated: Option<bool>,
+        term: Option<String>,
+        #[graphql(description = "Number of habitats after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of habitats before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<GenoHabitat>> {
+        let page = Page::<String>::new(first, after, last, before)?;
+        let conn = ctx.shared.db.get().context("Failed to connect to the DB")?;
+
+        let mut opts = GenoHabitatsParams {
+            mints,
+            owners,
+            renters,
+            harvesters,
+            genesis,
+            elements,
+            min_level,
+            max_level,
+            min_sequence,
+            max_sequence,
+            guilds,
+            min_durability,
+            max_durability,
+            min_expiry,
+            max_expiry,
+            harvester_open_market,
+            rental_open_market,
+            has_alchemist,
+            has_harvester,
+            has_max_ki,
+            is_activated,
+            term,
+            sort_field: None,
+            sort_desc: None,
+            limit: 0,
+            offset: 0,
+        }
+        .into_db_opts(ctx)
+        .await?;
+
+        opts.page = Pagination::Keyset {
+            cursor: page.cursor().cloned(),
+            forward: page.is_forward(),
+            limit: page.limit().try_into()?,
+        };
+
+        let (habitats, _) = queries::genopets::list_habitats(&conn, opts)?;
+
+        let rows = habitats
+            .into_iter()
+            .map(|h| (h.address.to_string(), h.into()))
+            .collect();
+
+        Connection::new(&page, rows)
+    }
+
     #[graphql(description = "Query zero or more Genopets habitats")]
     async fn geno_habitats_counted(
         &self,
@@ -1250,6 +2043,7 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(deprecated = "Use proposalsConnection instead")]
     fn proposals(
         &self,
         context: &AppContext,
@@ -1283,6 +2077,64 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Get a page of SPL governance proposals, most recently drafted first")]
+    fn proposals_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Filter on SPL Governance proposals")] addresses: Option<
+            Vec<PublicKey<Proposal>>,
+        >,
+        #[graphql(description = "Filter on spl governance")] governances: Option<
+            Vec<PublicKey<Governance>>,
+        >,
+        start_timestamp: Option<DateTime<Utc>>,
+        end_timestamp: Option<DateTime<Utc>>,
+        #[graphql(description = "Number of proposals after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of proposals before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<Proposal>> {
+        let page = Page::<(i64, String)>::new(first, after, last, before)?;
+        let (cursor_draft_at, cursor_address) = match page.cursor() {
+            Some((nanos, address)) => {
+                let draft_at = NaiveDateTime::from_timestamp_opt(
+                    nanos.div_euclid(1_000_000_000),
+                    nanos.rem_euclid(1_000_000_000).try_into()?,
+                )
+                .context("Invalid proposal cursor timestamp")?;
+
+                (Some(draft_at), Some(address))
+            },
+            None => (None, None),
+        };
+        let conn = context.shared.db.get()?;
+
+        let proposals: Vec<models::SplGovernanceProposal> =
+            queries::spl_governance::proposals_page(
+                &conn,
+                addresses,
+                governances,
+                start_timestamp.map(|t| t.naive_utc()),
+                end_timestamp.map(|t| t.naive_utc()),
+                cursor_draft_at,
+                cursor_address,
+                page.is_forward(),
+                page.limit(),
+            )?;
+
+        let rows = proposals
+            .into_iter()
+            .map(|p| {
+                let key = (p.draft_at.timestamp_nanos(), p.address.clone());
+
+                Ok((key, p.try_into()?))
+            })
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
+    #[graphql(deprecated = "Use voteRecordsConnection instead")]
     fn vote_records(
         &self,
         context: &AppContext,
@@ -1323,6 +2175,56 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Get a page of SPL governance vote records, ordered by address")]
+    fn vote_records_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Filter on SPL VoteRecordV2 pubkeys")] addresses: Option<
+            Vec<PublicKey<VoteRecord>>,
+        >,
+        #[graphql(description = "Filter on Proposals")] proposals: Option<Vec<PublicKey<Proposal>>>,
+        #[graphql(description = "Filter on GoverningTokenOwners")] governing_token_owners: Option<
+            Vec<PublicKey<Wallet>>,
+        >,
+        #[graphql(description = "Filter on is_relinquished")] is_relinquished: Option<bool>,
+        #[graphql(description = "Number of vote records after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of vote records before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<VoteRecord>> {
+        if addresses.is_none()
+            && proposals.is_none()
+            && governing_token_owners.is_none()
+            && is_relinquished.is_none()
+        {
+            return Err(FieldError::new(
+                "You must supply atleast one filter",
+                graphql_value!({ "Filters": "addresses: Vec<PublicKey<VoteRecordV2>>, proposals: Vec<PublicKey<Proposal>>, governing_token_owners: Vec<PublicKey<Wallet>>, is_relinquished: bool" }),
+            ));
+        }
+
+        let page = Page::<String>::new(first, after, last, before)?;
+        let conn = context.shared.db.get()?;
+
+        let vote_records: Vec<models::VoteRecord> = queries::spl_governance::vote_records_page(
+            &conn,
+            addresses,
+            proposals,
+            governing_token_owners,
+            is_relinquished,
+            page.cursor(),
+            page.is_forward(),
+            page.limit(),
+        )?;
+
+        let rows = vote_records
+            .into_iter()
+            .map(|v| Ok((v.address.to_string(), v.try_into()?)))
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
     fn signatory_records(
         &self,
         context: &AppContext,
@@ -1363,6 +2265,7 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(deprecated = "Use realmsConnection instead")]
     fn realms(
         &self,
         context: &AppContext,
@@ -1398,6 +2301,57 @@ impl QueryRoot {
             .map_err(Into::into)
     }
 
+    #[graphql(description = "Get a page of SPL governance realms, ordered by address")]
+    fn realms_connection(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Filter on SPL Realm pubkeys")] addresses: Option<
+            Vec<PublicKey<Realm>>,
+        >,
+        #[graphql(description = "Filter on Community mints")] community_mints: Option<
+            Vec<PublicKey<TokenMint>>,
+        >,
+        #[graphql(description = "Number of realms after the cursor")] first: Option<i32>,
+        #[graphql(description = "Cursor to start after")] after: Option<String>,
+        #[graphql(description = "Number of realms before the cursor")] last: Option<i32>,
+        #[graphql(description = "Cursor to end before")] before: Option<String>,
+    ) -> FieldResult<Connection<Realm>> {
+        let page = Page::<String>::new(first, after, last, before)?;
+        let conn = context.shared.db.get()?;
+
+        let mut query = realms::table.select(realms::all_columns).into_boxed();
+
+        if let Some(addresses) = addresses {
+            query = query.filter(realms::address.eq(any(addresses)));
+        }
+
+        if let Some(community_mints) = community_mints {
+            query = query.filter(realms::community_mint.eq(any(community_mints)));
+        }
+
+        query = match (page.is_forward(), page.cursor()) {
+            (true, Some(address)) => query.filter(realms::address.gt(address)),
+            (false, Some(address)) => query.filter(realms::address.lt(address)),
+            (_, None) => query,
+        };
+
+        query = if page.is_forward() {
+            query.order(realms::address.asc())
+        } else {
+            query.order(realms::address.desc())
+        };
+
+        let rows = query
+            .limit(page.limit())
+            .load::<models::Realm>(&conn)
+            .context("Failed to load spl governance realms.")?
+            .into_iter()
+            .map(|r| Ok((r.address.to_string(), r.try_into()?)))
+            .collect::<Result<_>>()?;
+
+        Connection::new(&page, rows)
+    }
+
     fn solana_network() -> SolanaNetwork {
         SolanaNetwork
     }
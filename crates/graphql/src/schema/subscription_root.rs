@@ -0,0 +1,158 @@
+use std::{future::Future, pin::Pin};
+
+use futures_util::{Stream, StreamExt};
+use indexer_core::{db::custom_types::ActivityTypeEnum, uuid::Uuid};
+use juniper::graphql_subscription;
+use objects::{
+    ah_listing::AhListing, ah_offer::Offer, ah_purchase::Purchase, feed_event::FeedEvent,
+    wallet::Wallet,
+};
+use scalars::PublicKey;
+
+use super::prelude::*;
+use crate::live_events::LiveEvent;
+
+type EventStream<T> = Pin<Box<dyn Stream<Item = FieldResult<T>> + Send>>;
+
+/// The number of attempts made to load the rows of a live event
+const LOAD_ATTEMPTS: u32 = 5;
+/// The delay before retrying to load the rows of a live event, doubled after
+/// each attempt
+const LOAD_RETRY_DELAY: StdDuration = StdDuration::from_millis(200);
+
+pub struct SubscriptionRoot;
+
+/// Load the rows of a live event, retrying while they are missing.
+///
+/// Notifications are sent by the primary database, so the rows they refer to
+/// may not yet be visible on the read replica queried by the API.
+async fn load_event<T, F, R>(load: F) -> FieldResult<Option<T>>
+where
+    F: Fn() -> R,
+    R: Future<Output = FieldResult<Option<T>>>,
+{
+    let mut delay = LOAD_RETRY_DELAY;
+
+    for _ in 1..LOAD_ATTEMPTS {
+        if let Some(row) = load().await? {
+            return Ok(Some(row));
+        }
+
+        tokio::time::sleep(delay).await;
+        delay *= 2;
+    }
+
+    let row = load().await?;
+
+    if row.is_none() {
+        warn!(
+            "Live event rows still missing after {} attempts, skipping event",
+            LOAD_ATTEMPTS
+        );
+    }
+
+    Ok(row)
+}
+
+/// Stream the rows loaded for each newly-inserted marketplace activity of the
+/// given type, skipping activities whose rows are not visible to the API
+fn activities<T, F, R>(context: &AppContext, ty: ActivityTypeEnum, load: F) -> EventStream<T>
+where
+    T: Send + 'static,
+    F: Fn(AppContext, Uuid) -> R + Send + Sync + 'static,
+    R: Future<Output = FieldResult<Option<T>>> + Send + 'static,
+{
+    let ctx = context.clone();
+    let load = Arc::new(load);
+
+    Box::pin(
+        context
+            .shared
+            .live_events
+            .stream()
+            .filter_map(move |event| {
+                let id = match event {
+                    LiveEvent::Activity {
+                        activity_type,
+                        activity_id,
+                    } if activity_type == ty => Some(activity_id),
+                    _ => None,
+                };
+                let ctx = ctx.clone();
+                let load = Arc::clone(&load);
+
+                async move {
+                    let id = id?;
+
+                    load_event(|| load(ctx.clone(), id)).await.transpose()
+                }
+            }),
+    )
+}
+
+#[graphql_subscription(context = AppContext)]
+impl SubscriptionRoot {
+    #[graphql(description = "Receive each new listing as it is created")]
+    async fn listing_created(&self, context: &AppContext) -> EventStream<AhListing> {
+        activities(
+            context,
+            ActivityTypeEnum::ListingCreated,
+            |ctx, id| async move { ctx.ah_listing_loader.load(id).await.map_err(Into::into) },
+        )
+    }
+
+    #[graphql(description = "Receive each new offer as it is created")]
+    async fn offer_created(&self, context: &AppContext) -> EventStream<Offer> {
+        activities(
+            context,
+            ActivityTypeEnum::OfferCreated,
+            |ctx, id| async move { ctx.offer_loader.load(id).await.map_err(Into::into) },
+        )
+    }
+
+    #[graphql(description = "Receive each new purchase as it is completed")]
+    async fn purchase_completed(&self, context: &AppContext) -> EventStream<Purchase> {
+        activities(context, ActivityTypeEnum::Purchase, |ctx, id| async move {
+            ctx.purchase_loader.load(id).await.map_err(Into::into)
+        })
+    }
+
+    #[graphql(description = "Receive each new event in a wallet's activity feed")]
+    async fn feed_event(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Address of the wallet")] wallet: PublicKey<Wallet>,
+    ) -> EventStream<FeedEvent> {
+        let ctx = context.clone();
+
+        Box::pin(
+            context
+                .shared
+                .live_events
+                .stream()
+                .filter_map(move |event| {
+                    let id = match event {
+                        LiveEvent::FeedEvent {
+                            feed_event_id,
+                            wallet_address,
+                        } if wallet_address == wallet.as_ref() => Some(feed_event_id),
+                        _ => None,
+                    };
+                    let ctx = ctx.clone();
+                    let wallet = wallet.clone();
+
+                    async move {
+                        let id = id?;
+
+                        load_event(|| async {
+                            let conn = ctx.shared.db.get()?;
+
+                            FeedEvent::load(&conn, id, wallet.clone()).map_err(FieldError::from)
+                        })
+                        .await
+                        .transpose()
+                    }
+                }),
+        )
+    }
+}
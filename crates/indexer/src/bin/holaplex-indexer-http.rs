@@ -40,7 +40,13 @@ fn main() {
     });
 }
 
-async fn run<E: Send + holaplex_indexer::http::Process + 'static>(
+async fn run<
+    E: Send
+        + Sync
+        + holaplex_indexer::http::Process
+        + holaplex_indexer::metrics::MessageInfo
+        + 'static,
+>(
     args: Args,
     params: holaplex_indexer::Params,
     db: holaplex_indexer::db::Pool,
@@ -59,7 +65,8 @@ async fn run<E: Send + holaplex_indexer::http::Process + 'static>(
         _ => sender.clone(),
     };
 
-    let conn = holaplex_indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
+    let conn = holaplex_indexer::amqp_connect(&amqp_url, env!("CARGO_BIN_NAME")).await?;
+    let health_conn = holaplex_indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
     let client = Client::new_rc(
         db,
         &conn,
@@ -77,6 +84,7 @@ async fn run<E: Send + holaplex_indexer::http::Process + 'static>(
     holaplex_indexer::amqp_consume(
         &params,
         conn,
+        health_conn,
         consumer,
         queue_type,
         StdDuration::from_millis(500),
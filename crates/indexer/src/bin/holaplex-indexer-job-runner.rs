@@ -31,15 +31,17 @@ fn main() {
              client,
          },
          params,
-         _db| async move {
+         db| async move {
             let queue_suffix = Suffix::from(queue_suffix);
 
-            let conn = holaplex_indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
+            let conn = holaplex_indexer::amqp_connect(&amqp_url, env!("CARGO_BIN_NAME")).await?;
+            let health_conn =
+                holaplex_indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
             let chan = conn
                 .create_channel()
                 .await
                 .context("Failed to create lazy queue channel")?;
-            let client = Client::new_rc(chan, queue_suffix.clone(), client)
+            let client = Client::new_rc(db, chan, queue_suffix.clone(), client)
                 .context("Failed to construct Client")?;
 
             let queue_type = job_runner::QueueType::new(&sender, &queue_suffix)?;
@@ -50,13 +52,14 @@ fn main() {
             holaplex_indexer::amqp_consume(
                 &params,
                 conn,
+                health_conn,
                 consumer,
                 queue_type,
                 StdDuration::from_secs(120),
                 move |m| {
                     let client = client.clone();
 
-                    async move { holaplex_indexer::jobs::process_message(&client, m).await }
+                    async move { holaplex_indexer::jobs::process_message(&client, &m).await }
                 },
             )
             .await
@@ -32,7 +32,9 @@ fn main() {
          },
          params,
          db| async move {
-            let conn = holaplex_indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
+            let conn = holaplex_indexer::amqp_connect(&amqp_url, env!("CARGO_BIN_NAME")).await?;
+            let health_conn =
+                holaplex_indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
 
             let queue_type = search_indexer::QueueType::new(&sender, &queue_suffix.into())?;
             let consumer =
@@ -47,12 +49,13 @@ fn main() {
             let ret = holaplex_indexer::amqp_consume(
                 &params,
                 conn,
+                health_conn,
                 consumer,
                 queue_type,
                 StdDuration::from_millis(500),
                 move |m| {
                     let client = client.clone();
-                    async move { holaplex_indexer::search::process_message(m, &client).await }
+                    async move { holaplex_indexer::search::process_message(&m, &client).await }
                 },
             )
             .await;
@@ -0,0 +1,499 @@
+//! Coalescing write batches for high-throughput consumers
+//!
+//! While a consumer is processing a startup snapshot it receives far more
+//! account updates than it can write if every write performs its own database
+//! round-trip.  A [`Batcher`] collects the writes submitted by concurrent
+//! message handlers over a short window and flushes them together in a single
+//! transaction.  Each write runs inside its own savepoint, so a failing write
+//! only fails the handler that submitted it, and the statements of a single
+//! write are always applied atomically.  If the batch as a whole cannot be
+//! committed, each of its writes is run again in its own transaction, so
+//! writes are required to be repeatable.
+//!
+//! Up to one batch per pooled connection is flushed at a time, so new writes
+//! keep being collected while earlier batches commit.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use indexer_core::{clap, db::PooledConnection};
+use tokio::sync::{mpsc, oneshot, Semaphore};
+
+use super::Pool;
+use crate::{metrics, prelude::*};
+
+/// Command-line arguments for a [`Batcher`]
+#[derive(Debug, Clone, Copy, clap::Args)]
+#[group(skip)]
+pub struct Args {
+    /// Maximum number of milliseconds to wait for more writes before flushing
+    /// a batch
+    ///
+    /// Set this to zero to disable batching and run each write in its own
+    /// transaction.
+    #[arg(long, env, default_value_t = 10)]
+    db_batch_window_ms: u64,
+
+    /// Maximum number of writes to flush in a single transaction
+    #[arg(long, env, default_value_t = 256)]
+    db_batch_size: usize,
+}
+
+type WriteFn = Box<dyn Fn(&PooledConnection) -> Result<usize> + Send>;
+type WriteResult = Result<usize>;
+
+/// Identifies the row affected by a write, so that writes to the same row
+/// within a batch can be coalesced
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct RowKey {
+    table: &'static str,
+    key: String,
+}
+
+struct Write {
+    row: Option<(RowKey, (u64, u64))>,
+    write: WriteFn,
+    done: oneshot::Sender<WriteResult>,
+}
+
+/// Handle for submitting database writes to be flushed in batches
+#[derive(Debug, Clone)]
+pub struct Batcher {
+    db: Pool,
+    tx: Option<mpsc::Sender<Write>>,
+}
+
+impl Batcher {
+    /// Construct a new batcher, spawning its flush task on the current
+    /// runtime if batching is enabled
+    #[must_use]
+    pub fn new(
+        db: Pool,
+        Args {
+            db_batch_window_ms,
+            db_batch_size,
+        }: Args,
+    ) -> Self {
+        let size = db_batch_size.max(1);
+
+        let tx = (db_batch_window_ms > 0).then(|| {
+            let (tx, rx) = mpsc::channel(size);
+
+            tokio::spawn(run(
+                db.clone(),
+                rx,
+                StdDuration::from_millis(db_batch_window_ms),
+                size,
+            ));
+
+            tx
+        });
+
+        Self { db, tx }
+    }
+
+    /// Submit a write which is not associated with a single versioned row
+    ///
+    /// All statements executed by `f` are applied in a single transaction.
+    /// The returned future resolves with the value returned by `f` once the
+    /// batch containing the write has been committed.  `f` is run again in a
+    /// transaction of its own if its batch fails to commit, so it must not
+    /// have side effects outside the database.
+    ///
+    /// # Errors
+    /// This function fails if the write or the transaction containing it
+    /// fails.
+    pub async fn write<T: Send + 'static, E: Into<indexer_core::error::Error>>(
+        &self,
+        f: impl Fn(&PooledConnection) -> Result<T, E> + Send + 'static,
+    ) -> Result<T> {
+        let value = Arc::new(Mutex::new(None));
+
+        self.submit(
+            None,
+            Box::new({
+                let value = Arc::clone(&value);
+
+                move |db: &PooledConnection| {
+                    let v = f(db).map_err(Into::into)?;
+                    *value
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(v);
+
+                    Ok(0)
+                }
+            }),
+        )
+        .await?;
+
+        let v = value
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take();
+        v.context("Batched write did not produce a value")
+    }
+
+    /// Submit an upsert of the row identified by `table` and `key`, written
+    /// from an account update at the given slot and write version
+    ///
+    /// All statements executed by `f` are applied in a single transaction, and
+    /// `f` should return the number of rows affected by the upsert.  If a
+    /// newer upsert of the same row is submitted before this one is flushed,
+    /// this upsert is dropped and reports zero affected rows, as if it had
+    /// been skipped for being stale.  Because of this, `f` may only write
+    /// state that a newer upsert of the same row fully rewrites, such as the
+    /// row itself and child rows replaced along with it, and must not have
+    /// side effects outside the database, as it is run again if its batch
+    /// fails to commit.
+    ///
+    /// # Errors
+    /// This function fails if the write or the transaction containing it
+    /// fails.
+    pub async fn upsert<E: Into<indexer_core::error::Error>>(
+        &self,
+        table: &'static str,
+        key: impl Into<String>,
+        (slot, write_version): (u64, u64),
+        f: impl Fn(&PooledConnection) -> Result<usize, E> + Send + 'static,
+    ) -> Result<usize> {
+        let row = RowKey {
+            table,
+            key: key.into(),
+        };
+
+        self.submit(
+            Some((row, (slot, write_version))),
+            Box::new(move |db: &PooledConnection| f(db).map_err(Into::into)),
+        )
+        .await
+    }
+
+    async fn submit(&self, row: Option<(RowKey, (u64, u64))>, write: WriteFn) -> Result<usize> {
+        let tx = if let Some(ref tx) = self.tx {
+            tx
+        } else {
+            let start = Instant::now();
+            let res = self.db.run(|db| db.transaction(|| write(db))).await;
+            metrics::observe_db_writes(metrics::WriteMode::Direct, 1, start);
+
+            return res;
+        };
+
+        let (done, rx) = oneshot::channel();
+
+        tx.send(Write { row, write, done })
+            .await
+            .map_err(|_| anyhow!("Write batcher was shut down"))?;
+
+        rx.await.context("Write batcher dropped a pending write")?
+    }
+}
+
+#[derive(Default)]
+struct Batch {
+    writes: Vec<Option<Write>>,
+    rows: HashMap<RowKey, usize>,
+    superseded: Vec<oneshot::Sender<WriteResult>>,
+}
+
+impl Batch {
+    fn len(&self) -> usize {
+        self.writes.len() - self.superseded.len()
+    }
+
+    fn push(&mut self, write: Write) {
+        let Some((ref row, version)) = write.row else {
+            self.writes.push(Some(write));
+            return;
+        };
+
+        if let Some(&idx) = self.rows.get(row) {
+            let prev = self.writes[idx]
+                .as_ref()
+                .and_then(|w| w.row.as_ref())
+                .map(|&(_, v)| v);
+
+            metrics::observe_db_write_coalesced();
+
+            if prev.map_or(false, |p| p >= version) {
+                self.superseded.push(write.done);
+                self.writes.push(None);
+                return;
+            }
+
+            if let Some(prev) = self.writes[idx].take() {
+                self.superseded.push(prev.done);
+            }
+        }
+
+        self.rows.insert(row.clone(), self.writes.len());
+        self.writes.push(Some(write));
+    }
+
+    async fn flush(self, db: &Pool) {
+        let Self {
+            writes, superseded, ..
+        } = self;
+        let (writes, dones): (Vec<_>, Vec<_>) = writes
+            .into_iter()
+            .flatten()
+            .map(|w| (w.write, w.done))
+            .unzip();
+        let count = writes.len();
+        let start = Instant::now();
+
+        let res = db
+            .run(move |db| {
+                let res = db
+                    .build_transaction()
+                    .read_write()
+                    .run(|| Result::<_>::Ok(apply(db, &writes)));
+
+                Result::<_>::Ok((writes, res))
+            })
+            .await;
+
+        // A batch which fails to commit is retried one write at a time on a
+        // fresh connection, so only the writes which fail on their own are
+        // reported as failed
+        let res = match res {
+            Ok((_, Ok(results))) => Ok(results),
+            Ok((writes, Err(e))) => {
+                warn!(
+                    "Failed to commit batch of {} write(s), retrying separately: {:?}",
+                    count, e
+                );
+
+                db.run(move |db| Result::<_>::Ok(apply(db, &writes))).await
+            },
+            Err(e) => Err(e),
+        };
+
+        metrics::observe_db_writes(metrics::WriteMode::Batched, count, start);
+
+        match res {
+            Ok(results) => {
+                for (done, res) in dones.into_iter().zip(results) {
+                    done.send(res).ok();
+                }
+
+                for done in superseded {
+                    done.send(Ok(0)).ok();
+                }
+            },
+            Err(e) => {
+                warn!("Failed to flush batch of {} write(s): {:?}", count, e);
+
+                for done in dones.into_iter().chain(superseded) {
+                    done.send(Err(anyhow!("Batched write transaction failed: {:?}", e)))
+                        .ok();
+                }
+            },
+        }
+    }
+}
+
+/// Run each write in its own savepoint, or its own transaction if not called
+/// inside one, so that a failing write rolls back only its own statements
+fn apply(db: &PooledConnection, writes: &[WriteFn]) -> Vec<WriteResult> {
+    writes.iter().map(|w| db.transaction(|| w(db))).collect()
+}
+
+async fn run(db: Pool, mut rx: mpsc::Receiver<Write>, window: StdDuration, size: usize) {
+    let max_flushes = db.max_size().max(1);
+    let flushes = Arc::new(Semaphore::new(max_flushes.try_into().unwrap_or(1)));
+
+    while let Some(first) = rx.recv().await {
+        let mut batch = Batch::default();
+        batch.push(first);
+
+        let deadline = tokio::time::sleep(window);
+        tokio::pin!(deadline);
+
+        while batch.len() < size {
+            tokio::select! {
+                w = rx.recv() => match w {
+                    Some(w) => batch.push(w),
+                    None => break,
+                },
+                () = &mut deadline => break,
+            }
+        }
+
+        let Ok(permit) = Arc::clone(&flushes).acquire_owned().await else {
+            break;
+        };
+        let db = db.clone();
+
+        tokio::spawn(async move {
+            batch.flush(&db).await;
+            drop(permit);
+        });
+    }
+
+    // Wait for in-flight batches before reporting shutdown
+    let _flushed = flushes.acquire_many(max_flushes).await;
+
+    debug!("Write batcher shutting down");
+}
+
+#[cfg(test)]
+mod tests {
+    use indexer_core::db::{sql_query, ConnectionManager, Pool as DbPool};
+
+    use super::*;
+
+    fn write(key: &str, version: (u64, u64)) -> (Write, oneshot::Receiver<WriteResult>) {
+        let (done, rx) = oneshot::channel();
+        let write = Write {
+            row: Some((
+                RowKey {
+                    table: "test",
+                    key: key.into(),
+                },
+                version,
+            )),
+            write: Box::new(|_| Ok(0)),
+            done,
+        };
+
+        (write, rx)
+    }
+
+    /// Versions of the writes left in the batch after coalescing
+    fn pending(batch: &Batch) -> Vec<(String, (u64, u64))> {
+        batch
+            .writes
+            .iter()
+            .flatten()
+            .filter_map(|w| w.row.as_ref())
+            .map(|(row, version)| (row.key.clone(), *version))
+            .collect()
+    }
+
+    /// Resolve the superseded writes and report which receivers were notified
+    fn superseded(batch: Batch, rxs: &mut [oneshot::Receiver<WriteResult>]) -> Vec<bool> {
+        for done in batch.superseded {
+            done.send(Ok(0)).ok();
+        }
+
+        rxs.iter_mut().map(|rx| rx.try_recv().is_ok()).collect()
+    }
+
+    #[test]
+    fn test_newer_write_replaces_older() {
+        let mut batch = Batch::default();
+        let (a, rx_a) = write("a", (10, 1));
+        let (b, rx_b) = write("a", (10, 2));
+
+        batch.push(a);
+        batch.push(b);
+
+        assert_eq!(batch.len(), 1);
+        assert_eq!(pending(&batch), vec![("a".into(), (10, 2))]);
+        assert_eq!(superseded(batch, &mut [rx_a, rx_b]), vec![true, false]);
+    }
+
+    #[test]
+    fn test_older_write_is_dropped() {
+        let mut batch = Batch::default();
+        let (a, rx_a) = write("a", (11, 0));
+        let (b, rx_b) = write("a", (10, 5));
+        let (c, rx_c) = write("a", (11, 0));
+
+        batch.push(a);
+        batch.push(b);
+        batch.push(c);
+
+        assert_eq!(batch.len(), 1);
+        assert_eq!(pending(&batch), vec![("a".into(), (11, 0))]);
+        assert_eq!(superseded(batch, &mut [rx_a, rx_b, rx_c]), vec![
+            false, true, true
+        ]);
+    }
+
+    #[test]
+    fn test_distinct_rows_are_kept() {
+        let mut batch = Batch::default();
+        let (a, rx_a) = write("a", (10, 2));
+        let (b, rx_b) = write("b", (10, 1));
+        let (mut c, rx_c) = write("a", (10, 1));
+        c.row.as_mut().unwrap().0.table = "other";
+        let (mut d, rx_d) = write("a", (9, 0));
+        d.row = None;
+
+        batch.push(a);
+        batch.push(b);
+        batch.push(c);
+        batch.push(d);
+
+        assert_eq!(batch.len(), 4);
+        assert_eq!(batch.writes.iter().flatten().count(), 4);
+        assert_eq!(superseded(batch, &mut [rx_a, rx_b, rx_c, rx_d]), vec![
+            false, false, false, false
+        ]);
+    }
+
+    /// Connect to the database named by `TEST_DATABASE_URL`, or return `None`
+    /// to skip tests requiring a database
+    fn connect() -> Option<PooledConnection> {
+        let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return None;
+        };
+
+        let pool = DbPool::builder()
+            .max_size(1)
+            .build(ConnectionManager::new(url))
+            .unwrap();
+        let db = pool.get().unwrap();
+
+        db.begin_test_transaction().unwrap();
+        sql_query("CREATE TEMPORARY TABLE batch_test (id INTEGER PRIMARY KEY)")
+            .execute(&db)
+            .unwrap();
+
+        Some(db)
+    }
+
+    fn insert(db: &PooledConnection, id: i32) -> Result<usize> {
+        sql_query(format!("INSERT INTO batch_test (id) VALUES ({id})"))
+            .execute(db)
+            .map_err(Into::into)
+    }
+
+    #[test]
+    fn test_failed_write_rolls_back_savepoint() {
+        let Some(db) = connect() else { return };
+
+        let writes: Vec<WriteFn> = vec![
+            Box::new(|db| insert(db, 1)),
+            Box::new(|db| {
+                insert(db, 2)?;
+                insert(db, 1)
+            }),
+            Box::new(|db| insert(db, 3)),
+        ];
+
+        let results = apply(&db, &writes);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), &1);
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap(), &1);
+
+        let deleted: Vec<_> = [1, 2, 3]
+            .into_iter()
+            .map(|id| {
+                sql_query(format!("DELETE FROM batch_test WHERE id = {id}"))
+                    .execute(&db)
+                    .unwrap()
+            })
+            .collect();
+
+        assert_eq!(deleted, vec![1, 0, 1]);
+    }
+}
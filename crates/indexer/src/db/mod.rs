@@ -1,15 +1,20 @@
 //! Support module for running Diesel operations in an async context.
 
+mod batch;
+
+use std::time::Instant;
+
+pub use batch::{Args as BatchArgs, Batcher};
 use indexer_core::{
     db,
     db::{ConnectResult, PooledConnection},
 };
 
-use crate::prelude::*;
+use crate::{metrics, prelude::*};
 
 /// Handle to a database pool used by an indexer consumer
 #[repr(transparent)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Pool(ConnectResult);
 
 impl Pool {
@@ -29,6 +34,12 @@ impl Pool {
         self.0.migrated
     }
 
+    /// Get the maximum number of connections held by this pool
+    #[must_use]
+    pub fn max_size(&self) -> u32 {
+        self.0.pool.max_size()
+    }
+
     /// Spawn a blocking thread to perform operations on the database.
     ///
     /// # Errors
@@ -39,14 +50,33 @@ impl Pool {
         &self,
         f: impl FnOnce(&PooledConnection) -> Result<T, E> + Send + 'static,
     ) -> Result<T> {
+        let start = Instant::now();
         let db = self
             .0
             .pool
             .get()
             .context("Failed to acquire database connection");
+        metrics::observe_pool_wait(start);
 
         tokio::task::spawn_blocking(|| f(&db?).map_err(Into::into))
             .await
             .context("Blocking task failed")?
     }
+
+    /// Check that a connection can be acquired from the pool within the given
+    /// timeout.
+    ///
+    /// # Errors
+    /// This function fails if no connection becomes available in time.
+    pub async fn check(&self, timeout: StdDuration) -> Result<()> {
+        let pool = self.0.pool.clone();
+
+        tokio::task::spawn_blocking(move || {
+            pool.get_timeout(timeout)
+                .map(|_| ())
+                .context("Failed to acquire database connection")
+        })
+        .await
+        .context("Blocking task failed")?
+    }
 }
@@ -0,0 +1,243 @@
+//! Liveness and readiness checks for indexer consumers
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Instant,
+};
+
+use indexer_core::serde_json::{json, Value};
+use indexer_rabbitmq::lapin::{
+    options::QueueDeclareOptions, types::FieldTable, Channel, Connection, ConnectionStatus,
+};
+use lazy_static::lazy_static;
+
+use crate::{db::Pool, prelude::*};
+
+/// Maximum time to wait for a database connection during a readiness check
+const DB_CHECK_TIMEOUT: StdDuration = StdDuration::from_secs(2);
+
+/// Interval between polls of the consumed queue's depth
+const QUEUE_POLL_INTERVAL: StdDuration = StdDuration::from_secs(10);
+
+/// Milliseconds since [`EPOCH`] at which any worker last acknowledged or
+/// rejected a message, with zero indicating none has yet
+static LAST_SETTLED: AtomicU64 = AtomicU64::new(0);
+
+lazy_static! {
+    static ref EPOCH: Instant = Instant::now();
+    static ref STATE: Mutex<State> = Mutex::new(State::default());
+}
+
+/// Milliseconds since [`EPOCH`], offset by one so that zero can indicate an
+/// unset timestamp
+fn now() -> u64 {
+    u64::try_from(EPOCH.elapsed().as_millis())
+        .unwrap_or(u64::MAX - 1)
+        .saturating_add(1)
+}
+
+#[derive(Default)]
+struct State {
+    stall_timeout: StdDuration,
+    amqp: Option<ConnectionStatus>,
+    queue_depth: Option<u32>,
+    db: Option<Pool>,
+    workers: Vec<Arc<Worker>>,
+}
+
+fn state() -> std::sync::MutexGuard<'static, State> {
+    STATE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Activity tracker for a single consumer worker
+#[derive(Debug, Default)]
+pub(crate) struct Worker {
+    /// Milliseconds since [`EPOCH`] at which the current message started
+    /// processing, offset by one so that zero indicates an idle worker
+    busy_since: AtomicU64,
+}
+
+impl Worker {
+    /// Register a new worker to be monitored for stalls
+    pub(crate) fn register() -> Arc<Self> {
+        let worker = Arc::new(Self::default());
+        state().workers.push(Arc::clone(&worker));
+        worker
+    }
+
+    /// Mark the worker as having started processing a message
+    pub(crate) fn start(&self) {
+        self.busy_since.store(now(), Ordering::Relaxed);
+    }
+
+    /// Mark the worker as having finished processing its message
+    pub(crate) fn finish(&self) {
+        self.busy_since.store(0, Ordering::Relaxed);
+    }
+
+    /// Record that the worker's last message was acknowledged or rejected
+    pub(crate) fn settled(&self) {
+        LAST_SETTLED.store(now(), Ordering::Relaxed);
+    }
+
+    fn busy_for(&self) -> Option<StdDuration> {
+        match self.busy_since.load(Ordering::Relaxed) {
+            0 => None,
+            t => Some(StdDuration::from_millis(now().saturating_sub(t))),
+        }
+    }
+}
+
+pub(crate) fn set_stall_timeout(timeout: StdDuration) {
+    lazy_static::initialize(&EPOCH);
+    state().stall_timeout = timeout;
+}
+
+pub(crate) fn set_db(db: Pool) {
+    state().db = Some(db);
+}
+
+pub(crate) fn set_amqp(status: ConnectionStatus) {
+    state().amqp = Some(status);
+}
+
+/// Periodically poll the number of ready messages in the consumed queue, so
+/// that consumers which stop receiving deliveries while a backlog remains are
+/// reported as stalled
+pub(crate) fn watch_queue(conn: Connection, queue: String) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(QUEUE_POLL_INTERVAL);
+        let mut chan = None;
+
+        loop {
+            interval.tick().await;
+
+            match queue_depth(&conn, &mut chan, &queue).await {
+                Ok(depth) => state().queue_depth = Some(depth),
+                Err(e) => {
+                    // A failed passive declare closes the channel, so a new
+                    // one is opened for the next poll
+                    error!("Failed to get depth of queue {:?}: {:?}", queue, e);
+                    state().queue_depth = None;
+                    chan = None;
+                },
+            }
+        }
+    });
+}
+
+/// Get the number of ready messages in the given queue, opening a channel
+/// to query it with if none is open
+async fn queue_depth(conn: &Connection, chan: &mut Option<Channel>, queue: &str) -> Result<u32> {
+    let chan = match chan {
+        Some(c) => c,
+        None => chan.insert(
+            conn.create_channel()
+                .await
+                .context("Failed to create queue depth channel")?,
+        ),
+    };
+
+    let q = chan
+        .queue_declare(
+            queue,
+            QueueDeclareOptions {
+                passive: true,
+                ..QueueDeclareOptions::default()
+            },
+            FieldTable::default(),
+        )
+        .await
+        .context("Failed to declare queue")?;
+
+    Ok(q.message_count())
+}
+
+/// Returns the time since any worker last acknowledged or rejected a message,
+/// or since startup if none has
+fn since_settled() -> StdDuration {
+    StdDuration::from_millis(now().saturating_sub(LAST_SETTLED.load(Ordering::Relaxed)))
+}
+
+/// Returns true if messages are waiting in the consumed queue but no worker
+/// has settled a message within the stall timeout, e.g. because all workers
+/// are blocked waiting for deliveries
+fn consumer_stalled(state: &State) -> bool {
+    state.queue_depth.map_or(false, |d| d > 0) && since_settled() > state.stall_timeout
+}
+
+/// Returns the IDs of all workers which have been processing a single
+/// message for longer than the stall timeout
+fn stalled_workers(state: &State) -> Vec<usize> {
+    state
+        .workers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, w)| {
+            w.busy_for()
+                .and_then(|t| (t > state.stall_timeout).then_some(i))
+        })
+        .collect()
+}
+
+/// Check that no workers have stalled
+pub(crate) fn liveness() -> (bool, Value) {
+    let (stalled, consumer_stalled, queue_depth) = {
+        let state = state();
+
+        (
+            stalled_workers(&state),
+            consumer_stalled(&state),
+            state.queue_depth,
+        )
+    };
+
+    (
+        stalled.is_empty() && !consumer_stalled,
+        json!({
+            "stalled_workers": stalled,
+            "consumer_stalled": consumer_stalled,
+            "queue_depth": queue_depth,
+            "secs_since_ack": since_settled().as_secs(),
+        }),
+    )
+}
+
+/// Check that the AMQP connection is open, a database connection can be
+/// acquired, and no workers have stalled
+pub(crate) async fn readiness() -> (bool, Value) {
+    let (amqp, db, stalled, consumer_stalled) = {
+        let state = state();
+
+        (
+            state.amqp.as_ref().map(ConnectionStatus::connected),
+            state.db.clone(),
+            stalled_workers(&state),
+            consumer_stalled(&state),
+        )
+    };
+
+    let db_ok = match db {
+        Some(db) => db.check(DB_CHECK_TIMEOUT).await.map_err(|e| {
+            warn!("Database readiness check failed: {:?}", e);
+        }),
+        None => Err(()),
+    }
+    .is_ok();
+    let amqp_ok = amqp.unwrap_or(false);
+
+    (
+        amqp_ok && db_ok && stalled.is_empty() && !consumer_stalled,
+        json!({
+            "amqp_connected": amqp_ok,
+            "db_available": db_ok,
+            "stalled_workers": stalled,
+            "consumer_stalled": consumer_stalled,
+        }),
+    )
+}
@@ -32,35 +32,41 @@ impl fmt::Display for MessageId {
 /// Define processing logic for an incoming entity type
 #[async_trait::async_trait]
 pub trait Process: Entity {
-    /// Process and consume an incoming entity
-    async fn process(self, client: &Client) -> MessageResult<MessageId>;
+    /// Process an incoming entity
+    async fn process(&self, client: &Client) -> MessageResult<MessageId>;
 }
 
 #[async_trait::async_trait]
 impl Process for MetadataJson {
-    async fn process(self, client: &Client) -> MessageResult<MessageId> {
+    async fn process(&self, client: &Client) -> MessageResult<MessageId> {
         let MetadataJson {
             meta_address,
             first_verified_creator,
-            uri,
+            ref uri,
             slot_info,
-        } = self;
+        } = *self;
 
-        metadata_json::process(client, meta_address, first_verified_creator, uri, slot_info)
-            .await
-            .map_err(|e| MessageError::new(e, MessageId::MetadataJson(meta_address)))
+        metadata_json::process(
+            client,
+            meta_address,
+            first_verified_creator,
+            uri.clone(),
+            slot_info,
+        )
+        .await
+        .map_err(|e| MessageError::new(e, MessageId::MetadataJson(meta_address)))
     }
 }
 
 #[async_trait::async_trait]
 impl Process for StoreConfig {
-    async fn process(self, client: &Client) -> MessageResult<MessageId> {
+    async fn process(&self, client: &Client) -> MessageResult<MessageId> {
         let StoreConfig {
             config_address,
-            uri,
-        } = self;
+            ref uri,
+        } = *self;
 
-        store_config::process(client, config_address, uri)
+        store_config::process(client, config_address, uri.clone())
             .await
             .map_err(|e| MessageError::new(e, MessageId::StoreConfig(config_address)))
     }
@@ -5,7 +5,7 @@ use indexer_rabbitmq::{geyser, lapin, suffix::Suffix};
 use indexer_selector::InstructionSelector;
 use solana_client::rpc_client::RpcClient;
 
-use crate::prelude::*;
+use crate::{db::Pool, prelude::*};
 
 /// Common arguments for job runner indexer usage
 #[derive(Debug, clap::Args)]
@@ -23,6 +23,11 @@ pub struct Args {
     /// Path to a JSON file containing the Geyser selector configuration
     #[arg(long, env)]
     selector_config: PathBuf,
+
+    /// Maximum time in seconds a cached table refresh may run before it is
+    /// cancelled
+    #[arg(long, env, default_value_t = 600)]
+    refresh_timeout: u64,
 }
 
 // rpc_client::RpcClient doesn't implement Debug for some reason
@@ -47,12 +52,14 @@ impl std::ops::Deref for Rpc {
 /// Wrapper for handling networking logic
 #[derive(Debug)]
 pub struct Client {
+    db: Pool,
     rpc: Rpc,
     geyser_chan: lapin::Channel,
     geyser_prod: DashMap<geyser::StartupType, geyser::Producer>,
     geyser_network: geyser::Network,
     suffix: Suffix,
     ins_sel: InstructionSelector,
+    refresh_timeout: StdDuration,
 }
 
 impl Client {
@@ -60,11 +67,17 @@ impl Client {
     ///
     /// # Errors
     /// This function fails of the instruction selector configuration is invalid
-    pub fn new_rc(geyser_chan: lapin::Channel, suffix: Suffix, args: Args) -> Result<Arc<Self>> {
+    pub fn new_rc(
+        db: Pool,
+        geyser_chan: lapin::Channel,
+        suffix: Suffix,
+        args: Args,
+    ) -> Result<Arc<Self>> {
         let Args {
             solana_endpoint,
             network,
             selector_config,
+            refresh_timeout,
         } = args;
 
         let config_file = std::fs::File::open(&selector_config)
@@ -73,6 +86,7 @@ impl Client {
             .context("Failed to parse instruction selector config")?;
 
         Ok(Arc::new(Self {
+            db,
             rpc: Rpc(RpcClient::new(solana_endpoint).into()),
             geyser_chan,
             geyser_prod: DashMap::default(),
@@ -80,9 +94,24 @@ impl Client {
             suffix,
             ins_sel: InstructionSelector::from_config(config)
                 .context("Failed to construct instruction selector")?,
+            refresh_timeout: StdDuration::from_secs(refresh_timeout),
         }))
     }
 
+    /// Get a reference to the database
+    #[inline]
+    #[must_use]
+    pub fn db(&self) -> &Pool {
+        &self.db
+    }
+
+    /// The maximum duration of a cached table refresh
+    #[inline]
+    #[must_use]
+    pub fn refresh_timeout(&self) -> StdDuration {
+        self.refresh_timeout
+    }
+
     /// The configured instruction selector for block reindexing
     #[inline]
     #[must_use]
@@ -8,6 +8,7 @@ use indexer_rabbitmq::job_runner::{Message, SlotReindex};
 use crate::prelude::*;
 
 mod client;
+mod refresh;
 mod slot_reindex;
 
 /// Message identifier
@@ -34,24 +35,15 @@ impl fmt::Display for MessageId {
 ///
 /// # Errors
 /// This function fails if an error occurs processing the message body.
-pub async fn process_message(client: &Client, msg: Message) -> MessageResult<MessageId> {
-    let id = match msg {
+pub async fn process_message(client: &Client, msg: &Message) -> MessageResult<MessageId> {
+    let id = match *msg {
         Message::RefreshTable(ref n) => MessageId::RefreshTable(n.clone()),
         Message::ReindexSlot(s) => MessageId::ReindexSlot(s),
     };
 
-    match msg {
-        Message::RefreshTable(n) => process_refresh(n).await,
+    match *msg {
+        Message::RefreshTable(ref n) => refresh::process(client, n.clone()).await,
         Message::ReindexSlot(s) => slot_reindex::process(client, s).await,
     }
     .map_err(|e| MessageError::new(e, id))
 }
-
-#[allow(unreachable_code)]
-async fn process_refresh(name: String) -> Result<()> {
-    debug!("Refreshing table {:?}", name);
-
-    todo!("Not yet implemented!");
-
-    std::future::ready(Ok(())).await
-}
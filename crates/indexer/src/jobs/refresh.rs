@@ -0,0 +1,345 @@
+use indexer_core::{
+    db::{
+        expression::dsl::sql,
+        select, sql_query,
+        sql_types::{Array, Bool, Text},
+        Connection,
+    },
+    pubkeys,
+};
+
+use super::Client;
+use crate::prelude::*;
+
+/// A cached table with a registered refresh routine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum CachedTable {
+    AttributeGroups,
+    CollectionTrends,
+    WalletTotalRewards,
+    WalletTotals,
+}
+
+impl CachedTable {
+    /// Rebuild the table from its source rows, deleting any rows which no
+    /// longer have a source in the same statement
+    fn refresh(self, db: &Connection) -> Result<usize> {
+        let rows = match self {
+            Self::AttributeGroups => sql_query(REFRESH_ATTRIBUTE_GROUPS).execute(db),
+            Self::CollectionTrends => sql_query(REFRESH_COLLECTION_TRENDS)
+                .bind::<Array<Text>, _>(trend_marketplaces())
+                .execute(db),
+            Self::WalletTotalRewards => sql_query(REFRESH_WALLET_TOTAL_REWARDS).execute(db),
+            Self::WalletTotals => sql_query(REFRESH_WALLET_TOTALS).execute(db),
+        }?;
+
+        Ok(rows)
+    }
+}
+
+/// Marketplace programs whose sales and listings are counted towards
+/// collection trends
+fn trend_marketplaces() -> Vec<String> {
+    [pubkeys::ME_HAUS].iter().map(ToString::to_string).collect()
+}
+
+const REFRESH_ATTRIBUTE_GROUPS: &str = r"
+WITH source AS (
+    SELECT collection_mints.collection_id, attributes.trait_type, attributes.value, COUNT(*) AS count
+        FROM collection_mints
+        INNER JOIN metadatas ON (metadatas.mint_address = collection_mints.mint)
+        INNER JOIN attributes ON (attributes.metadata_address = metadatas.address)
+        WHERE metadatas.burned_at IS NULL
+        AND attributes.trait_type IS NOT NULL
+        AND attributes.trait_type <> ''
+        AND attributes.value IS NOT NULL
+        AND attributes.value <> ''
+        GROUP BY collection_mints.collection_id, attributes.trait_type, attributes.value
+),
+pruned AS (
+    DELETE FROM attribute_groups
+        WHERE NOT EXISTS (
+            SELECT 1 FROM source
+                WHERE source.collection_id = attribute_groups.collection_id
+                AND source.trait_type = attribute_groups.trait_type
+                AND source.value = attribute_groups.value
+        )
+)
+INSERT INTO attribute_groups (collection_id, trait_type, value, count)
+SELECT collection_id, trait_type, value, count
+    FROM source
+ON CONFLICT (collection_id, trait_type, value)
+DO UPDATE SET count = excluded.count;
+";
+
+// Matches the per-column definitions collection_trends was maintained with:
+// Metaplex collections are keyed by address and Magic Eden collections by ID,
+// volume counts every sale of a Metaplex collection's NFTs but only
+// marketplace sales for Magic Eden collections, sales counts and floors only
+// count verified Metaplex collection members, and marketcaps multiply the
+// lowest listing by the number of listings open at the start of each period.
+const REFRESH_COLLECTION_TRENDS: &str = r"
+WITH collections AS (
+    SELECT collection_address AS collection
+        FROM metadata_collection_keys
+    UNION
+    SELECT id::text AS collection
+        FROM me_collections
+),
+collection_metadatas AS (
+    SELECT collection_address AS collection, metadata_address, verified, false AS me
+        FROM metadata_collection_keys
+    UNION ALL
+    SELECT collection_id::text AS collection, metadata_address, true AS verified, true AS me
+        FROM me_metadata_collections
+),
+nft_counts AS (
+    SELECT collection, COUNT(*) FILTER (WHERE verified) AS nft_count
+        FROM collection_metadatas
+        GROUP BY collection
+),
+collection_purchases AS (
+    SELECT collection_metadatas.collection,
+        purchases.price,
+        purchases.created_at,
+        collection_metadatas.verified AND purchases.marketplace_program = ANY($1) AS counted
+        FROM collection_metadatas
+        INNER JOIN purchases ON (purchases.metadata = collection_metadatas.metadata_address)
+        WHERE purchases.created_at >= NOW() - INTERVAL '2 months'
+        AND (NOT collection_metadatas.me OR purchases.marketplace_program = ANY($1))
+),
+sales AS (
+    SELECT collection,
+        SUM(price) FILTER (WHERE created_at >= NOW() - INTERVAL '1 days') AS _1d_volume,
+        SUM(price) FILTER (WHERE created_at >= NOW() - INTERVAL '7 days') AS _7d_volume,
+        SUM(price) FILTER (WHERE created_at >= NOW() - INTERVAL '30 days') AS _30d_volume,
+        SUM(price) FILTER (WHERE created_at >= NOW() - INTERVAL '2 days'
+            AND created_at <= NOW() - INTERVAL '1 days') AS _prev_1d_volume,
+        SUM(price) FILTER (WHERE created_at >= NOW() - INTERVAL '2 weeks'
+            AND created_at <= NOW() - INTERVAL '1 weeks') AS _prev_7d_volume,
+        SUM(price) FILTER (WHERE created_at >= NOW() - INTERVAL '2 months'
+            AND created_at <= NOW() - INTERVAL '1 months') AS _prev_30d_volume,
+        COUNT(*) FILTER (WHERE counted AND created_at >= NOW() - INTERVAL '1 days') AS _1d_sales_count,
+        COUNT(*) FILTER (WHERE counted AND created_at >= NOW() - INTERVAL '1 weeks') AS _7d_sales_count,
+        COUNT(*) FILTER (WHERE counted AND created_at >= NOW() - INTERVAL '1 months') AS _30d_sales_count,
+        COUNT(*) FILTER (WHERE counted AND created_at >= NOW() - INTERVAL '2 days'
+            AND created_at <= NOW() - INTERVAL '1 days') AS prev_1d_sales_count,
+        COUNT(*) FILTER (WHERE counted AND created_at >= NOW() - INTERVAL '2 weeks'
+            AND created_at <= NOW() - INTERVAL '1 weeks') AS prev_7d_sales_count,
+        COUNT(*) FILTER (WHERE counted AND created_at >= NOW() - INTERVAL '2 months'
+            AND created_at <= NOW() - INTERVAL '1 months') AS prev_30d_sales_count
+        FROM collection_purchases
+        GROUP BY collection
+),
+collection_listings AS (
+    SELECT collection_metadatas.collection,
+        collection_metadatas.verified,
+        listings.price,
+        listings.created_at
+        FROM collection_metadatas
+        INNER JOIN listings ON (listings.metadata = collection_metadatas.metadata_address)
+        WHERE listings.purchase_id IS NULL
+        AND listings.canceled_at IS NULL
+        AND listings.marketplace_program = ANY($1)
+),
+floors AS (
+    SELECT collection,
+        MIN(price) FILTER (WHERE verified) AS floor_price,
+        MIN(price) FILTER (WHERE verified AND created_at <= NOW() - INTERVAL '1 days') AS prev_1d_floor_price,
+        MIN(price) FILTER (WHERE verified AND created_at <= NOW() - INTERVAL '1 weeks') AS prev_7d_floor_price,
+        MIN(price) FILTER (WHERE verified AND created_at <= NOW() - INTERVAL '1 months') AS prev_30d_floor_price,
+        MIN(price) FILTER (WHERE created_at <= NOW() - INTERVAL '1 days')
+            * COUNT(*) FILTER (WHERE created_at <= NOW() - INTERVAL '1 days') AS _1d_marketcap,
+        MIN(price) FILTER (WHERE created_at >= NOW() - INTERVAL '2 days'
+            AND created_at <= NOW() - INTERVAL '1 days')
+            * COUNT(*) FILTER (WHERE created_at >= NOW() - INTERVAL '2 days'
+            AND created_at <= NOW() - INTERVAL '1 days') AS prev_1d_marketcap,
+        MIN(price) FILTER (WHERE created_at <= NOW() - INTERVAL '1 weeks')
+            * COUNT(*) FILTER (WHERE created_at <= NOW() - INTERVAL '1 weeks') AS _7d_marketcap,
+        MIN(price) FILTER (WHERE created_at >= NOW() - INTERVAL '2 weeks'
+            AND created_at <= NOW() - INTERVAL '1 weeks')
+            * COUNT(*) FILTER (WHERE created_at >= NOW() - INTERVAL '2 weeks'
+            AND created_at <= NOW() - INTERVAL '1 weeks') AS prev_7d_marketcap,
+        MIN(price) FILTER (WHERE created_at <= NOW() - INTERVAL '1 months')
+            * COUNT(*) FILTER (WHERE created_at <= NOW() - INTERVAL '1 months') AS _30d_marketcap,
+        MIN(price) FILTER (WHERE created_at >= NOW() - INTERVAL '2 months'
+            AND created_at <= NOW() - INTERVAL '1 months')
+            * COUNT(*) FILTER (WHERE created_at >= NOW() - INTERVAL '2 months'
+            AND created_at <= NOW() - INTERVAL '1 months') AS prev_30d_marketcap
+        FROM collection_listings
+        GROUP BY collection
+),
+pruned AS (
+    DELETE FROM collection_trends
+        WHERE NOT EXISTS (
+            SELECT 1 FROM collections WHERE collections.collection = collection_trends.collection
+        )
+)
+INSERT INTO collection_trends (
+    collection,
+    _1d_volume, _7d_volume, _30d_volume,
+    _prev_1d_volume, _prev_7d_volume, _prev_30d_volume,
+    _1d_sales_count, prev_1d_sales_count,
+    _7d_sales_count, prev_7d_sales_count,
+    _30d_sales_count, prev_30d_sales_count,
+    floor_price, prev_1d_floor_price, prev_7d_floor_price, prev_30d_floor_price,
+    _1d_marketcap, prev_1d_marketcap,
+    _7d_marketcap, prev_7d_marketcap,
+    _30d_marketcap, prev_30d_marketcap,
+    nft_count
+)
+SELECT collections.collection,
+    COALESCE(sales._1d_volume, 0)::numeric,
+    COALESCE(sales._7d_volume, 0)::numeric,
+    COALESCE(sales._30d_volume, 0)::numeric,
+    COALESCE(sales._prev_1d_volume, 0)::numeric,
+    COALESCE(sales._prev_7d_volume, 0)::numeric,
+    COALESCE(sales._prev_30d_volume, 0)::numeric,
+    COALESCE(sales._1d_sales_count, 0)::numeric,
+    COALESCE(sales.prev_1d_sales_count, 0)::numeric,
+    COALESCE(sales._7d_sales_count, 0)::numeric,
+    COALESCE(sales.prev_7d_sales_count, 0)::numeric,
+    COALESCE(sales._30d_sales_count, 0)::numeric,
+    COALESCE(sales.prev_30d_sales_count, 0)::numeric,
+    COALESCE(floors.floor_price, 0)::numeric,
+    COALESCE(floors.prev_1d_floor_price, 0)::numeric,
+    COALESCE(floors.prev_7d_floor_price, 0)::numeric,
+    COALESCE(floors.prev_30d_floor_price, 0)::numeric,
+    COALESCE(floors._1d_marketcap, 0)::numeric,
+    COALESCE(floors.prev_1d_marketcap, 0)::numeric,
+    COALESCE(floors._7d_marketcap, 0)::numeric,
+    COALESCE(floors.prev_7d_marketcap, 0)::numeric,
+    COALESCE(floors._30d_marketcap, 0)::numeric,
+    COALESCE(floors.prev_30d_marketcap, 0)::numeric,
+    COALESCE(nft_counts.nft_count, 0)
+    FROM collections
+    LEFT JOIN nft_counts ON (nft_counts.collection = collections.collection)
+    LEFT JOIN sales ON (sales.collection = collections.collection)
+    LEFT JOIN floors ON (floors.collection = collections.collection)
+ON CONFLICT (collection) DO UPDATE SET
+    _1d_volume = excluded._1d_volume,
+    _7d_volume = excluded._7d_volume,
+    _30d_volume = excluded._30d_volume,
+    _prev_1d_volume = excluded._prev_1d_volume,
+    _prev_7d_volume = excluded._prev_7d_volume,
+    _prev_30d_volume = excluded._prev_30d_volume,
+    _1d_sales_count = excluded._1d_sales_count,
+    prev_1d_sales_count = excluded.prev_1d_sales_count,
+    _7d_sales_count = excluded._7d_sales_count,
+    prev_7d_sales_count = excluded.prev_7d_sales_count,
+    _30d_sales_count = excluded._30d_sales_count,
+    prev_30d_sales_count = excluded.prev_30d_sales_count,
+    floor_price = excluded.floor_price,
+    prev_1d_floor_price = excluded.prev_1d_floor_price,
+    prev_7d_floor_price = excluded.prev_7d_floor_price,
+    prev_30d_floor_price = excluded.prev_30d_floor_price,
+    _1d_marketcap = excluded._1d_marketcap,
+    prev_1d_marketcap = excluded.prev_1d_marketcap,
+    _7d_marketcap = excluded._7d_marketcap,
+    prev_7d_marketcap = excluded.prev_7d_marketcap,
+    _30d_marketcap = excluded._30d_marketcap,
+    prev_30d_marketcap = excluded.prev_30d_marketcap,
+    nft_count = excluded.nft_count;
+";
+
+const REFRESH_WALLET_TOTAL_REWARDS: &str = r"
+WITH rewards AS (
+    SELECT buyer AS wallet_address, reward_center AS reward_center_address, buyer_reward AS reward
+        FROM reward_payouts
+    UNION ALL
+    SELECT seller AS wallet_address, reward_center AS reward_center_address, seller_reward AS reward
+        FROM reward_payouts
+),
+source AS (
+    SELECT wallet_address, reward_center_address, SUM(reward) AS total_reward
+        FROM rewards
+        GROUP BY wallet_address, reward_center_address
+),
+pruned AS (
+    DELETE FROM wallet_total_rewards
+        WHERE NOT EXISTS (
+            SELECT 1 FROM source
+                WHERE source.wallet_address = wallet_total_rewards.wallet_address
+                AND source.reward_center_address = wallet_total_rewards.reward_center_address
+        )
+)
+INSERT INTO wallet_total_rewards (wallet_address, reward_center_address, total_reward)
+SELECT wallet_address, reward_center_address, total_reward
+    FROM source
+ON CONFLICT (wallet_address, reward_center_address)
+DO UPDATE SET total_reward = excluded.total_reward;
+";
+
+const REFRESH_WALLET_TOTALS: &str = r"
+WITH connections AS (
+    SELECT from_account AS address, 1 AS following, 0 AS followers
+        FROM graph_connections
+        WHERE disconnected_at IS NULL
+    UNION ALL
+    SELECT to_account AS address, 0 AS following, 1 AS followers
+        FROM graph_connections
+        WHERE disconnected_at IS NULL
+),
+source AS (
+    SELECT address, SUM(following) AS following, SUM(followers) AS followers
+        FROM connections
+        GROUP BY address
+),
+pruned AS (
+    DELETE FROM wallet_totals
+        WHERE NOT EXISTS (SELECT 1 FROM source WHERE source.address = wallet_totals.address)
+)
+INSERT INTO wallet_totals (address, following, followers)
+SELECT address, following, followers
+    FROM source
+ON CONFLICT (address)
+DO UPDATE SET following = excluded.following, followers = excluded.followers;
+";
+
+fn is_materialized_view(db: &Connection, name: &str) -> Result<bool> {
+    select(
+        sql::<Bool>("EXISTS (SELECT 1 FROM pg_matviews WHERE matviewname = ")
+            .bind::<Text, _>(name)
+            .sql(")"),
+    )
+    .get_result(db)
+    .context("Failed to look up materialized views")
+}
+
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+pub async fn process(client: &Client, name: String) -> Result<()> {
+    let timeout_ms = client.refresh_timeout().as_millis();
+
+    let rows = client
+        .db()
+        .run(move |db| {
+            db.build_transaction().read_write().run(|| {
+                sql_query(format!("SET LOCAL statement_timeout = {timeout_ms}"))
+                    .execute(db)
+                    .context("Failed to set refresh statement timeout")?;
+
+                if let Ok(table) = name.parse::<CachedTable>() {
+                    return table
+                        .refresh(db)
+                        .with_context(|| format!("Failed to refresh cached table {table}"));
+                }
+
+                if is_materialized_view(db, &name)? {
+                    return sql_query(format!("REFRESH MATERIALIZED VIEW {}", quote_ident(&name)))
+                        .execute(db)
+                        .with_context(|| format!("Failed to refresh materialized view {name:?}"));
+                }
+
+                Err(anyhow!("No refresh routine registered for table {name:?}"))
+            })
+        })
+        .await?;
+
+    debug!("Refresh affected {rows} row(s)");
+
+    Ok(())
+}
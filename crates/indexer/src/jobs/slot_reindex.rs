@@ -5,9 +5,10 @@ use indexer_rabbitmq::{
 use indexer_selector::{InstructionInfo, InstructionSelector};
 use solana_client::rpc_config::RpcBlockConfig;
 use solana_program::instruction::CompiledInstruction;
-use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::{commitment_config::CommitmentConfig, message::VersionedMessage};
 use solana_transaction_status::{
-    TransactionDetails, UiInstruction, UiParsedInstruction, UiTransactionEncoding,
+    TransactionDetails, UiInstruction, UiLoadedAddresses, UiParsedInstruction,
+    UiTransactionEncoding,
 };
 
 use super::Client;
@@ -107,6 +108,46 @@ impl<'a> InstructionInfo<'a> for InstructionShim<'a> {
     }
 }
 
+/// Resolve the full list of account keys referenced by a transaction's
+/// instructions.  For v0 transactions this appends the addresses loaded from
+/// address lookup tables to the static keys, writable addresses first, in the
+/// same order the runtime uses when compiling account indices.
+fn account_keys(
+    message: &VersionedMessage,
+    loaded: Option<UiLoadedAddresses>,
+) -> Result<Vec<Pubkey>> {
+    let static_keys = message.static_account_keys();
+    let num_lookups = message.address_table_lookups().map_or(0, |l| {
+        l.iter()
+            .map(|l| l.writable_indexes.len() + l.readonly_indexes.len())
+            .sum()
+    });
+
+    let Some(UiLoadedAddresses { writable, readonly }) = loaded else {
+        ensure!(
+            num_lookups == 0,
+            "Transaction uses address lookup tables but has no loaded addresses"
+        );
+
+        return Ok(static_keys.to_vec());
+    };
+
+    ensure!(
+        writable.len() + readonly.len() == num_lookups,
+        "Loaded address count does not match address table lookups"
+    );
+
+    static_keys
+        .iter()
+        .copied()
+        .map(Ok)
+        .chain(writable.iter().chain(&readonly).map(|a| {
+            a.parse()
+                .with_context(|| format!("Couldn't parse loaded address {a:?}"))
+        }))
+        .collect()
+}
+
 fn process_instruction(
     sel: &InstructionSelector,
     ins: Result<(InstructionIndex, InstructionShim<'_>)>,
@@ -188,9 +229,17 @@ pub async fn process(client: &Client, SlotReindex { slot, startup }: SlotReindex
             continue;
         };
 
-        // TODO: I don't think this accounts for address lookup tables but
-        //       honestly I don't want to deal with that right now.
-        let keys = tx.message.static_account_keys();
+        let keys = match account_keys(
+            &tx.message,
+            Option::<UiLoadedAddresses>::from(meta.loaded_addresses),
+        ) {
+            Ok(k) => k,
+            Err(e) => {
+                warn!("Failed to resolve account keys for {signature}: {e:?}");
+                continue;
+            },
+        };
+        let keys = &*keys;
 
         // The messages have to be collected into a Vec before sending because
         // borrowing transaction data across an await causes a rat's nest of
@@ -233,3 +282,85 @@ pub async fn process(client: &Client, SlotReindex { slot, startup }: SlotReindex
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_transaction_status::UiConfirmedBlock;
+
+    use super::*;
+
+    /// Hand-built v0 block with synthetic blockhashes and keys.
+    ///
+    /// TODO: replace with a recorded mainnet `getBlock` response for a block
+    /// containing a lookup-table transaction, fetched with
+    /// `{"encoding": "base64", "maxSupportedTransactionVersion": 0,
+    /// "transactionDetails": "full", "rewards": false}`, and update the
+    /// expected keys below to match.
+    fn block() -> UiConfirmedBlock {
+        serde_json::from_str(include_str!("fixtures/v0_lookup_table_block.json"))
+            .expect("Failed to parse block fixture")
+    }
+
+    fn pubkeys(keys: &[&str]) -> Vec<Pubkey> {
+        keys.iter().map(|k| k.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_v0_account_keys() {
+        let tx = block().transactions.unwrap().remove(0);
+        let meta = tx.meta.unwrap();
+        let tx = tx.transaction.decode().unwrap();
+        let loaded = Option::<UiLoadedAddresses>::from(meta.loaded_addresses);
+
+        let keys = account_keys(&tx.message, loaded).unwrap();
+        assert_eq!(
+            keys,
+            pubkeys(&[
+                "AWxggjuZRmWULwxwPeM6ZZxRtdDdekVq22mFRx2QbW7U",
+                "hausS13jsjafwWwGqZTUQRmWyvyxn9EQpqMwV1PBBmk",
+                "9aeLYUk9SHxcWqkFn7xXrT8od9xQELETjvKpyjNULPJm",
+                "55z1xKHET2FA1XCR41u368L4SmZzFqw6Y5rwxTHhMZFQ",
+                "AmxxuTVqEyxBri9wx7TKPJ7Bu1k8R5mFWFk2KVnTXZCo",
+            ])
+        );
+
+        let ins = InstructionShim::from(&tx.message.instructions()[0]);
+        let accounts: Vec<_> = ins.account_indices().map(|i| keys[i as usize]).collect();
+        assert_eq!(
+            accounts,
+            pubkeys(&[
+                "AWxggjuZRmWULwxwPeM6ZZxRtdDdekVq22mFRx2QbW7U",
+                "9aeLYUk9SHxcWqkFn7xXrT8od9xQELETjvKpyjNULPJm",
+                "55z1xKHET2FA1XCR41u368L4SmZzFqw6Y5rwxTHhMZFQ",
+                "AmxxuTVqEyxBri9wx7TKPJ7Bu1k8R5mFWFk2KVnTXZCo",
+            ])
+        );
+
+        let inner = Option::<Vec<_>>::from(meta.inner_instructions).unwrap();
+        let inner = InstructionShim::try_from_ui(&inner[0].instructions[0], &keys).unwrap();
+        assert_eq!(
+            keys[inner.program_index() as usize],
+            "AmxxuTVqEyxBri9wx7TKPJ7Bu1k8R5mFWFk2KVnTXZCo"
+                .parse()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_missing_loaded_addresses() {
+        let tx = block().transactions.unwrap().remove(0);
+        let tx = tx.transaction.decode().unwrap();
+
+        assert!(account_keys(&tx.message, None).is_err());
+        assert!(
+            account_keys(
+                &tx.message,
+                Some(UiLoadedAddresses {
+                    writable: vec![],
+                    readonly: vec![],
+                })
+            )
+            .is_err()
+        );
+    }
+}
@@ -10,10 +10,12 @@
 #![warn(clippy::pedantic, clippy::cargo, missing_docs)]
 
 pub mod db;
+mod health;
 #[cfg(feature = "http")]
 pub mod http;
 #[cfg(feature = "job-runner")]
 pub mod jobs;
+pub mod metrics;
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
 #[cfg(feature = "search")]
@@ -21,6 +23,7 @@ pub mod search;
 #[cfg(feature = "search-dispatch")]
 /// Search dispatch module for creating client and dispatching AMQP messages to the search indexer
 pub mod search_dispatch;
+mod status;
 pub mod util;
 
 pub use runtime::*;
@@ -38,6 +41,12 @@ mod runtime {
     use std::{
         fmt::{Debug, Display},
         future::Future,
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        time::Instant,
     };
 
     use futures_util::{stream::FuturesUnordered, FutureExt, StreamExt};
@@ -45,6 +54,8 @@ mod runtime {
         clap,
         clap::{Args, Parser},
         db,
+        db::{mutations::dead_letter, queries},
+        serde_json,
     };
     use indexer_rabbitmq::{
         consumer::Consumer,
@@ -54,7 +65,13 @@ mod runtime {
     };
     use tokio::sync::{broadcast, broadcast::error::RecvError};
 
-    use super::{db::Pool, prelude::*};
+    use super::{
+        db::Pool,
+        health,
+        metrics::{MessageInfo, MessageLabels},
+        prelude::*,
+        status,
+    };
 
     #[derive(Debug, Parser)]
     struct Opts<T: Debug + Args> {
@@ -67,6 +84,17 @@ mod runtime {
         #[arg(long, short, env)]
         migrate_db: bool,
 
+        /// Address to serve Prometheus metrics and health checks on, e.g.
+        /// `0.0.0.0:9100`
+        #[arg(long, env)]
+        metrics_addr: Option<SocketAddr>,
+
+        /// Number of seconds a worker may spend processing a single message,
+        /// or the consumer may go without settling a message while its queue
+        /// is non-empty, before it is reported as stalled by the health checks
+        #[arg(long, env, default_value_t = 300)]
+        stall_timeout: u64,
+
         #[command(flatten)]
         db: db::ConnectArgs,
 
@@ -79,6 +107,7 @@ mod runtime {
     #[derive(Debug)]
     pub struct Params {
         concurrency: usize,
+        db: Pool,
     }
 
     /// Entrypoint for `holaplex-indexer` binaries
@@ -94,6 +123,8 @@ mod runtime {
                 thread_count,
                 db,
                 migrate_db: migrate,
+                metrics_addr,
+                stall_timeout,
                 extra,
             } = opts;
 
@@ -116,7 +147,29 @@ mod runtime {
 
             let concurrency = thread_count.unwrap_or_else(indexer_core::num_cpus::get);
 
-            rt.block_on(f(extra, Params { concurrency }, db))
+            health::set_stall_timeout(StdDuration::from_secs(stall_timeout));
+            health::set_db(db.clone());
+
+            rt.block_on(async move {
+                if let Some(addr) = metrics_addr {
+                    tokio::spawn(async move {
+                        status::serve(addr)
+                            .await
+                            .map_err(|e| error!("{:?}", e))
+                            .ok();
+                    });
+                }
+
+                f(
+                    extra,
+                    Params {
+                        concurrency,
+                        db: db.clone(),
+                    },
+                    db,
+                )
+                .await
+            })
         })
     }
 
@@ -171,15 +224,122 @@ mod runtime {
     /// Convenience alias for the result of a message processor function
     pub type MessageResult<D> = Result<(), MessageError<D>>;
 
+    /// Get a short name for a queue type to identify dead-lettered messages,
+    /// e.g. `geyser` or `http_indexer<MetadataJson>`
+    fn queue_kind<Q>() -> String {
+        fn strip(path: &str) -> &str {
+            path.rsplit("::").next().unwrap_or(path)
+        }
+
+        let name = std::any::type_name::<Q>();
+        let (path, args) = name.split_once('<').unwrap_or((name, ""));
+        let kind = path
+            .strip_suffix("::QueueType")
+            .map_or_else(|| strip(path), strip);
+
+        if args.is_empty() {
+            kind.to_owned()
+        } else {
+            let args = args
+                .trim_end_matches('>')
+                .split(", ")
+                .map(strip)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("{kind}<{args}>")
+        }
+    }
+
+    /// How long after its last failure a message may still be retried through
+    /// the dead-letter exchange, after which its `dead_letters` row is kept as
+    /// a permanent failure
+    const DEAD_LETTER_RETRY_WINDOW: StdDuration = StdDuration::from_secs(60 * 60);
+
+    /// Interval between checks for messages retried through the dead-letter
+    /// exchange
+    const DEAD_LETTER_POLL_INTERVAL: StdDuration = StdDuration::from_secs(5);
+
+    /// Tracks whether a queue has failed messages which may still be retried,
+    /// so successful messages only look for a dead letter to clear while one
+    /// could exist
+    #[derive(Debug)]
+    struct DeadLetters {
+        queue: String,
+        pending: AtomicBool,
+    }
+
+    impl DeadLetters {
+        fn new(queue: String) -> Arc<Self> {
+            Arc::new(Self {
+                queue,
+                pending: AtomicBool::new(true),
+            })
+        }
+
+        /// Periodically re-check the database, as retries of messages which
+        /// failed on other consumers may be delivered to this one.  A failed
+        /// message is recorded before it is rejected, so this only misses a
+        /// retry delivered sooner than the poll interval.
+        async fn poll(self: Arc<Self>, db: Pool) {
+            let mut interval = tokio::time::interval(DEAD_LETTER_POLL_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                let queue = self.queue.clone();
+
+                match db
+                    .run(move |db| {
+                        queries::dead_letter::failed_within(db, &queue, DEAD_LETTER_RETRY_WINDOW)
+                    })
+                    .await
+                {
+                    Ok(p) => self.pending.store(p, Ordering::Relaxed),
+                    Err(e) => error!("Failed to check for dead letters: {:?}", e),
+                }
+            }
+        }
+    }
+
+    async fn record_dead_letter<M: serde::Serialize, D: Display>(
+        db: &Pool,
+        queue: &str,
+        msg: &M,
+        err: &MessageError<D>,
+    ) -> Result<()> {
+        let queue = queue.to_owned();
+        let message_id = err.1.to_string();
+        let payload = serde_json::to_value(msg).context("Failed to serialize message")?;
+        let error = format!("{:?}", err.0);
+
+        db.run(move |db| dead_letter::record(db, &queue, &message_id, &payload, &error))
+            .await
+    }
+
+    async fn clear_dead_letter<M: serde::Serialize>(db: &Pool, queue: &str, msg: &M) -> Result<()> {
+        let queue = queue.to_owned();
+        let payload = serde_json::to_value(msg).context("Failed to serialize message")?;
+
+        db.run(move |db| dead_letter::clear(db, &queue, &payload))
+            .await
+            .map(|_| ())
+    }
+
     async fn consume_one<Q: QueueType, F: Future<Output = MessageResult<D>>, D: Display>(
         worker_id: usize,
         mut consumer: Consumer<Q>,
-        process: impl Fn(Q::Message) -> F,
+        db: Pool,
+        dead_letters: Arc<DeadLetters>,
+        worker: Arc<health::Worker>,
+        process: impl Fn(Arc<Q::Message>) -> F,
         mut stop_rx: broadcast::Receiver<()>,
     ) -> Result<StopType>
     where
-        Q::Message: Debug + for<'de> serde::Deserialize<'de>,
+        Q::Message: Debug + MessageInfo + serde::Serialize + for<'de> serde::Deserialize<'de>,
     {
+        let queue = &dead_letters.queue;
+
         enum Delivery<T> {
             Message(Result<Option<(T, lapin::acker::Acker)>, indexer_rabbitmq::Error>),
             Stop,
@@ -211,25 +371,58 @@ mod runtime {
 
             trace!("Worker {}: {:?}", worker_id, msg);
 
-            match process(msg).await {
-                Ok(()) => acker
-                    .ack(BasicAckOptions::default())
-                    .await
-                    .context("Failed to send ACK for delivery")?,
+            // Shared with the processor so a failed message can be serialized
+            // for the dead-letter table without copying every delivery
+            let msg = Arc::new(msg);
+            let labels = MessageLabels::new(&*msg);
+            let start = Instant::now();
+            worker.start();
+            let res = process(Arc::clone(&msg)).await;
+            worker.finish();
+
+            labels.observe(queue, start, res.is_ok());
+
+            match res {
+                Ok(()) => {
+                    // This may be a successful retry of a message recorded as
+                    // failed, which should no longer be listed or replayed
+                    if dead_letters.pending.load(Ordering::Relaxed) {
+                        clear_dead_letter(&db, queue, &*msg)
+                            .await
+                            .map_err(|e| error!("Failed to clear dead letter: {:?}", e))
+                            .ok();
+                    }
+
+                    acker
+                        .ack(BasicAckOptions::default())
+                        .await
+                        .context("Failed to send ACK for delivery")?;
+                },
                 Err(e) => {
                     warn!("Failed to process {}: {:?}", e.1, e.0);
 
+                    record_dead_letter(&db, queue, &*msg, &e)
+                        .await
+                        .map_err(|e| error!("Failed to record dead letter: {:?}", e))
+                        .ok();
+                    dead_letters.pending.store(true, Ordering::Relaxed);
+
                     acker
                         .reject(BasicRejectOptions { requeue: false })
                         .await
                         .context("Failed to send NAK for delivery")?;
                 },
             }
+
+            worker.settled();
         }
     }
 
     /// Consume messages from an AMQP consumer until the connection closes
     ///
+    /// The depth of the consumed queue is polled over `health_conn`, kept
+    /// apart from `conn` so failed polls can reopen their channel.
+    ///
     /// # Errors
     /// This function fails if a message cannot be received, but _does not_ fail
     /// if a received message fails to process.
@@ -244,15 +437,23 @@ mod runtime {
     >(
         params: &Params,
         conn: indexer_rabbitmq::lapin::Connection,
+        health_conn: indexer_rabbitmq::lapin::Connection,
         consumer: Consumer<Q>,
         queue_type: Q,
         grace_period: StdDuration,
-        process: impl Fn(Q::Message) -> F + Send + Sync + Clone + 'static,
+        process: impl Fn(Arc<Q::Message>) -> F + Send + Sync + Clone + 'static,
     ) -> Result<()>
     where
-        Q::Message: Debug + Send + for<'a> serde::Deserialize<'a>,
+        Q::Message:
+            Debug + Send + Sync + MessageInfo + serde::Serialize + for<'a> serde::Deserialize<'a>,
     {
-        let Params { concurrency } = *params;
+        let Params {
+            concurrency,
+            ref db,
+        } = *params;
+
+        health::set_amqp(conn.status().clone());
+        health::watch_queue(health_conn, queue_type.info().queue().into());
 
         let dl_task = tokio::spawn(indexer_rabbitmq::dl_consumer::run(
             conn,
@@ -260,6 +461,9 @@ mod runtime {
             tokio::time::sleep,
         ));
 
+        let dead_letters = DeadLetters::new(queue_kind::<Q>());
+        let dl_poll_task = tokio::spawn(Arc::clone(&dead_letters).poll(db.clone()));
+
         let (stop_tx, _stop_rx) = broadcast::channel(1);
 
         let mut q_tasks = (0..concurrency)
@@ -267,6 +471,9 @@ mod runtime {
                 tokio::spawn(consume_one(
                     i,
                     consumer.clone(),
+                    db.clone(),
+                    Arc::clone(&dead_letters),
+                    health::Worker::register(),
                     process.clone(),
                     stop_tx.subscribe(),
                 ))
@@ -338,6 +545,7 @@ mod runtime {
 
         stop_tx.send(()).unwrap();
         dl_task.abort();
+        dl_poll_task.abort();
 
         if !q_tasks.is_empty() {
             info!("Waiting for additional jobs to finish...");
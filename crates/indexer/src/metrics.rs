@@ -0,0 +1,285 @@
+//! Prometheus metrics for indexer consumers
+
+use std::{sync::Arc, time::Instant};
+
+use lazy_static::lazy_static;
+use prometheus::{
+    exponential_buckets, register_histogram, register_histogram_vec, register_int_counter,
+    register_int_counter_vec, register_int_gauge, register_int_gauge_vec, Encoder, Histogram,
+    HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, TextEncoder,
+};
+
+use crate::prelude::*;
+
+lazy_static! {
+    static ref MESSAGES: IntCounterVec =
+        register_int_counter_vec!("indexer_messages_total", "Number of messages consumed", &[
+            "queue", "kind", "program"
+        ])
+        .unwrap();
+    static ref MESSAGE_ERRORS: IntCounterVec = register_int_counter_vec!(
+        "indexer_message_errors_total",
+        "Number of messages which failed to process",
+        &["queue", "kind", "program"]
+    )
+    .unwrap();
+    static ref MESSAGE_DURATION: HistogramVec = register_histogram_vec!(
+        "indexer_message_duration_seconds",
+        "Time taken to process a message",
+        &["queue", "kind"],
+        exponential_buckets(0.000_5, 2.0, 16).unwrap()
+    )
+    .unwrap();
+    static ref UNHANDLED_MESSAGES: IntCounterVec = register_int_counter_vec!(
+        "indexer_unhandled_messages_total",
+        "Number of messages for programs with no registered handler",
+        &["kind", "program"]
+    )
+    .unwrap();
+    static ref LAST_MESSAGE_SLOT: IntGaugeVec = register_int_gauge_vec!(
+        "indexer_last_message_slot",
+        "Slot of the most recently consumed message",
+        &["queue"]
+    )
+    .unwrap();
+    static ref CHAIN_SLOT: IntGauge = register_int_gauge!(
+        "indexer_chain_slot",
+        "Latest slot reported by the Solana RPC node"
+    )
+    .unwrap();
+    static ref DB_POOL_WAIT: Histogram = register_histogram!(
+        "indexer_db_pool_wait_seconds",
+        "Time spent waiting to acquire a database connection",
+        exponential_buckets(0.000_1, 2.0, 16).unwrap()
+    )
+    .unwrap();
+    static ref DB_WRITES: IntCounterVec = register_int_counter_vec!(
+        "indexer_db_writes_total",
+        "Number of database writes executed, by whether they were batched",
+        &["mode"]
+    )
+    .unwrap();
+    static ref DB_WRITE_COALESCED: IntCounter = register_int_counter!(
+        "indexer_db_writes_coalesced_total",
+        "Number of batched writes dropped in favor of a newer write to the same row"
+    )
+    .unwrap();
+    static ref DB_WRITE_FLUSH_DURATION: HistogramVec = register_histogram_vec!(
+        "indexer_db_write_flush_duration_seconds",
+        "Time taken to execute a single direct write or a batch of writes",
+        &["mode"],
+        exponential_buckets(0.000_5, 2.0, 16).unwrap()
+    )
+    .unwrap();
+    static ref DB_WRITE_BATCH_SIZE: Histogram = register_histogram!(
+        "indexer_db_write_batch_size",
+        "Number of writes committed per batch",
+        exponential_buckets(1.0, 2.0, 12).unwrap()
+    )
+    .unwrap();
+}
+
+/// Describes a consumed message for the purposes of collecting metrics
+pub trait MessageInfo {
+    /// A short name for the variant of this message, e.g. `account_update`
+    fn kind(&self) -> &'static str;
+
+    /// The program this message relates to, if any
+    fn program(&self) -> Option<Pubkey> {
+        None
+    }
+
+    /// The slot this message was produced in, if known
+    fn slot(&self) -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(feature = "geyser")]
+impl MessageInfo for indexer_rabbitmq::geyser::Message {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::AccountUpdate(_) => "account_update",
+            Self::InstructionNotify(_) => "instruction",
+            Self::SlotStatusUpdate(_) => "slot_status",
+        }
+    }
+
+    fn program(&self) -> Option<Pubkey> {
+        match self {
+            Self::AccountUpdate(u) => Some(u.owner),
+            Self::InstructionNotify(i) => Some(i.program),
+            Self::SlotStatusUpdate(_) => None,
+        }
+    }
+
+    fn slot(&self) -> Option<u64> {
+        Some(match self {
+            Self::AccountUpdate(u) => u.slot,
+            Self::InstructionNotify(i) => i.slot,
+            Self::SlotStatusUpdate(s) => s.slot,
+        })
+    }
+}
+
+#[cfg(feature = "job-runner")]
+impl MessageInfo for indexer_rabbitmq::job_runner::Message {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::RefreshTable(_) => "refresh_table",
+            Self::ReindexSlot(_) => "reindex_slot",
+        }
+    }
+
+    fn slot(&self) -> Option<u64> {
+        match self {
+            Self::RefreshTable(_) => None,
+            Self::ReindexSlot(s) => Some(s.slot),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl MessageInfo for indexer_rabbitmq::http_indexer::MetadataJson {
+    fn kind(&self) -> &'static str {
+        "metadata_json"
+    }
+}
+
+#[cfg(feature = "http")]
+impl MessageInfo for indexer_rabbitmq::http_indexer::StoreConfig {
+    fn kind(&self) -> &'static str {
+        "store_config"
+    }
+}
+
+#[cfg(feature = "search")]
+impl MessageInfo for indexer_rabbitmq::search_indexer::Message {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Upsert { .. } => "upsert",
+            Self::IndirectMetadata { .. } => "indirect_metadata",
+        }
+    }
+}
+
+/// Labels captured from a message before it is consumed by its processor
+pub(crate) struct MessageLabels {
+    kind: &'static str,
+    program: String,
+    slot: Option<u64>,
+}
+
+impl MessageLabels {
+    pub(crate) fn new<M: MessageInfo>(msg: &M) -> Self {
+        Self {
+            kind: msg.kind(),
+            program: msg.program().map(|p| p.to_string()).unwrap_or_default(),
+            slot: msg.slot(),
+        }
+    }
+
+    pub(crate) fn observe(&self, queue: &str, start: Instant, ok: bool) {
+        let Self {
+            kind,
+            ref program,
+            slot,
+        } = *self;
+
+        MESSAGES.with_label_values(&[queue, kind, program]).inc();
+        MESSAGE_DURATION
+            .with_label_values(&[queue, kind])
+            .observe(start.elapsed().as_secs_f64());
+
+        if !ok {
+            MESSAGE_ERRORS
+                .with_label_values(&[queue, kind, program])
+                .inc();
+        }
+
+        if let Some(slot) = slot.and_then(|s| i64::try_from(s).ok()) {
+            LAST_MESSAGE_SLOT.with_label_values(&[queue]).set(slot);
+        }
+    }
+}
+
+/// Record a message which was dropped because no handler is registered for
+/// its program
+pub fn observe_unhandled<M: MessageInfo>(msg: &M) {
+    let program = msg.program().map(|p| p.to_string()).unwrap_or_default();
+
+    UNHANDLED_MESSAGES
+        .with_label_values(&[msg.kind(), &program])
+        .inc();
+}
+
+pub(crate) fn observe_pool_wait(start: Instant) {
+    DB_POOL_WAIT.observe(start.elapsed().as_secs_f64());
+}
+
+/// How a database write was committed
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum WriteMode {
+    /// The write ran in its own transaction
+    Direct,
+    /// The write was flushed together with others in a shared transaction
+    Batched,
+}
+
+pub(crate) fn observe_db_writes(mode: WriteMode, count: usize, start: Instant) {
+    let mode = match mode {
+        WriteMode::Direct => "direct",
+        WriteMode::Batched => {
+            DB_WRITE_BATCH_SIZE.observe(u32::try_from(count).map_or(f64::MAX, f64::from));
+            "batched"
+        },
+    };
+
+    DB_WRITES
+        .with_label_values(&[mode])
+        .inc_by(count.try_into().unwrap_or(u64::MAX));
+    DB_WRITE_FLUSH_DURATION
+        .with_label_values(&[mode])
+        .observe(start.elapsed().as_secs_f64());
+}
+
+pub(crate) fn observe_db_write_coalesced() {
+    DB_WRITE_COALESCED.inc();
+}
+
+/// Periodically poll the current chain slot, so consumer lag can be computed
+/// as `indexer_chain_slot - indexer_last_message_slot`
+pub fn watch_chain_slot(
+    get_slot: impl Fn() -> Result<u64> + Send + Sync + 'static,
+    interval: StdDuration,
+) {
+    let get_slot = Arc::new(get_slot);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(interval);
+
+        loop {
+            interval.tick().await;
+
+            let get_slot = Arc::clone(&get_slot);
+            match tokio::task::spawn_blocking(move || get_slot()).await {
+                Ok(Ok(slot)) => CHAIN_SLOT.set(slot.try_into().unwrap_or(i64::MAX)),
+                Ok(Err(e)) => warn!("Failed to get current chain slot: {:?}", e),
+                Err(e) => error!("Chain slot poller task failed: {:?}", e),
+            }
+        }
+    });
+}
+
+/// Encode all registered metrics in the Prometheus text format, returning the
+/// encoded metrics and their content type
+pub(crate) fn encode() -> Result<(Vec<u8>, String)> {
+    let encoder = TextEncoder::new();
+    let mut buf = vec![];
+
+    encoder
+        .encode(&prometheus::gather(), &mut buf)
+        .context("Failed to encode metrics")?;
+
+    Ok((buf, encoder.format_type().to_owned()))
+}
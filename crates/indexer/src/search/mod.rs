@@ -55,11 +55,11 @@ impl From<search_indexer::Document> for Document {
 ///
 /// # Errors
 /// This function fails if an error occurs processing the message body.
-pub async fn process_message(msg: Message, client: &Client) -> MessageResult<MessageId> {
+pub async fn process_message(msg: &Message, client: &Client) -> MessageResult<MessageId> {
     match msg {
         Message::Upsert { index, document } => {
             client
-                .upsert_documents(index, Some(document.into()))
+                .upsert_documents(index.clone(), Some(document.clone().into()))
                 .await
                 .map_err(|e| MessageError::new(e, MessageId::Upsert))?;
 
@@ -67,14 +67,14 @@ pub async fn process_message(msg: Message, client: &Client) -> MessageResult<Mes
         },
         Message::IndirectMetadata { index, mint } => {
             let mint_address = mint.to_string();
-            let msg_id = MessageId::IndirectMetadata(mint);
+            let msg_id = MessageId::IndirectMetadata(*mint);
             let doc = get_indirect_metadata(client, mint_address.clone())
                 .await
                 .map_err(|e| MessageError::new(e, msg_id))?;
 
             client
                 .upsert_documents(
-                    index,
+                    index.clone(),
                     Some(Document {
                         id: mint_address,
                         body: serde_json::to_value(doc)
@@ -0,0 +1,62 @@
+//! HTTP server exposing metrics and health checks for indexer consumers
+
+use std::{convert::Infallible, net::SocketAddr};
+
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use indexer_core::serde_json::Value;
+
+use crate::{health, metrics, prelude::*};
+
+fn check_response((ok, report): (bool, Value)) -> hyper::http::Result<Response<Body>> {
+    Response::builder()
+        .status(if ok {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        })
+        .header(CONTENT_TYPE, "application/json")
+        .body(report.to_string().into())
+}
+
+async fn handle(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let res = match (req.method(), req.uri().path()) {
+        (&Method::GET, "/metrics") => match metrics::encode() {
+            Ok((buf, content_type)) => Response::builder()
+                .header(CONTENT_TYPE, content_type)
+                .body(buf.into()),
+            Err(e) => {
+                error!("{:?}", e);
+                Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body(Body::empty())
+            },
+        },
+        (&Method::GET, "/healthz") => check_response(health::liveness()),
+        (&Method::GET, "/readyz") => check_response(health::readiness().await),
+        _ => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::empty()),
+    };
+
+    Ok(res.unwrap_or_else(|e| {
+        error!("Failed to build response: {:?}", e);
+        Response::new(Body::empty())
+    }))
+}
+
+/// Serve Prometheus metrics and health checks over HTTP on the given address
+pub(crate) async fn serve(addr: SocketAddr) -> Result<()> {
+    let server = Server::try_bind(&addr)
+        .with_context(|| format!("Failed to bind status server to {addr}"))?
+        .serve(make_service_fn(|_| async {
+            Ok::<_, Infallible>(service_fn(handle))
+        }));
+
+    info!("Serving metrics and health checks on {}", addr);
+
+    server.await.context("Status server failed")
+}